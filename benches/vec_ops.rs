@@ -0,0 +1,99 @@
+//!
+//! Benchmarks of the hot small-`N` vec operations against hand-written loops over arrays.
+//!
+//! Every operation is run over [`LEN`] vecs, and the vec version should stay within
+//! a few percent of the array one: the operators go through `apply_binary` and friends,
+//! which are expected to compile down to the same code on stable.
+//!
+
+#[macro_use]
+extern crate criterion;
+extern crate rokoko;
+
+use criterion::{Criterion, Throughput, black_box};
+use rokoko::prelude::*;
+
+/// Number of vecs an operation is run over
+const LEN: usize = 10_000;
+
+/// `LEN` arrays of distinct values starting from `seed`
+fn arrays <const N: usize> (seed: f32) -> Vec <[f32; N]> {
+    (0..LEN).map(|i| core::array::from_fn(|j| (i * N + j) as f32 * 0.001 + seed)).collect()
+}
+
+macro_rules! bench {
+    ($( $n:literal $vec:ident ),*) => {$(
+        fn $vec(c: &mut Criterion) {
+            let (a, b) = (arrays::<$n>(1.0), arrays::<$n>(2.0));
+            let va: Vec <$vec> = a.iter().map(|&x| $vec::from(x)).collect();
+            let vb: Vec <$vec> = b.iter().map(|&x| $vec::from(x)).collect();
+            let mut out = vec![[0.0; $n]; LEN];
+            let mut vout = vec![$vec::single(0.0); LEN];
+
+            let mut group = c.benchmark_group(stringify!($vec));
+            group.throughput(Throughput::Elements(LEN as u64));
+
+            group.bench_function("add/array", |bencher| bencher.iter(|| {
+                for ((o, x), y) in out.iter_mut().zip(&a).zip(&b) {
+                    for j in 0..$n {
+                        o[j] = x[j] + y[j]
+                    }
+                }
+                black_box(&mut out);
+            }));
+            group.bench_function("add/vec", |bencher| bencher.iter(|| {
+                for ((o, x), y) in vout.iter_mut().zip(&va).zip(&vb) {
+                    *o = *x + *y
+                }
+                black_box(&mut vout);
+            }));
+
+            group.bench_function("mul/array", |bencher| bencher.iter(|| {
+                for ((o, x), y) in out.iter_mut().zip(&a).zip(&b) {
+                    for j in 0..$n {
+                        o[j] = x[j] * y[j]
+                    }
+                }
+                black_box(&mut out);
+            }));
+            group.bench_function("mul/vec", |bencher| bencher.iter(|| {
+                for ((o, x), y) in vout.iter_mut().zip(&va).zip(&vb) {
+                    *o = *x * *y
+                }
+                black_box(&mut vout);
+            }));
+
+            group.bench_function("dot/array", |bencher| bencher.iter(|| {
+                let mut sum = 0.0;
+                for (x, y) in a.iter().zip(&b) {
+                    for j in 0..$n {
+                        sum += x[j] * y[j]
+                    }
+                }
+                black_box(sum)
+            }));
+            group.bench_function("dot/vec", |bencher| bencher.iter(|| {
+                let mut sum = 0.0;
+                for (x, y) in va.iter().zip(&vb) {
+                    sum += x.dot(*y)
+                }
+                black_box(sum)
+            }));
+
+            // `black_box` keeps the comparison from being folded to `true`
+            group.bench_function("eq/array", |bencher| bencher.iter(|| {
+                a.iter().filter(|&&x| x == black_box(x)).count()
+            }));
+            group.bench_function("eq/vec", |bencher| bencher.iter(|| {
+                va.iter().filter(|&&x| x == black_box(x)).count()
+            }));
+
+            group.finish()
+        }
+    )*};
+}
+
+bench!(3 vec3, 4 vec4);
+
+criterion_group!(vec_ops, vec3, vec4);
+criterion_main!(vec_ops);
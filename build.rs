@@ -6,6 +6,9 @@
 //! - provides `cfg(std)` to determine if
 //! a `#![no_std]` can be used
 //!
+//! - provides `cfg(reusable_event_loop)` on the platforms
+//! where `winit` can run the event loop with `run_return`
+//!
 
 extern crate rustc_version;
 use rustc_version::{version_meta, Channel};
@@ -24,14 +27,28 @@ fn exists(var: &str) -> bool {
 }
 
 fn main() {
-    if let Channel::Nightly = version_meta().unwrap().channel {
+    let nightly = matches!(version_meta().unwrap().channel, Channel::Nightly);
+    if nightly {
         println!("cargo:rustc-cfg=nightly")
     }
 
+    // `lib.rs` fails with a `compile_error!` as well, but warnings
+    // are shown even if some other crate fails first
+    if exists("CARGO_FEATURE_WINDOW") && !nightly {
+        println!("cargo:warning=the `window` feature of `rokoko` requires nightly Rust, \
+            install it with `rustup toolchain install nightly` and build with `cargo +nightly build`")
+    }
+
     if exists("CARGO_FEATURE_WINDOW") {
         println!("cargo:rustc-cfg=std")
     }
 
+    // The target, not the host the script runs on
+    let os = std::env::var("CARGO_CFG_TARGET_OS").unwrap_or_default();
+    if matches!(os.as_str(), "windows" | "macos" | "linux" | "android" | "dragonfly" | "freebsd" | "netbsd" | "openbsd") {
+        println!("cargo:rustc-cfg=reusable_event_loop")
+    }
+
     // if is_vulkan_supported() {
     //     println!("cargo:rustc-cfg=vulkan")
     // }
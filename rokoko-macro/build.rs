@@ -0,0 +1,16 @@
+//!
+//! This build script detects if a `nightly` toolchain is used
+//! and provides `cfg(nightly)` to detect it.
+//!
+//! It is the same check as in `rokoko`'s build script: macros
+//! must agree with the crate they expand in.
+//!
+
+extern crate rustc_version;
+use rustc_version::{version_meta, Channel};
+
+fn main() {
+    if let Channel::Nightly = version_meta().unwrap().channel {
+        println!("cargo:rustc-cfg=nightly")
+    }
+}
@@ -3,6 +3,8 @@
 //! lib- and user-spaces.
 //!
 
+#![deny(unsafe_op_in_unsafe_fn, clippy::undocumented_unsafe_blocks)]
+
 use proc_macro::*;
 
 pub(crate) mod tools;
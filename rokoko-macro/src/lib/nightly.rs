@@ -74,7 +74,7 @@ pub fn nightly(args: TokenStream, input: TokenStream) -> TokenStream {
     ///     #[nightly(const(T: ATrait, U: SomeOtherTrait + OneAnother))]
     ///  ```
     ///
-    #[cfg(feature = "nightly")]
+    #[cfg(nightly)]
     fn r#const(args: &str, input: TokenStream) -> TokenStream {
         use syn::__private::ToTokens;
 
@@ -233,13 +233,17 @@ pub fn nightly(args: TokenStream, input: TokenStream) -> TokenStream {
 
             let item_reqs = get_trait_reqs(&input[precalc_generics.open..precalc_generics.close], true);
 
+            // `args` keep spacing as written, while `input` is printed token by token,
+            // e.g. `Mul <Output = T>` and `Mul < Output = T >`
+            let same = |a: &str, b: &str| a.replace(char::is_whitespace, "") == b.replace(char::is_whitespace, "");
+
             let mut generics = String::new();
             for Requirement { generic, mut traits } in item_reqs {
                 for t in &mut traits {
                     if const_reqs
                         .iter()
                         .find(|req| req.generic == generic)
-                        .map(|req| req.traits.contains(t))
+                        .map(|req| req.traits.iter().any(|r| same(r, t)))
                         .unwrap_or_default() {
                         *t = format!("~const {t}")
                     }
@@ -272,8 +276,16 @@ pub fn nightly(args: TokenStream, input: TokenStream) -> TokenStream {
                         code = code[..t].to_string() + " const" + &code[t..];
                         generics
                     } else {
-                        // No trait
-                        Generics::find(code[code.find("impl").unwrap() + 4..].trim()).unwrap_or_default()
+                        // No trait, generics are right after `impl` if any, otherwise `<` belongs to the type.
+                        // Offsets are of the whole `code`, as `parse_and_add_requirements` expects
+                        let after_impl = code.find("impl").unwrap() + 4;
+                        match Generics::find(&code[after_impl..]) {
+                            Some(Generics { open, close }) if code[after_impl..].trim_start().starts_with('<') => Generics {
+                                open: after_impl + open,
+                                close: after_impl + close
+                            },
+                            _ => Generics::default()
+                        }
                     };
                     parse_and_add_requirements(code, generics)
                 },
@@ -305,7 +317,7 @@ pub fn nightly(args: TokenStream, input: TokenStream) -> TokenStream {
     ///     #[nightly(const(T: ATrait, U: SomeOtherTrait + OneAnother))]
     ///  ```
     ///
-    #[cfg(not(feature = "nightly"))]
+    #[cfg(not(nightly))]
     fn r#const(_: &str, input: TokenStream) -> TokenStream {
         input
     }
@@ -315,7 +327,7 @@ pub fn nightly(args: TokenStream, input: TokenStream) -> TokenStream {
         // if `nightly` is not enabled, `const` will leave item as it is,
         // and `const_force` will remove it.
         "const_force": |args, input| {
-            if cfg!(feature = "nightly") {
+            if cfg!(nightly) {
                 r#const(args, input)
             } else {
                 TokenStream::new()
@@ -329,7 +341,7 @@ pub fn nightly(args: TokenStream, input: TokenStream) -> TokenStream {
     ///
     /// Removes `item` if `nightly` feature is not used.
     ///
-    #[cfg(feature = "nightly")]
+    #[cfg(nightly)]
     fn enable_if(item: TokenStream) -> TokenStream {
         item
     }
@@ -339,7 +351,7 @@ pub fn nightly(args: TokenStream, input: TokenStream) -> TokenStream {
     ///
     /// Removes `item` if `nightly` feature is not used.
     ///
-    #[cfg(not(feature = "nightly"))]
+    #[cfg(not(nightly))]
     fn enable_if(_: TokenStream) -> TokenStream {
         TokenStream::new()
     }
@@ -277,7 +277,7 @@ pub fn impl_not_tuple_and_piece_and_conversions_to_and_from_vec_for_tuples(input
         let i = i.to_string();
 
         // `nightly` => `NotTuple` + `Piece`
-        if cfg!(feature = "nightly") {
+        if cfg!(nightly) {
             result.push_str(&format!("
 impl <{tuple}> !NotTuple for ({tuple}) {{}}
 
@@ -285,7 +285,9 @@ impl <T: Copy, {piece_bounds}> const Piece <T> for ({tuple}) {{
     const N: usize = {piece_n_size};
 
     unsafe fn embed(self, mut place: *mut T) {{
-        {piece_embed_expr}
+        // SAFETY: the caller guarantees there are `Self::N` slots,
+        // and each element is embedded right after the previous ones
+        unsafe {{ {piece_embed_expr} }}
     }}
 }}
             "))
@@ -295,6 +297,7 @@ impl <T: Copy, {piece_bounds}> const Piece <T> for ({tuple}) {{
 #[nightly(const({from_t}))]
 impl <T: Copy, {from_t}> From <vec <T, {i}>> for ({tuple}) {{
     fn from(x: vec <T, {i}>) -> Self {{
+        // SAFETY: every index is less than {i}
         unsafe {{ ({from_conversions}) }}
     }}
 }}
@@ -320,3 +323,77 @@ impl <T: {from_tuple_bounds}, {tuple_copy}> From <({tuple})> for vec <T, {i}> {{
 
     result.parse().unwrap()
 }
+
+///
+/// This macro implements `GLSL`-like swizzles on `vec`s of up to as many elements as names given:
+///     - `x()`, `x_mut()`, etc. for every element;
+///     - `xy()`, `yx()`, `xxzw()`, etc. for every combination of 2 and more elements, repeated included.
+///
+/// Only the elements a `vec` has are used, e.g. there is no `z()` for `vec <T, 2>`.
+///
+/// # Usage
+///     impl_swizzles_for_vec!(<name of element 0> <name of element 1> ...);
+///
+/// # Examples
+/// ```rust,norun
+/// rokoko_macro::impl_swizzles_for_vec! {
+///     x y z w
+/// }
+/// ```
+///
+#[proc_macro]
+#[doc(hidden)]
+pub fn impl_swizzles_for_vec(input: TokenStream) -> TokenStream {
+    let input = input.to_string();
+    let names = input.split_whitespace().collect::<Vec <_>>();
+    let mut result = String::new();
+
+    for n in 1..=names.len() {
+        let mut methods = String::new();
+
+        for (i, name) in names[..n].iter().enumerate() {
+            methods.push_str(&format!("
+    /// Returns `self[{i}]`
+    #[nightly(const)]
+    #[inline]
+    pub fn {name}(&self) -> T {{
+        self.0[{i}]
+    }}
+
+    /// Returns a mutable reference to `self[{i}]`
+    #[nightly(const)]
+    #[inline]
+    pub fn {name}_mut(&mut self) -> &mut T {{
+        &mut self.0[{i}]
+    }}
+            "))
+        }
+
+        // Indices of a swizzle, as digits of a number in base `n`
+        for len in 2..=names.len() {
+            for number in 0..n.pow(len as u32) {
+                let indices = (0..len).rev().map(|digit| number / n.pow(digit as u32) % n).collect::<Vec <_>>();
+                let name = indices.iter().map(|&i| names[i]).collect::<String>();
+                let elements = indices.iter().map(|i| format!("self.0[{i}]")).collect::<Vec <_>>().join(", ");
+                let doc = indices.iter().map(|i| format!("self[{i}]")).collect::<Vec <_>>().join(", ");
+
+                methods.push_str(&format!("
+    /// Returns `[{doc}]`
+    #[nightly(const)]
+    #[inline]
+    pub fn {name}(&self) -> vec <T, {len}> {{
+        vec([{elements}])
+    }}
+                "))
+            }
+        }
+
+        result.push_str(&format!("
+impl <T: Copy> vec <T, {n}> {{
+    {methods}
+}}
+        "))
+    }
+
+    result.parse().unwrap()
+}
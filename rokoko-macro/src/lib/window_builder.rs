@@ -71,7 +71,9 @@ pub fn window_builder_data(input: TokenStream) -> TokenStream {
             ty
         } = field;
 
-        wb_statics::Data::add(ident.clone(), ty.is_none(), &mut attrs);
+        let stability = wb_statics::Stability::take(&mut attrs);
+        let setter_attrs = stability.setter_attrs();
+        let (validate_now, const_validate) = wb_statics::Data::add(ident.clone(), ty.as_ref().map(|t| t.to_token_stream().to_string()).unwrap_or_default(), stability, &mut attrs);
 
         let (inner, braced_lifetimes, lifetimes) = if ty.is_some() {
             let mut lifetimes = String::new();
@@ -131,14 +133,41 @@ impl <{lifetimes} C: ~const GetData <{data_ty} {braced_lifetimes}>> const {data_
 }}
         "));
 
+        if !validate_now.is_empty() {
+            result.push_str(&format!("
+impl <C: ~const core::marker::Destruct> WindowBuilder <C> {{
+    ///
+    /// ## Signature
+    /// `.try_{ident}(..) -> Result <WindowBuilder, InvalidValue>` -> the same as [`WindowBuilder::{ident}`],
+    /// but checks the value right away instead of in [`WindowBuilder::validate`] and [`WindowBuilder::create`].
+    ///
+    /// Can be used in `const` contexts, rejecting invalid values at compile time.
+    ///
+    {setter_attrs}
+    pub const fn try_{ident} <{lifetimes} T: ~const Into <{inner}>> (self, x: T)
+        -> Result <WindowBuilder <With <{data_ty} {braced_lifetimes}, C>>, InvalidValue> {{
+        let x = x.into();
+        match {validate_now}(&x) {{
+            Ok(()) => Ok(self.{ident}(x)),
+            Err(reason) => Err(InvalidValue {{
+                field: {ident:?},
+                reason
+            }})
+        }}
+    }}
+}}
+            "))
+        }
+
         result.push_str(&if ty.is_some() {
             format!("
 impl <C> WindowBuilder <C> {{
     {attrs}
+    {setter_attrs}
     pub const fn {ident} <{lifetimes} T: ~const Into <{inner}>> (self, x: T)
         -> WindowBuilder <With <{data_ty} {braced_lifetimes}, C>> {{
         WindowBuilder(With {{
-            data: {data_ty}(x.into()),
+            data: {data_ty}({const_validate}(x.into())),
             next: self.to_inner()
         }})
     }}
@@ -148,6 +177,7 @@ impl <C> WindowBuilder <C> {{
             format!("
 impl <C> WindowBuilder <C> {{
     {attrs}
+    {setter_attrs}
     pub const fn {ident}(self)
         -> WindowBuilder <With <{data_ty}, C>> {{
         WindowBuilder(With {{
@@ -252,18 +282,28 @@ pub fn window_builder_events(input: TokenStream) -> TokenStream {
             ret
         } = cb;
 
-        wb_statics::Callback::add(ident.clone(), args.iter().map(|p| p.name.clone()).collect::<Vec <_>>().join(","), &mut attrs);
+        let ret = match ret {
+            ReturnType::Default => String::from("()"),
+            ReturnType::Type(_, ty) => ty.to_token_stream().to_string()
+        };
+
+        let stability = wb_statics::Stability::take(&mut attrs);
+        let setter_attrs = stability.setter_attrs();
+        wb_statics::Callback::add(
+            ident.clone(),
+            args.iter().map(|p| p.name.clone()).collect::<Vec <_>>().join(","),
+            args.iter().map(|p| p.ty.to_token_stream().to_string()).collect(),
+            ret.clone(),
+            stability,
+            &mut attrs
+        );
 
         let cb_ty = tools::snake_to_upper_case(&ident);
 
         let cb_trait = cb_ty.clone() + "Trait";
 
         wb_statics::add_trait(cb_trait.clone());
-
-        let ret = match ret {
-            ReturnType::Default => String::from("()"),
-            ReturnType::Type(_, ty) => ty.to_token_stream().to_string()
-        };
+        wb_statics::add_trait(format!("Dispatch <{cb_ty}>"));
 
         let attrs = attrs
             .into_iter()
@@ -296,13 +336,25 @@ impl Callback for {cb_ty} {{
     type Args = ({args},);
 }}
 
+#[cfg(not(feature = \"dyn-callbacks\"))]
 impl <C> WindowBuilder <C> {{
     {attrs}
-    pub const fn {ident} <F: FnMut <<{cb_ty} as Callback>::Args, Output = <{cb_ty} as Callback>::Output>> (self, cb: F)
+    {setter_attrs}
+    pub const fn {ident} <F: FnMut({args}) -> {ret}> (self, cb: F)
         -> WindowBuilder <With <OnEventFnContainer <{cb_ty}, F>, C>> {{
         self.on_event::<{cb_ty}, F>(cb)
     }}
 }}
+
+// Boxed into the table instead, so that the type stays the same, see `dispatch`
+#[cfg(feature = \"dyn-callbacks\")]
+impl <C: FindDynCallbacks> WindowBuilder <C> {{
+    {attrs}
+    {setter_attrs}
+    pub fn {ident} <F: FnMut({args}) -> {ret} + 'static> (self, cb: F) -> Self {{
+        self.on_dyn_event::<{cb_ty}, F>(cb)
+    }}
+}}
         "))
     }
 
@@ -350,6 +402,24 @@ pub fn window_builder_create(_: TokenStream) -> TokenStream {
         met: u8
     }
 
+    ///
+    /// The lookup: an expression telling whether any callback of `upper` is registered in `data`,
+    /// a reference to the type list.
+    ///
+    /// Resolved in compile-time or through the table of `dyn-callbacks`, see `dispatch::Dispatch`.
+    ///
+    fn has_callback(upper: &str, data: &str) -> String {
+        format!("Dispatch::<{upper}>::has_callback({data})")
+    }
+
+    ///
+    /// The call: an expression calling all the callbacks of `upper` in `data`, the owned type list,
+    /// with `args`, evaluating to their chained results.
+    ///
+    fn call_callbacks(upper: &str, args: &str) -> String {
+        format!("Dispatch::<{upper}>::call_callbacks(&mut data, ({args},))")
+    }
+
     let lifetimes = wb_statics::lifetimes();
     let traits = wb_statics::traits();
 
@@ -358,9 +428,59 @@ pub fn window_builder_create(_: TokenStream) -> TokenStream {
     let mut conflicts_to_be_checked = Vec::new();
     let mut conflicts = String::new();
     let mut requirements = String::new();
+    let mut validations = String::new();
+    let mut validations_now = String::new();
+    let mut after_build = String::new();
+    let mut config = String::new();
+    let mut options = String::new();
+    let mut methods_check = String::new();
+    let mut trace_options = String::new();
 
     for (idx, one) in full.iter().enumerate() {
         let lower = &one.lower;
+        let upper = tools::snake_to_upper_case(lower);
+
+        // Metadata
+        let ty = one.ty.replace("& ", "&");
+        let (ty_desc, turbofish) = if one.short {
+            (String::from("None"), String::new())
+        } else {
+            (format!("Some({ty:?})"), format!("::<{ty}>"))
+        };
+        let default_desc = if one.default.is_empty() {
+            String::from("None")
+        } else {
+            format!("Some({:?})", one.default)
+        };
+        options.push_str(&format!("
+meta::OptionDesc {{
+    name: {lower:?},
+    ty: {ty_desc},
+    default: {default_desc},
+    conflicts: &{:?},
+    requires: &{:?},
+    validated: {},
+    {}
+    doc: {:?}
+}},
+        ", one.conflict, one.require, one.validated, one.stability.desc_fields(), one.doc));
+        methods_check.push_str(&format!("{} let _ = WindowBuilder::<Empty>::{lower}{turbofish};", one.stability.cfg()));
+        trace_options.push_str(&format!("({lower:?}, data.{lower}().is_some()),"));
+
+        // Configuration summary
+        config.push_str(&if one.short {
+            format!(r#"
+if data.{lower}().is_some() {{
+    config_data.push(String::from("{lower}"))
+}}
+            "#)
+        } else {
+            format!(r#"
+if let Some({upper}({lower})) = data.{lower}() {{
+    config_data.push(format!("{lower}={{}}", config_value({lower})))
+}}
+            "#)
+        });
 
         // Usage
         let usage = &one.usage;
@@ -369,7 +489,6 @@ pub fn window_builder_create(_: TokenStream) -> TokenStream {
             let (wrapper, deref) = if one.short {
                 (String::from("_"), String::new())
             } else {
-                let upper = tools::snake_to_upper_case(&*lower);
                 (format!("{upper}({lower})"), format!("let {lower} = *{lower};"))
             };
 
@@ -393,6 +512,46 @@ if let Some({wrapper}) = data.{lower}() {{
             "))
         }
 
+        // Validation
+        if !one.validate.is_empty() {
+            let validate = &one.validate;
+            validations.push_str(&format!("
+if let Some({upper}({lower})) = data.{lower}() {{
+    {validate}({lower})
+}}
+            "))
+        }
+
+        if !one.validate_now.is_empty() {
+            let validate_now = &one.validate_now;
+            validations_now.push_str(&format!("
+if let Some({upper}({lower})) = data.{lower}() {{
+    if let Err(reason) = {validate_now}({lower}) {{
+        return Err(InvalidValue {{
+            field: {lower:?},
+            reason
+        }})
+    }}
+}}
+            "))
+        }
+
+        // After build
+        if !one.after_build.is_empty() {
+            let code = &one.after_build;
+            let (wrapper, deref) = if one.short {
+                (String::from("_"), String::new())
+            } else {
+                (format!("{upper}({lower})"), format!("let {lower} = *{lower};"))
+            };
+            after_build.push_str(&format!("
+if let Some({wrapper}) = data.{lower}() {{
+    {deref}
+    {code}
+}}
+            "))
+        }
+
         // Requirements
         for require in &one.require {
             requirements.push_str(&format!(r#"assert!(data.{lower}().is_none() || data.{require}().is_some(), "{lower} requires {require}, which is not specified");"#));
@@ -427,17 +586,94 @@ if let Some({wrapper}) = data.{lower}() {{
     let mut events = String::new();
     let full = wb_statics::Callback::get();
     let mut unique_init = String::new();
+    let mut unique_drag_select = String::new();
+    let mut unique_action_map = String::new();
+    let mut gesture_arms = String::new();
+
+    let mut events_desc = String::new();
+    let mut trace_events = String::new();
 
     for one in &full {
         let lower = &one.lower;
         let args = &one.args;
 
+        // Metadata
+        let arg_types = one.arg_types.iter().map(|t| t.replace("& ", "&")).collect::<Vec <_>>();
+        let ret = &one.ret;
+        let some_or_none = |s: &str| if s.is_empty() {
+            String::from("None")
+        } else {
+            format!("Some({s:?})")
+        };
+        events_desc.push_str(&format!("
+meta::EventDesc {{
+    name: {lower:?},
+    args: &{arg_types:?},
+    ret: {ret:?},
+    on: {},
+    unique: {},
+    {}
+    doc: {:?}
+}},
+        ", some_or_none(&one.on), some_or_none(&one.unique), one.stability.desc_fields(), one.doc));
+        methods_check.push_str(&format!("{} let _ = <WindowBuilder>::{lower}::<fn({}) -> {ret}>;", one.stability.cfg(), arg_types.join(",")));
+
+        let upper = tools::snake_to_upper_case(lower);
+        let has = has_callback(&upper, "&data");
+        let call = call_callbacks(&upper, args);
+        trace_events.push_str(&format!("({lower:?}, {}),", has_callback(&upper, "data")));
+        config.push_str(&format!(r#"
+if {has} {{
+    config_callbacks.push("{lower}")
+}}
+        "#));
+
+        // Counted before the call, so that the callback already sees its own run
+        let count = format!(r#"
+if counting {{
+    window.data().counters.ran("{lower}")
+}}
+        "#);
+
         if one.unique == "init" {
             unique_init = format!("
-if let Some(cb) = data.{lower}() {{
-    cb({args})
+if {has} {{
+    {count}
+    {call}
 }}
             ")
+        } else if one.unique == "drag_select" {
+            unique_drag_select = format!("
+if let Some(drag) = &mut drag {{
+    if let Some((rect, phase)) = drag.update(event) {{
+        if {has} {{
+            {count}
+            {call}
+        }}
+    }}
+}}
+            ")
+        } else if one.unique == "action_map" {
+            // Collected first, so that callbacks may use the map themselves
+            unique_action_map = format!("
+if mapping_actions {{
+    let actions = window.data().actions.borrow_mut().update(event);
+    for (action, pressed) in actions {{
+        if {has} {{
+            {count}
+            {call}
+        }}
+    }}
+}}
+            ")
+        } else if one.unique == "gestures" {
+            // All the gesture callbacks share a single recognizer, matching on its result
+            gesture_arms.push_str(&format!("
+{} => if {has} {{
+    {count}
+    {call}
+}},
+            ", one.on))
         } else if !one.unique.is_empty() {
             panic!("unknown value for #[unique] = {}", one.unique)
         } else {
@@ -452,60 +688,466 @@ else {{
 }}
                 ")
             };
+            let bind = if one.bind.is_empty() {
+                String::new()
+            } else {
+                format!("{};", one.bind)
+            };
+            // Every registered callback is called, in the order of registration
+            let call_all = if one.then.is_empty() {
+                call
+            } else {
+                format!("
+let output = {call};
+{}
+                ", one.then)
+            };
             let call = format!("
-if let Some(cb) = data.{lower}() {{
-    cb({args})
+if {has} {{
+    {count}
+    {bind}
+    {call_all}
 }} {else_branch}
             ");
-            let branch = if on.find("UserEvent :: Close").is_some() {
-                format!("{{
+            let (branch, guard) = if on.find("UserEvent :: CloseScheduled").is_some() {
+                // Sent to every window run by the loop as well, so only the one that scheduled announces it.
+                // The borrow ends before the call, since the callback may schedule or cancel again
+                (format!("{{
+let remaining = window.data().close_schedule.borrow_mut().announce(std::time::Instant::now());
+if let Some(remaining) = remaining {{
+    {call}
+}}
+                }}"), String::new())
+            } else if on.find("UserEvent :: Close").is_some() {
+                // Sent to every window run by the loop, see `Windows`
+                (format!("{{
 {call}
+event_loop::end_generation(generation);
 *cf = ControlFlow::Exit
-                }}")
+                }}"), String::from("if window.data().close_requested.get()"))
+            } else if on.find("MainEventsCleared").is_some() {
+                (format!("{{
+if let Some(dt) = frame_loop.begin() {{
+    {call}
+}}
+*cf = window.data().close_schedule.borrow().control_flow(
+    frame_loop.control_flow(window.data().tracked.visible.get(), window.data().tracked.focused.get())
+)
+                }}"), String::new())
+            } else if one.default.is_empty() {
+                // Nothing to do for an absent callback, so without `dyn-callbacks` the arm is statically dead
+                (call, format!("if {has}"))
             } else {
-                call
+                (call, String::new())
             };
             events.push_str(&format!("
-{on} => {branch},
+{on} {guard} => {branch},
             "))
         }
     }
 
+    let unique_gestures = if gesture_arms.is_empty() {
+        String::new()
+    } else {
+        format!("
+if let Some(gestures) = &mut gestures {{
+    if let Some(gesture) = gestures.update(event, gestures_start.elapsed()) {{
+        match gesture {{
+            {gesture_arms}
+        }}
+    }}
+}}
+        ")
+    };
+
+    let frame_callback = has_callback("OnFrame", "&data");
+
     let k =format!("
-impl <{lifetimes} C: 'static + {traits}> WindowBuilder <C> {{
-    pub fn create(self) -> Result <(), winit::error::OsError> {{
-        let Self(mut data) = self;
+///
+/// Description of all the data, see [`meta`].
+///
+#[doc(hidden)]
+pub const OPTIONS: &[meta::OptionDesc] = &[{options}];
 
-        let mut builder = winit::window::WindowBuilder::new();
+///
+/// Description of all the events, see [`meta`].
+///
+#[doc(hidden)]
+pub const EVENTS: &[meta::EventDesc] = &[{events_desc}];
 
-        {data}
+// Checks that every description has a corresponding method
+const _: () = {{
+    #[allow(dead_code, deprecated)]
+    fn check() {{
+        {methods_check}
+    }}
+}};
 
+impl <{lifetimes} C: {traits} TakeFn <WinitOverrides>> WindowBuilder <C> {{
+    ///
+    /// Applies the data onto `builder`, then [`WindowBuilder::winit_overrides`], if any.
+    ///
+    /// Panics if the data is invalid.
+    ///
+    fn configure(data: &mut C, mut builder: winit::window::WindowBuilder) -> winit::window::WindowBuilder {{
         {requirements}
 
-        let event_loop = EventLoop::with_user_event();
+        {conflicts}
 
-        let winit_window = builder.build(&event_loop)?;
+        {validations}
 
-        let mut window_data = WindowData {{
-            proxy: event_loop.create_proxy(),
-            winit: WinitRef::new(&winit_window)
+        if let Err(invalid) = Self::validate_data(data) {{
+            panic!(\"{{invalid}}\")
+        }}
+
+        // Only once checked, as converting may rely on the checks, e.g. `window_icon`
+        {data}
+
+        if let Some(overrides) = TakeFn::<WinitOverrides>::take(data) {{
+            builder = overrides(builder)
+        }}
+
+        builder
+    }}
+
+    /// Checks the values that [`WindowBuilder::validate`] checks
+    fn validate_data(data: &C) -> Result <(), InvalidValue> {{
+        {validations_now}
+        Ok(())
+    }}
+
+    ///
+    /// ## Signature
+    /// `.validate() -> Result <(), InvalidValue>` -> checks the values given to the setters
+    /// that have a `try_` variant, e.g. [`WindowBuilder::size`], returning the first invalid one.
+    ///
+    /// [`WindowBuilder::create`] panics with the very same error.
+    ///
+    /// ## Example
+    /// ```
+    /// # use rokoko::window::Window;
+    /// assert!(Window::new().size((800., 600.)).validate().is_ok());
+    ///
+    /// let invalid = Window::new().size((f32::NAN, 600.)).validate().unwrap_err();
+    /// assert_eq!(invalid.field, \"size\");
+    /// ```
+    ///
+    pub fn validate(&self) -> Result <(), InvalidValue> {{
+        let Self(data) = self;
+        Self::validate_data(data)
+    }}
+
+    ///
+    /// ## Signature
+    /// `.apply_to_winit(winit::window::WindowBuilder) -> winit::window::WindowBuilder` ->
+    /// applies the configuration onto an existing `winit` builder instead of creating the window.
+    ///
+    /// Useful for adopting `rokoko` gradually in code that already uses `winit`.
+    ///
+    /// ## Note
+    /// The order is the given builder, then the data(defaults included), then
+    /// [`WindowBuilder::winit_overrides`]; the latter ones win.
+    ///
+    /// Data applied after the window is built, e.g. [`WindowBuilder::auto_size`],
+    /// and all the callbacks are ignored.
+    ///
+    /// ## Note
+    /// Panics if the data is invalid, just as [`WindowBuilder::create`] does.
+    ///
+    /// ## Note
+    /// Requires `winit-interop` feature.
+    ///
+    /// ## Example
+    /// ```
+    /// # use rokoko::window::Window;
+    /// let builder = Window::new()
+    ///     .title(\"Adopted\")
+    ///     .apply_to_winit(winit::window::WindowBuilder::new().with_resizable(false));
+    ///
+    /// assert_eq!(builder.window.title, \"Adopted\");
+    /// assert!(!builder.window.resizable);
+    /// ```
+    ///
+    #[cfg(feature = \"winit-interop\")]
+    pub fn apply_to_winit(self, builder: winit::window::WindowBuilder) -> winit::window::WindowBuilder {{
+        let Self(mut data) = self;
+        Self::configure(&mut data, builder)
+    }}
+
+    ///
+    /// ## Signature
+    /// `.config_trace() -> meta::ConfigTrace` -> returns which options and events are
+    /// statically present in this builder, see [`meta::ConfigTrace`].
+    ///
+    /// [`WindowBuilder::create`] prints it to `stderr` on its own and
+    /// keeps it for [`doctor`](crate::window::doctor::doctor).
+    ///
+    /// ## Note
+    /// Requires `config-trace` feature.
+    ///
+    /// ## Example
+    /// ```
+    /// # use rokoko::window::Window;
+    /// let builder = Window::new()
+    ///     .title(\"Traced\")
+    ///     .on_close(Window::close);
+    ///
+    /// let trace = builder.config_trace();
+    /// assert_eq!(trace.is_present(\"title\"), Some(true));
+    /// assert_eq!(trace.is_present(\"on_close\"), Some(true));
+    /// assert_eq!(trace.is_present(\"on_init\"), Some(false));
+    /// assert!(trace.to_string().contains(\"\\non_init: STATICALLY ABSENT\\n\"));
+    /// ```
+    ///
+    #[cfg(feature = \"config-trace\")]
+    pub fn config_trace(&self) -> meta::ConfigTrace {{
+        let Self(data) = self;
+        meta::ConfigTrace {{
+            options: vec![{trace_options}],
+            events: vec![{trace_events}]
+        }}
+    }}
+}}
+
+impl <{lifetimes} C: 'static + {traits} TakeFn <WinitOverrides> + CollectUserData + TakeLoader> WindowBuilder <C> {{
+    ///
+    /// ## Signature
+    /// `.create() -> Result <(), event_loop::Error>` -> builds the window and runs the event loop until it is closed.
+    ///
+    /// ## Note
+    /// Where `winit` supports `run_return`(Windows, macOS, Linux, BSDs and Android) the loop is run with it,
+    /// so this returns `Ok(())` once the window is closed and [`WindowBuilder::on_exit`] is called,
+    /// and keeps the loop for the next window. Elsewhere(iOS and the web) the loop cannot give control back,
+    /// so this never returns. See [`event_loop::REUSABLE`] to tell them apart,
+    /// or [`WindowBuilder::create_returning`] to rely on returning.
+    ///
+    /// ## Panics
+    /// If conflicting data is specified, e.g. both [`WindowBuilder::size`] and [`WindowBuilder::maximized`],
+    /// if some data requires other data that is not specified, or if the data is invalid, see [`WindowBuilder::validate`].
+    ///
+    pub fn create(self) -> Result <(), event_loop::Error> {{
+        let event_loop = event_loop::acquire()?;
+        let mut handler = self.open(&event_loop)?;
+
+        // The handler is dropped once the loop is done, invalidating handles to the window
+        event_loop::run(event_loop, move |event, _, cf| {{
+            handler.handle(event);
+            *cf = handler.control_flow()
+        }})
+    }}
+
+    ///
+    /// Builds the window on `event_loop`, calls [`WindowBuilder::on_init`], and returns
+    /// the handler of the events of the window, which owns it from then on.
+    ///
+    /// Shared by [`WindowBuilder::create`] and [`Windows::create`](crate::window::Windows::create).
+    ///
+    pub(crate) fn open(self, event_loop: &EventLoop <UserEvent>) -> Result <Handler, event_loop::Error> {{
+        #[cfg(feature = \"config-trace\")]
+        meta::record_trace(self.config_trace());
+
+        let Self(mut data) = self;
+
+        let mut config_data = Vec::<String>::new();
+        let mut config_callbacks = Vec::<&str>::new();
+        {config}
+        let config = format!(\"{{}} callbacks=[{{}}]\", config_data.join(\" \"), config_callbacks.join(\",\"));
+
+        let panic_context = if data.no_panic_context().is_none() {{
+            Some(PanicContext::install(config.clone()))
+        }} else {{
+            None
         }};
 
-        let window = Window::from(&mut window_data);
+        let builder = Self::configure(&mut data, winit::window::WindowBuilder::new());
+
+        // Boxed, so that `WinitRef` stays valid once moved into the handler
+        let winit_window = Box::new(builder.build(event_loop)?);
 
-        {unique_init}
+        {after_build}
+
+        let counting = data.count_events().is_some();
+        let mapping_actions = data.action_map().is_some();
+
+        let mut user_data = UserDataStore::default();
+        data.collect(&mut user_data);
+        let mut loading = data.take_loader(&mut user_data, std::time::Instant::now());
+
+        let mut window_data = Box::new(WindowData {{
+            proxy: event_loop.create_proxy(),
+            winit: WinitRef::new(&winit_window),
+            counters: if counting {{
+                EventCounters::new(&config_callbacks)
+            }} else {{
+                EventCounters::default()
+            }},
+            actions: RefCell::new(data.action_map().map_or_else(Default::default, |ActionMap(map)| map.clone())),
+            fullscreen: RefCell::new(data.fullscreen_management().map(|_| FullscreenManager::new(
+                fullscreen::DEFER_UNTIL_FOCUS,
+                data.minimize_on_focus_loss().is_some()
+            ))),
+            config,
+            tracked: Tracked::default(),
+            generation: event_loop::begin_generation(),
+            close_requested: Cell::new(false),
+            close_schedule: RefCell::new(CloseSchedule::new()),
+            user_data
+        }});
+        let generation = window_data.generation;
+
+        let window = Window::from(&mut *window_data);
+
+        // Without management it is already fullscreen, see `WindowBuilder::fullscreen`
+        if data.fullscreen().is_some() && data.fullscreen_management().is_some() {{
+            window.set_fullscreen(true)
+        }}
+
+        // Otherwise called once loaded
+        if loading.is_none() {{
+            {unique_init}
+        }}
+
+        let mut frame_loop = FrameLoop::new(
+            {frame_callback},
+            data.target_fps().map(|TargetFps(fps)| *fps),
+            data.power_mode().map_or_else(Default::default, |PowerMode(mode)| *mode)
+        );
+
+        let mut drag = data.drag_select().map(|_| drag_select::DragSelect::new(
+            data.drag_select_threshold().map_or(drag_select::DEFAULT_THRESHOLD, |DragSelectThreshold(threshold)| *threshold)
+        ));
+
+        let mut gestures = data.gestures().map(|_| gestures::GestureRecognizer::new(
+            data.double_tap_interval().map_or(gestures::DEFAULT_DOUBLE_TAP_INTERVAL, |DoubleTapInterval(interval)| *interval)
+        ));
+        let gestures_start = std::time::Instant::now();
+
+        Ok(Handler::new(winit_window.id(), generation, move |event, cf| {{
+            // Moves the context and the window into the handler so that they live as long as it does
+            let _ = (&panic_context, &winit_window, &window_data);
 
-        event_loop.run(move |event, _, cf| {{
             if *cf == ControlFlow::Exit {{
                 return
             }}
-            *cf = ControlFlow::Wait;
+            *cf = window.data().close_schedule.borrow().control_flow(
+                frame_loop.control_flow(window.data().tracked.visible.get(), window.data().tracked.focused.get())
+            );
+
+            // Scheduled by `Window::close_after`, the loop wakes up by the deadline to close
+            let due = window.data().close_schedule.borrow_mut().fire(std::time::Instant::now());
+            if due {{
+                window.close()
+            }}
+
+            // Closed before the loop started(e.g. by `on_init`), when the proxy
+            // may not have delivered the request
+            let event = match event {{
+                Event::NewEvents(StartCause::Init) if window.data().close_requested.get() => Event::UserEvent(UserEvent::Close),
+                event => event
+            }};
+
+            if counting {{
+                window.data().counters.count(&event)
+            }}
+
+            // Tracked state is updated before any callback is called
+            if let Event::WindowEvent {{ event, .. }} = &event {{
+                window.data().tracked.update(event);
+
+                // Released before the command is applied, as applying it may dispatch events
+                let command = window.data().fullscreen.borrow_mut().as_mut().and_then(|manager| match event {{
+                    WindowEvent::Focused(focused) => manager.focused(*focused),
+                    WindowEvent::Resized(_) => manager.observed(window.is_fullscreen()),
+                    _ => None
+                }});
+                if let Some(command) = command {{
+                    command.apply(window.data().winit.get())
+                }}
+            }}
+
+            // Until loaded, only the loader is called, see `WindowBuilder::loading_phase`
+            if let Some(phase) = loading.as_mut().filter(|phase| !phase.is_done()) {{
+                if window.data().close_requested.get() || matches!(event, Event::WindowEvent {{ event: WindowEvent::CloseRequested, .. }}) {{
+                    phase.abort()
+                }}
+                match &event {{
+                    Event::MainEventsCleared => {{
+                        // Keeps iterating without waiting for events
+                        *cf = ControlFlow::Poll;
+                        match phase.step(std::time::Instant::now(), &window.data().user_data) {{
+                            Some(Step::Loaded) => {{
+                                {unique_init}
+                            }},
+                            Some(Step::Aborted) => window.close(),
+                            _ => ()
+                        }}
+                        return
+                    }},
+                    // Lets the close end the loop, calling `on_exit`
+                    Event::UserEvent(UserEvent::Close) => (),
+                    _ => return
+                }}
+            }}
+
+            if let Event::WindowEvent {{ event, .. }} = &event {{
+                {unique_drag_select}
+                {unique_gestures}
+                {unique_action_map}
+            }}
 
             match event {{
                 {events}
                 _ => ()
             }}
-        }})
+        }}))
+    }}
+
+    ///
+    /// ## Signature
+    /// `.create_returning() -> Result <(), event_loop::Error>` -> same as [`WindowBuilder::create`],
+    /// but only available where it is guaranteed to return once the window is closed
+    /// and [`WindowBuilder::on_exit`] is called, see [`event_loop::REUSABLE`].
+    ///
+    /// ## Note
+    /// Only available where `winit` supports `run_return`: Windows, macOS, Linux, BSDs and Android.
+    /// On iOS and the web the loop cannot give control back, so only [`WindowBuilder::create`] is available there.
+    ///
+    /// The loop is kept for the next window, so code after it can still open more windows.
+    ///
+    /// ## Example
+    /// ```no_run
+    /// # use rokoko::window::Window;
+    /// use std::process::ExitCode;
+    ///
+    /// fn main() -> ExitCode {{
+    ///     let result = Window::new()
+    ///         .title(\"Returning\")
+    ///         .on_init(Window::close)
+    ///         .create_returning();
+    ///
+    ///     // Reachable: cleanup and the exit status are up to the caller
+    ///     match result {{
+    ///         Ok(()) => ExitCode::SUCCESS,
+    ///         Err(e) => {{
+    ///             eprintln!(\"{{e}}\");
+    ///             ExitCode::FAILURE
+    ///         }}
+    ///     }}
+    /// }}
+    /// ```
+    ///
+    #[cfg(reusable_event_loop)]
+    pub fn create_returning(self) -> Result <(), event_loop::Error> {{
+        self.create()
+    }}
+}}
+
+impl <{lifetimes} C: 'static + {traits} TakeFn <WinitOverrides> + CollectUserData + TakeLoader, N: OpenAll> OpenAll for With <WindowBuilder <C>, N> {{
+    fn open_all(self, event_loop: &EventLoop <UserEvent>, handlers: &mut Vec <Handler>) -> Result <(), event_loop::Error> {{
+        // Earlier builders are deeper in the list, so are opened first
+        self.next.open_all(event_loop, handlers)?;
+        handlers.push(self.data.open(event_loop)?);
+        Ok(())
     }}
 }}
     ");println!("{k}");
@@ -5,15 +5,120 @@
 
 use core::mem::take;
 use syn::{
-    Attribute,
+    Attribute, Meta, NestedMeta, Lit,
     __private::ToTokens
 };
 
+///
+/// Stability markers of an option or event:
+/// - `#[unstable(feature = "name")]` makes the setter available only with `unstable-name` feature;
+/// - `#[deprecated_option(since = "version", note = "text")]` makes the setter `#[deprecated]`.
+///
+/// Only setters are affected: the data types, getters and the rest of the generated code
+/// stay as is, so that `create` handles configurations made through them as before.
+///
+#[derive(Clone, Default)]
+pub struct Stability {
+    /// The feature enabling the setter, e.g. `unstable-name`, empty string if stable
+    pub feature: String,
+
+    /// `since` and `note` of `#[deprecated_option]`, if deprecated
+    pub deprecated: Option <(String, String)>
+}
+
+impl Stability {
+    /// Removes the markers from `attrs`, returning them
+    pub fn take(attrs: &mut Vec <Attribute>) -> Self {
+        let mut result = Self::default();
+
+        attrs.retain(|attr| {
+            let path = attr.path.to_token_stream().to_string();
+            if path != "unstable" && path != "deprecated_option" {
+                return true
+            }
+
+            let pairs = match attr.parse_meta() {
+                Ok(Meta::List(list)) => list.nested.into_iter().map(|nested| match nested {
+                    NestedMeta::Meta(Meta::NameValue(nv)) => match nv.lit {
+                        Lit::Str(s) => (nv.path.to_token_stream().to_string(), s.value()),
+                        _ => panic!("expected a string in #[{path}]")
+                    },
+                    _ => panic!("expected `key = \"value\"` in #[{path}]")
+                }).collect::<Vec <_>>(),
+                _ => panic!("expected #[{path}(...)]")
+            };
+            let get = |key: &str| pairs.iter().find(|(k, _)| k == key).map(|(_, v)| v.clone());
+
+            if path == "unstable" {
+                assert!(result.feature.is_empty(), "cannot have multiple #[unstable]s");
+                result.feature = format!("unstable-{}", get("feature").expect("expected #[unstable(feature = \"name\")]"))
+            } else {
+                assert!(result.deprecated.is_none(), "cannot have multiple #[deprecated_option]s");
+                result.deprecated = Some((
+                    get("since").expect("expected `since` in #[deprecated_option]"),
+                    get("note").expect("expected `note` in #[deprecated_option]")
+                ))
+            }
+            false
+        });
+
+        result
+    }
+
+    /// `#[cfg]` that removes an item along with the setter, empty string if stable
+    pub fn cfg(&self) -> String {
+        if self.feature.is_empty() {
+            String::new()
+        } else {
+            format!("#[cfg(feature = {:?})]", self.feature)
+        }
+    }
+
+    /// Attributes, documentation included, to be put on the setter
+    pub fn setter_attrs(&self) -> String {
+        let mut result = self.cfg();
+        if !self.feature.is_empty() {
+            result.push_str(&format!(
+                "#[doc = \" ## Unstable\"] #[doc = \" Requires `{}` feature, may change or be removed in any release.\"] #[doc = \"\"]",
+                self.feature
+            ))
+        }
+        if let Some((since, note)) = &self.deprecated {
+            result.push_str(&format!("#[deprecated(since = {since:?}, note = {note:?})]"))
+        }
+        result
+    }
+
+    /// `unstable` and `deprecated` fields of descriptions in `meta`
+    pub fn desc_fields(&self) -> String {
+        let unstable = if self.feature.is_empty() {
+            String::from("None")
+        } else {
+            format!("Some({:?})", self.feature)
+        };
+        let deprecated = match &self.deprecated {
+            Some((_, note)) => format!("Some({note:?})"),
+            None => String::from("None")
+        };
+        format!("unstable: {unstable}, deprecated: {deprecated},")
+    }
+}
+
 /// A data to use in process of creation `create`
 pub struct Data {
     /// The lowercase name of data, e.g. `title`
     pub lower: String,
 
+    ///
+    /// The type of data as written, e.g. `& str`.
+    ///
+    /// Empty string if data does not contain anything
+    ///
+    pub ty: String,
+
+    /// See [`doc_summary`]
+    pub doc: String,
+
     ///
     /// The default value for data of corresponding type,
     /// e.g. `"rokoko window"`.
@@ -41,16 +146,65 @@ pub struct Data {
     ///
     pub usage: String,
 
+    ///
+    /// A function that checks the inner value in `create`
+    /// before the window is built, panicking if it is invalid.
+    ///
+    /// Empty string if no validation is needed
+    ///
+    pub validate: String,
+
+    ///
+    /// A `const` function that checks the inner value, returning
+    /// `Result <(), &'static str>` with the reason it is invalid.
+    ///
+    /// It is called by the `try_` setter right away, and by `validate` and `create` otherwise.
+    ///
+    /// Empty string if no such check is needed
+    ///
+    pub validate_now: String,
+
+    ///
+    /// Code to run in `create` after the window is built,
+    /// e.g. for data that cannot be applied to a `winit` builder.
+    ///
+    /// The built window is available as `winit_window`.
+    ///
+    /// Empty string if nothing is needed
+    ///
+    pub after_build: String,
+
+    /// `true` if the inner value is checked, i.e. there is `#[validate]`, `#[validate_now]` or `#[const_validate]`
+    pub validated: bool,
+
     /// `true` if data does not contain anything
-    pub short: bool
+    pub short: bool,
+
+    /// See [`Stability`]
+    pub stability: Stability
 }
 
 impl Data {
-    pub fn add(lower: String, short: bool, attrs: &mut Vec <Attribute>) {
+    ///
+    /// Collects the data, removing its attributes from `attrs`.
+    ///
+    /// Returns [`Data::validate_now`] and the function given to `#[const_validate]`, as the setters are generated right away.
+    ///
+    /// The latter takes the inner value given to the setter and returns it back, failing the build
+    /// if the setter is evaluated at compile time with an invalid value; empty string if there is none.
+    ///
+    pub fn add(lower: String, ty: String, stability: Stability, attrs: &mut Vec <Attribute>) -> (String, String) {
+        let short = ty.is_empty();
+        let doc = doc_summary(attrs);
         let mut default = String::new();
         let mut conflict = Vec::new();
         let mut require = Vec::new();
         let mut usage = String::new();
+        let mut validate = String::new();
+        let mut validate_now = String::new();
+        let mut const_validate = String::new();
+        let mut after_build = String::new();
+        let mut flag = false;
 
         let mut i = 0;
         while i < attrs.len() {
@@ -68,7 +222,28 @@ impl Data {
                 "usage" => {
                     assert!(usage.is_empty(), "cannot have multiple usages");
                     usage = after_eq(&attrs[i])
-                }
+                },
+                "validate" => {
+                    assert!(validate.is_empty(), "cannot have multiple validations");
+                    assert!(!short, "fields without inners cannot be validated");
+                    validate = after_eq(&attrs[i])
+                },
+                "validate_now" => {
+                    assert!(validate_now.is_empty(), "cannot have multiple #[validate_now]s");
+                    assert!(!short, "fields without inners cannot be validated");
+                    validate_now = after_eq(&attrs[i])
+                },
+                "const_validate" => {
+                    assert!(const_validate.is_empty(), "cannot have multiple #[const_validate]s");
+                    assert!(!short, "fields without inners cannot be validated");
+                    const_validate = after_eq(&attrs[i])
+                },
+                "after_build" => {
+                    assert!(after_build.is_empty(), "cannot have multiple #[after_build]s");
+                    after_build = after_eq(&attrs[i])
+                },
+                // Data is read directly in `create`, so it needs no usage
+                "flag" => flag = true,
                 _ => {
                     remove = false;
                     i += 1
@@ -80,21 +255,34 @@ impl Data {
             }
         }
 
-        assert!(!usage.is_empty() || !require.is_empty(), "#[usage] or 1+ #[require] must be specified");
+        assert!(!usage.is_empty() || !after_build.is_empty() || !require.is_empty() || flag, "#[usage], #[after_build], #[flag] or 1+ #[require] must be specified");
+
+        let validated = !validate.is_empty() || !validate_now.is_empty() || !const_validate.is_empty();
 
+        // SAFETY: macros are expanded one after another on a single thread
         unsafe {
             DATA.push(Self {
                 lower,
+                ty,
+                doc,
                 default,
                 conflict,
                 require,
                 usage,
-                short
+                validate,
+                validate_now: validate_now.clone(),
+                after_build,
+                validated,
+                short,
+                stability
             })
         }
+
+        (validate_now, const_validate)
     }
 
     pub fn get() -> Vec <Data> {
+        // SAFETY: macros are expanded one after another on a single thread
         unsafe { take(&mut DATA) }
     }
 }
@@ -122,15 +310,46 @@ pub struct Callback {
     /// Specify the event to be called on
     pub on: String,
 
+    ///
+    /// Code run right before the callback is called, binding the arguments
+    /// the event pattern in `on` cannot bind itself, e.g. converted ones
+    ///
+    /// `""` if the pattern binds all of them
+    ///
+    pub bind: String,
+
+    ///
+    /// Code run right after the callbacks are called, with their chained result
+    /// bound to `output`, see `getters::Chain`
+    ///
+    /// `""` if the result is `()`
+    ///
+    pub then: String,
+
     /// List of variables(separated with comma) to be used as arguments
-    pub args: String
+    pub args: String,
+
+    /// Types of arguments as written
+    pub arg_types: Vec <String>,
+
+    /// The return type as written
+    pub ret: String,
+
+    /// See [`doc_summary`]
+    pub doc: String,
+
+    /// See [`Stability`]
+    pub stability: Stability
 }
 
 impl Callback {
-    pub fn add(lower: String, args: String, attrs: &mut Vec <Attribute>) {
+    pub fn add(lower: String, args: String, arg_types: Vec <String>, ret: String, stability: Stability, attrs: &mut Vec <Attribute>) {
+        let doc = doc_summary(attrs);
         let mut unique = String::new();
         let mut default = String::new();
         let mut on = String::new();
+        let mut bind = String::new();
+        let mut then = String::new();
 
         let mut i = 0;
         while i < attrs.len() {
@@ -150,6 +369,14 @@ impl Callback {
                     assert!(on.is_empty(), "cannot specify multiple #[on]s");
                     on = after_eq(&attrs[i])
                 },
+                "bind" => {
+                    assert!(bind.is_empty(), "cannot specify multiple #[bind]s");
+                    bind = after_eq(&attrs[i])
+                },
+                "then" => {
+                    assert!(then.is_empty(), "cannot specify multiple #[then]s");
+                    then = after_eq(&attrs[i])
+                },
                 _ => {
                     remove = false;
                     i += 1
@@ -162,19 +389,28 @@ impl Callback {
         }
 
         assert!(!on.is_empty() || !unique.is_empty(), "#[on] or #[unique] must be specified");
+        assert!(then.is_empty() == (ret == "()"), "#[then] must be specified exactly for callbacks returning something");
 
+        // SAFETY: macros are expanded one after another on a single thread
         unsafe {
             CALLBACKS.push(Self {
                 lower,
                 unique,
                 default,
                 on,
-                args
+                bind,
+                then,
+                args,
+                arg_types,
+                ret,
+                doc,
+                stability
             })
         }
     }
 
     pub fn get() -> Vec <Callback> {
+        // SAFETY: macros are expanded one after another on a single thread
         unsafe { take(&mut CALLBACKS) }
     }
 }
@@ -182,6 +418,7 @@ impl Callback {
 static mut CALLBACKS: Vec <Callback> = Vec::new();
 
 pub fn add_trait(ty: String) {
+    // SAFETY: macros are expanded one after another on a single thread
     unsafe {
         TRAITS.push_str(&ty);
         TRAITS.push('+')
@@ -189,23 +426,48 @@ pub fn add_trait(ty: String) {
 }
 
 pub fn traits() -> String {
+    // SAFETY: macros are expanded one after another on a single thread
     unsafe { take(&mut TRAITS) }
 }
 
 static mut TRAITS: String = String::new();
 
 pub fn add_lifetimes(ty: String) {
+    // SAFETY: macros are expanded one after another on a single thread
     unsafe {
         LIFETIMES.push_str(&ty)
     }
 }
 
 pub fn lifetimes() -> String {
+    // SAFETY: macros are expanded one after another on a single thread
     unsafe { take(&mut LIFETIMES) }
 }
 
 static mut LIFETIMES: String = String::new();
 
+///
+/// Returns the first line of doc comments that is neither empty nor a heading,
+/// e.g. "`.title(&str)` -> specifies a title to the window."
+///
+/// Empty string if there is no such line
+///
+fn doc_summary(attrs: &[Attribute]) -> String {
+    attrs
+        .iter()
+        .filter(|a| a.path.is_ident("doc"))
+        .filter_map(|a| match a.parse_meta() {
+            Ok(Meta::NameValue(nv)) => match nv.lit {
+                Lit::Str(s) => Some(s.value()),
+                _ => None
+            },
+            _ => None
+        })
+        .map(|line| line.trim().to_string())
+        .find(|line| !line.is_empty() && !line.starts_with('#'))
+        .unwrap_or_default()
+}
+
 /// Splits the attribute after `=` and returns the trimmed latter
 fn after_eq(attr: &Attribute) -> String {
     attr.tokens.to_string().split_once('=').expect("expected `=`").1.trim().to_string()
@@ -0,0 +1,335 @@
+//!
+//! This crate scaffolds new projects using `rokoko`,
+//! see `cargo rokoko-new --help`.
+//!
+//! A project consists of:
+//! - `Cargo.toml` with the chosen features,
+//! - `rust-toolchain.toml` pinning nightly if any of the features requires it,
+//! - `src/main.rs` made from one of the [`Template`]s,
+//! - `.gitignore`.
+//!
+//! # Examples
+//!
+//! ```
+//! use rokoko_template::{Project, Template};
+//!
+//! let mut project = Project::new("mygame", Template::Game);
+//! project.features.push(String::from("dialogs"));
+//!
+//! let files = project.generate().unwrap();
+//! let paths = files.iter().map(|f| f.path).collect::<Vec <_>>();
+//! assert_eq!(paths, ["Cargo.toml", "rust-toolchain.toml", "src/main.rs", ".gitignore"]);
+//!
+//! // Features required by the template are added
+//! assert!(files[0].contents.contains(r#"features = ["math", "window", "dialogs"]"#));
+//! assert!(files[2].contents.contains(r#".title("mygame")"#));
+//! ```
+//!
+
+use std::fmt;
+
+/// Repository `rokoko` is fetched from by default
+pub const REPOSITORY: &str = "https://github.com/OrbitalStation/rokoko";
+
+/// Toolchain pinned by projects using features that require nightly
+pub const NIGHTLY: &str = "nightly-2022-11-01";
+
+///
+/// A feature of `rokoko`.
+///
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Feature {
+    /// Name as in `Cargo.toml`
+    pub name: &'static str,
+
+    /// Features this one enables
+    pub requires: &'static [&'static str],
+
+    /// Whether it requires nightly Rust
+    pub nightly: bool
+}
+
+///
+/// All the features of `rokoko`, except for `default`.
+///
+/// Mirrors `[features]` of its `Cargo.toml`.
+///
+pub const FEATURES: &[Feature] = &[
+    Feature { name: "math", requires: &[], nightly: false },
+    Feature { name: "libm", requires: &[], nightly: false },
+    Feature { name: "alloc", requires: &[], nightly: false },
+    Feature { name: "window", requires: &[], nightly: true },
+    Feature { name: "dialogs", requires: &["window"], nightly: false },
+    Feature { name: "debug-ui", requires: &["window"], nightly: false },
+    Feature { name: "menus", requires: &["window"], nightly: false },
+    Feature { name: "winit-interop", requires: &["window"], nightly: false },
+    Feature { name: "config-trace", requires: &["window"], nightly: false },
+    Feature { name: "unstable-minimize-on-focus-loss", requires: &["window"], nightly: false }
+];
+
+/// Returns a feature by its name
+pub fn feature(name: &str) -> Option <&'static Feature> {
+    FEATURES.iter().find(|f| f.name == name)
+}
+
+///
+/// Returns `requested` features together with all the features they enable,
+/// in order of [`FEATURES`].
+///
+/// # Examples
+///
+/// ```
+/// use rokoko_template::{resolve_features, Error};
+///
+/// assert_eq!(resolve_features(&["menus", "math"]).unwrap(), ["math", "window", "menus"]);
+/// assert_eq!(resolve_features(&["gpu"]), Err(Error::UnknownFeature(String::from("gpu"))));
+/// ```
+///
+pub fn resolve_features(requested: &[&str]) -> Result <Vec <&'static str>, Error> {
+    let mut enabled = vec![false; FEATURES.len()];
+    let mut stack = requested.to_vec();
+
+    while let Some(name) = stack.pop() {
+        let idx = FEATURES
+            .iter()
+            .position(|f| f.name == name)
+            .ok_or_else(|| Error::UnknownFeature(name.to_string()))?;
+        if !enabled[idx] {
+            enabled[idx] = true;
+            stack.extend_from_slice(FEATURES[idx].requires)
+        }
+    }
+
+    Ok(FEATURES
+        .iter()
+        .zip(enabled)
+        .filter(|(_, enabled)| *enabled)
+        .map(|(f, _)| f.name)
+        .collect())
+}
+
+///
+/// A skeleton of `src/main.rs`.
+///
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Template {
+    /// An empty window
+    Minimal,
+
+    /// A game loop with frame pacing
+    Game,
+
+    /// A frame loop with the debug UI on top
+    Overlay
+}
+
+impl Template {
+    /// All the templates
+    pub const ALL: [Template; 3] = [Template::Minimal, Template::Game, Template::Overlay];
+
+    /// Returns the name used on the command line
+    pub const fn name(self) -> &'static str {
+        match self {
+            Template::Minimal => "minimal",
+            Template::Game => "game",
+            Template::Overlay => "overlay"
+        }
+    }
+
+    /// Returns a template by its name
+    pub fn from_name(name: &str) -> Result <Self, Error> {
+        Self::ALL
+            .into_iter()
+            .find(|t| t.name() == name)
+            .ok_or_else(|| Error::UnknownTemplate(name.to_string()))
+    }
+
+    /// Returns a one-line description
+    pub const fn description(self) -> &'static str {
+        match self {
+            Template::Minimal => "an empty window",
+            Template::Game => "a game loop with frame pacing",
+            Template::Overlay => "a frame loop with the debug UI on top"
+        }
+    }
+
+    /// Returns features the template cannot be compiled without
+    pub const fn features(self) -> &'static [&'static str] {
+        match self {
+            Template::Minimal => &["window"],
+            Template::Game => &["math", "window"],
+            Template::Overlay => &["math", "debug-ui"]
+        }
+    }
+
+    /// Returns the source of `src/main.rs` with placeholders
+    pub const fn source(self) -> &'static str {
+        match self {
+            Template::Minimal => include_str!("../templates/minimal.rs"),
+            Template::Game => include_str!("../templates/game.rs"),
+            Template::Overlay => include_str!("../templates/overlay.rs")
+        }
+    }
+}
+
+///
+/// Where `rokoko` is taken from.
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    /// [`REPOSITORY`]
+    Git,
+
+    /// A local checkout
+    Path(String)
+}
+
+///
+/// Errors of generation.
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    UnknownFeature(String),
+    UnknownTemplate(String),
+
+    /// The name is not a valid package name
+    InvalidName(String)
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter <'_>) -> fmt::Result {
+        match self {
+            Error::UnknownFeature(name) => {
+                let known = FEATURES.iter().map(|f| f.name).collect::<Vec <_>>().join(", ");
+                write!(f, "unknown feature `{name}`, expected one of: {known}")
+            },
+            Error::UnknownTemplate(name) => {
+                let known = Template::ALL.map(Template::name).join(", ");
+                write!(f, "unknown template `{name}`, expected one of: {known}")
+            },
+            Error::InvalidName(name) => write!(f, "`{name}` is not a valid package name: \
+                expected ASCII letters, digits, `-` or `_`, not starting with a digit")
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+///
+/// Replaces each `{{key}}` in `template` with the corresponding value.
+///
+/// # Examples
+///
+/// ```
+/// use rokoko_template::substitute;
+///
+/// assert_eq!(substitute("{{a}} and {{b}}, {{c}}", &[("a", "1"), ("b", "2")]), "1 and 2, {{c}}");
+/// ```
+///
+pub fn substitute(template: &str, vars: &[(&str, &str)]) -> String {
+    let mut result = template.to_string();
+    for (key, value) in vars {
+        result = result.replace(&format!("{{{{{key}}}}}"), value)
+    }
+    result
+}
+
+///
+/// A file to be written, relatively to the project root.
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct File {
+    pub path: &'static str,
+    pub contents: String
+}
+
+///
+/// Description of a project to be generated.
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Project {
+    /// Package name
+    pub name: String,
+
+    pub template: Template,
+
+    /// Features in addition to ones required by the template
+    pub features: Vec <String>,
+
+    pub source: Source
+}
+
+impl Project {
+    /// Creates a project fetching `rokoko` from [`REPOSITORY`] with no additional features
+    pub fn new(name: &str, template: Template) -> Self {
+        Self {
+            name: name.to_string(),
+            template,
+            features: Vec::new(),
+            source: Source::Git
+        }
+    }
+
+    ///
+    /// Returns all the features of the project, see [`resolve_features`].
+    ///
+    pub fn resolved_features(&self) -> Result <Vec <&'static str>, Error> {
+        let mut requested = self.template.features().to_vec();
+        requested.extend(self.features.iter().map(String::as_str));
+        resolve_features(&requested)
+    }
+
+    ///
+    /// Generates all the files of the project.
+    ///
+    /// `rust-toolchain.toml` is only generated if nightly is required.
+    ///
+    pub fn generate(&self) -> Result <Vec <File>, Error> {
+        let name = &self.name;
+        let valid = name.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+            && name.chars().next().is_some_and(|c| !c.is_ascii_digit());
+        if !valid {
+            return Err(Error::InvalidName(name.clone()))
+        }
+
+        let features = self.resolved_features()?;
+
+        let source = match &self.source {
+            Source::Git => format!("git = {REPOSITORY:?}"),
+            Source::Path(path) => format!("path = {path:?}")
+        };
+        let mut files = vec![File {
+            path: "Cargo.toml",
+            contents: format!("\
+[package]
+name = {name:?}
+version = \"0.1.0\"
+edition = \"2021\"
+
+[dependencies.rokoko]
+{source}
+default-features = false
+features = {features:?}
+")
+        }];
+
+        if features.iter().any(|name| feature(name).is_some_and(|f| f.nightly)) {
+            files.push(File {
+                path: "rust-toolchain.toml",
+                contents: format!("[toolchain]\nchannel = {NIGHTLY:?}\n")
+            })
+        }
+
+        files.push(File {
+            path: "src/main.rs",
+            contents: substitute(self.template.source(), &[("name", name)])
+        });
+
+        files.push(File {
+            path: ".gitignore",
+            contents: include_str!("../templates/gitignore").to_string()
+        });
+
+        Ok(files)
+    }
+}
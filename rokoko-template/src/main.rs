@@ -0,0 +1,143 @@
+//!
+//! `cargo rokoko-new` - scaffolds a new project using `rokoko`.
+//!
+//! Everything not given by flags is asked interactively.
+//!
+
+use rokoko_template::{Project, Template, Source, FEATURES};
+use std::{
+    io::{self, Write, BufRead},
+    path::PathBuf,
+    process::exit
+};
+
+const HELP: &str = "\
+Scaffolds a new project using `rokoko`
+
+USAGE:
+    cargo rokoko-new [OPTIONS]
+
+OPTIONS:
+    --name <NAME>            Package name, also the directory to create
+    --template <TEMPLATE>    One of: minimal, game, overlay
+    --features <FEATURES>    Comma-separated additional features of `rokoko`
+    --path <PATH>            Use a local checkout of `rokoko` instead of git
+    --dir <DIR>              Directory to create, defaults to the name
+    -h, --help               Print help
+";
+
+/// Values of command line flags
+#[derive(Default)]
+struct Args {
+    name: Option <String>,
+    template: Option <String>,
+    features: Option <String>,
+    path: Option <String>,
+    dir: Option <PathBuf>
+}
+
+fn parse_args() -> Result <Args, String> {
+    let mut args = std::env::args().skip(1).peekable();
+
+    // Skip the subcommand name when invoked via `cargo`
+    if args.peek().map(String::as_str) == Some("rokoko-new") {
+        args.next();
+    }
+
+    let mut result = Args::default();
+    while let Some(arg) = args.next() {
+        if arg == "-h" || arg == "--help" {
+            print!("{HELP}");
+            exit(0)
+        }
+
+        let slot = match arg.as_str() {
+            "--name" => &mut result.name,
+            "--template" => &mut result.template,
+            "--features" => &mut result.features,
+            "--path" => &mut result.path,
+            "--dir" => {
+                let dir = args.next().ok_or("`--dir` requires a value")?;
+                result.dir = Some(dir.into());
+                continue
+            },
+            _ => return Err(format!("unexpected argument `{arg}`, see `--help`"))
+        };
+        *slot = Some(args.next().ok_or_else(|| format!("`{arg}` requires a value"))?)
+    }
+
+    Ok(result)
+}
+
+/// Asks the user for a line, returning `default` if it is empty
+fn prompt(question: &str, default: &str) -> io::Result <String> {
+    print!("{question} [{default}]: ");
+    io::stdout().flush()?;
+
+    let mut line = String::new();
+    io::stdin().lock().read_line(&mut line)?;
+    let line = line.trim();
+
+    Ok(if line.is_empty() { default } else { line }.to_string())
+}
+
+fn run() -> Result <(), Box <dyn std::error::Error>> {
+    let args = parse_args()?;
+
+    let name = match args.name {
+        Some(name) => name,
+        None => prompt("Package name", "my-project")?
+    };
+
+    let template = match args.template {
+        Some(template) => template,
+        None => {
+            for template in Template::ALL {
+                println!("    {:<10}{}", template.name(), template.description())
+            }
+            prompt("Template", Template::Minimal.name())?
+        }
+    };
+
+    let features = match args.features {
+        Some(features) => features,
+        None => {
+            let known = FEATURES.iter().map(|f| f.name).collect::<Vec <_>>().join(", ");
+            prompt(&format!("Additional features({known})"), "")?
+        }
+    };
+
+    let project = Project {
+        template: Template::from_name(&template)?,
+        features: features
+            .split(',')
+            .map(str::trim)
+            .filter(|f| !f.is_empty())
+            .map(String::from)
+            .collect(),
+        source: args.path.map_or(Source::Git, Source::Path),
+        name
+    };
+    let files = project.generate()?;
+
+    let dir = args.dir.unwrap_or_else(|| PathBuf::from(&project.name));
+    if dir.exists() && dir.read_dir()?.next().is_some() {
+        return Err(format!("`{}` already exists and is not empty", dir.display()).into())
+    }
+
+    for file in files {
+        let path = dir.join(file.path);
+        std::fs::create_dir_all(path.parent().unwrap())?;
+        std::fs::write(&path, file.contents)?
+    }
+
+    println!("Created `{}` in `{}`", project.name, dir.display());
+    Ok(())
+}
+
+fn main() {
+    if let Err(e) = run() {
+        eprintln!("error: {e}");
+        exit(1)
+    }
+}
@@ -0,0 +1,10 @@
+use rokoko::prelude::*;
+
+fn main() {
+    Window::new()
+        .title("{{name}}")
+        .on_init(|_| println!("Initialized!"))
+        .on_close(Window::close)
+        .create()
+        .unwrap()
+}
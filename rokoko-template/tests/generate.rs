@@ -0,0 +1,136 @@
+//!
+//! Tests of generated projects.
+//!
+//! Snapshots live in `tests/snapshots/<template>/`;
+//! run with `UPDATE_SNAPSHOTS=1` to rewrite them.
+//!
+
+use rokoko_template::{Project, Template, Source, FEATURES, resolve_features};
+use std::{
+    path::{Path, PathBuf},
+    process::Command
+};
+
+/// Root of this crate
+fn manifest_dir() -> &'static Path {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+}
+
+#[test]
+fn snapshots() {
+    let update = std::env::var_os("UPDATE_SNAPSHOTS").is_some();
+
+    for template in Template::ALL {
+        let dir = manifest_dir().join("tests/snapshots").join(template.name());
+        let project = Project::new("example", template);
+
+        for file in project.generate().unwrap() {
+            let path = dir.join(file.path);
+            if update {
+                std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+                std::fs::write(&path, &file.contents).unwrap()
+            } else {
+                let expected = std::fs::read_to_string(&path)
+                    .unwrap_or_else(|e| panic!("cannot read `{}`: {e}", path.display()));
+                assert_eq!(file.contents, expected, "`{}` differs from the snapshot", path.display())
+            }
+        }
+    }
+}
+
+/// Parses `[features]` of `rokoko`'s `Cargo.toml`
+fn real_features() -> Vec <(String, Vec <String>)> {
+    let manifest = std::fs::read_to_string(manifest_dir().join("../Cargo.toml")).unwrap();
+    manifest
+        .lines()
+        .skip_while(|line| line.trim() != "[features]")
+        .skip(1)
+        .take_while(|line| !line.starts_with('['))
+        .filter(|line| !line.starts_with('#') && line.contains('='))
+        .map(|line| {
+            let (name, value) = line.split_once('=').unwrap();
+            let requires = value
+                .trim()
+                .trim_matches(|c| c == '[' || c == ']')
+                .split(',')
+                .map(|f| f.trim().trim_matches('"'))
+                .filter(|f| !f.is_empty() && !f.starts_with("dep:"))
+                .map(String::from)
+                .collect();
+            (name.trim().to_string(), requires)
+        })
+        .filter(|(name, _)| name != "default")
+        .collect()
+}
+
+#[test]
+fn features_mirror_the_crate() {
+    let mut real = real_features();
+    let mut mirrored = FEATURES
+        .iter()
+        .map(|f| (f.name.to_string(), f.requires.iter().map(|r| r.to_string()).collect::<Vec <_>>()))
+        .collect::<Vec <_>>();
+    real.sort();
+    mirrored.sort();
+    assert_eq!(mirrored, real)
+}
+
+#[test]
+fn features_are_resolved() {
+    assert_eq!(resolve_features(&[]).unwrap(), Vec::<&str>::new());
+    assert_eq!(resolve_features(&["debug-ui", "dialogs", "window"]).unwrap(), ["window", "dialogs", "debug-ui"]);
+
+    let mut project = Project::new("x", Template::Minimal);
+    project.features = vec![String::from("libm")];
+    assert_eq!(project.resolved_features().unwrap(), ["libm", "window"]);
+
+    project.features.push(String::from("nope"));
+    assert!(project.generate().is_err())
+}
+
+#[test]
+fn names_are_validated() {
+    for name in ["", "1game", "my game", "gäme"] {
+        assert!(Project::new(name, Template::Minimal).generate().is_err(), "{name:?} must be rejected")
+    }
+    for name in ["game", "my-game", "my_game2"] {
+        assert!(Project::new(name, Template::Minimal).generate().is_ok(), "{name:?} must be accepted")
+    }
+}
+
+///
+/// Builds each generated project against this checkout.
+///
+/// Needs the pinned nightly toolchain and network access for dependencies,
+/// so it is only run on demand: `cargo test -p rokoko-template -- --ignored`.
+///
+#[test]
+#[ignore]
+fn generated_projects_build() {
+    let rokoko = manifest_dir().parent().unwrap().to_str().unwrap().to_string();
+
+    for template in Template::ALL {
+        let dir: PathBuf = std::env::temp_dir().join(format!("rokoko-template-{}-{}", template.name(), std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+
+        let mut project = Project::new(&format!("check-{}", template.name()), template);
+        project.source = Source::Path(rokoko.clone());
+        for file in project.generate().unwrap() {
+            let path = dir.join(file.path);
+            std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+            std::fs::write(path, file.contents).unwrap()
+        }
+
+        let status = Command::new("cargo")
+            .arg("build")
+            .current_dir(&dir)
+            // Otherwise the toolchain of this crate would be used instead of the pinned one
+            .env_remove("RUSTUP_TOOLCHAIN")
+            .env_remove("CARGO")
+            .status()
+            .unwrap();
+        assert!(status.success(), "template `{}` does not build", template.name());
+
+        std::fs::remove_dir_all(&dir).unwrap()
+    }
+}
@@ -0,0 +1,27 @@
+use rokoko::prelude::*;
+
+/// Size of the field the square moves in
+const FIELD: [f32; 2] = [1280.0, 720.0];
+
+fn main() {
+    let mut pos = vec2::ZERO;
+    let mut velocity = vec2::from([240.0, 160.0]);
+
+    Window::new()
+        .title("example")
+        .size_720p()
+        .target_fps(60.0)
+        .on_frame(move |_, dt| {
+            // Update
+            pos += velocity * dt;
+            for i in 0..2 {
+                if pos[i] < 0.0 || pos[i] > FIELD[i] {
+                    velocity[i] = -velocity[i]
+                }
+            }
+
+            // Draw the square at `pos` here
+        })
+        .create()
+        .unwrap()
+}
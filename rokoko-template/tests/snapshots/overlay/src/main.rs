@@ -0,0 +1,29 @@
+use rokoko::{
+    prelude::*,
+    window::debug_ui::UiCtx
+};
+
+fn main() {
+    let mut ui = UiCtx::new();
+    let mut speed = 1.0;
+    let mut paused = false;
+
+    Window::new()
+        .title("example")
+        .on_frame(move |_, dt| {
+            if !paused {
+                // Update the scene using `speed * dt` here
+                let _ = speed * dt;
+            }
+
+            ui.begin(vec2::ZERO);
+            ui.slider("speed", &mut speed, 0.0..=10.0);
+            ui.checkbox("paused", &mut paused);
+            let commands = ui.end();
+
+            // Draw the scene, then `commands` on top of it here
+            let _ = commands;
+        })
+        .create()
+        .unwrap()
+}
@@ -9,14 +9,26 @@
     const_refs_to_cell,
     const_convert,
     const_type_id,
+    const_fn_floating_point_arithmetic,
     auto_traits,
     negative_impls,
     unboxed_closures,
-    fn_traits
+    fn_traits,
+    const_eval_select,
+    core_intrinsics
 ))]
 
+// Every unsafe operation has to be in an explicit `unsafe` block
+// with a `// SAFETY:` comment explaining why it is sound
+#![deny(unsafe_op_in_unsafe_fn, clippy::undocumented_unsafe_blocks)]
+
+// `core` is only injected on `no_std`, so it is declared for `use core::...` paths otherwise;
+// `std`-only items are named through `std::` explicitly
 #[cfg(std)]
-pub(crate) use std as core;
+extern crate core;
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
 
 extern crate cfg_if;
 
@@ -26,13 +38,32 @@ extern crate winit;
 #[cfg(feature = "window")]
 extern crate raw_window_handle;
 
+#[cfg(feature = "libm")]
+extern crate libm;
+
+#[cfg(feature = "global-hotkeys")]
+extern crate global_hotkey;
+
 #[doc(hidden)]
 pub extern crate rokoko_macro;
 pub use rokoko_macro::nightly;
 
-#[cfg(feature = "window")]
+#[cfg(all(feature = "window", nightly))]
 pub mod window;
 
+///
+/// Only `nightly` is supported for `window` for now.
+///
+/// The module itself is not compiled on stable at all, so that
+/// that error is the only one reported.
+///
+#[cfg(all(feature = "window", not(nightly)))]
+compile_error!("\
+the `window` feature of `rokoko` requires nightly Rust.
+Either install nightly with `rustup toolchain install nightly` and build with `cargo +nightly build`
+(or pin it for the project with a `rust-toolchain.toml` containing `[toolchain] channel = \"nightly\"`),
+or disable the feature with `default-features = false, features = [\"math\"]`.");
+
 pub mod math;
 
 pub mod prelude;
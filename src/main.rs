@@ -2,7 +2,6 @@ extern crate rokoko;
 extern crate raw_window_handle;
 
 use raw_window_handle::HasRawWindowHandle;
-use rokoko::prelude::*;
 /*
 use rokoko::prelude::*;
 
@@ -31,14 +30,13 @@ fn main() {
 */
 
 fn main() {
-    Window::new()
-        .size((1000., 1000.))
-        .on_init(|w| println!("Initialization completed! Handle = {:?}", w.raw_window_handle()))
-        .on_exit(|_| println!("Dropping!"))
-        .on_close(|w| {
+    rokoko::run!(
+        size: (1000., 1000.),
+        on_init: |w| println!("Initialization completed! Handle = {:?}", w.raw_window_handle()),
+        on_exit: |_| println!("Dropping!"),
+        on_close: |w| {
             println!("Closing!");
             w.close()
-        })
-        .create()
-        .unwrap()
+        }
+    )
 }
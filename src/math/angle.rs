@@ -0,0 +1,118 @@
+//!
+//! This module provides [`Deg`] and [`Rad`] - angles annotated with their unit,
+//! so that passing degrees where radians are expected is a type error instead of a bug.
+//!
+//! Both are [`SignedScalar`]s, so vecs of them work with the usual operators,
+//! e.g. `vec <Deg <f32>, 3>` of Euler angles. Units are converted with `From`,
+//! for single angles and vecs alike, plain float vecs have
+//! [`vec::to_radians`] and [`vec::to_degrees`].
+//!
+//! Helpers for vecs of angles, e.g. [`vec::lerp_angles`], take radians.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use rokoko::prelude::*;
+//!
+//! let euler = vec::from([Deg(90.0f32), Deg(0.0), Deg(-45.0)]);
+//! let turned = euler + vec::from([Deg(90.0), Deg(0.0), Deg(0.0)]);
+//! assert_eq!(turned, vec::from([Deg(180.0), Deg(0.0), Deg(-45.0)]));
+//!
+//! let radians: vec <Rad <f32>, 3> = turned.into();
+//! assert!((radians[0].0 - core::f32::consts::PI).abs() < 1e-6);
+//! ```
+//!
+//! Mixing the units does not compile:
+//!
+//! ```compile_fail
+//! use rokoko::prelude::*;
+//!
+//! fn rotate(euler: vec <Rad <f32>, 3>) -> vec <Rad <f32>, 3> {
+//!     euler
+//! }
+//!
+//! let euler = vec::from([Deg(90.0f32), Deg(0.0), Deg(0.0)]);
+//! rotate(euler);
+//! ```
+//!
+//! [`vec::to_radians`]: super::vec::vec::to_radians
+//! [`vec::to_degrees`]: super::vec::vec::to_degrees
+//! [`vec::lerp_angles`]: super::vec::vec::lerp_angles
+//!
+
+use super::scalar::{Scalar, SignedScalar, Float};
+use core::ops::{Add, Sub, Mul, Div, Neg};
+
+macro_rules! unit {
+    ($( $(#[$attr:meta])* $unit:ident ),*) => {$(
+        $(#[$attr])*
+        #[derive(Debug, Default, Copy, Clone, PartialEq, PartialOrd)]
+        pub struct $unit <T> (pub T);
+
+        unit!(@ops $unit, Add add, Sub sub, Mul mul, Div div);
+
+        impl <T: Neg <Output = T>> Neg for $unit <T> {
+            type Output = Self;
+
+            #[inline]
+            fn neg(self) -> Self {
+                $unit(-self.0)
+            }
+        }
+
+        impl <T: Scalar> Scalar for $unit <T> {
+            const ZERO: Self = $unit(T::ZERO);
+            const ONE: Self = $unit(T::ONE);
+        }
+
+        impl <T: SignedScalar> SignedScalar for $unit <T> {
+            #[inline]
+            fn abs(self) -> Self {
+                $unit(self.0.abs())
+            }
+        }
+    )*};
+
+    (@ops $unit:ident, $( $big:ident $small:ident ),*) => {$(
+        impl <T: $big <Output = T>> $big for $unit <T> {
+            type Output = Self;
+
+            #[inline]
+            fn $small(self, rhs: Self) -> Self {
+                $unit(self.0.$small(rhs.0))
+            }
+        }
+    )*};
+}
+
+unit! {
+    ///
+    /// An angle in degrees, see [module documentation](self).
+    ///
+    /// Multiplying or dividing two angles gives an angle too, as [`Scalar`] requires,
+    /// so a factor is written as an angle as well, e.g. `Deg(30.0) * Deg(2.0)`.
+    ///
+    Deg,
+
+    ///
+    /// An angle in radians, see [module documentation](self).
+    ///
+    /// Multiplying or dividing two angles gives an angle too, as [`Scalar`] requires,
+    /// so a factor is written as an angle as well, e.g. `Rad(0.5) * Rad(2.0)`.
+    ///
+    Rad
+}
+
+impl <T: Float> From <Deg <T>> for Rad <T> {
+    #[inline]
+    fn from(Deg(x): Deg <T>) -> Self {
+        Rad(x.to_radians())
+    }
+}
+
+impl <T: Float> From <Rad <T>> for Deg <T> {
+    #[inline]
+    fn from(Rad(x): Rad <T>) -> Self {
+        Deg(x.to_degrees())
+    }
+}
@@ -0,0 +1,467 @@
+//!
+//! This module provides fixed-point number types: [`fix32`] and [`fix64`].
+//!
+//! `fix32 <FRAC>` is a Q-format number backed by an `i32`, where the lowest `FRAC`
+//! bits represent a fraction, i.e. `fix32 <16>` is Q16.16. `fix64 <FRAC>` is the same, but backed by an `i64`.
+//!
+//! Unlike floats, the results of fixed-point arithmetic are the same on every platform,
+//! which makes them suitable for deterministic(e.g. lockstep) game math.
+//!
+//! Both types implement [`Scalar`] and [`SignedScalar`], so they work with `vec`.
+//!
+//! # Overflow
+//!
+//! Plain operators(`+`, `-`, `*`, `/`) behave just like ones of the backing integer:
+//! overflow panics in debug and wraps in release.
+//!
+//! If you need a defined behaviour, use `wrapping_*` or `saturating_*` methods.
+//!
+//! Division by zero always panics.
+//!
+//! # Precision
+//!
+//! - Multiplication and division are computed in a twice as wide integer, so the
+//!   intermediate result never overflows.
+//! - Multiplication rounds towards negative infinity(the low bits are shifted out).
+//! - Division rounds towards zero.
+//! - Conversion from floats rounds half away from zero, saturates on overflow and maps `NaN` to zero.
+//!
+//! `FRAC` must be less than the number of bits of the backing integer.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use rokoko::prelude::*;
+//!
+//! type fix = fix32 <16>;
+//!
+//! let a = vec::from_array([fix::from(1.5), fix::from(-2.0), fix::from(0.25)]);
+//! let b = vec::single(fix::from(2.0));
+//!
+//! assert_eq!(a * b, vec::from_array([fix::from(3.0), fix::from(-4.0), fix::from(0.5)]));
+//! assert_eq!(a + vec::ONE, vec::from_array([fix::from(2.5), fix::from(-1.0), fix::from(1.25)]));
+//! assert_eq!(format!("{}", a[0]), "1.5");
+//! ```
+//!
+//! Results stay within the precision of the format when compared with `f64`:
+//! ```rust
+//! use rokoko::prelude::*;
+//!
+//! type fix = fix32 <16>;
+//!
+//! // Simple deterministic pseudo-random generator
+//! let mut state = 0x2545F491u32;
+//! let mut random = move || {
+//!     state ^= state << 13;
+//!     state ^= state >> 17;
+//!     state ^= state << 5;
+//!     (state % 20000) as f64 / 100.0 - 100.0
+//! };
+//!
+//! let tolerance = 2.0 / 65536.0;
+//!
+//! for _ in 0..1000 {
+//!     let (a, b) = (random(), random());
+//!     let (fa, fb) = (fix::from(a), fix::from(b));
+//!
+//!     assert!((f64::from(fa + fb) - (a + b)).abs() <= tolerance);
+//!     assert!((f64::from(fa - fb) - (a - b)).abs() <= tolerance);
+//!     assert!((f64::from(fa * fb) - (a * b)).abs() <= 200.0 * tolerance);
+//!
+//!     if b.abs() >= 1.0 {
+//!         assert!((f64::from(fa / fb) - (a / b)).abs() <= 200.0 * tolerance);
+//!     }
+//! }
+//! ```
+//!
+
+#![allow(non_camel_case_types)]
+
+use super::scalar::{Scalar, SignedScalar};
+use core::{
+    ops::*,
+    fmt
+};
+
+macro_rules! fixed {
+    ($( $(#[$attr:meta])* $name:ident $int:ident $wide:ident $uwide:ident ),*) => {$(
+        $(#[$attr])*
+        #[derive(Copy, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        #[repr(transparent)]
+        pub struct $name <const FRAC: u32> ($int);
+
+        impl <const FRAC: u32> $name <FRAC> {
+            /// The smallest positive value
+            pub const EPSILON: Self = Self(1);
+
+            /// The smallest value
+            pub const MIN: Self = Self($int::MIN);
+
+            /// The largest value
+            pub const MAX: Self = Self($int::MAX);
+
+            /// `2^FRAC`
+            const SCALE: $wide = 1 << FRAC;
+
+            /// Mask of the fractional bits
+            const FRAC_MASK: $int = (1 << FRAC) - 1;
+
+            ///
+            /// Creates a number from its raw representation.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// use rokoko::prelude::*;
+            ///
+            #[doc = concat!("assert_eq!(", stringify!($name), "::<16>::from_bits(1 << 15), ", stringify!($name), "::from(0.5));")]
+            /// ```
+            ///
+            #[inline]
+            pub const fn from_bits(bits: $int) -> Self {
+                Self(bits)
+            }
+
+            /// Returns the raw representation.
+            #[inline]
+            pub const fn to_bits(self) -> $int {
+                self.0
+            }
+
+            ///
+            /// Creates a number from an integer.
+            ///
+            /// High bits that do not fit are lost.
+            ///
+            #[inline]
+            pub const fn from_int(x: $int) -> Self {
+                Self(x << FRAC)
+            }
+
+            /// Returns the largest integer less than or equal to `self`.
+            #[inline]
+            pub const fn floor(self) -> Self {
+                Self(self.0 & !Self::FRAC_MASK)
+            }
+
+            /// Returns the integer part, rounded towards negative infinity.
+            #[inline]
+            pub const fn to_int(self) -> $int {
+                self.0 >> FRAC
+            }
+
+            ///
+            /// Converts `f64` into the number.
+            ///
+            /// Rounds half away from zero, saturates on overflow, `NaN` becomes zero.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// use rokoko::prelude::*;
+            ///
+            #[doc = concat!("type fix = ", stringify!($name), " <2>;")]
+            ///
+            /// // 0.125 is exactly between 0.0 and 0.25
+            /// assert_eq!(fix::from_f64(0.125), fix::from(0.25));
+            /// assert_eq!(fix::from_f64(-0.125), fix::from(-0.25));
+            /// assert_eq!(fix::from_f64(f64::INFINITY), fix::MAX);
+            /// assert_eq!(fix::from_f64(f64::NAN), fix::default());
+            /// ```
+            ///
+            pub fn from_f64(x: f64) -> Self {
+                let scaled = x * Self::SCALE as f64;
+                // `as` truncates towards zero and saturates
+                let truncated = scaled as $wide;
+                let rest = scaled - truncated as f64;
+                let rounded = if rest >= 0.5 {
+                    truncated.saturating_add(1)
+                } else if rest <= -0.5 {
+                    truncated.saturating_sub(1)
+                } else {
+                    truncated
+                };
+                Self::saturate(rounded)
+            }
+
+            /// Converts the number into `f64`.
+            #[inline]
+            pub fn to_f64(self) -> f64 {
+                self.0 as f64 / Self::SCALE as f64
+            }
+
+            /// Wrapping addition
+            #[inline]
+            pub const fn wrapping_add(self, rhs: Self) -> Self {
+                Self(self.0.wrapping_add(rhs.0))
+            }
+
+            /// Wrapping subtraction
+            #[inline]
+            pub const fn wrapping_sub(self, rhs: Self) -> Self {
+                Self(self.0.wrapping_sub(rhs.0))
+            }
+
+            /// Wrapping multiplication
+            #[inline]
+            pub const fn wrapping_mul(self, rhs: Self) -> Self {
+                Self(Self::wide_mul(self, rhs) as $int)
+            }
+
+            /// Wrapping division
+            #[inline]
+            pub const fn wrapping_div(self, rhs: Self) -> Self {
+                Self(Self::wide_div(self, rhs) as $int)
+            }
+
+            /// Saturating addition
+            #[inline]
+            pub const fn saturating_add(self, rhs: Self) -> Self {
+                Self(self.0.saturating_add(rhs.0))
+            }
+
+            /// Saturating subtraction
+            #[inline]
+            pub const fn saturating_sub(self, rhs: Self) -> Self {
+                Self(self.0.saturating_sub(rhs.0))
+            }
+
+            ///
+            /// Saturating multiplication
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// use rokoko::prelude::*;
+            ///
+            #[doc = concat!("type fix = ", stringify!($name), " <16>;")]
+            ///
+            /// assert_eq!(fix::MAX.saturating_mul(fix::from(2.0)), fix::MAX);
+            /// assert_eq!(fix::MAX.saturating_mul(fix::from(-2.0)), fix::MIN);
+            /// ```
+            ///
+            #[inline]
+            pub const fn saturating_mul(self, rhs: Self) -> Self {
+                Self::saturate(Self::wide_mul(self, rhs))
+            }
+
+            /// Saturating division
+            #[inline]
+            pub const fn saturating_div(self, rhs: Self) -> Self {
+                Self::saturate(Self::wide_div(self, rhs))
+            }
+
+            /// Multiplies in the wide integer
+            #[inline]
+            const fn wide_mul(a: Self, b: Self) -> $wide {
+                (a.0 as $wide * b.0 as $wide) >> FRAC
+            }
+
+            /// Divides in the wide integer
+            #[inline]
+            const fn wide_div(a: Self, b: Self) -> $wide {
+                ((a.0 as $wide) << FRAC) / b.0 as $wide
+            }
+
+            /// Clamps the wide integer into the range of the backing one
+            #[inline]
+            const fn saturate(x: $wide) -> Self {
+                if x > $int::MAX as $wide {
+                    Self::MAX
+                } else if x < $int::MIN as $wide {
+                    Self::MIN
+                } else {
+                    Self(x as $int)
+                }
+            }
+
+            /// Narrows the wide integer, panicking on overflow in debug
+            #[inline]
+            fn narrow(x: $wide) -> Self {
+                debug_assert!(x >= $int::MIN as $wide && x <= $int::MAX as $wide, "fixed-point arithmetic overflow");
+                Self(x as $int)
+            }
+        }
+
+        impl <const FRAC: u32> Add for $name <FRAC> {
+            type Output = Self;
+
+            #[inline]
+            fn add(self, rhs: Self) -> Self {
+                Self(self.0 + rhs.0)
+            }
+        }
+
+        impl <const FRAC: u32> Sub for $name <FRAC> {
+            type Output = Self;
+
+            #[inline]
+            fn sub(self, rhs: Self) -> Self {
+                Self(self.0 - rhs.0)
+            }
+        }
+
+        impl <const FRAC: u32> Mul for $name <FRAC> {
+            type Output = Self;
+
+            #[inline]
+            fn mul(self, rhs: Self) -> Self {
+                Self::narrow(Self::wide_mul(self, rhs))
+            }
+        }
+
+        impl <const FRAC: u32> Div for $name <FRAC> {
+            type Output = Self;
+
+            #[inline]
+            fn div(self, rhs: Self) -> Self {
+                Self::narrow(Self::wide_div(self, rhs))
+            }
+        }
+
+        impl <const FRAC: u32> Neg for $name <FRAC> {
+            type Output = Self;
+
+            #[inline]
+            fn neg(self) -> Self {
+                Self(-self.0)
+            }
+        }
+
+        impl <const FRAC: u32> AddAssign for $name <FRAC> {
+            #[inline]
+            fn add_assign(&mut self, rhs: Self) {
+                *self = *self + rhs
+            }
+        }
+
+        impl <const FRAC: u32> SubAssign for $name <FRAC> {
+            #[inline]
+            fn sub_assign(&mut self, rhs: Self) {
+                *self = *self - rhs
+            }
+        }
+
+        impl <const FRAC: u32> MulAssign for $name <FRAC> {
+            #[inline]
+            fn mul_assign(&mut self, rhs: Self) {
+                *self = *self * rhs
+            }
+        }
+
+        impl <const FRAC: u32> DivAssign for $name <FRAC> {
+            #[inline]
+            fn div_assign(&mut self, rhs: Self) {
+                *self = *self / rhs
+            }
+        }
+
+        impl <const FRAC: u32> Scalar for $name <FRAC> {
+            const ZERO: Self = Self(0);
+            const ONE: Self = Self(1 << FRAC);
+        }
+
+        impl <const FRAC: u32> SignedScalar for $name <FRAC> {}
+
+        impl <const FRAC: u32> From <$int> for $name <FRAC> {
+            #[inline]
+            fn from(x: $int) -> Self {
+                Self::from_int(x)
+            }
+        }
+
+        impl <const FRAC: u32> From <f64> for $name <FRAC> {
+            #[inline]
+            fn from(x: f64) -> Self {
+                Self::from_f64(x)
+            }
+        }
+
+        impl <const FRAC: u32> From <f32> for $name <FRAC> {
+            #[inline]
+            fn from(x: f32) -> Self {
+                Self::from_f64(x as f64)
+            }
+        }
+
+        impl <const FRAC: u32> From <$name <FRAC>> for f64 {
+            #[inline]
+            fn from(x: $name <FRAC>) -> Self {
+                x.to_f64()
+            }
+        }
+
+        impl <const FRAC: u32> From <$name <FRAC>> for f32 {
+            #[inline]
+            fn from(x: $name <FRAC>) -> Self {
+                x.to_f64() as f32
+            }
+        }
+
+        ///
+        /// Writes the exact decimal representation.
+        ///
+        /// If precision is specified, the digits after it are truncated.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use rokoko::prelude::*;
+        ///
+        #[doc = concat!("type fix = ", stringify!($name), " <16>;")]
+        ///
+        /// assert_eq!(format!("{}", fix::from(-2.75)), "-2.75");
+        /// assert_eq!(format!("{}", fix::from(3)), "3");
+        /// assert_eq!(format!("{}", fix::EPSILON), "0.0000152587890625");
+        /// assert_eq!(format!("{:.2}", fix::from(0.125)), "0.12");
+        /// ```
+        ///
+        impl <const FRAC: u32> fmt::Display for $name <FRAC> {
+            fn fmt(&self, f: &mut fmt::Formatter <'_>) -> fmt::Result {
+                if self.0 < 0 {
+                    f.write_str("-")?
+                }
+                let abs = (self.0 as $wide).unsigned_abs();
+                let mask = ((1 as $uwide) << FRAC) - 1;
+                write!(f, "{}", abs >> FRAC)?;
+
+                let mut frac = abs & mask;
+                let mut digits = f.precision().unwrap_or(usize::MAX);
+                if frac != 0 && digits != 0 {
+                    f.write_str(".")?
+                }
+                // Every binary fraction has a finite decimal representation,
+                // so this loop always ends
+                while frac != 0 && digits != 0 {
+                    frac *= 10;
+                    write!(f, "{}", frac >> FRAC)?;
+                    frac &= mask;
+                    digits -= 1
+                }
+                Ok(())
+            }
+        }
+
+        impl <const FRAC: u32> fmt::Debug for $name <FRAC> {
+            fn fmt(&self, f: &mut fmt::Formatter <'_>) -> fmt::Result {
+                write!(f, "{}<{}>({})", stringify!($name), FRAC, self)
+            }
+        }
+    )*};
+}
+
+fixed! {
+    ///
+    /// Fixed-point number backed by an `i32` with `FRAC` fractional bits.
+    ///
+    /// See module documentation for more information.
+    ///
+    fix32 i32 i64 u64,
+
+    ///
+    /// Fixed-point number backed by an `i64` with `FRAC` fractional bits.
+    ///
+    /// See module documentation for more information.
+    ///
+    fix64 i64 i128 u128
+}
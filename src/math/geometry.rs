@@ -0,0 +1,488 @@
+//!
+//! This module provides bounding volumes and queries between them
+//! for culling: [`Aabb`], [`Sphere`] and [`Frustum`].
+//!
+//! Every query classifies one shape against another as a [`Containment`],
+//! not just a `bool`: hierarchical culling skips tests of children
+//! of a node that is entirely [`Containment::Inside`].
+//!
+//! All the shapes are closed, i.e. they contain their boundaries,
+//! so shapes that touch exactly intersect.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use rokoko::prelude::*;
+//! use rokoko::math::geometry::{Aabb, Sphere, Frustum, Plane, Containment};
+//!
+//! // A box-shaped "frustum" of an orthographic camera: `-10 <= x, y, z <= 10`
+//! let planes = [
+//!     Plane::new(vec3::from([1.0, 0.0, 0.0]), 10.0),
+//!     Plane::new(vec3::from([-1.0, 0.0, 0.0]), 10.0),
+//!     Plane::new(vec3::from([0.0, 1.0, 0.0]), 10.0),
+//!     Plane::new(vec3::from([0.0, -1.0, 0.0]), 10.0),
+//!     Plane::new(vec3::from([0.0, 0.0, 1.0]), 10.0),
+//!     Plane::new(vec3::from([0.0, 0.0, -1.0]), 10.0)
+//! ];
+//! let frustum = Frustum::from_planes(planes);
+//!
+//! let node = Aabb::new(vec3::from([-20.0, 0.0, 0.0]), vec3::from([0.0, 5.0, 5.0]));
+//! assert_eq!(frustum.classify_aabb(&node), Containment::Intersects);
+//!
+//! // So children have to be tested
+//! let near = Sphere::new(vec3::from([-5.0, 2.0, 2.0]), 1.0);
+//! let far = Sphere::new(vec3::from([-15.0, 2.0, 2.0]), 1.0);
+//! assert_eq!(frustum.classify_sphere(&near), Containment::Inside);
+//! assert_eq!(frustum.classify_sphere(&far), Containment::Outside);
+//! ```
+//!
+
+use super::{
+    vec::vec,
+    scalar::Float
+};
+
+///
+/// How a shape relates to another one.
+///
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Containment {
+    /// The shapes do not have common points
+    Outside,
+
+    /// The shapes have common points, but the shape is not entirely inside
+    Intersects,
+
+    /// The shape is entirely inside the other one
+    Inside
+}
+
+impl Containment {
+    /// Returns `true` if the shapes have common points, i.e. unless [`Containment::Outside`]
+    #[inline]
+    pub const fn intersects(self) -> bool {
+        !matches!(self, Containment::Outside)
+    }
+}
+
+/// Returns sum of products of corresponding elements
+#[inline]
+fn dot <T: Float, const N: usize> (a: vec <T, N>, b: vec <T, N>) -> T {
+    let mut sum = T::ZERO;
+    for i in 0..N {
+        sum = sum + a[i] * b[i]
+    }
+    sum
+}
+
+///
+/// An axis-aligned bounding box.
+///
+/// `min` must not be greater than `max` in any dimension;
+/// `min == max` is allowed and describes a point.
+///
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Aabb <T: Copy, const N: usize> {
+    pub min: vec <T, N>,
+    pub max: vec <T, N>
+}
+
+impl <T: Float, const N: usize> Aabb <T, N> {
+    /// Creates a box from its minimal and maximal corners
+    #[inline]
+    pub const fn new(min: vec <T, N>, max: vec <T, N>) -> Self {
+        Self { min, max }
+    }
+
+    ///
+    /// Creates a box from any two opposite corners.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rokoko::prelude::*;
+    /// use rokoko::math::geometry::Aabb;
+    ///
+    /// let aabb = Aabb::from_corners(vec2::from([3.0, -1.0]), vec2::from([1.0, 2.0]));
+    /// assert_eq!(aabb, Aabb::new(vec2::from([1.0, -1.0]), vec2::from([3.0, 2.0])));
+    /// ```
+    ///
+    pub fn from_corners(a: vec <T, N>, b: vec <T, N>) -> Self {
+        Self {
+            min: a.apply_binary(b, |a, b| if a < b { a } else { b }),
+            max: a.apply_binary(b, |a, b| if a < b { b } else { a })
+        }
+    }
+
+    /// Returns `true` if `point` is inside the box
+    pub fn contains_point(&self, point: vec <T, N>) -> bool {
+        (0..N).all(|i| self.min[i] <= point[i] && point[i] <= self.max[i])
+    }
+
+    ///
+    /// Classifies `other` against `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rokoko::prelude::*;
+    /// use rokoko::math::geometry::{Aabb, Containment};
+    ///
+    /// let aabb = Aabb::new(vec2::from([0.0, 0.0]), vec2::from([4.0, 4.0]));
+    ///
+    /// let inner = Aabb::new(vec2::from([1.0, 1.0]), vec2::from([2.0, 2.0]));
+    /// assert_eq!(aabb.classify_aabb(&inner), Containment::Inside);
+    /// assert_eq!(inner.classify_aabb(&aabb), Containment::Intersects);
+    ///
+    /// // Touching exactly
+    /// let touching = Aabb::new(vec2::from([4.0, 0.0]), vec2::from([5.0, 1.0]));
+    /// assert_eq!(aabb.classify_aabb(&touching), Containment::Intersects);
+    ///
+    /// let apart = Aabb::new(vec2::from([4.5, 0.0]), vec2::from([5.0, 1.0]));
+    /// assert_eq!(aabb.classify_aabb(&apart), Containment::Outside);
+    ///
+    /// // Degenerate boxes are points
+    /// let point = Aabb::new(vec2::from([4.0, 4.0]), vec2::from([4.0, 4.0]));
+    /// assert_eq!(aabb.classify_aabb(&point), Containment::Inside);
+    /// assert_eq!(point.classify_aabb(&point), Containment::Inside);
+    /// assert_eq!(point.classify_aabb(&aabb), Containment::Intersects);
+    /// ```
+    ///
+    pub fn classify_aabb(&self, other: &Self) -> Containment {
+        if (0..N).any(|i| other.max[i] < self.min[i] || self.max[i] < other.min[i]) {
+            Containment::Outside
+        } else if self.contains_point(other.min) && self.contains_point(other.max) {
+            Containment::Inside
+        } else {
+            Containment::Intersects
+        }
+    }
+
+    ///
+    /// Classifies `sphere` against `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rokoko::prelude::*;
+    /// use rokoko::math::geometry::{Aabb, Sphere, Containment};
+    ///
+    /// let aabb = Aabb::new(vec2::from([0.0, 0.0]), vec2::from([4.0, 4.0]));
+    ///
+    /// assert_eq!(aabb.classify_sphere(&Sphere::new(vec2::from([2.0, 2.0]), 2.0)), Containment::Inside);
+    /// assert_eq!(aabb.classify_sphere(&Sphere::new(vec2::from([2.0, 2.0]), 2.5)), Containment::Intersects);
+    ///
+    /// // Touching the corner exactly
+    /// assert_eq!(aabb.classify_sphere(&Sphere::new(vec2::from([7.0, 8.0]), 5.0)), Containment::Intersects);
+    /// assert_eq!(aabb.classify_sphere(&Sphere::new(vec2::from([7.0, 8.0]), 4.9)), Containment::Outside);
+    ///
+    /// // Zero radius spheres are points
+    /// assert_eq!(aabb.classify_sphere(&Sphere::new(vec2::from([4.0, 1.0]), 0.0)), Containment::Inside);
+    /// assert_eq!(aabb.classify_sphere(&Sphere::new(vec2::from([5.0, 1.0]), 0.0)), Containment::Outside);
+    /// ```
+    ///
+    pub fn classify_sphere(&self, sphere: &Sphere <T, N>) -> Containment {
+        if self.distance_squared(sphere.center) > sphere.radius * sphere.radius {
+            Containment::Outside
+        } else if (0..N).all(|i| {
+            self.min[i] <= sphere.center[i] - sphere.radius && sphere.center[i] + sphere.radius <= self.max[i]
+        }) {
+            Containment::Inside
+        } else {
+            Containment::Intersects
+        }
+    }
+
+    /// Returns the squared distance from `point` to the closest point of the box
+    fn distance_squared(&self, point: vec <T, N>) -> T {
+        let mut sum = T::ZERO;
+        for i in 0..N {
+            let d = if point[i] < self.min[i] {
+                self.min[i] - point[i]
+            } else if point[i] > self.max[i] {
+                point[i] - self.max[i]
+            } else {
+                T::ZERO
+            };
+            sum = sum + d * d
+        }
+        sum
+    }
+}
+
+///
+/// A ball given by its center and radius.
+///
+/// `radius` must not be negative; `0` is allowed and describes a point.
+///
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Sphere <T: Copy, const N: usize> {
+    pub center: vec <T, N>,
+    pub radius: T
+}
+
+impl <T: Float, const N: usize> Sphere <T, N> {
+    /// Creates a sphere
+    #[inline]
+    pub const fn new(center: vec <T, N>, radius: T) -> Self {
+        Self { center, radius }
+    }
+
+    ///
+    /// Classifies `other` against `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rokoko::prelude::*;
+    /// use rokoko::math::geometry::{Sphere, Containment};
+    ///
+    /// let sphere = Sphere::new(vec3::single(0.0), 5.0);
+    ///
+    /// assert_eq!(sphere.classify_sphere(&Sphere::new(vec3::from([3.0, 0.0, 0.0]), 2.0)), Containment::Inside);
+    /// assert_eq!(sphere.classify_sphere(&Sphere::new(vec3::from([3.0, 0.0, 0.0]), 2.5)), Containment::Intersects);
+    /// assert_eq!(sphere.classify_sphere(&Sphere::new(vec3::from([0.0, 8.0, 0.0]), 3.0)), Containment::Intersects);
+    /// assert_eq!(sphere.classify_sphere(&Sphere::new(vec3::from([0.0, 8.0, 0.0]), 2.5)), Containment::Outside);
+    ///
+    /// // Zero radius spheres are points
+    /// let point = Sphere::new(vec3::from([0.0, 0.0, 5.0]), 0.0);
+    /// assert_eq!(sphere.classify_sphere(&point), Containment::Inside);
+    /// assert_eq!(point.classify_sphere(&point), Containment::Inside);
+    /// assert_eq!(point.classify_sphere(&sphere), Containment::Intersects);
+    /// ```
+    ///
+    pub fn classify_sphere(&self, other: &Self) -> Containment {
+        let d = self.center - other.center;
+        let distance_squared = dot(d, d);
+        let sum = self.radius + other.radius;
+        let difference = self.radius - other.radius;
+
+        if distance_squared > sum * sum {
+            Containment::Outside
+        } else if difference >= T::ZERO && distance_squared <= difference * difference {
+            Containment::Inside
+        } else {
+            Containment::Intersects
+        }
+    }
+
+    ///
+    /// Classifies `aabb` against `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rokoko::prelude::*;
+    /// use rokoko::math::geometry::{Aabb, Sphere, Containment};
+    ///
+    /// let sphere = Sphere::new(vec2::single(0.0), 5.0);
+    ///
+    /// // The farthest corner is exactly on the boundary
+    /// assert_eq!(sphere.classify_aabb(&Aabb::new(vec2::from([-3.0, -4.0]), vec2::from([3.0, 4.0]))), Containment::Inside);
+    /// assert_eq!(sphere.classify_aabb(&Aabb::new(vec2::from([-3.0, -4.0]), vec2::from([3.0, 4.5]))), Containment::Intersects);
+    /// assert_eq!(sphere.classify_aabb(&Aabb::new(vec2::from([5.0, -1.0]), vec2::from([6.0, 1.0]))), Containment::Intersects);
+    /// assert_eq!(sphere.classify_aabb(&Aabb::new(vec2::from([4.0, 4.0]), vec2::from([6.0, 6.0]))), Containment::Outside);
+    /// ```
+    ///
+    pub fn classify_aabb(&self, aabb: &Aabb <T, N>) -> Containment {
+        let radius_squared = self.radius * self.radius;
+        if aabb.distance_squared(self.center) > radius_squared {
+            return Containment::Outside
+        }
+
+        // The farthest corner decides
+        let mut farthest = T::ZERO;
+        for i in 0..N {
+            let to_min = (self.center[i] - aabb.min[i]).abs();
+            let to_max = (aabb.max[i] - self.center[i]).abs();
+            let d = if to_min > to_max { to_min } else { to_max };
+            farthest = farthest + d * d
+        }
+
+        if farthest <= radius_squared {
+            Containment::Inside
+        } else {
+            Containment::Intersects
+        }
+    }
+}
+
+///
+/// A plane of points `p` such that `dot(normal, p) + d == 0`.
+///
+/// Points with `dot(normal, p) + d >= 0` are considered inside,
+/// i.e. `normal` points inwards.
+///
+/// `normal` does not have to be normalized unless distances are needed,
+/// but then the same scale has to be used for `d`.
+///
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Plane <T: Copy> {
+    pub normal: vec <T, 3>,
+    pub d: T
+}
+
+impl <T: Float> Plane <T> {
+    /// Creates a plane
+    #[inline]
+    pub const fn new(normal: vec <T, 3>, d: T) -> Self {
+        Self { normal, d }
+    }
+
+    ///
+    /// Creates a plane passing through `point` with the inside towards `normal`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rokoko::prelude::*;
+    /// use rokoko::math::geometry::Plane;
+    ///
+    /// let floor = Plane::from_point_normal(vec3::from([0.0, 2.0, 0.0]), vec3::from([0.0, 1.0, 0.0]));
+    /// assert_eq!(floor.signed_distance(vec3::from([5.0, 3.0, -1.0])), 1.0);
+    /// assert_eq!(floor.signed_distance(vec3::from([5.0, 0.0, -1.0])), -2.0);
+    /// ```
+    ///
+    pub fn from_point_normal(point: vec <T, 3>, normal: vec <T, 3>) -> Self {
+        Self {
+            normal,
+            d: -dot(normal, point)
+        }
+    }
+
+    ///
+    /// Returns the distance from the plane to `point`, negative if it is outside.
+    ///
+    /// Scaled by the length of `normal`, if it is not normalized.
+    ///
+    #[inline]
+    pub fn signed_distance(&self, point: vec <T, 3>) -> T {
+        dot(self.normal, point) + self.d
+    }
+}
+
+///
+/// A convex volume bounded by six planes, e.g. the view volume of a camera.
+///
+/// Planes must point inwards, see [`Plane`].
+///
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Frustum <T: Copy> {
+    pub planes: [Plane <T>; 6]
+}
+
+impl <T: Float> Frustum <T> {
+    ///
+    /// Creates a frustum from its planes, in any order.
+    ///
+    #[inline]
+    pub const fn from_planes(planes: [Plane <T>; 6]) -> Self {
+        Self { planes }
+    }
+
+    ///
+    /// Classifies `aabb` against `self`.
+    ///
+    /// Uses the p-vertex test: for each plane only two corners are checked,
+    /// the one farthest along the normal(outside if it is outside) and the opposite one
+    /// (intersects if it is outside).
+    ///
+    /// The answer is conservative: a box that is outside but close to an edge
+    /// of the frustum may be reported as [`Containment::Intersects`], never the other way.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rokoko::prelude::*;
+    /// use rokoko::math::geometry::{Aabb, Frustum, Plane, Containment};
+    ///
+    /// // The unit cube
+    /// let axes = [vec3::from([1.0, 0.0, 0.0]), vec3::from([0.0, 1.0, 0.0]), vec3::from([0.0, 0.0, 1.0])];
+    /// let frustum = Frustum::from_planes([
+    ///     Plane::new(axes[0], 0.0), Plane::new(-axes[0], 1.0),
+    ///     Plane::new(axes[1], 0.0), Plane::new(-axes[1], 1.0),
+    ///     Plane::new(axes[2], 0.0), Plane::new(-axes[2], 1.0)
+    /// ]);
+    ///
+    /// let aabb = |min: [f32; 3], max: [f32; 3]| Aabb::new(vec3::from(min), vec3::from(max));
+    ///
+    /// assert_eq!(frustum.classify_aabb(&aabb([0.25; 3], [0.75; 3])), Containment::Inside);
+    /// assert_eq!(frustum.classify_aabb(&aabb([0.0; 3], [1.0; 3])), Containment::Inside);
+    /// assert_eq!(frustum.classify_aabb(&aabb([0.5; 3], [1.5; 3])), Containment::Intersects);
+    /// assert_eq!(frustum.classify_aabb(&aabb([1.0, 0.0, 0.0], [2.0, 1.0, 1.0])), Containment::Intersects);
+    /// assert_eq!(frustum.classify_aabb(&aabb([1.5; 3], [2.0; 3])), Containment::Outside);
+    ///
+    /// // Degenerate
+    /// assert_eq!(frustum.classify_aabb(&aabb([0.5; 3], [0.5; 3])), Containment::Inside);
+    /// ```
+    ///
+    pub fn classify_aabb(&self, aabb: &Aabb <T, 3>) -> Containment {
+        let mut result = Containment::Inside;
+        for plane in &self.planes {
+            let mut positive = aabb.min;
+            let mut negative = aabb.max;
+            for i in 0..3 {
+                if plane.normal[i] >= T::ZERO {
+                    positive[i] = aabb.max[i];
+                    negative[i] = aabb.min[i]
+                }
+            }
+
+            if plane.signed_distance(positive) < T::ZERO {
+                return Containment::Outside
+            }
+            if plane.signed_distance(negative) < T::ZERO {
+                result = Containment::Intersects
+            }
+        }
+        result
+    }
+
+    ///
+    /// Classifies `sphere` against `self`.
+    ///
+    /// Planes must be normalized.
+    ///
+    /// Just as [`Frustum::classify_aabb`], the answer is conservative near edges.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rokoko::prelude::*;
+    /// use rokoko::math::geometry::{Sphere, Frustum, Plane, Containment};
+    ///
+    /// // The unit cube
+    /// let axes = [vec3::from([1.0, 0.0, 0.0]), vec3::from([0.0, 1.0, 0.0]), vec3::from([0.0, 0.0, 1.0])];
+    /// let frustum = Frustum::from_planes([
+    ///     Plane::new(axes[0], 0.0), Plane::new(-axes[0], 1.0),
+    ///     Plane::new(axes[1], 0.0), Plane::new(-axes[1], 1.0),
+    ///     Plane::new(axes[2], 0.0), Plane::new(-axes[2], 1.0)
+    /// ]);
+    ///
+    /// let center = vec3::single(0.5);
+    /// assert_eq!(frustum.classify_sphere(&Sphere::new(center, 0.5)), Containment::Inside);
+    /// assert_eq!(frustum.classify_sphere(&Sphere::new(center, 0.6)), Containment::Intersects);
+    ///
+    /// // Touching exactly
+    /// let outside = vec3::from([1.5, 0.5, 0.5]);
+    /// assert_eq!(frustum.classify_sphere(&Sphere::new(outside, 0.5)), Containment::Intersects);
+    /// assert_eq!(frustum.classify_sphere(&Sphere::new(outside, 0.25)), Containment::Outside);
+    ///
+    /// // Zero radius
+    /// assert_eq!(frustum.classify_sphere(&Sphere::new(vec3::single(1.0), 0.0)), Containment::Inside);
+    /// ```
+    ///
+    pub fn classify_sphere(&self, sphere: &Sphere <T, 3>) -> Containment {
+        let mut result = Containment::Inside;
+        for plane in &self.planes {
+            let distance = plane.signed_distance(sphere.center);
+            if distance < -sphere.radius {
+                return Containment::Outside
+            }
+            if distance < sphere.radius {
+                result = Containment::Intersects
+            }
+        }
+        result
+    }
+}
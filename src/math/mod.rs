@@ -5,12 +5,22 @@
 //!
 //! This module is `#![no_std]`-friendly, i.e. it does not require `std`.
 //!
-
-use crate::*;
+//! Helpers that allocate, e.g. `vec::flatten`, require the `alloc` feature,
+//! which needs only the `alloc` crate.
+//!
 
 cfg_if::cfg_if! {
     if #[cfg(feature = "math")] {
+        pub mod scalar;
+
+        pub mod fixed;
+
+        pub mod angle;
+
         pub mod vec;
+
+        pub mod geometry;
+        pub mod solve;
     } else {
         /// Stub.
         pub mod vec {
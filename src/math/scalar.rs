@@ -0,0 +1,513 @@
+//!
+//! This module provides traits describing numbers `vec` can work with.
+//!
+//! Numeric features of `vec`(length, interpolation, etc.) are built on these traits
+//! instead of spelling out operator bounds one by one, so that all of them share
+//! the very same set of requirements.
+//!
+//! # Hierarchy
+//!
+//! - [`Scalar`] - a number: `Copy`, `PartialOrd`, arithmetic operators, `ZERO` and `ONE`.
+//! - [`SignedScalar`] - a [`Scalar`] that can be negated.
+//! - [`IntScalar`] - a primitive integer.
+//! - [`Float`] - a primitive floating-point number.
+//! - [`Abs`] - a signed primitive, with an absolute value computable in `const` contexts.
+//! - [`TotalOrd`] - a primitive with a total order, floats included.
+//!
+//! [`Scalar`] and [`SignedScalar`] are open - implement them on your own number type
+//! (fixed-point, rationals, units, etc.) and it will work with `vec` just like primitives do.
+//!
+//! [`IntScalar`], [`Float`], [`Abs`] and [`TotalOrd`] are sealed, since they describe properties of primitives only.
+//!
+//! # Float backend
+//!
+//! Functions like `sqrt` or `sin` are not available in `core`,
+//! so [`Float`] is implemented only if either
+//! - `std` is available(e.g. `window` feature is enabled), or
+//! - `libm` feature is enabled.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use rokoko::prelude::*;
+//! use rokoko::math::scalar::Scalar;
+//! use std::ops::*;
+//!
+//! /// A custom number type
+//! #[derive(Debug, Copy, Clone, PartialEq, PartialOrd)]
+//! struct Meters(f32);
+//!
+//! impl Add for Meters {
+//!     type Output = Self;
+//!     fn add(self, rhs: Self) -> Self { Meters(self.0 + rhs.0) }
+//! }
+//!
+//! impl Sub for Meters {
+//!     type Output = Self;
+//!     fn sub(self, rhs: Self) -> Self { Meters(self.0 - rhs.0) }
+//! }
+//!
+//! impl Mul for Meters {
+//!     type Output = Self;
+//!     fn mul(self, rhs: Self) -> Self { Meters(self.0 * rhs.0) }
+//! }
+//!
+//! impl Div for Meters {
+//!     type Output = Self;
+//!     fn div(self, rhs: Self) -> Self { Meters(self.0 / rhs.0) }
+//! }
+//!
+//! impl Scalar for Meters {
+//!     const ZERO: Self = Meters(0.0);
+//!     const ONE: Self = Meters(1.0);
+//! }
+//!
+//! let a = vec::from_array([Meters(1.0), Meters(2.5)]);
+//!
+//! // Operators work as with primitives
+//! assert_eq!(a + vec::ONE, vec::from_array([Meters(2.0), Meters(3.5)]));
+//! assert_eq!(a * Meters(2.0), vec::from_array([Meters(2.0), Meters(5.0)]));
+//! assert_eq!(a - a, vec::ZERO);
+//!
+//! // And so do helpers
+//! assert_eq!(a.apply_binary(vec::ONE, |a, b| a / b), a);
+//! ```
+//!
+
+use crate::nightly;
+use core::ops::{Add, Sub, Mul, Div, Rem, Neg};
+use core::cmp::Ordering;
+
+///
+/// A number.
+///
+/// See module documentation for more information.
+///
+pub trait Scalar: Copy + PartialOrd
+    + Add <Output = Self>
+    + Sub <Output = Self>
+    + Mul <Output = Self>
+    + Div <Output = Self> {
+    /// Additive identity
+    const ZERO: Self;
+
+    /// Multiplicative identity
+    const ONE: Self;
+}
+
+///
+/// A number that can be negated.
+///
+pub trait SignedScalar: Scalar + Neg <Output = Self> {
+    ///
+    /// Returns the absolute value of `self`.
+    ///
+    /// Default implementation negates `self` if it is less than `ZERO`.
+    ///
+    #[inline]
+    fn abs(self) -> Self {
+        if self < Self::ZERO {
+            -self
+        } else {
+            self
+        }
+    }
+}
+
+///
+/// A signed primitive integer or float, see [`vec::abs`](super::vec::vec::abs).
+///
+/// Same as [`SignedScalar::abs`] of the primitives, but `const` when `nightly` feature is enabled,
+/// which the open [`SignedScalar`] cannot promise.
+///
+/// This trait is sealed.
+///
+#[cfg_attr(nightly, const_trait)]
+pub trait Abs: Copy + private::Sealed {
+    ///
+    /// Returns the absolute value of `self`.
+    ///
+    /// Integers overflow for `MIN`, just as their own `abs` does,
+    /// while floats get their sign bit cleared, so `-0.0` becomes `0.0` and `NaN`s stay `NaN`s.
+    ///
+    fn abs(self) -> Self;
+}
+
+///
+/// A primitive with a total order, see [`vec::cmp_by_axis`](super::vec::vec::cmp_by_axis).
+///
+/// Integers are ordered by value, floats as by their own `total_cmp`:
+/// negative `NaN`s, `-inf`, negative numbers, `-0.0`, `0.0`, positive numbers, `inf`, positive `NaN`s.
+///
+/// This trait is sealed.
+///
+pub trait TotalOrd: Copy + private::Sealed {
+    /// Compares `self` and `other` in the total order
+    fn total_cmp(&self, other: &Self) -> Ordering;
+
+    ///
+    /// Compares the squared distances from `origin` to the points `a` and `b`,
+    /// given as elements of the same length.
+    ///
+    /// Integers are compared exactly, in an arithmetic wide enough not to overflow.
+    /// Floats are compared by `total_cmp`, with `NaN` distances greater than any other.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rokoko::math::scalar::TotalOrd;
+    /// use std::cmp::Ordering;
+    ///
+    /// // Both squared distances overflow `i64`, but not the comparison
+    /// assert_eq!(i64::cmp_distances_squared(&[i64::MAX, i64::MAX], &[i64::MIN, 0], &[0, 0]), Ordering::Greater);
+    /// assert_eq!(f32::cmp_distances_squared(&[f32::NAN], &[f32::INFINITY], &[0.0]), Ordering::Greater);
+    /// ```
+    ///
+    fn cmp_distances_squared(a: &[Self], b: &[Self], origin: &[Self]) -> Ordering;
+}
+
+///
+/// Returns `x * x` as its high and low halves.
+///
+fn square_wide(x: u128) -> (u128, u128) {
+    let (hi, lo) = (x >> 64, x & u64::MAX as u128);
+    let cross = hi * lo;
+    // x * x = hi * hi * 2^128 + cross * 2^65 + lo * lo
+    let (low, carry) = (lo * lo).overflowing_add(cross << 65);
+    (hi * hi + (cross >> 63) + carry as u128, low)
+}
+
+///
+/// Returns the sum of squared distances between corresponding elements,
+/// as the overflow count, the high and the low halves of a 256-bit sum, which compare lexicographically.
+///
+fn distance_squared_wide <T: IntScalar> (point: &[T], origin: &[T]) -> [u128; 3] {
+    let mut sum = [0u128; 3];
+    for (&e, &o) in point.iter().zip(origin) {
+        let (high, low) = square_wide(e.ordered_bits().abs_diff(o.ordered_bits()));
+        let (low, low_carry) = sum[2].overflowing_add(low);
+        let (high, high_carry) = sum[1].overflowing_add(high);
+        let (high, carry) = high.overflowing_add(low_carry as u128);
+        sum = [sum[0] + high_carry as u128 + carry as u128, high, low]
+    }
+    sum
+}
+
+///
+/// A primitive integer.
+///
+/// This trait is sealed.
+///
+pub trait IntScalar: Scalar + Ord + Rem <Output = Self> + private::Sealed {
+    /// The smallest value of the type
+    const MIN: Self;
+
+    /// The largest value of the type
+    const MAX: Self;
+
+    /// Size of the type in bits
+    const BITS: u32;
+
+    ///
+    /// Returns `self - MIN` as `u128`, i.e. the bits of `self` with the sign bit flipped for signed types,
+    /// which are ordered the same way the values are.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rokoko::math::scalar::IntScalar;
+    ///
+    /// assert_eq!(i8::MIN.ordered_bits(), 0);
+    /// assert_eq!((-1i8).ordered_bits(), 127);
+    /// assert_eq!(0i8.ordered_bits(), 128);
+    /// assert_eq!(7u32.ordered_bits(), 7);
+    /// ```
+    ///
+    fn ordered_bits(self) -> u128;
+}
+
+///
+/// A primitive floating-point number.
+///
+/// This trait is sealed.
+///
+/// See module documentation about availability.
+///
+/// # Examples
+///
+/// ```rust
+/// use rokoko::math::scalar::Float;
+///
+/// fn hypot <T: Float> (a: T, b: T) -> T {
+///     (a * a + b * b).sqrt()
+/// }
+///
+/// assert_eq!(hypot(3.0f32, 4.0), 5.0);
+/// assert_eq!(hypot(5.0f64, 12.0), 13.0);
+/// ```
+///
+pub trait Float: SignedScalar + private::Sealed {
+    /// Machine epsilon
+    const EPSILON: Self;
+
+    /// Positive infinity
+    const INFINITY: Self;
+
+    /// Negative infinity
+    const NEG_INFINITY: Self;
+
+    /// Not a Number
+    const NAN: Self;
+
+    /// Archimedes' constant
+    const PI: Self;
+
+    /// Returns `true` if `self` is `NaN`
+    fn is_nan(self) -> bool;
+
+    /// Returns the square root of `self`
+    fn sqrt(self) -> Self;
+
+    /// Returns the sine of `self`(in radians)
+    fn sin(self) -> Self;
+
+    /// Returns the cosine of `self`(in radians)
+    fn cos(self) -> Self;
+
+    /// Returns the tangent of `self`(in radians)
+    fn tan(self) -> Self;
+
+    /// Returns the arcsine of `self`(in radians)
+    fn asin(self) -> Self;
+
+    /// Returns the arccosine of `self`(in radians)
+    fn acos(self) -> Self;
+
+    /// Returns the arctangent of `self`(in radians)
+    fn atan(self) -> Self;
+
+    /// Returns the four quadrant arctangent of `self`(`y`) and `other`(`x`) in radians
+    fn atan2(self, other: Self) -> Self;
+
+    /// Returns `e^self`
+    fn exp(self) -> Self;
+
+    /// Returns the natural logarithm of `self`
+    fn ln(self) -> Self;
+
+    /// Returns `self^n`
+    fn powf(self, n: Self) -> Self;
+
+    /// Returns the largest integer less than or equal to `self`
+    fn floor(self) -> Self;
+
+    /// Returns the smallest integer greater than or equal to `self`
+    fn ceil(self) -> Self;
+
+    /// Returns the nearest integer to `self`, rounding half-way cases away from `0.0`
+    fn round(self) -> Self;
+
+    /// Returns the integer part of `self`
+    fn trunc(self) -> Self;
+
+    /// Returns a number with the magnitude of `self` and the sign of `sign`, `-0.0` and `NaN`s included
+    fn copysign(self, sign: Self) -> Self;
+
+    /// Converts `self` from degrees to radians
+    fn to_radians(self) -> Self;
+
+    /// Converts `self` from radians to degrees
+    fn to_degrees(self) -> Self;
+}
+
+mod private {
+    /// Prevents [`super::IntScalar`], [`super::Float`], [`super::Abs`] and [`super::TotalOrd`] from being implemented outside of the crate
+    pub trait Sealed {}
+}
+
+macro_rules! int {
+    (@ $( $t:ident )*) => {$(
+        impl private::Sealed for $t {}
+
+        impl Scalar for $t {
+            const ZERO: Self = 0;
+            const ONE: Self = 1;
+        }
+
+        impl IntScalar for $t {
+            const MIN: Self = $t::MIN;
+            const MAX: Self = $t::MAX;
+            const BITS: u32 = $t::BITS;
+
+            #[inline]
+            fn ordered_bits(self) -> u128 {
+                // Sign-extended, so this is `self - MIN` modulo 2^128 for signed types too
+                (self as u128).wrapping_sub($t::MIN as u128)
+            }
+        }
+
+        impl TotalOrd for $t {
+            #[inline]
+            fn total_cmp(&self, other: &Self) -> Ordering {
+                self.cmp(other)
+            }
+
+            #[inline]
+            fn cmp_distances_squared(a: &[Self], b: &[Self], origin: &[Self]) -> Ordering {
+                distance_squared_wide(a, origin).cmp(&distance_squared_wide(b, origin))
+            }
+        }
+    )*};
+
+    (signed $( $t:ident )*) => {
+        int!(@ $( $t )*);
+
+        $(
+            impl SignedScalar for $t {
+                #[inline]
+                fn abs(self) -> Self {
+                    $t::abs(self)
+                }
+            }
+
+            #[nightly(const)]
+            impl Abs for $t {
+                #[inline]
+                fn abs(self) -> Self {
+                    $t::abs(self)
+                }
+            }
+        )*
+    };
+
+    (unsigned $( $t:ident )*) => {
+        int!(@ $( $t )*);
+    };
+}
+
+int!(signed i8 i16 i32 i64 i128 isize);
+int!(unsigned u8 u16 u32 u64 u128 usize);
+
+///
+/// Implements `Float` for a primitive.
+///
+/// Every function is routed either to `std`(if available) or to `libm`
+/// under the name specified after `=>`.
+///
+macro_rules! float {
+    ($t:ident $bits:ident { $( $func:ident ( $( $arg:ident ),* ) => $libm:ident ),* }) => {
+        impl private::Sealed for $t {}
+
+        impl Scalar for $t {
+            const ZERO: Self = 0.0;
+            const ONE: Self = 1.0;
+        }
+
+        impl SignedScalar for $t {
+            ///
+            /// Clears the sign bit, so `-0.0` becomes `0.0`
+            /// and `NaN`s stay `NaN`s.
+            ///
+            #[inline]
+            fn abs(self) -> Self {
+                $t::from_bits(self.to_bits() & !(1 << ($bits::BITS - 1)))
+            }
+        }
+
+        #[nightly(const)]
+        impl Abs for $t {
+            #[inline]
+            fn abs(self) -> Self {
+                // Not `from_bits` and `to_bits`, which are not `const` yet
+                // SAFETY: `$t` and `$bits` have the same size, and every bit pattern is valid for both
+                unsafe { core::mem::transmute::<$bits, $t>(core::mem::transmute::<$t, $bits>(self) & !(1 << ($bits::BITS - 1))) }
+            }
+        }
+
+        impl TotalOrd for $t {
+            #[inline]
+            fn total_cmp(&self, other: &Self) -> Ordering {
+                $t::total_cmp(self, other)
+            }
+
+            fn cmp_distances_squared(a: &[Self], b: &[Self], origin: &[Self]) -> Ordering {
+                let distance = |point: &[Self]| point.iter().zip(origin).map(|(&e, &o)| (e - o) * (e - o)).sum::<Self>();
+                let (a, b) = (distance(a), distance(b));
+                a.is_nan().cmp(&b.is_nan()).then_with(|| a.total_cmp(&b))
+            }
+        }
+
+        #[cfg(any(std, feature = "libm"))]
+        impl Float for $t {
+            const EPSILON: Self = $t::EPSILON;
+            const INFINITY: Self = $t::INFINITY;
+            const NEG_INFINITY: Self = $t::NEG_INFINITY;
+            const NAN: Self = $t::NAN;
+            const PI: Self = core::$t::consts::PI;
+
+            #[inline]
+            fn is_nan(self) -> bool {
+                $t::is_nan(self)
+            }
+
+            #[inline]
+            fn to_radians(self) -> Self {
+                $t::to_radians(self)
+            }
+
+            #[inline]
+            fn to_degrees(self) -> Self {
+                $t::to_degrees(self)
+            }
+
+            $(
+                #[inline]
+                fn $func(self $(, $arg: Self )*) -> Self {
+                    #[cfg(std)]
+                    return $t::$func(self $(, $arg )*);
+
+                    #[cfg(not(std))]
+                    return libm::$libm(self $(, $arg )*);
+                }
+            )*
+        }
+    };
+}
+
+float!(f32 u32 {
+    sqrt() => sqrtf,
+    sin() => sinf,
+    cos() => cosf,
+    tan() => tanf,
+    asin() => asinf,
+    acos() => acosf,
+    atan() => atanf,
+    atan2(other) => atan2f,
+    exp() => expf,
+    ln() => logf,
+    powf(n) => powf,
+    floor() => floorf,
+    ceil() => ceilf,
+    round() => roundf,
+    trunc() => truncf,
+    copysign(sign) => copysignf
+});
+
+float!(f64 u64 {
+    sqrt() => sqrt,
+    sin() => sin,
+    cos() => cos,
+    tan() => tan,
+    asin() => asin,
+    acos() => acos,
+    atan() => atan,
+    atan2(other) => atan2,
+    exp() => exp,
+    ln() => log,
+    powf(n) => pow,
+    floor() => floor,
+    ceil() => ceil,
+    round() => round,
+    trunc() => trunc,
+    copysign(sign) => copysign
+});
@@ -0,0 +1,270 @@
+//!
+//! This module provides solvers of tiny linear systems, 2x2 and 3x3,
+//! with matrices given as arrays of vecs, e.g. for collision and intersection code.
+//!
+//! # Conventions
+//!
+//! A matrix is an array of its **rows**, so `a[i][j]` is the element
+//! of the `i`-th row and `j`-th column, and the system `a * x = b` means
+//! `dot(a[i], x) == b[i]` for every `i`.
+//!
+//! Determinants follow the usual sign convention: the identity has `1`,
+//! swapping two rows flips the sign, and `det3` of rows `X`, `Y`, `Z` of
+//! a right-handed basis is positive.
+//!
+//! # Singularity
+//!
+//! A matrix is considered singular if `|det| <= EPSILON * (|a[0]| * |a[1]| * ...)`,
+//! where `|a[i]|` is the sum of absolute values of the row. The product bounds `|det|`
+//! from above, so the check does not depend on the scale of the rows,
+//! only on how close they are to being linearly dependent.
+//! NaNs are singular as well.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use rokoko::prelude::*;
+//! use rokoko::math::solve::{solve2, inverse2, det2};
+//!
+//! // x + y = 3
+//! // x - y = 1
+//! let a = [dvec2::from([1.0, 1.0]), dvec2::from([1.0, -1.0])];
+//! assert_eq!(det2(a), -2.0);
+//! assert_eq!(solve2(a, dvec2::from([3.0, 1.0])), Some(dvec2::from([2.0, 1.0])));
+//! assert_eq!(inverse2(a), Some([dvec2::from([0.5, 0.5]), dvec2::from([0.5, -0.5])]));
+//!
+//! // Parallel lines never intersect
+//! let parallel = [dvec2::from([1.0, 2.0]), dvec2::from([2.0, 4.0])];
+//! assert_eq!(solve2(parallel, dvec2::from([1.0, 1.0])), None);
+//! assert_eq!(inverse2(parallel), None);
+//! ```
+//!
+
+use super::{
+    vec::vec,
+    scalar::Float
+};
+
+/// Returns the sum of absolute values of the elements
+#[inline]
+fn l1 <T: Float, const N: usize> (v: vec <T, N>) -> T {
+    let mut sum = T::ZERO;
+    for i in 0..N {
+        sum = sum + v[i].abs()
+    }
+    sum
+}
+
+/// Returns `true` if `det` is too small for rows of the given norms, see [module documentation](self)
+#[inline]
+fn singular <T: Float, const N: usize> (det: T, rows: [vec <T, N>; N]) -> bool {
+    let mut bound = T::ONE;
+    for row in rows {
+        bound = bound * l1(row)
+    }
+    det.is_nan() || det.abs() <= T::EPSILON * bound
+}
+
+/// Returns the cross product of `a` and `b`
+#[inline]
+fn cross <T: Float> (a: vec <T, 3>, b: vec <T, 3>) -> vec <T, 3> {
+    vec::from([
+        a[1] * b[2] - a[2] * b[1],
+        a[2] * b[0] - a[0] * b[2],
+        a[0] * b[1] - a[1] * b[0]
+    ])
+}
+
+/// Returns sum of products of corresponding elements
+#[inline]
+fn dot <T: Float> (a: vec <T, 3>, b: vec <T, 3>) -> T {
+    a[0] * b[0] + a[1] * b[1] + a[2] * b[2]
+}
+
+/// Returns `a` with the `j`-th column replaced by `b`
+#[inline]
+fn with_column <T: Float, const N: usize> (mut a: [vec <T, N>; N], j: usize, b: vec <T, N>) -> [vec <T, N>; N] {
+    for i in 0..N {
+        a[i][j] = b[i]
+    }
+    a
+}
+
+///
+/// Returns the determinant of a 2x2 matrix of rows `a`.
+///
+/// # Examples
+///
+/// ```rust
+/// use rokoko::prelude::*;
+/// use rokoko::math::solve::det2;
+///
+/// let x = vec2::from([1.0, 0.0]);
+/// let y = vec2::from([0.0, 1.0]);
+///
+/// assert_eq!(det2([x, y]), 1.0);
+/// assert_eq!(det2([y, x]), -1.0);
+/// // The signed area of the parallelogram
+/// assert_eq!(det2([x * 3.0, x + y * 2.0]), 6.0);
+/// assert_eq!(det2([x, x]), 0.0);
+/// ```
+///
+#[inline]
+pub fn det2 <T: Float> (a: [vec <T, 2>; 2]) -> T {
+    a[0][0] * a[1][1] - a[0][1] * a[1][0]
+}
+
+///
+/// Returns the determinant of a 3x3 matrix of rows `a`,
+/// the same as the triple product `dot(a[0], cross(a[1], a[2]))`.
+///
+/// # Examples
+///
+/// ```rust
+/// use rokoko::prelude::*;
+/// use rokoko::math::solve::det3;
+///
+/// let (x, y, z) = (dvec3::UNIT_X, dvec3::UNIT_Y, dvec3::UNIT_Z);
+///
+/// assert_eq!(det3([x, y, z]), 1.0);
+/// assert_eq!(det3([y, x, z]), -1.0);
+/// // Cyclic shifts keep the sign
+/// assert_eq!(det3([z, x, y]), 1.0);
+/// // The signed volume of the parallelepiped
+/// assert_eq!(det3([x * 2.0, y * 3.0, x + y + z * 4.0]), 24.0);
+/// assert_eq!(det3([x, y, x + y]), 0.0);
+/// ```
+///
+#[inline]
+pub fn det3 <T: Float> (a: [vec <T, 3>; 3]) -> T {
+    dot(a[0], cross(a[1], a[2]))
+}
+
+///
+/// Solves `a * x = b` for a 2x2 matrix of rows `a` by Cramer's rule.
+///
+/// Returns `None` if `a` is singular, see [module documentation](self).
+///
+/// # Examples
+///
+/// ```rust
+/// use rokoko::prelude::*;
+/// use rokoko::math::solve::solve2;
+///
+/// // 2x + 3y = 8
+/// // 4x - 1y = 2
+/// let a = [vec2::from([2.0, 3.0]), vec2::from([4.0, -1.0])];
+/// assert_eq!(solve2(a, vec2::from([8.0, 2.0])), Some(vec2::from([1.0, 2.0])));
+///
+/// // The threshold: rows `[1, 0]` and `[1, t]` are singular up to `t == EPSILON`
+/// let nearly = |t: f32| [vec2::from([1.0, 0.0]), vec2::from([1.0, t])];
+/// assert_eq!(solve2(nearly(f32::EPSILON), vec2::from([1.0, 1.0])), None);
+/// assert_eq!(solve2(nearly(2.0 * f32::EPSILON), vec2::from([1.0, 1.0])), Some(vec2::from([1.0, 0.0])));
+///
+/// // Independent of the scale of the rows
+/// let tiny = [dvec2::from([1e-100, 0.0]), dvec2::from([0.0, 1e-100])];
+/// assert_eq!(solve2(tiny, dvec2::from([1e-100, 2e-100])), Some(dvec2::from([1.0, 2.0])));
+///
+/// // Zeros and NaNs
+/// assert_eq!(solve2([vec2::single(0.0); 2], vec2::single(0.0)), None);
+/// assert_eq!(solve2([vec2::single(f32::NAN), vec2::from([0.0, 1.0])], vec2::single(0.0)), None);
+/// ```
+///
+pub fn solve2 <T: Float> (a: [vec <T, 2>; 2], b: vec <T, 2>) -> Option <vec <T, 2>> {
+    let det = det2(a);
+    if singular(det, a) {
+        return None
+    }
+    Some(vec::from([
+        det2(with_column(a, 0, b)) / det,
+        det2(with_column(a, 1, b)) / det
+    ]))
+}
+
+///
+/// Solves `a * x = b` for a 3x3 matrix of rows `a` by Cramer's rule.
+///
+/// Returns `None` if `a` is singular, see [module documentation](self).
+///
+/// # Examples
+///
+/// ```rust
+/// use rokoko::prelude::*;
+/// use rokoko::math::solve::solve3;
+///
+/// // The intersection of three planes: x + y + z = 6, y = 2, x - z = -2
+/// let a = [dvec3::from([1.0, 1.0, 1.0]), dvec3::from([0.0, 1.0, 0.0]), dvec3::from([1.0, 0.0, -1.0])];
+/// assert_eq!(solve3(a, dvec3::from([6.0, 2.0, -2.0])), Some(dvec3::from([1.0, 2.0, 3.0])));
+///
+/// // Planes sharing a line
+/// let a = [dvec3::from([1.0, 0.0, 0.0]), dvec3::from([0.0, 1.0, 0.0]), dvec3::from([1.0, 1.0, 0.0])];
+/// assert_eq!(solve3(a, dvec3::from([1.0, 1.0, 2.0])), None);
+/// ```
+///
+pub fn solve3 <T: Float> (a: [vec <T, 3>; 3], b: vec <T, 3>) -> Option <vec <T, 3>> {
+    let det = det3(a);
+    if singular(det, a) {
+        return None
+    }
+    Some(vec::from([
+        det3(with_column(a, 0, b)) / det,
+        det3(with_column(a, 1, b)) / det,
+        det3(with_column(a, 2, b)) / det
+    ]))
+}
+
+///
+/// Returns the inverse of a 2x2 matrix of rows `a`, as rows too.
+///
+/// Returns `None` if `a` is singular, see [module documentation](self).
+///
+/// # Examples
+///
+/// ```rust
+/// use rokoko::prelude::*;
+/// use rokoko::math::solve::inverse2;
+///
+/// let a = [vec2::from([4.0, 7.0]), vec2::from([2.0, 6.0])];
+/// assert_eq!(inverse2(a), Some([vec2::from([0.6, -0.7]), vec2::from([-0.2, 0.4])]));
+/// assert_eq!(inverse2([vec2::from([1.0, 2.0]), vec2::from([-0.5, -1.0])]), None);
+/// ```
+///
+pub fn inverse2 <T: Float> (a: [vec <T, 2>; 2]) -> Option <[vec <T, 2>; 2]> {
+    let det = det2(a);
+    if singular(det, a) {
+        return None
+    }
+    Some([
+        vec::from([a[1][1] / det, -a[0][1] / det]),
+        vec::from([-a[1][0] / det, a[0][0] / det])
+    ])
+}
+
+///
+/// Returns the inverse of a 3x3 matrix of rows `a`, as rows too.
+///
+/// Returns `None` if `a` is singular, see [module documentation](self).
+///
+/// # Examples
+///
+/// ```rust
+/// use rokoko::prelude::*;
+/// use rokoko::math::solve::inverse3;
+///
+/// // Scaling and a shear
+/// let a = [dvec3::from([2.0, 0.0, 0.0]), dvec3::from([0.0, 4.0, 0.0]), dvec3::from([1.0, 0.0, 1.0])];
+/// assert_eq!(inverse3(a), Some([dvec3::from([0.5, 0.0, 0.0]), dvec3::from([0.0, 0.25, 0.0]), dvec3::from([-0.5, 0.0, 1.0])]));
+///
+/// assert_eq!(inverse3([dvec3::UNIT_X, dvec3::UNIT_Y, dvec3::UNIT_X * -3.0]), None);
+/// ```
+///
+pub fn inverse3 <T: Float> (a: [vec <T, 3>; 3]) -> Option <[vec <T, 3>; 3]> {
+    let det = det3(a);
+    if singular(det, a) {
+        return None
+    }
+    // Columns of the inverse, i.e. of the adjugate divided by `det`
+    let columns = [cross(a[1], a[2]), cross(a[2], a[0]), cross(a[0], a[1])];
+    let row = |i: usize| vec::from([columns[0][i] / det, columns[1][i] / det, columns[2][i] / det]);
+    Some([row(0), row(1), row(2)])
+}
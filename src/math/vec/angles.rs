@@ -0,0 +1,226 @@
+//!
+//! This module provides helpers for vecs of angles(in radians),
+//! e.g. Euler angles stored per-axis.
+//!
+//! Prefer the ones on `vec <Rad <T>, N>`, where the unit is part of the type,
+//! see [`angle`](crate::math::angle). Vecs of [`Deg`] convert to them with `into`.
+//!
+//! Naive arithmetic breaks at the `±π` seam: lerping from `170°` to `-170°`
+//! goes the long way through `0°`. These helpers always take the shortest arc.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use rokoko::prelude::*;
+//! use std::f32::consts::PI;
+//!
+//! let a = vec3::from([PI - 0.1, 0.0, 0.0]);
+//! let b = vec3::from([-PI + 0.1, 0.0, 0.0]);
+//!
+//! // Naive lerp crosses zero
+//! assert!(((a + (b - a) * 0.5)[0]).abs() < 1e-6);
+//!
+//! // While this one lands on the seam
+//! assert!((a.lerp_angles(b, 0.5)[0] - PI).abs() < 1e-6);
+//! ```
+//!
+
+use super::vec;
+use crate::math::{
+    scalar::Float,
+    angle::{Deg, Rad}
+};
+
+/// Wraps `x` to `(-π, π]`
+#[inline]
+fn wrap <T: Float> (x: T) -> T {
+    let two_pi = T::PI + T::PI;
+    let wrapped = x - two_pi * ((x + T::PI) / two_pi).floor();
+    if wrapped <= -T::PI {
+        wrapped + two_pi
+    } else {
+        wrapped
+    }
+}
+
+impl <T: Float, const N: usize> vec <T, N> {
+    ///
+    /// Wraps each angle to `(-π, π]`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rokoko::prelude::*;
+    /// use std::f64::consts::PI;
+    ///
+    /// let wrapped = dvec3::from([3.0 * PI, -PI, 0.5]).wrap_angles();
+    ///
+    /// assert!((wrapped[0] - PI).abs() < 1e-12);
+    /// assert!((wrapped[1] - PI).abs() < 1e-12);
+    /// assert_eq!(wrapped[2], 0.5);
+    ///
+    /// // Idempotent
+    /// assert_eq!(wrapped.wrap_angles(), wrapped);
+    /// ```
+    ///
+    #[inline]
+    pub fn wrap_angles(self) -> Self {
+        self.apply_unary(wrap)
+    }
+
+    ///
+    /// Returns the shortest signed rotation from `self` to `other` for each angle,
+    /// each in `(-π, π]`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rokoko::prelude::*;
+    /// use std::f32::consts::PI;
+    ///
+    /// let a = vec2::from([PI - 0.1, 0.3]);
+    /// let b = vec2::from([-PI + 0.1, 0.1]);
+    ///
+    /// let difference = a.angle_difference(b);
+    ///
+    /// // Across the seam: small and positive
+    /// assert!((difference[0] - 0.2).abs() < 1e-5);
+    /// // Otherwise just the difference
+    /// assert!((difference[1] + 0.2).abs() < 1e-6);
+    ///
+    /// // And backwards
+    /// assert!((b.angle_difference(a)[0] + 0.2).abs() < 1e-5);
+    /// ```
+    ///
+    #[inline]
+    pub fn angle_difference(self, other: Self) -> Self {
+        other.apply_binary(self, |b, a| wrap(b - a))
+    }
+
+    ///
+    /// Interpolates each angle from `self` to `other` along the shortest arc,
+    /// the result is wrapped to `(-π, π]`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rokoko::prelude::*;
+    /// use std::f32::consts::PI;
+    ///
+    /// let a = vec2::from([PI - 0.2, 0.0]);
+    /// let b = vec2::from([-PI + 0.2, 1.0]);
+    ///
+    /// let mid = a.lerp_angles(b, 0.5);
+    /// assert!((mid[0] - PI).abs() < 1e-5);
+    /// assert!((mid[1] - 0.5).abs() < 1e-6);
+    ///
+    /// assert_eq!(a.lerp_angles(b, 0.0), a);
+    /// assert!((a.lerp_angles(b, 1.0)[0] - b[0]).abs() < 1e-5);
+    /// ```
+    ///
+    #[inline]
+    pub fn lerp_angles(self, other: Self, t: T) -> Self {
+        self.apply_binary(self.angle_difference(other), |a, d| wrap(a + d * t))
+    }
+
+    ///
+    /// Converts each element from degrees to radians.
+    ///
+    /// Prefer `vec <Deg <T>, N>` and `vec <Rad <T>, N>` where the unit is not obvious.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rokoko::prelude::*;
+    /// use std::f64::consts::PI;
+    ///
+    /// let v = dvec3::from([180.0, -90.0, 0.0]);
+    /// assert_eq!(v.to_radians(), dvec3::from([PI, -PI / 2.0, 0.0]));
+    /// assert_eq!(v.to_radians().to_degrees(), v);
+    /// ```
+    ///
+    #[inline]
+    pub fn to_radians(self) -> Self {
+        self.apply_unary(T::to_radians)
+    }
+
+    ///
+    /// Converts each element from radians to degrees.
+    ///
+    /// Prefer `vec <Deg <T>, N>` and `vec <Rad <T>, N>` where the unit is not obvious.
+    ///
+    #[inline]
+    pub fn to_degrees(self) -> Self {
+        self.apply_unary(T::to_degrees)
+    }
+}
+
+impl <T: Float, const N: usize> From <vec <Deg <T>, N>> for vec <Rad <T>, N> {
+    #[inline]
+    fn from(x: vec <Deg <T>, N>) -> Self {
+        x.apply_unary(Rad::from)
+    }
+}
+
+impl <T: Float, const N: usize> From <vec <Rad <T>, N>> for vec <Deg <T>, N> {
+    #[inline]
+    fn from(x: vec <Rad <T>, N>) -> Self {
+        x.apply_unary(Deg::from)
+    }
+}
+
+// The same helpers with the unit in the type
+impl <T: Float, const N: usize> vec <Rad <T>, N> {
+    /// Strips the unit
+    #[inline]
+    fn radians(self) -> vec <T, N> {
+        self.apply_unary(|Rad(x)| x)
+    }
+
+    ///
+    /// Wraps each angle to `(-π, π]`, see [`vec::wrap_angles`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rokoko::prelude::*;
+    ///
+    /// let euler: vec <Rad <f32>, 2> = vec::from([Deg(270.0), Deg(-90.0)]).into();
+    /// let wrapped: vec <Deg <f32>, 2> = euler.wrap_angles().into();
+    ///
+    /// assert!((wrapped[0].0 + 90.0).abs() < 1e-4);
+    /// assert!((wrapped[1].0 + 90.0).abs() < 1e-4);
+    /// ```
+    ///
+    #[inline]
+    pub fn wrap_angles(self) -> Self {
+        self.radians().wrap_angles().apply_unary(Rad)
+    }
+
+    /// Returns the shortest signed rotation from `self` to `other` for each angle, see [`vec::angle_difference`]
+    #[inline]
+    pub fn angle_difference(self, other: Self) -> Self {
+        self.radians().angle_difference(other.radians()).apply_unary(Rad)
+    }
+
+    ///
+    /// Interpolates each angle from `self` to `other` along the shortest arc, see [`vec::lerp_angles`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rokoko::prelude::*;
+    ///
+    /// let a: vec <Rad <f64>, 2> = vec::from([Deg(170.0), Deg(0.0)]).into();
+    /// let b: vec <Rad <f64>, 2> = vec::from([Deg(-170.0), Deg(90.0)]).into();
+    ///
+    /// let mid: vec <Deg <f64>, 2> = a.lerp_angles(b, 0.5).into();
+    /// assert!((mid[0].0 - 180.0).abs() < 1e-9);
+    /// assert!((mid[1].0 - 45.0).abs() < 1e-9);
+    /// ```
+    ///
+    #[inline]
+    pub fn lerp_angles(self, other: Self, t: T) -> Self {
+        self.radians().lerp_angles(other.radians(), t).apply_unary(Rad)
+    }
+}
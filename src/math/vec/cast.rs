@@ -0,0 +1,212 @@
+//!
+//! This module provides conversions of vec elements that may not fit the target type:
+//! checked ones, reporting the first element that does not fit, and saturating ones for integers.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use rokoko::prelude::*;
+//!
+//! // E.g. untrusted sizes, which have to fit `i32`
+//! let parsed = uvec3::from([640, 480, u32::MAX]);
+//!
+//! let error = parsed.try_cast::<i32>().unwrap_err();
+//! assert_eq!(error.index(), 2);
+//! assert_eq!(error.to_string(), "component 2: 4294967295 out of range for i32");
+//!
+//! // Or clamped into it
+//! assert_eq!(parsed.cast_saturating::<i32>(), ivec3::from([640, 480, i32::MAX]));
+//! ```
+//!
+
+use super::vec;
+use core::{
+    convert::TryFrom,
+    fmt::{self, Debug, Display, Formatter, Write},
+    mem::MaybeUninit
+};
+
+/// Longest rendering of a value kept by [`CastError`], longer ones are truncated
+const VALUE_CAPACITY: usize = 40;
+
+///
+/// An error of [`vec::try_cast`]: the first element out of range of the target type.
+///
+/// Keeps the `Debug` rendering of the value(at most 40 bytes) instead of the value itself,
+/// so that it does not allocate and is not generic.
+///
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub struct CastError {
+    index: usize,
+    value: [u8; VALUE_CAPACITY],
+    len: u8,
+    target: &'static str
+}
+
+impl CastError {
+    /// Renders `value`, the element at `index` which does not fit `target`
+    fn new(index: usize, value: &dyn Debug, target: &'static str) -> Self {
+        let mut error = Self { index, value: [0; VALUE_CAPACITY], len: 0, target };
+        let _ = write!(error, "{value:?}");
+        error
+    }
+
+    /// Returns the index of the element
+    pub const fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Returns the `Debug` rendering of the element
+    pub fn value(&self) -> &str {
+        // The buffer is only written with whole `str`s, or whole chars of them
+        core::str::from_utf8(&self.value[..self.len as usize]).expect("rendering must be UTF-8")
+    }
+
+    /// Returns the name of the target type, as of [`core::any::type_name`]
+    pub const fn target(&self) -> &'static str {
+        self.target
+    }
+}
+
+/// Appends to the rendering, dropping the chars that do not fit
+impl Write for CastError {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        for c in s.chars() {
+            let len = self.len as usize;
+            if len + c.len_utf8() > VALUE_CAPACITY {
+                break
+            }
+            c.encode_utf8(&mut self.value[len..]);
+            self.len += c.len_utf8() as u8
+        }
+        Ok(())
+    }
+}
+
+impl Debug for CastError {
+    fn fmt(&self, f: &mut Formatter <'_>) -> fmt::Result {
+        f.debug_struct("CastError")
+            .field("index", &self.index)
+            .field("value", &self.value())
+            .field("target", &self.target)
+            .finish()
+    }
+}
+
+impl Display for CastError {
+    fn fmt(&self, f: &mut Formatter <'_>) -> fmt::Result {
+        write!(f, "component {}: {} out of range for {}", self.index, self.value(), self.target)
+    }
+}
+
+#[cfg(std)]
+impl std::error::Error for CastError {}
+
+///
+/// Conversion from `T` clamping values out of range to the nearest representable one,
+/// see [`vec::cast_saturating`].
+///
+/// Implemented for every pair of primitive integers, and from `f32` and `f64` to them,
+/// where `NaN` becomes `0` and fractions are truncated, as with `as`.
+///
+pub trait SaturatingFrom <T> {
+    fn saturating_from(value: T) -> Self;
+}
+
+macro_rules! impl_saturating_from {
+    ($($to:ty)*) => {
+        $(impl_saturating_from!(@ $to; i8 i16 i32 i64 i128 isize u8 u16 u32 u64 u128 usize);)*
+    };
+    (@ $to:ty; $($from:ty)*) => {
+        $(
+            impl SaturatingFrom <$from> for $to {
+                #[inline]
+                fn saturating_from(value: $from) -> Self {
+                    // Zero always fits, so the value is out of range on the side of its sign
+                    <$to>::try_from(value).unwrap_or(if value > 0 { <$to>::MAX } else { <$to>::MIN })
+                }
+            }
+        )*
+
+        impl SaturatingFrom <f32> for $to {
+            #[inline]
+            fn saturating_from(value: f32) -> Self {
+                // `as` saturates since Rust 1.45
+                value as $to
+            }
+        }
+
+        impl SaturatingFrom <f64> for $to {
+            #[inline]
+            fn saturating_from(value: f64) -> Self {
+                value as $to
+            }
+        }
+    };
+}
+
+impl_saturating_from!(i8 i16 i32 i64 i128 isize u8 u16 u32 u64 u128 usize);
+
+impl <T: Copy, const N: usize> vec <T, N> {
+    ///
+    /// Converts every element into `U`, failing on the first one that does not fit,
+    /// see [module documentation](self).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rokoko::prelude::*;
+    ///
+    /// assert_eq!(ivec2::from([1, -1]).try_cast::<i8>().unwrap(), vec::<i8, 2>::from([1, -1]));
+    ///
+    /// let error = ivec3::from([1, -1, 300]).try_cast::<u8>().unwrap_err();
+    /// assert_eq!((error.index(), error.value(), error.target()), (1, "-1", "u8"));
+    /// ```
+    ///
+    pub fn try_cast <U: TryFrom <T>> (self) -> Result <vec <U, N>, CastError>
+    where T: Debug {
+        let mut i = 0;
+        // Not `vec::uninit`, since `U` may have drop glue: on an error,
+        // elements converted so far are leaked instead of dropping the unwritten ones
+        let mut result = MaybeUninit::<vec <U, N>>::uninit();
+        let base = &mut result as *mut MaybeUninit <vec <U, N>> as *mut U;
+        while i < N {
+            let value = self[i];
+            let converted = U::try_from(value).map_err(|_| CastError::new(i, &value, core::any::type_name::<U>()))?;
+
+            // SAFETY: safe because `i` iterates from 0 to N(exclusively), so the address
+            // is in bounds, and the element is not initialized yet, so does not need to be dropped
+            unsafe { core::ptr::write(base.add(i), converted) }
+            i += 1
+        }
+        // SAFETY: all `N` elements are written in the loop above
+        Ok(unsafe { result.assume_init() })
+    }
+
+    ///
+    /// Converts every element into `U`, clamping the ones out of range, see [`SaturatingFrom`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rokoko::prelude::*;
+    ///
+    /// let v = vec3::from([-1.5, 127.9, 1e9]);
+    /// assert_eq!(v.cast_saturating::<u8>(), vec::<u8, 3>::from([0, 127, 255]));
+    /// assert_eq!(ivec2::from([-1, 70000]).cast_saturating::<u16>(), vec::<u16, 2>::from([0, u16::MAX]));
+    /// ```
+    ///
+    pub fn cast_saturating <U: SaturatingFrom <T>> (self) -> vec <U, N> {
+        let mut i = 0;
+        let mut result = MaybeUninit::<vec <U, N>>::uninit();
+        let base = &mut result as *mut MaybeUninit <vec <U, N>> as *mut U;
+        while i < N {
+            // SAFETY: safe because `i` iterates from 0 to N(exclusively), so the address
+            // is in bounds, and the element is not initialized yet, so does not need to be dropped
+            unsafe { core::ptr::write(base.add(i), U::saturating_from(self[i])) }
+            i += 1
+        }
+        // SAFETY: all `N` elements are written in the loop above
+        unsafe { result.assume_init() }
+    }
+}
@@ -0,0 +1,180 @@
+//!
+//! This module provides [`CellVec`] - a vec that can be changed through a shared reference,
+//! e.g. a camera position shared between callbacks.
+//!
+//! Unlike `RefCell <vec <T, N>>`, nothing is ever borrowed: every method copies the vec in or out,
+//! so there is no borrow to panic on, however callbacks call each other. It needs only `core`.
+//!
+//! # Examples
+//!
+//! A camera position moved by keys and read every frame, with an `Rc <CellVec <..>>`
+//! in place of an `Rc <RefCell <..>>`:
+//!
+//! ```rust
+//! use rokoko::prelude::*;
+//! use rokoko::math::vec::CellVec;
+//! use std::rc::Rc;
+//!
+//! let camera = Rc::new(CellVec::new(vec2::ZERO));
+//!
+//! let on_key = {
+//!     let camera = camera.clone();
+//!     move |right: bool| camera.update(|pos| pos + vec2::from([if right { 1.0 } else { -1.0 }, 0.0]))
+//! };
+//! let on_frame = {
+//!     let camera = camera.clone();
+//!     move |dt: f32| {
+//!         // Falling, and may move sideways meanwhile
+//!         camera.set_component(1, camera.get_component(1) - 10.0 * dt);
+//!         on_key(true);
+//!         camera.get()
+//!     }
+//! };
+//!
+//! assert_eq!(on_frame(0.5), vec2::from([1.0, -5.0]));
+//! assert_eq!(camera.get(), vec2::from([1.0, -5.0]));
+//! ```
+//!
+
+use super::vec;
+use crate::math::scalar::Scalar;
+use core::{cell::Cell, fmt};
+
+///
+/// A `vec` that can be changed through a shared reference, see the [module documentation](self).
+///
+/// Has the layout of `vec <T, N>`, just as `Cell <vec <T, N>>` does.
+///
+#[repr(transparent)]
+pub struct CellVec <T, const N: usize> (Cell <vec <T, N>>);
+
+impl <T, const N: usize> CellVec <T, N> {
+    /// Creates a cell holding `value`
+    #[inline]
+    pub const fn new(value: vec <T, N>) -> Self {
+        Self(Cell::new(value))
+    }
+
+    /// Returns the vec held, consuming the cell
+    #[inline]
+    pub fn into_inner(self) -> vec <T, N> {
+        self.0.into_inner()
+    }
+
+    /// Replaces the vec held with `value`, returning the old one
+    #[inline]
+    pub fn replace(&self, value: vec <T, N>) -> vec <T, N> {
+        self.0.replace(value)
+    }
+
+    ///
+    /// Returns a mutable reference to the vec held.
+    ///
+    /// Checked in compile-time, since it needs the cell to be borrowed mutably.
+    ///
+    #[inline]
+    pub fn get_mut(&mut self) -> &mut vec <T, N> {
+        self.0.get_mut()
+    }
+}
+
+impl <T: Copy, const N: usize> CellVec <T, N> {
+    /// Returns a copy of the vec held
+    #[inline]
+    pub fn get(&self) -> vec <T, N> {
+        self.0.get()
+    }
+
+    /// Sets the vec held to `value`
+    #[inline]
+    pub fn set(&self, value: vec <T, N>) {
+        self.0.set(value)
+    }
+
+    ///
+    /// Sets the vec held to `f` of it, returning the new one.
+    ///
+    /// `f` is given a copy, so it may use the cell itself, e.g. through other callbacks,
+    /// although whatever it sets is overwritten once it returns.
+    ///
+    /// # Examples
+    /// ```
+    /// use rokoko::prelude::*;
+    /// use rokoko::math::vec::CellVec;
+    ///
+    /// let v = CellVec::new(ivec2::from([1, 2]));
+    /// assert_eq!(v.update(|v| v * 10), ivec2::from([10, 20]));
+    /// assert_eq!(v.get(), ivec2::from([10, 20]));
+    /// ```
+    ///
+    #[inline]
+    pub fn update <F: FnOnce(vec <T, N>) -> vec <T, N>> (&self, f: F) -> vec <T, N> {
+        let new = f(self.get());
+        self.set(new);
+        new
+    }
+
+    ///
+    /// Returns the element `i` of the vec held.
+    ///
+    /// # Panics
+    ///
+    /// If `i` is not less than `N`.
+    ///
+    #[inline]
+    pub fn get_component(&self, i: usize) -> T {
+        self.get()[i]
+    }
+
+    ///
+    /// Sets the element `i` of the vec held to `value`.
+    ///
+    /// # Panics
+    ///
+    /// If `i` is not less than `N`.
+    ///
+    /// # Examples
+    /// ```
+    /// use rokoko::prelude::*;
+    /// use rokoko::math::vec::CellVec;
+    ///
+    /// let v = CellVec::new(vec3::ZERO);
+    /// v.set_component(2, 5.0);
+    /// assert_eq!(v.get(), vec3::from([0.0, 0.0, 5.0]));
+    /// assert_eq!(v.get_component(2), 5.0);
+    /// ```
+    ///
+    #[inline]
+    pub fn set_component(&self, i: usize, value: T) {
+        let mut v = self.get();
+        v[i] = value;
+        self.set(v)
+    }
+}
+
+impl <T: Copy, const N: usize> Clone for CellVec <T, N> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self::new(self.get())
+    }
+}
+
+impl <T: Scalar, const N: usize> Default for CellVec <T, N> {
+    #[inline]
+    fn default() -> Self {
+        Self::new(vec::default())
+    }
+}
+
+impl <T, const N: usize> From <vec <T, N>> for CellVec <T, N> {
+    #[inline]
+    fn from(value: vec <T, N>) -> Self {
+        Self::new(value)
+    }
+}
+
+impl <T: Copy + fmt::Debug, const N: usize> fmt::Debug for CellVec <T, N> {
+    fn fmt(&self, f: &mut fmt::Formatter <'_>) -> fmt::Result {
+        f.debug_tuple("CellVec").field(&self.get()).finish()
+    }
+}
@@ -0,0 +1,188 @@
+//!
+//! This module provides componentwise [`min`](vec::min), [`max`](vec::max),
+//! [`clamp`](vec::clamp) and [`abs`](vec::abs).
+//!
+//! All of them are built on [`apply_binary`](vec::apply_binary) and [`apply_unary`](vec::apply_unary),
+//! so they are `const` with `nightly` feature, as long as comparisons(or [`Abs`]) of `T` are.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use rokoko::prelude::*;
+//!
+//! let a = ivec3::from([1, -5, 3]);
+//! let b = ivec3::from([2, -7, 3]);
+//!
+//! assert_eq!(a.min(b), ivec3::from([1, -7, 3]));
+//! assert_eq!(a.max(b), ivec3::from([2, -5, 3]));
+//! assert_eq!(a.abs(), ivec3::from([1, 5, 3]));
+//!
+//! // E.g. keeping a position within the screen
+//! let position = vec2::from([-12.0, 480.5]);
+//! assert_eq!(position.clamp(vec2::ZERO, vec2::from([640.0, 360.0])), vec2::from([0.0, 360.0]));
+//! assert_eq!(position.clamp_scalar(0.0, 100.0), vec2::from([0.0, 100.0]));
+//! ```
+//!
+
+use super::vec;
+use crate::{nightly, math::scalar::Abs};
+
+/// The least of `a` and `b`, `a` unless `b` is less
+#[inline(always)]
+#[nightly(const(T: PartialOrd))]
+fn min <T: PartialOrd> (a: T, b: T) -> T {
+    if b < a { b } else { a }
+}
+
+/// The greatest of `a` and `b`, `a` unless `b` is greater
+#[inline(always)]
+#[nightly(const(T: PartialOrd))]
+fn max <T: PartialOrd> (a: T, b: T) -> T {
+    if b > a { b } else { a }
+}
+
+/// `a <= b`, as a function to be passed to `apply_binary_bool`
+#[inline(always)]
+#[nightly(const(T: PartialOrd))]
+fn le <T: PartialOrd> (a: T, b: T) -> bool {
+    a <= b
+}
+
+/// Same as the method of [`Abs`], to be passed to `apply_unary`
+#[inline(always)]
+#[nightly(const(T: Abs))]
+fn abs <T: Abs> (x: T) -> T {
+    x.abs()
+}
+
+#[nightly(const(T: PartialOrd))]
+impl <T: Copy + PartialOrd, const N: usize> vec <T, N> {
+    ///
+    /// Returns the least of corresponding elements of `self` and `rhs`.
+    ///
+    /// The element of `self` is kept unless the one of `rhs` is less,
+    /// so `NaN`s of `self` stay, while the ones of `rhs` are ignored.
+    ///
+    /// # Constness
+    ///
+    /// Const when `T` is `~const PartialOrd` and `nightly` feature is enabled.
+    ///
+    /// # Examples
+    /// ```
+    /// use rokoko::prelude::*;
+    ///
+    /// assert_eq!(ivec2::from_array([1, 8]).min(ivec2::from_array([4, 2])), ivec2::from_array([1, 2]));
+    ///
+    /// let nan = vec2::from_array([f32::NAN, 1.0]);
+    /// assert_eq!(vec2::ONE.min(nan), vec2::ONE);
+    /// assert!(nan.min(vec2::ZERO)[0].is_nan());
+    /// ```
+    ///
+    #[nightly(const)]
+    #[inline]
+    pub fn min(self, rhs: Self) -> Self {
+        self.apply_binary(rhs, min)
+    }
+
+    ///
+    /// Returns the greatest of corresponding elements of `self` and `rhs`.
+    ///
+    /// The element of `self` is kept unless the one of `rhs` is greater,
+    /// so `NaN`s of `self` stay, while the ones of `rhs` are ignored.
+    ///
+    /// # Constness
+    ///
+    /// Const when `T` is `~const PartialOrd` and `nightly` feature is enabled.
+    ///
+    /// # Examples
+    /// ```
+    /// use rokoko::prelude::*;
+    ///
+    /// assert_eq!(ivec2::from_array([1, 8]).max(ivec2::from_array([4, 2])), ivec2::from_array([4, 8]));
+    /// ```
+    ///
+    #[nightly(const)]
+    #[inline]
+    pub fn max(self, rhs: Self) -> Self {
+        self.apply_binary(rhs, max)
+    }
+
+    ///
+    /// Restricts every element to the range between the corresponding elements of `lo` and `hi`,
+    /// i.e. `self.max(lo).min(hi)`. `NaN`s stay `NaN`s.
+    ///
+    /// # Panics
+    ///
+    /// In debug builds, if any element of `lo` is not less than or equal to the one of `hi`,
+    /// including if either of them is `NaN`.
+    ///
+    /// # Constness
+    ///
+    /// Const when `T` is `~const PartialOrd` and `nightly` feature is enabled.
+    ///
+    /// # Examples
+    /// ```
+    /// use rokoko::prelude::*;
+    ///
+    /// let lo = ivec3::from_array([0, 0, -1]);
+    /// let hi = ivec3::from_array([10, 0, 1]);
+    /// assert_eq!(ivec3::from_array([-4, 5, 7]).clamp(lo, hi), ivec3::from_array([0, 0, 1]));
+    /// ```
+    ///
+    #[nightly(const)]
+    #[inline]
+    pub fn clamp(self, lo: Self, hi: Self) -> Self {
+        debug_assert!(lo.apply_binary_bool(hi, le), "every element of `lo` must be less than or equal to the one of `hi`");
+        self.max(lo).min(hi)
+    }
+
+    ///
+    /// Same as [`clamp`](vec::clamp), but with the same `lo` and `hi` for all the elements.
+    ///
+    /// # Panics
+    ///
+    /// In debug builds, if `lo` is not less than or equal to `hi`.
+    ///
+    /// # Constness
+    ///
+    /// Const when `T` is `~const PartialOrd` and `nightly` feature is enabled.
+    ///
+    /// # Examples
+    /// ```
+    /// use rokoko::prelude::*;
+    ///
+    /// assert_eq!(vec4::from_array([-0.5, 0.25, 1.0, 7.0]).clamp_scalar(0.0, 1.0), vec4::from_array([0.0, 0.25, 1.0, 1.0]));
+    /// ```
+    ///
+    #[nightly(const)]
+    #[inline]
+    pub fn clamp_scalar(self, lo: T, hi: T) -> Self {
+        self.clamp(Self::single(lo), Self::single(hi))
+    }
+}
+
+#[nightly(const(T: Abs))]
+impl <T: Abs, const N: usize> vec <T, N> {
+    ///
+    /// Returns the absolute values of elements, see [`Abs`].
+    ///
+    /// # Constness
+    ///
+    /// Const when `nightly` feature is enabled.
+    ///
+    /// # Examples
+    /// ```
+    /// use rokoko::prelude::*;
+    ///
+    /// assert_eq!(ivec3::from_array([-3, 0, 3]).abs(), ivec3::from_array([3, 0, 3]));
+    ///
+    /// let v = vec3::from_array([-0.0, -2.5, f32::NEG_INFINITY]).abs();
+    /// assert_eq!(v.into_array().map(f32::to_bits), [0, 2.5f32.to_bits(), f32::INFINITY.to_bits()]);
+    /// ```
+    ///
+    #[nightly(const)]
+    #[inline]
+    pub fn abs(self) -> Self {
+        self.apply_unary(abs)
+    }
+}
@@ -0,0 +1,69 @@
+//!
+//! This module provides conversions between collections of vecs and flat collections of scalars,
+//! e.g. for vertex buffers.
+//!
+//! Requires `alloc` feature, but not `std`.
+//!
+
+use alloc::vec::Vec;
+use core::convert::TryInto;
+use super::vec;
+
+///
+/// Returns the elements of `vecs`, one vec after another.
+///
+/// # Examples
+///
+/// ```
+/// use rokoko::prelude::*;
+/// use rokoko::math::vec::flatten;
+///
+/// let positions = [vec2::from([1.0, 2.0]), vec2::from([3.0, 4.0])];
+/// assert_eq!(flatten(&positions), [1.0, 2.0, 3.0, 4.0]);
+///
+/// assert_eq!(flatten::<i32, 3>(&[]), []);
+/// ```
+///
+pub fn flatten <T: Copy, const N: usize> (vecs: &[vec <T, N>]) -> Vec <T> {
+    let mut flat = Vec::with_capacity(vecs.len() * N);
+    for v in vecs {
+        flat.extend_from_slice(v.as_array())
+    }
+    flat
+}
+
+///
+/// Groups `flat` into vecs of `N` elements, the inverse of [`flatten`].
+///
+/// Returns `None` if the length of `flat` is not a multiple of `N`.
+///
+/// # Examples
+///
+/// ```
+/// use rokoko::prelude::*;
+/// use rokoko::math::vec::{flatten, unflatten};
+///
+/// let flat = [1, 2, 3, 4, 5, 6];
+/// assert_eq!(unflatten(&flat), Some(vec![ivec3::from([1, 2, 3]), ivec3::from([4, 5, 6])]));
+/// assert_eq!(unflatten::<i32, 2>(&flat).map(|v| flatten(&v)), Some(flat.to_vec()));
+///
+/// // Leftovers
+/// assert_eq!(unflatten::<i32, 4>(&flat), None);
+///
+/// // Only nothing is made of zero-sized vecs
+/// assert_eq!(unflatten::<i32, 0>(&[]), Some(vec![]));
+/// assert_eq!(unflatten::<i32, 0>(&flat), None);
+/// ```
+///
+pub fn unflatten <T: Copy, const N: usize> (flat: &[T]) -> Option <Vec <vec <T, N>>> {
+    if N == 0 {
+        return flat.is_empty().then(Vec::new)
+    }
+    if flat.len() % N != 0 {
+        return None
+    }
+    Some(flat
+        .chunks_exact(N)
+        .map(|chunk| vec::from_array(chunk.try_into().expect("chunks are exactly `N` long")))
+        .collect())
+}
@@ -0,0 +1,148 @@
+//!
+//! This module provides elementwise square roots, trigonometric and exponential functions
+//! of float vecs, like `GLSL`'s built-ins of the same names.
+//!
+//! They need a [`Float`], so they are available only with a float backend(`std` or `libm`),
+//! see [`scalar`](crate::math::scalar) module documentation. Results are the ones of
+//! the backend for every element, so they match `f32::sin` and friends with `std`.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use rokoko::prelude::*;
+//! use std::f32::consts::PI;
+//!
+//! // Points on a unit circle
+//! let angles = vec4::from([0.0, PI / 2.0, PI, 3.0 * PI / 2.0]);
+//! let (x, y) = (angles.cos(), angles.sin());
+//! assert!((x * x + y * y - vec4::single(1.0)).abs().into_array().iter().all(|&e| e < 1e-6));
+//!
+//! // Gamma correction
+//! let linear = vec3::from([0.0, 0.25, 1.0]);
+//! assert_eq!(linear.powf(0.5), linear.sqrt());
+//! ```
+//!
+
+use super::vec;
+use crate::math::scalar::Float;
+
+impl <T: Float, const N: usize> vec <T, N> {
+    ///
+    /// Returns the square roots of elements, `NaN` for negative ones.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rokoko::prelude::*;
+    ///
+    /// assert_eq!(vec3::from([4.0, 0.25, 0.0]).sqrt(), vec3::from([2.0, 0.5, 0.0]));
+    /// assert!(vec1::single(-1.0).sqrt()[0].is_nan());
+    /// ```
+    ///
+    #[inline]
+    pub fn sqrt(self) -> Self {
+        self.apply_unary(T::sqrt)
+    }
+
+    ///
+    /// Returns the sines of elements(in radians).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rokoko::prelude::*;
+    /// use std::f64::consts::FRAC_PI_2;
+    ///
+    /// assert_eq!(dvec2::from([0.0, FRAC_PI_2]).sin(), dvec2::from([0.0, 1.0]));
+    /// ```
+    ///
+    #[inline]
+    pub fn sin(self) -> Self {
+        self.apply_unary(T::sin)
+    }
+
+    ///
+    /// Returns the cosines of elements(in radians).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rokoko::prelude::*;
+    /// use std::f64::consts::PI;
+    ///
+    /// assert_eq!(dvec2::from([0.0, PI]).cos(), dvec2::from([1.0, -1.0]));
+    /// ```
+    ///
+    #[inline]
+    pub fn cos(self) -> Self {
+        self.apply_unary(T::cos)
+    }
+
+    ///
+    /// Returns the tangents of elements(in radians).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rokoko::prelude::*;
+    /// use std::f64::consts::FRAC_PI_4;
+    ///
+    /// let v = dvec2::from([0.0, FRAC_PI_4]).tan();
+    /// assert_eq!(v[0], 0.0);
+    /// assert!((v[1] - 1.0).abs() < 1e-15);
+    /// ```
+    ///
+    #[inline]
+    pub fn tan(self) -> Self {
+        self.apply_unary(T::tan)
+    }
+
+    ///
+    /// Returns `e` raised to elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rokoko::prelude::*;
+    ///
+    /// assert_eq!(vec3::from([0.0, f32::NEG_INFINITY, f32::INFINITY]).exp(), vec3::from([1.0, 0.0, f32::INFINITY]));
+    /// ```
+    ///
+    #[inline]
+    pub fn exp(self) -> Self {
+        self.apply_unary(T::exp)
+    }
+
+    ///
+    /// Returns the natural logarithms of elements, `NaN` for negative ones
+    /// and negative infinity for zeros.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rokoko::prelude::*;
+    ///
+    /// assert_eq!(vec2::from([1.0, 0.0]).ln(), vec2::from([0.0, f32::NEG_INFINITY]));
+    /// ```
+    ///
+    #[inline]
+    pub fn ln(self) -> Self {
+        self.apply_unary(T::ln)
+    }
+
+    ///
+    /// Raises elements to the power of `n`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rokoko::prelude::*;
+    ///
+    /// assert_eq!(vec3::from([2.0, 3.0, 0.5]).powf(2.0), vec3::from([4.0, 9.0, 0.25]));
+    /// ```
+    ///
+    #[inline]
+    pub fn powf(self, n: T) -> Self {
+        self.apply_binary_single(n, T::powf)
+    }
+}
@@ -0,0 +1,135 @@
+//!
+//! This module provides hashing of float vecs, e.g. for caches keyed by positions.
+//!
+//! Floats implement neither `Hash` nor `Eq`: `NaN` is not equal to itself, while `-0.0`
+//! is equal to `0.0` despite different bits. So vecs are hashed through their canonical bits,
+//! see [`vec::canonical_bits`], and compared the same way by [`HashableVec`].
+//!
+//! # Equivalence
+//!
+//! Two elements are equal in [`HashableVec`] if and only if:
+//! - both are `NaN`s, whatever their signs and payloads are;
+//! - both are zeros, whatever their signs are;
+//! - otherwise, they have the same bits, i.e. they are equal as floats.
+//!
+//! That is the float equality, except for `NaN`s being equal to each other,
+//! so it is reflexive, as `Eq` requires.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use rokoko::prelude::*;
+//! use rokoko::math::vec::HashableVec;
+//! use std::collections::HashMap;
+//!
+//! // An expensive computation per position, done once per distinct position
+//! let mut cache: HashMap <HashableVec <f32, 2>, f32> = HashMap::new();
+//! let mut computed = 0;
+//! let mut noise = |pos: vec2| *cache.entry(pos.hashable()).or_insert_with(|| {
+//!     computed += 1;
+//!     (pos[0] * 12.9898 + pos[1] * 78.233).sin()
+//! });
+//!
+//! let a = noise(vec2::from([1.0, 2.0]));
+//! assert_eq!(noise(vec2::from([1.0, 2.0])), a);
+//! // The same key
+//! noise(vec2::from([-0.0, 0.0]));
+//! noise(vec2::from([0.0, -0.0]));
+//! noise(vec2::from([f32::NAN, 1.0]));
+//! noise(vec2::from([-f32::NAN, 1.0]));
+//! drop(noise);
+//!
+//! assert_eq!(computed, 3);
+//! ```
+//!
+
+use super::vec;
+use core::hash::{Hash, Hasher};
+
+///
+/// A float vec that implements `Hash` and `Eq` through its canonical bits,
+/// see [module documentation](self).
+///
+/// Created with [`vec::hashable`], the vec itself is [`HashableVec::0`].
+///
+#[derive(Debug, Copy, Clone)]
+pub struct HashableVec <T, const N: usize> (pub vec <T, N>);
+
+macro_rules! hashable {
+    ($( $float:ident $bits:ident ),*) => {$(
+        impl <const N: usize> vec <$float, N> {
+            ///
+            /// Returns the bits of the elements, with `-0.0` as `0.0` and
+            #[doc = concat!("every `NaN` as [`", stringify!($float), "::NAN`],")]
+            /// see [`HashableVec`] for the equivalence this gives.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// use rokoko::prelude::*;
+            ///
+            #[doc = concat!("let nan = ", stringify!($float), "::from_bits(", stringify!($float), "::NAN.to_bits() | 1);")]
+            #[doc = concat!("let v = vec::<", stringify!($float), ", 4>::from([-0.0, nan, -nan, 1.5]);")]
+            ///
+            /// assert_eq!(v.canonical_bits(), vec::from([
+            ///     0,
+            #[doc = concat!("    ", stringify!($float), "::NAN.to_bits(),")]
+            #[doc = concat!("    ", stringify!($float), "::NAN.to_bits(),")]
+            #[doc = concat!("    1.5", stringify!($float), ".to_bits()")]
+            /// ]));
+            /// ```
+            ///
+            pub fn canonical_bits(self) -> vec <$bits, N> {
+                let mut bits = [0; N];
+                for (b, x) in bits.iter_mut().zip(self.into_array()) {
+                    *b = if x.is_nan() {
+                        $float::NAN.to_bits()
+                    } else if x == 0.0 {
+                        0
+                    } else {
+                        x.to_bits()
+                    }
+                }
+                vec::from_array(bits)
+            }
+
+            ///
+            /// Wraps `self` into [`HashableVec`], e.g. to be used as a key of a `HashMap`.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// use rokoko::prelude::*;
+            ///
+            #[doc = concat!("let v = vec::<", stringify!($float), ", 2>::from([1.0, 2.0]);")]
+            /// assert_eq!(v.hashable(), v.hashable());
+            /// assert_eq!(v.hashable().0, v);
+            ///
+            #[doc = concat!("assert_eq!(vec::<", stringify!($float), ", 2>::from([-0.0, ", stringify!($float), "::NAN]).hashable(), vec::from([0.0, -", stringify!($float), "::NAN]).hashable());")]
+            #[doc = concat!("assert_ne!(v.hashable(), vec::<", stringify!($float), ", 2>::from([1.0, -2.0]).hashable());")]
+            /// ```
+            ///
+            pub fn hashable(self) -> HashableVec <$float, N> {
+                HashableVec(self)
+            }
+        }
+
+        impl <const N: usize> PartialEq for HashableVec <$float, N> {
+            #[inline]
+            fn eq(&self, other: &Self) -> bool {
+                self.0.canonical_bits().into_array() == other.0.canonical_bits().into_array()
+            }
+        }
+
+        impl <const N: usize> Eq for HashableVec <$float, N> {}
+
+        impl <const N: usize> Hash for HashableVec <$float, N> {
+            #[inline]
+            fn hash <H: Hasher> (&self, state: &mut H) {
+                self.0.canonical_bits().into_array().hash(state)
+            }
+        }
+    )*};
+}
+
+hashable!(f32 u32, f64 u64);
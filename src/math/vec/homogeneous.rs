@@ -0,0 +1,153 @@
+//!
+//! This module provides conversions between 3D vecs and homogeneous coordinates.
+//!
+//! In homogeneous coordinates a 3D vec gets a fourth element, `w`, telling what it means:
+//! - a *point*(a position) has `w = 1`, so it is moved by translations;
+//! - a *direction*(e.g. a velocity or a normal) has `w = 0`, so translations do not affect it.
+//!
+//! Going back divides by `w`(the "perspective divide"), which is what projections rely on.
+//!
+//! `transform_point` and `transform_direction` will be built on top of these once there is a `mat4`.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use rokoko::prelude::*;
+//!
+//! let position = vec3::from([1.0, 2.0, 3.0]);
+//! let velocity = vec3::from([0.0, 0.0, -1.0]);
+//!
+//! // A translation by `t` adds `t * w` to `xyz`
+//! let t = vec4::from([10.0, 0.0, 0.0, 0.0]);
+//! let translate = |v: vec4| v + t * vec4::single(v[3]);
+//!
+//! // The point moves, the direction does not
+//! assert_eq!(translate(position.to_point4()).to_cartesian(), vec3::from([11.0, 2.0, 3.0]));
+//! assert_eq!(translate(velocity.to_direction4()), velocity.to_direction4());
+//! ```
+//!
+
+use super::vec;
+use crate::math::scalar::{Scalar, Float};
+
+impl <T: Scalar> vec <T, 3> {
+    ///
+    /// Returns `self` as a point in homogeneous coordinates, i.e. with `w = 1`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rokoko::prelude::*;
+    ///
+    /// let p = ivec3::from([1, 2, 3]);
+    /// assert_eq!(p.to_point4(), ivec4::from([1, 2, 3, 1]));
+    /// assert_eq!(vec3::from([1.0, 2.0, 3.0]).to_point4().to_cartesian(), vec3::from([1.0, 2.0, 3.0]));
+    /// ```
+    ///
+    #[inline]
+    pub fn to_point4(self) -> vec <T, 4> {
+        let [x, y, z] = self.into_array();
+        vec::from_array([x, y, z, T::ONE])
+    }
+
+    ///
+    /// Returns `self` as a direction in homogeneous coordinates, i.e. with `w = 0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rokoko::prelude::*;
+    ///
+    /// let d = vec3::from([0.0, 1.0, 0.0]);
+    /// assert_eq!(d.to_direction4(), vec4::from([0.0, 1.0, 0.0, 0.0]));
+    ///
+    /// // Directions are at infinity, so they have no cartesian form
+    /// assert_eq!(d.to_direction4().try_to_cartesian(), None);
+    /// ```
+    ///
+    #[inline]
+    pub fn to_direction4(self) -> vec <T, 4> {
+        let [x, y, z] = self.into_array();
+        vec::from_array([x, y, z, T::ZERO])
+    }
+}
+
+impl <T: Float> vec <T, 4> {
+    ///
+    /// Returns `xyz` divided by `w`, or `None` if `|w|` is not greater than [`Float::EPSILON`],
+    /// i.e. for directions and points too close to infinity.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rokoko::prelude::*;
+    ///
+    /// // The perspective divide
+    /// let clip = vec4::from([2.0, -4.0, 1.0, 4.0]);
+    /// assert_eq!(clip.try_to_cartesian(), Some(vec3::from([2.0 / 4.0, -4.0 / 4.0, 1.0 / 4.0])));
+    ///
+    /// // Negative `w` divides just as well
+    /// assert_eq!(vec4::from([2.0, 4.0, 6.0, -2.0]).try_to_cartesian(), Some(vec3::from([-1.0, -2.0, -3.0])));
+    ///
+    /// // The threshold
+    /// assert_eq!(vec4::from([1.0, 1.0, 1.0, 0.0]).try_to_cartesian(), None);
+    /// assert_eq!(vec4::from([1.0, 1.0, 1.0, -0.0]).try_to_cartesian(), None);
+    /// assert_eq!(vec4::from([1.0, 1.0, 1.0, 1e-30]).try_to_cartesian(), None);
+    /// assert_eq!(vec4::from([1.0, 1.0, 1.0, f32::EPSILON]).try_to_cartesian(), None);
+    /// assert_eq!(vec4::from([1.0, 1.0, 1.0, -f32::EPSILON]).try_to_cartesian(), None);
+    /// assert!(vec4::from([1.0, 1.0, 1.0, 2.0 * f32::EPSILON]).try_to_cartesian().is_some());
+    /// assert_eq!(dvec4::from([1.0, 1.0, 1.0, 1e-30]).try_to_cartesian(), None);
+    ///
+    /// // `NaN` is rejected as well
+    /// assert_eq!(vec4::from([1.0, 1.0, 1.0, f32::NAN]).try_to_cartesian(), None);
+    /// ```
+    ///
+    #[inline]
+    pub fn try_to_cartesian(self) -> Option <vec <T, 3>> {
+        let [x, y, z, w] = self.into_array();
+        // `NaN` fails the comparison
+        if w.abs() > T::EPSILON {
+            Some(vec::from_array([x / w, y / w, z / w]))
+        } else {
+            None
+        }
+    }
+
+    ///
+    /// Returns `xyz` divided by `w`, or `xyz` unscaled if that is impossible,
+    /// see [`try_to_cartesian`](vec::try_to_cartesian).
+    ///
+    /// Unscaled `xyz` of a direction is the direction itself, so
+    /// `to_cartesian` is the inverse of both [`to_point4`](vec::to_point4)
+    /// and [`to_direction4`](vec::to_direction4).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rokoko::prelude::*;
+    ///
+    /// let v = dvec3::from([-1.5, 0.25, 8.0]);
+    ///
+    /// // Round trips
+    /// assert_eq!(v.to_point4().to_cartesian(), v);
+    /// assert_eq!(v.to_direction4().to_cartesian(), v);
+    ///
+    /// // Against the manual computation
+    /// let h = dvec4::from([3.0, 6.0, -9.0, 3.0]);
+    /// assert_eq!(h.to_cartesian(), dvec3::from([3.0 / 3.0, 6.0 / 3.0, -9.0 / 3.0]));
+    ///
+    /// // Points at infinity are kept as directions
+    /// assert_eq!(dvec4::from([3.0, 6.0, -9.0, 1e-30]).to_cartesian(), dvec3::from([3.0, 6.0, -9.0]));
+    /// ```
+    ///
+    #[inline]
+    pub fn to_cartesian(self) -> vec <T, 3> {
+        match self.try_to_cartesian() {
+            Some(cartesian) => cartesian,
+            None => {
+                let [x, y, z, _] = self.into_array();
+                vec::from_array([x, y, z])
+            }
+        }
+    }
+}
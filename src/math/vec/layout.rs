@@ -0,0 +1,40 @@
+//!
+//! Compile-time checks of the layout guarantees of `vec`.
+//!
+//! See `Layout` section of the `vec` module documentation.
+//!
+
+use super::vec;
+use core::{
+    mem::{size_of, align_of},
+    num::NonZeroU32
+};
+
+/// A user type with an alignment bigger than its size
+#[repr(align(16))]
+#[allow(dead_code)]
+struct Aligned(u8);
+
+/// Checks that `vec <T, N>` is laid out exactly as `[T; N]`
+macro_rules! assert_layout {
+    ($( $t:ty: $( $n:literal )* ),*) => {$($(
+        const _: () = assert!(size_of::<vec <$t, $n>>() == size_of::<[$t; $n]>());
+        const _: () = assert!(align_of::<vec <$t, $n>>() == align_of::<[$t; $n]>());
+    )*)*};
+}
+
+assert_layout! {
+    bool: 0 1 2 3 4,
+    u8: 0 1 2 3 4,
+    u16: 1 2 3 4,
+    i32: 1 2 3 4,
+    u32: 1 2 3 4,
+    f32: 1 2 3 4 16,
+    f64: 1 2 3 4 16,
+    u128: 1 3,
+    Aligned: 0 1 3 4,
+    NonZeroU32: 1 2
+}
+
+// The niche of the array is kept
+const _: () = assert!(size_of::<Option <vec <NonZeroU32, 2>>>() == size_of::<vec <NonZeroU32, 2>>());
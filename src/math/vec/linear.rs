@@ -0,0 +1,170 @@
+//!
+//! This module provides linear operations expressible with `vec`s alone,
+//! i.e. without a matrix type, including cross products of `vec3`s and perp dot products of `vec2`s.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use rokoko::prelude::*;
+//!
+//! let a = ivec2::from([1, 2]);
+//! let b = ivec3::from([3, 4, 5]);
+//!
+//! // Outer product is a vec of vecs
+//! let outer = a.outer(b);
+//! assert_eq!(outer, vec::from([
+//!     ivec3::from([3, 4, 5]),
+//!     ivec3::from([6, 8, 10])
+//! ]));
+//!
+//! // Which can be debug-printed just like a flat vec
+//! assert_eq!(format!("{:?}", outer), "vec<vec<i32, 3>, 2>(vec<i32, 3>(3, 4, 5), vec<i32, 3>(6, 8, 10))");
+//! assert_eq!(format!("{:?}", ivec1::from([5])), "vec<i32, 1>(5)");
+//! ```
+//!
+
+use super::vec;
+use crate::{nightly, math::scalar::Scalar};
+use core::ops::{Mul, Sub};
+
+impl <T: Copy, const N: usize> vec <T, N> {
+    ///
+    /// Returns the outer product of `self` and `rhs`,
+    /// i.e. `result[i][j] = self[i] * rhs[j]`.
+    ///
+    /// The result is `N` rows of `M` elements each.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rokoko::prelude::*;
+    ///
+    /// let outer = ivec2::from([1, -1]).outer(ivec3::from([1, 2, 3]));
+    ///
+    /// assert_eq!(outer[0], ivec3::from([1, 2, 3]));
+    /// assert_eq!(outer[1], ivec3::from([-1, -2, -3]));
+    /// ```
+    ///
+    pub fn outer <U: Copy, R, const M: usize> (self, rhs: vec <U, M>) -> vec <vec <R, M>, N> where T: Mul <U, Output = R> {
+        self.apply_unary(|a| rhs.apply_unary(|b| a * b))
+    }
+
+    ///
+    /// Returns the component-wise product of `self` and `rhs`.
+    ///
+    /// Same as `self * rhs`, but allows `rhs` of another element type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rokoko::prelude::*;
+    ///
+    /// let a = vec3::from([1.0, 2.0, 3.0]);
+    /// let b = vec3::from([4.0, 0.5, -1.0]);
+    ///
+    /// assert_eq!(a.hadamard(b), vec3::from([4.0, 1.0, -3.0]));
+    /// assert_eq!(a.hadamard(b), a * b);
+    /// ```
+    ///
+    pub fn hadamard <U: Copy, R> (self, rhs: vec <U, N>) -> vec <R, N> where T: Mul <U, Output = R> {
+        self.apply_binary(rhs, |a, b| a * b)
+    }
+}
+
+impl <T: Scalar, const N: usize> vec <T, N> {
+    ///
+    /// Returns the sum of `basis` vecs, each multiplied by the corresponding
+    /// element of `coeffs`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rokoko::prelude::*;
+    ///
+    /// // Triangle vertices
+    /// let a = vec2::from([0.0, 0.0]);
+    /// let b = vec2::from([4.0, 0.0]);
+    /// let c = vec2::from([0.0, 8.0]);
+    ///
+    /// // Barycentric coordinates of a point
+    /// let coords = vec3::from([0.5, 0.25, 0.25]);
+    ///
+    /// assert_eq!(vec::linear_combine(coords, [a, b, c]), vec2::from([1.0, 2.0]));
+    ///
+    /// // Empty combination is zero
+    /// assert_eq!(vec::linear_combine(ivec::<0>::ZERO, []), ivec2::ZERO);
+    /// ```
+    ///
+    pub fn linear_combine <const K: usize> (coeffs: vec <T, K>, basis: [vec <T, N>; K]) -> Self {
+        let mut result = Self::ZERO;
+        let mut i = 0;
+        while i < K {
+            result = result + basis[i] * coeffs[i];
+            i += 1
+        }
+        result
+    }
+}
+
+#[nightly(const(T: Mul <Output = T> + Sub <Output = T>))]
+impl <T: Copy + Mul <Output = T> + Sub <Output = T>> vec <T, 3> {
+    ///
+    /// Returns the cross product of `self` and `rhs`, i.e. the vec perpendicular to both,
+    /// following the right-hand rule, with the length of the area of the parallelogram they span.
+    ///
+    /// # Constness
+    ///
+    /// Const when `T` is `~const Mul + ~const Sub` and `nightly` feature is enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rokoko::prelude::*;
+    ///
+    /// assert_eq!(ivec3::UNIT_X.cross(ivec3::UNIT_Y), ivec3::UNIT_Z);
+    /// assert_eq!(ivec3::UNIT_Y.cross(ivec3::UNIT_X), -ivec3::UNIT_Z);
+    ///
+    /// let a = vec3::from([1.0, 2.0, 3.0]);
+    /// assert_eq!(a.cross(a), vec3::ZERO);
+    /// ```
+    ///
+    #[nightly(const)]
+    #[inline]
+    pub fn cross(self, rhs: Self) -> Self {
+        let [ax, ay, az] = self.into_array();
+        let [bx, by, bz] = rhs.into_array();
+        Self([ay * bz - az * by, az * bx - ax * bz, ax * by - ay * bx])
+    }
+}
+
+#[nightly(const(T: Mul <Output = T> + Sub <Output = T>))]
+impl <T: Copy + Mul <Output = T> + Sub <Output = T>> vec <T, 2> {
+    ///
+    /// Returns the perp dot product of `self` and `rhs`, i.e. the `z` of the cross product of them
+    /// extended with zero `z`.
+    ///
+    /// It is the signed area of the parallelogram they span: positive if `rhs` is
+    /// counterclockwise from `self`, negative if clockwise and zero if they are parallel.
+    ///
+    /// # Constness
+    ///
+    /// Const when `T` is `~const Mul + ~const Sub` and `nightly` feature is enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rokoko::prelude::*;
+    ///
+    /// assert_eq!(ivec2::UNIT_X.perp_dot(ivec2::UNIT_Y), 1);
+    /// assert_eq!(ivec2::UNIT_Y.perp_dot(ivec2::UNIT_X), -1);
+    /// assert_eq!(vec2::from([2.0, 4.0]).perp_dot(vec2::from([1.0, 2.0])), 0.0);
+    /// ```
+    ///
+    #[nightly(const)]
+    #[inline]
+    pub fn perp_dot(self, rhs: Self) -> T {
+        let [ax, ay] = self.into_array();
+        let [bx, by] = rhs.into_array();
+        ax * by - ay * bx
+    }
+}
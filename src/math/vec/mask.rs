@@ -0,0 +1,133 @@
+//!
+//! This module provides shader-style masked writes:
+//! elements are updated only where a `bvec` mask is `true`,
+//! which allows per-component logic without branching on the whole vec.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use rokoko::prelude::*;
+//!
+//! let zero = vec3::single(0.0);
+//! let mut v = vec3::from([-1.0, 2.0, -3.0]);
+//!
+//! // Clamp negatives to zero
+//! v.assign_masked(v.apply_binary(zero, |a, b| a < b), zero);
+//! assert_eq!(v, vec3::from([0.0, 2.0, 0.0]));
+//! ```
+//!
+
+use super::{vec, bvec};
+use crate::nightly;
+
+impl <T: Copy, const N: usize> vec <T, N> {
+    ///
+    /// Replaces elements of `self` with those of `src` where `mask` is `true`.
+    ///
+    /// # Constness
+    ///
+    /// Const when `nightly` feature is enabled
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rokoko::prelude::*;
+    ///
+    /// let src = ivec4::from([5, 6, 7, 8]);
+    ///
+    /// let mut v = ivec4::from([1, 2, 3, 4]);
+    /// v.assign_masked(bvec4::single(true), src);
+    /// assert_eq!(v, src);
+    ///
+    /// let mut v = ivec4::from([1, 2, 3, 4]);
+    /// v.assign_masked(bvec4::single(false), src);
+    /// assert_eq!(v, ivec4::from([1, 2, 3, 4]));
+    ///
+    /// v.assign_masked(bvec4::from([true, false, true, false]), src);
+    /// assert_eq!(v, ivec4::from([5, 2, 7, 4]));
+    /// ```
+    ///
+    #[inline]
+    #[nightly(const)]
+    pub fn assign_masked(&mut self, mask: bvec <N>, src: Self) {
+        let mut i = 0;
+        while i < N {
+            // SAFETY: `i` < `N`
+            unsafe {
+                if *mask.get_unchecked(i) {
+                    *self.get_unchecked_mut(i) = *src.get_unchecked(i)
+                }
+            }
+            i += 1
+        }
+    }
+
+    ///
+    /// Applies `f` to elements of `self` where `mask` is `true`.
+    ///
+    /// # Constness
+    ///
+    /// Const when `nightly` feature is enabled
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rokoko::prelude::*;
+    ///
+    /// let mut v = ivec4::from([1, 2, 3, 4]);
+    ///
+    /// v.select_assign(bvec4::from([false, true, false, true]), |x| x * 10);
+    /// assert_eq!(v, ivec4::from([1, 20, 3, 40]));
+    ///
+    /// v.select_assign(bvec4::single(false), |_| unreachable!());
+    /// assert_eq!(v, ivec4::from([1, 20, 3, 40]));
+    ///
+    /// v.select_assign(bvec4::single(true), |x| -x);
+    /// assert_eq!(v, ivec4::from([-1, -20, -3, -40]));
+    /// ```
+    ///
+    #[inline]
+    #[nightly(const(F: Fn(T) -> T))]
+    pub fn select_assign <F: Fn(T) -> T + Copy> (&mut self, mask: bvec <N>, f: F) {
+        let mut i = 0;
+        while i < N {
+            // SAFETY: `i` < `N`
+            unsafe {
+                if *mask.get_unchecked(i) {
+                    let elem = self.get_unchecked_mut(i);
+                    *elem = f(*elem)
+                }
+            }
+            i += 1
+        }
+    }
+
+    ///
+    /// Returns `self` with elements replaced by those of `src` where `mask` is `true`.
+    ///
+    /// Non-mutating version of [`assign_masked`](vec::assign_masked).
+    ///
+    /// # Constness
+    ///
+    /// Const when `nightly` feature is enabled
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rokoko::prelude::*;
+    ///
+    /// let a = bvec3::from([true, true, true]);
+    /// let b = bvec3::from([false, false, false]);
+    ///
+    /// assert_eq!(a.merged(bvec3::from([false, true, false]), b), bvec3::from([true, false, true]));
+    /// assert_eq!(a.merged(bvec3::single(true), b), b);
+    /// assert_eq!(a.merged(bvec3::single(false), b), a);
+    /// ```
+    ///
+    #[inline]
+    #[nightly(const)]
+    pub fn merged(mut self, mask: bvec <N>, src: Self) -> Self {
+        self.assign_masked(mask, src);
+        self
+    }
+}
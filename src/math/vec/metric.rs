@@ -0,0 +1,126 @@
+//!
+//! This module provides dot product, lengths and distances of `vec`s.
+//!
+//! [`dot`](vec::dot) and the squared variants work for any [`Scalar`], integers included,
+//! while [`length`](vec::length) and [`distance`](vec::distance) need a square root, i.e. a [`Float`].
+//!
+//! None of them is `const` even with `nightly` feature, since operators of [`Scalar`]
+//! are not required to be `const`.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use rokoko::prelude::*;
+//!
+//! let a = vec3::from([1.0, 2.0, 2.0]);
+//! let b = vec3::from([4.0, 6.0, 2.0]);
+//!
+//! assert_eq!(a.length(), 3.0);
+//! assert_eq!(a.distance(b), 5.0);
+//!
+//! // Integers have exact squared lengths
+//! assert_eq!(ivec2::from([3, -4]).length_squared(), 25);
+//! ```
+//!
+
+use super::{vec, optics};
+use crate::math::scalar::{Scalar, Float};
+
+impl <T: Scalar, const N: usize> vec <T, N> {
+    ///
+    /// Returns the dot product of `self` and `rhs`, i.e. the sum of products of corresponding elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rokoko::prelude::*;
+    ///
+    /// assert_eq!(ivec3::from([1, 2, 3]).dot(ivec3::from([4, -5, 6])), 12);
+    /// assert_eq!(vec2::from([1.0, 0.0]).dot(vec2::from([0.0, 1.0])), 0.0);
+    ///
+    /// // Empty sum
+    /// assert_eq!(ivec::<0>::ZERO.dot(ivec::<0>::ZERO), 0);
+    /// ```
+    ///
+    #[inline]
+    pub fn dot(self, rhs: Self) -> T {
+        optics::dot(self, rhs)
+    }
+
+    ///
+    /// Returns the squared length of `self`, i.e. `self.dot(self)`.
+    ///
+    /// Cheaper than [`length`](vec::length) and exact for integers, e.g. to compare lengths.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rokoko::prelude::*;
+    ///
+    /// assert_eq!(ivec3::from([1, -2, 2]).length_squared(), 9);
+    /// assert_eq!(uvec2::from([3, 4]).length_squared(), 25);
+    /// ```
+    ///
+    #[inline]
+    pub fn length_squared(self) -> T {
+        self.dot(self)
+    }
+
+    ///
+    /// Returns the squared distance between `self` and `rhs`.
+    ///
+    /// For unsigned `T`, `self - rhs` underflows unless every element of `self` is the greater one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rokoko::prelude::*;
+    ///
+    /// assert_eq!(ivec2::from([1, 1]).distance_squared(ivec2::from([4, -3])), 25);
+    /// ```
+    ///
+    #[inline]
+    pub fn distance_squared(self, rhs: Self) -> T {
+        (self - rhs).length_squared()
+    }
+}
+
+impl <T: Float, const N: usize> vec <T, N> {
+    ///
+    /// Returns the length of `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rokoko::prelude::*;
+    ///
+    /// assert_eq!(vec2::from([3.0, -4.0]).length(), 5.0);
+    /// assert_eq!(dvec4::single(0.5).length(), 1.0);
+    /// assert_eq!(vec3::single(0.0).length(), 0.0);
+    /// ```
+    ///
+    #[inline]
+    pub fn length(self) -> T {
+        self.length_squared().sqrt()
+    }
+
+    ///
+    /// Returns the distance between `self` and `rhs`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rokoko::prelude::*;
+    ///
+    /// let a = vec2::from([1.0, 1.0]);
+    /// let b = vec2::from([4.0, 5.0]);
+    ///
+    /// assert_eq!(a.distance(b), 5.0);
+    /// assert_eq!(b.distance(a), 5.0);
+    /// ```
+    ///
+    #[inline]
+    pub fn distance(self, rhs: Self) -> T {
+        (self - rhs).length()
+    }
+}
@@ -41,15 +41,91 @@
 //!
 //! ```
 //!
+//! # Layout
+//!
+//! `vec <T, N>` is `#[repr(transparent)]` over `[T; N]`, so it has exactly
+//! the same size, alignment and ABI, without any padding of its own.
+//!
+//! That is a stable guarantee: it is safe to transmute or cast pointers
+//! between `vec <T, N>` and `[T; N]`, and to pass `vec`s to FFI or GPU
+//! wherever the corresponding arrays are expected.
+//!
+//! Niches of `T` are preserved as well, e.g. `Option <vec <NonZeroU32, 2>>`
+//! is as big as `vec <NonZeroU32, 2>`.
+//!
+//! ```rust
+//! use rokoko::prelude::*;
+//! use std::{mem::size_of, num::NonZeroU32};
+//!
+//! assert_eq!(size_of::<vec3>(), size_of::<[f32; 3]>());
+//! assert_eq!(size_of::<Option <vec <NonZeroU32, 2>>>(), 8);
+//!
+//! // Reinterpreting a slice of vecs as a slice of scalars
+//! let vecs = [vec2::from([1.0, 2.0]), vec2::from([3.0, 4.0])];
+//! // SAFETY: `vec2` has the layout of `[f32; 2]`
+//! let flat = unsafe { std::slice::from_raw_parts(vecs.as_ptr() as *const f32, 4) };
+//! assert_eq!(flat, [1.0, 2.0, 3.0, 4.0]);
+//! ```
+//!
 
 mod ops;
+pub use self::ops::vec_eq;
+
+mod linear;
+
+mod segment;
+
+mod angles;
+
+mod optics;
+
+mod metric;
+
+mod reduce;
+
+mod clamp;
+
+mod sort;
+
+mod mask;
+
+mod normalize;
+
+mod shader;
+
+mod elementary;
+
+mod layout;
+
+mod pack;
+
+mod hash;
+pub use self::hash::HashableVec;
+
+mod cell;
+pub use self::cell::CellVec;
+
+mod homogeneous;
+
+mod shuffle;
+
+mod swizzle;
+
+mod cast;
+pub use self::cast::{CastError, SaturatingFrom};
+
+#[cfg(feature = "alloc")]
+mod collections;
+#[cfg(feature = "alloc")]
+pub use self::collections::{flatten, unflatten};
 
 pub mod new;
 
 pub mod alias;
 pub use self::alias::*;
 
-use crate::*;
+use crate::nightly;
+use super::scalar::Scalar;
 
 ///
 /// The main type of the crate.
@@ -58,7 +134,10 @@ use crate::*;
 ///
 /// Not camel-case `Vec` to show it is among the basic types
 ///
+/// Has the layout of `[T; N]`, see `Layout` section of the module documentation.
+///
 #[allow(non_camel_case_types)]
+#[repr(transparent)]
 pub struct vec <T, const N: usize> ([T; N]);
 
 ///
@@ -82,17 +161,26 @@ impl <T: Copy, const N: usize> Copy for vec <T, N> {}
 impl <T: Eq, const N: usize> Eq for vec <T, N> where Self: PartialEq {}
 
 ///
-/// `vec` implements Default if `T` is Default and Clone.
+/// `vec` of [`Scalar`]s defaults to [`vec::ZERO`], whether `T` implements `Default` or not.
 ///
 /// # Constness
 ///
-/// Const when `T` is `~const Default` and `nightly` feature is enabled
+/// Const when `nightly` feature is enabled
+///
+/// # Examples
+///
+/// ```
+/// use rokoko::prelude::*;
+///
+/// assert_eq!(vec3::default(), vec3::ZERO);
+/// assert_eq!(uvec2::default(), uvec2::from([0, 0]));
+/// ```
 ///
-#[nightly(const(T: Default))]
-impl <T: Default + Copy, const N: usize> Default for vec <T, N> {
+#[nightly(const)]
+impl <T: Scalar, const N: usize> Default for vec <T, N> {
     #[inline]
     fn default() -> Self {
-        Self([T::default(); N])
+        Self::ZERO
     }
 }
 
@@ -135,6 +223,7 @@ impl <T, const N: usize> vec <T, N> {
     #[nightly(const)]
     #[inline]
     pub fn into_array(self) -> [T; N] {
+        // SAFETY: `self` is never dropped, so the array is moved out exactly once
         unsafe { core::ptr::read(&core::mem::ManuallyDrop::new(self).0) }
     }
 
@@ -188,6 +277,8 @@ impl <T: Copy, const N: usize> vec <T, N> {
     ///
     /// Creates a new vec filled with `value`s.
     ///
+    /// Needs only `Copy`, not [`Scalar`], since masks are made with it too, e.g. `bvec4::single(true)`.
+    ///
     /// # Examples
     ///
     /// ```
@@ -205,33 +296,105 @@ impl <T: Copy, const N: usize> vec <T, N> {
     }
 }
 
+impl <T: Scalar, const N: usize> vec <T, N> {
+    ///
+    /// A vec filled with [`Scalar::ZERO`]s.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rokoko::prelude::*;
+    ///
+    /// assert_eq!(ivec3::ZERO, ivec3::single(0));
+    /// ```
+    ///
+    pub const ZERO: Self = Self([T::ZERO; N]);
+
+    ///
+    /// A vec filled with [`Scalar::ONE`]s.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rokoko::prelude::*;
+    ///
+    /// assert_eq!(fvec2::ONE, fvec2::single(1.0));
+    /// ```
+    ///
+    pub const ONE: Self = Self([T::ONE; N]);
+}
+
+impl <T: Scalar> vec <T, 2> {
+    /// The unit vec along the first axis
+    pub const UNIT_X: Self = Self([T::ONE, T::ZERO]);
+
+    /// The unit vec along the second axis
+    pub const UNIT_Y: Self = Self([T::ZERO, T::ONE]);
+}
+
+impl <T: Scalar> vec <T, 3> {
+    /// The unit vec along the first axis
+    pub const UNIT_X: Self = Self([T::ONE, T::ZERO, T::ZERO]);
+
+    /// The unit vec along the second axis
+    pub const UNIT_Y: Self = Self([T::ZERO, T::ONE, T::ZERO]);
+
+    /// The unit vec along the third axis
+    pub const UNIT_Z: Self = Self([T::ZERO, T::ZERO, T::ONE]);
+}
+
+impl <T: Scalar> vec <T, 4> {
+    ///
+    /// The unit vec along the first axis.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rokoko::prelude::*;
+    ///
+    /// assert_eq!(ivec4::UNIT_X + ivec4::UNIT_Y + ivec4::UNIT_Z + ivec4::UNIT_W, ivec4::ONE);
+    /// ```
+    ///
+    pub const UNIT_X: Self = Self([T::ONE, T::ZERO, T::ZERO, T::ZERO]);
+
+    /// The unit vec along the second axis
+    pub const UNIT_Y: Self = Self([T::ZERO, T::ONE, T::ZERO, T::ZERO]);
+
+    /// The unit vec along the third axis
+    pub const UNIT_Z: Self = Self([T::ZERO, T::ZERO, T::ONE, T::ZERO]);
+
+    /// The unit vec along the fourth axis
+    pub const UNIT_W: Self = Self([T::ZERO, T::ZERO, T::ZERO, T::ONE]);
+}
+
 impl <T, const N: usize> vec <T, N> {
     ///
     /// Returns an uninitialized vec.
     ///
-    /// # Safety
-    ///
-    /// Caller must guarantee that it will fill vec fully
-    /// before using it.
+    /// Every element must be written, e.g. through [`MaybeUninit::as_mut_ptr`](core::mem::MaybeUninit::as_mut_ptr),
+    /// before calling [`MaybeUninit::assume_init`](core::mem::MaybeUninit::assume_init).
     ///
     /// # Examples
     ///
     /// ```
     /// use rokoko::prelude::*;
     ///
+    /// let mut uninit = bvec3::uninit();
+    /// let base = uninit.as_mut_ptr() as *mut bool;
+    ///
+    /// // SAFETY: all 3 elements are written before `assume_init`
     /// let vec = unsafe {
-    ///     let mut uninit = bvec3::uninit();
-    ///     uninit[0] = true;
-    ///     uninit[1] = false;
-    ///     uninit[2] = false;
-    ///     uninit
+    ///     base.write(true);
+    ///     base.add(1).write(false);
+    ///     base.add(2).write(false);
+    ///     uninit.assume_init()
     /// };
     ///
     /// assert_eq!(vec, vec::from_array([true, false, false]));
     /// ```
     ///
     #[inline]
-    pub const unsafe fn uninit() -> Self {
-        core::mem::MaybeUninit::uninit().assume_init()
+    pub const fn uninit() -> core::mem::MaybeUninit <Self> {
+        core::mem::MaybeUninit::uninit()
     }
 }
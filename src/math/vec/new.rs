@@ -142,7 +142,8 @@ pub trait Piece <T> : Copy {
 ///
 #[nightly]
 pub const unsafe fn offset <T> (array: *mut T, offset: usize) -> *mut T {
-    (core::mem::transmute::<_, usize>(array) + core::mem::size_of::<T>() * offset) as *mut T
+    // SAFETY: the caller guarantees the result stays within the same allocation
+    unsafe { array.add(offset) }
 }
 
 ///
@@ -184,7 +185,8 @@ impl <T: From <U> + Copy, U: Copy + NotArray + NotTuple> Piece <T> for U {
 
     #[inline]
     unsafe fn embed(self, array: *mut T) {
-        *array = T::from(self)
+        // SAFETY: the caller guarantees `array` points to a slot
+        unsafe { *array = T::from(self) }
     }
 }
 
@@ -199,8 +201,12 @@ impl <T: Copy, U: Piece <T>, const N: usize> Piece <T> for [U; N] {
         let mut i = 0;
         let v = vec::from(self);
         while i < N {
-            v.get_unchecked(i).embed(place);
-            place = offset(place, U::N);
+            // SAFETY: `i` < `N`, and the caller guarantees there are `N * U::N` slots
+            // starting from `place`, `U::N` of which are used on each iteration
+            unsafe {
+                v.get_unchecked(i).embed(place);
+                place = offset(place, U::N)
+            }
             i += 1
         }
     }
@@ -215,7 +221,8 @@ impl <T: Copy, U: Piece <T>, const N: usize> Piece <T> for vec <U, N> {
 
     #[inline]
     unsafe fn embed(self, place: *mut T) {
-        self.0.embed(place)
+        // SAFETY: `vec <U, N>` uses exactly as many slots as `[U; N]`
+        unsafe { self.0.embed(place) }
     }
 }
 
@@ -244,12 +251,13 @@ impl <Args: Piece <T>, T: Default + Copy, const N: usize> Fn <Args> for New <T,
     extern "rust-call" fn call(&self, args: Args) -> Self::Output {
         assert!(Args::N <= N, "too many args");
 
-        // SAFETY: safe because values are filled in the loop & `embed` below
-        let mut result = unsafe { vec::uninit() };
+        let mut result = vec::<T, N>::uninit();
+
+        let base = &mut result as *mut core::mem::MaybeUninit <vec <T, N>> as *mut T;
 
-        // SAFETY: safe because `ptr` is used only if N >= Args::N
-        // so we are not out of bounds
-        let mut ptr = unsafe { result.get_unchecked_mut(Args::N) } as *mut T;
+        // SAFETY: safe because `Args::N <= N`, so `ptr` is at most one past the end
+        // and is dereferenced only while it is in bounds
+        let mut ptr = unsafe { offset(base, Args::N) };
         let mut i = 0;
         while i < (N - Args::N) {
             // SAFETY: safe because ptr is guaranteed to be correct(see previous `SAFETY`)
@@ -264,10 +272,11 @@ impl <Args: Piece <T>, T: Default + Copy, const N: usize> Fn <Args> for New <T,
         // is written by me, so that impl is safe unless some bugs,
         // but it seems there's none of them :)
         unsafe {
-            args.embed(&mut result as *mut vec <T, N> as *mut T)
+            args.embed(base)
         }
 
-        result
+        // SAFETY: all `N` elements are filled in the loop & `embed` above
+        unsafe { result.assume_init() }
     }
 }
 
@@ -0,0 +1,208 @@
+//!
+//! This module provides normalization and construction of an orthonormal basis around a normal.
+//!
+//! Dividing by the length of a zero vector poisons everything downstream
+//! with `NaN`s, which is what [`normalize`](vec::normalize) does, so every other variant
+//! here decides what to return instead.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use rokoko::prelude::*;
+//!
+//! let up = vec3::from([0.0, 1.0, 0.0]);
+//!
+//! // A degenerate direction, e.g. the difference of two equal points
+//! let p = vec3::from([1.0, 2.0, 3.0]);
+//! let dir = (p - p).normalize_or(up);
+//! assert_eq!(dir, up);
+//!
+//! // Tangent space of a surface
+//! let (tangent, bitangent) = vec3::orthonormal_basis(up);
+//! assert_eq!(tangent * up, vec3::single(0.0));
+//! assert_eq!(bitangent * up, vec3::single(0.0));
+//! ```
+//!
+
+use super::vec;
+use crate::math::scalar::Float;
+
+impl <T: Float, const N: usize> vec <T, N> {
+    ///
+    /// Returns `self` scaled to the unit length.
+    ///
+    /// A zero vector has no direction, so it becomes `NaN` in every element, as does a vector
+    /// of infinite or `NaN` length. Use [`normalize_or`](vec::normalize_or) or
+    /// [`try_normalize`](vec::try_normalize) where such vectors may occur.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rokoko::prelude::*;
+    ///
+    /// assert_eq!(vec2::from([3.0, -4.0]).normalize(), vec2::from([0.6, -0.8]));
+    /// assert_eq!(dvec3::from([0.0, 0.0, 2.0]).normalize(), dvec3::from([0.0, 0.0, 1.0]));
+    ///
+    /// assert!(vec3::single(0.0).normalize().into_array().iter().all(|x| x.is_nan()));
+    /// ```
+    ///
+    #[inline]
+    pub fn normalize(self) -> Self {
+        self / self.length()
+    }
+
+    ///
+    /// Returns `self` scaled to the unit length, or `None` if its length is not greater than `epsilon`
+    /// (or is infinite or `NaN`).
+    ///
+    /// `epsilon` of `0` only rejects vectors that cannot be normalized at all.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rokoko::prelude::*;
+    ///
+    /// assert_eq!(vec2::from([3.0, 4.0]).try_normalize(0.0), Some(vec2::from([0.6, 0.8])));
+    ///
+    /// // Zero and near-zero
+    /// assert_eq!(vec2::single(0.0).try_normalize(0.0), None);
+    /// assert_eq!(vec2::single(1e-30).try_normalize(0.0), None);
+    /// assert_eq!(vec2::from([1e-4, 0.0]).try_normalize(1e-3), None);
+    /// assert_eq!(vec2::from([1e-4, 0.0]).try_normalize(0.0), Some(vec2::from([1.0, 0.0])));
+    ///
+    /// // Poisoned
+    /// assert_eq!(vec2::from([f32::NAN, 1.0]).try_normalize(0.0), None);
+    /// assert_eq!(vec2::from([f32::INFINITY, 1.0]).try_normalize(0.0), None);
+    /// ```
+    ///
+    #[inline]
+    pub fn try_normalize(self, epsilon: T) -> Option <Self> {
+        let len = self.length();
+        // `NaN` fails both
+        if len > epsilon && len < T::INFINITY {
+            Some(self / len)
+        } else {
+            None
+        }
+    }
+
+    ///
+    /// Returns `self` scaled to the unit length, or `fallback` if that is impossible.
+    ///
+    /// See [`try_normalize`](vec::try_normalize).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rokoko::prelude::*;
+    ///
+    /// let fallback = vec3::from([0.0, 0.0, 1.0]);
+    ///
+    /// assert_eq!(vec3::from([2.0, 0.0, 0.0]).normalize_or(fallback), vec3::from([1.0, 0.0, 0.0]));
+    /// assert_eq!(vec3::single(0.0).normalize_or(fallback), fallback);
+    /// assert_eq!(vec3::single(-1e-30).normalize_or(fallback), fallback);
+    /// ```
+    ///
+    #[inline]
+    pub fn normalize_or(self, fallback: Self) -> Self {
+        match self.try_normalize(T::ZERO) {
+            Some(normalized) => normalized,
+            None => fallback
+        }
+    }
+
+    ///
+    /// Returns `self` scaled to the unit length, or zero vector if that is impossible.
+    ///
+    /// See [`try_normalize`](vec::try_normalize).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rokoko::prelude::*;
+    ///
+    /// assert_eq!(dvec2::from([0.0, -5.0]).normalize_or_zero(), dvec2::from([0.0, -1.0]));
+    /// assert_eq!(dvec2::single(0.0).normalize_or_zero(), dvec2::single(0.0));
+    /// assert_eq!(dvec2::single(1e-200).normalize_or_zero(), dvec2::single(0.0));
+    /// ```
+    ///
+    #[inline]
+    pub fn normalize_or_zero(self) -> Self {
+        self.normalize_or(Self::single(T::ZERO))
+    }
+}
+
+impl <T: Float> vec <T, 3> {
+    ///
+    /// Returns two unit vectors that together with `normal` form a right-handed orthonormal basis,
+    /// i.e. `tangent × bitangent = normal`.
+    ///
+    /// `normal` must be normalized.
+    ///
+    /// Uses the method of Frisvad, revised by Duff et al. in "Building an Orthonormal Basis, Revisited",
+    /// which has no singularity at `(0, 0, -1)` unlike the original one.
+    /// The result is fully determined by `normal`, so the same normal always gets the same basis.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rokoko::prelude::*;
+    ///
+    /// fn cross(a: dvec3, b: dvec3) -> dvec3 {
+    ///     dvec3::from([a[1] * b[2] - a[2] * b[1], a[2] * b[0] - a[0] * b[2], a[0] * b[1] - a[1] * b[0]])
+    /// }
+    /// fn dot(a: dvec3, b: dvec3) -> f64 {
+    ///     a[0] * b[0] + a[1] * b[1] + a[2] * b[2]
+    /// }
+    ///
+    /// // A sphere of normals, including the poles and their neighbourhoods
+    /// let mut normals = vec![
+    ///     dvec3::from([0.0, 0.0, 1.0]),
+    ///     dvec3::from([0.0, 0.0, -1.0]),
+    ///     dvec3::from([1e-9, 0.0, -1.0]).normalize_or_zero(),
+    ///     dvec3::from([0.0, -1e-9, -1.0]).normalize_or_zero()
+    /// ];
+    /// let n = 500;
+    /// for i in 0..n {
+    ///     let z = 1.0 - 2.0 * (i as f64 + 0.5) / n as f64;
+    ///     let r = (1.0 - z * z).sqrt();
+    ///     let phi = i as f64 * 2.399963229728653;
+    ///     normals.push(dvec3::from([r * phi.cos(), r * phi.sin(), z]))
+    /// }
+    ///
+    /// for normal in normals {
+    ///     let (t, b) = dvec3::orthonormal_basis(normal);
+    ///
+    ///     assert!((dot(t, t) - 1.0).abs() < 1e-12);
+    ///     assert!((dot(b, b) - 1.0).abs() < 1e-12);
+    ///     assert!(dot(t, b).abs() < 1e-12);
+    ///     assert!(dot(t, normal).abs() < 1e-12);
+    ///     assert!(dot(b, normal).abs() < 1e-12);
+    ///
+    ///     // Right-handed
+    ///     assert!(dot(cross(t, b), normal) > 1.0 - 1e-12);
+    ///
+    ///     // Deterministic
+    ///     assert_eq!(dvec3::orthonormal_basis(normal), (t, b))
+    /// }
+    ///
+    /// // Exactly at the poles
+    /// assert_eq!(vec3::orthonormal_basis(vec3::from([0.0, 0.0, 1.0])), (vec3::from([1.0, 0.0, 0.0]), vec3::from([0.0, 1.0, 0.0])));
+    /// assert_eq!(vec3::orthonormal_basis(vec3::from([0.0, 0.0, -1.0])), (vec3::from([1.0, 0.0, 0.0]), vec3::from([0.0, -1.0, 0.0])));
+    /// ```
+    ///
+    #[inline]
+    pub fn orthonormal_basis(normal: Self) -> (Self, Self) {
+        let [x, y, z] = normal.into_array();
+
+        // Not `z.signum()`: it has to be `±1` for `z == 0` as well
+        let sign = if z < T::ZERO { -T::ONE } else { T::ONE };
+        let a = -T::ONE / (sign + z);
+        let b = x * y * a;
+
+        (
+            vec::from_array([T::ONE + sign * x * x * a, sign * b, -sign * x]),
+            vec::from_array([b, sign + y * y * a, -y])
+        )
+    }
+}
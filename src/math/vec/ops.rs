@@ -7,15 +7,47 @@ use crate::nightly;
 use core::{
     ops::*,
     borrow::*,
+    mem::MaybeUninit,
     fmt
 };
 
-impl <T: fmt::Debug + Copy, const N: usize> fmt::Debug for vec <T, N> {
+///
+/// Writes a type name with all the paths stripped,
+/// e.g. `rokoko::math::vec::vec<rokoko::math::vec::vec<i32, 2>, 3>`
+/// becomes `vec<vec<i32, 2>, 3>`.
+///
+struct ShortTypeName(&'static str);
+
+impl fmt::Display for ShortTypeName {
+    fn fmt(&self, f: &mut fmt::Formatter <'_>) -> fmt::Result {
+        let mut segment_begin = 0;
+        for (i, c) in self.0.char_indices() {
+            if c == ':' {
+                segment_begin = i + 1
+            } else if !(c.is_alphanumeric() || c == '_') {
+                f.write_str(&self.0[segment_begin..=i])?;
+                segment_begin = i + 1
+            }
+        }
+        f.write_str(&self.0[segment_begin..])
+    }
+}
+
+impl <T: fmt::Debug, const N: usize> fmt::Debug for vec <T, N> {
     fn fmt(&self, f: &mut fmt::Formatter <'_>) -> fmt::Result {
-        let type_name = core::any::type_name::<Self>();
-        let mut tuple = f.debug_tuple(&type_name[type_name.find("vec<").unwrap()..]);
+        write!(f, "{}", ShortTypeName(core::any::type_name::<Self>()))?;
+
+        // `debug_tuple` with empty name would print `(x,)` for a single field
+        if N == 1 && !f.alternate() {
+            f.write_str("(")?;
+            fmt::Debug::fmt(&self[0], f)?;
+            return f.write_str(")")
+        }
+
+        let mut tuple = f.debug_tuple("");
         let mut i = 0;
         while i < N {
+            // SAFETY: `i` < `N`
             tuple.field(unsafe { self.get_unchecked(i) });
             i += 1
         }
@@ -32,7 +64,7 @@ impl <T, const N: usize> From <[T; N]> for vec <T, N> {
 }
 
 #[nightly(const)]
-impl <T: Copy, const N: usize> Into <[T; N]> for vec <T, N> {
+impl <T, const N: usize> Into <[T; N]> for vec <T, N> {
     #[inline]
     fn into(self) -> [T; N] {
         self.0
@@ -102,10 +134,9 @@ impl <T, const N: usize> vec <T, N> {
     ///
     #[inline]
     pub const unsafe fn get_unchecked(&self, idx: usize) -> &T {
-        let begin_address: usize = core::mem::transmute(self);
-        let elem_address = begin_address + idx * core::mem::size_of::<T>();
-        let elem_address = elem_address as *const T;
-        &*elem_address
+        // SAFETY: `vec` is a transparent wrapper over `[T; N]`,
+        // and the caller guarantees that `idx` < `N`
+        unsafe { &*(self as *const Self as *const T).add(idx) }
     }
 
     ///
@@ -143,8 +174,9 @@ impl <T, const N: usize> vec <T, N> {
     #[nightly(const)]
     #[inline]
     pub unsafe fn get_unchecked_mut(&mut self, idx: usize) -> &mut T {
-        // Such a cast is possible there since we know that `self` is mutable
-        &mut *(self.get_unchecked(idx) as *const T as *mut T)
+        // SAFETY: same as for `get_unchecked`; the pointer is derived from `&mut self`,
+        // so writing through it is allowed
+        unsafe { &mut *(self as *mut Self as *mut T).add(idx) }
     }
 }
 
@@ -170,9 +202,12 @@ impl <T: Copy, const N: usize> vec <T, N> {
     #[nightly(const(F: Fn(T, U) -> R))]
     pub fn apply_binary <U: Copy, R, F: Fn(T, U) -> R + Copy> (self, rhs: vec <U, N>, op: F) -> vec <R, N> {
         let mut i = 0;
-        // SAFETY: all elements gain proper value in the loop below
-        let mut result = unsafe { vec::uninit() };
+        // Not `vec::uninit`, since `R` may have drop glue: if `op` panics,
+        // elements written so far are leaked instead of dropping the unwritten ones
+        let mut result = MaybeUninit::<vec <R, N>>::uninit();
+        let base = &mut result as *mut MaybeUninit <vec <R, N>> as *mut R;
         while i < N {
+            // SAFETY: every step is justified below
             unsafe {
                 // SAFETY: safe because `i` iterates from 0 to N(exclusively)
                 // and thus is never out of bounds
@@ -188,7 +223,7 @@ impl <T: Copy, const N: usize> vec <T, N> {
 
                 // SAFETY: safe because `i` iterates from 0 to N(exclusively)
                 // and thus is never out of bounds
-                let result_address = result.get_unchecked_mut(i);
+                let result_address = base.add(i);
 
                 // SAFETY: safe because address is guaranteed to be correct(see previous `SAFETY`)
                 // and value does not need to be dropped(because it is not currently initialized)
@@ -196,7 +231,8 @@ impl <T: Copy, const N: usize> vec <T, N> {
             }
             i += 1
         }
-        result
+        // SAFETY: all `N` elements are written in the loop above
+        unsafe { result.assume_init() }
     }
 
     ///
@@ -221,9 +257,12 @@ impl <T: Copy, const N: usize> vec <T, N> {
     #[nightly(const(F: Fn(T, U) -> R))]
     pub fn apply_binary_single <U: Copy, R, F: Fn(T, U) -> R + Copy> (self, rhs: U, op: F) -> vec <R, N> {
         let mut i = 0;
-        // SAFETY: all elements gain proper value in the loop below
-        let mut result = unsafe { vec::uninit() };
+        // Not `vec::uninit`, since `R` may have drop glue: if `op` panics,
+        // elements written so far are leaked instead of dropping the unwritten ones
+        let mut result = MaybeUninit::<vec <R, N>>::uninit();
+        let base = &mut result as *mut MaybeUninit <vec <R, N>> as *mut R;
         while i < N {
+            // SAFETY: every step is justified below
             unsafe {
                 // SAFETY: safe because `i` iterates from 0 to N(exclusively)
                 // and thus is never out of bounds
@@ -237,7 +276,7 @@ impl <T: Copy, const N: usize> vec <T, N> {
 
                 // SAFETY: safe because `i` iterates from 0 to N(exclusively)
                 // and thus is never out of bounds
-                let result_address = result.get_unchecked_mut(i);
+                let result_address = base.add(i);
 
                 // SAFETY: safe because address is guaranteed to be correct(see previous `SAFETY`)
                 // and value does not need to be dropped(because it is not currently initialized)
@@ -245,7 +284,8 @@ impl <T: Copy, const N: usize> vec <T, N> {
             }
             i += 1
         }
-        result
+        // SAFETY: all `N` elements are written in the loop above
+        unsafe { result.assume_init() }
     }
 
     ///
@@ -269,9 +309,12 @@ impl <T: Copy, const N: usize> vec <T, N> {
     #[nightly(const(F: Fn(T) -> R))]
     pub fn apply_unary <R, F: Fn(T) -> R + Copy> (self, op: F) -> vec <R, N> {
         let mut i = 0;
-        // SAFETY: all elements gain proper value in the loop below
-        let mut result = unsafe { vec::uninit() };
+        // Not `vec::uninit`, since `R` may have drop glue: if `op` panics,
+        // elements written so far are leaked instead of dropping the unwritten ones
+        let mut result = MaybeUninit::<vec <R, N>>::uninit();
+        let base = &mut result as *mut MaybeUninit <vec <R, N>> as *mut R;
         while i < N {
+            // SAFETY: every step is justified below
             unsafe {
                 // SAFETY: safe because `i` iterates from 0 to N(exclusively)
                 // and thus is never out of bounds
@@ -285,7 +328,7 @@ impl <T: Copy, const N: usize> vec <T, N> {
 
                 // SAFETY: safe because `i` iterates from 0 to N(exclusively)
                 // and thus is never out of bounds
-                let result_address = result.get_unchecked_mut(i);
+                let result_address = base.add(i);
 
                 // SAFETY: safe because address is guaranteed to be correct(see previous `SAFETY`)
                 // and value does not need to be dropped(because it is not currently initialized)
@@ -293,7 +336,62 @@ impl <T: Copy, const N: usize> vec <T, N> {
             }
             i += 1
         }
-        result
+        // SAFETY: all `N` elements are written in the loop above
+        unsafe { result.assume_init() }
+    }
+
+    ///
+    /// Applies `op` to all elements together with their indices and returns the result,
+    /// same as [`apply_unary`](vec::apply_unary) otherwise.
+    ///
+    /// # Constness
+    ///
+    /// Const when `nightly` feature is enabled.
+    ///
+    /// # Examples
+    /// ```
+    /// use rokoko::prelude::*;
+    ///
+    /// let vec = ivec4::single(10);
+    /// let offset = vec.map_indexed(|i, e| e + i as i32);
+    /// assert_eq!(offset, ivec4::from_array([10, 11, 12, 13]));
+    ///
+    /// // E.g. a mask of the elements at even indices
+    /// assert_eq!(vec.map_indexed(|i, _| i % 2 == 0), bvec4::from_array([true, false, true, false]));
+    /// ```
+    ///
+    #[nightly(const(F: Fn(usize, T) -> R))]
+    pub fn map_indexed <R, F: Fn(usize, T) -> R + Copy> (self, op: F) -> vec <R, N> {
+        let mut i = 0;
+        // Not `vec::uninit`, since `R` may have drop glue: if `op` panics,
+        // elements written so far are leaked instead of dropping the unwritten ones
+        let mut result = MaybeUninit::<vec <R, N>>::uninit();
+        let base = &mut result as *mut MaybeUninit <vec <R, N>> as *mut R;
+        while i < N {
+            // SAFETY: every step is justified below
+            unsafe {
+                // SAFETY: safe because `i` iterates from 0 to N(exclusively)
+                // and thus is never out of bounds
+                let address = self.get_unchecked(i);
+
+                // SAFETY: safe because address is guaranteed to be correct(see previous `SAFETY`)
+                // and value does not need to be dropped(because `T` is Copy)
+                let elem = core::ptr::read(address);
+
+                let calculated = op(i, elem);
+
+                // SAFETY: safe because `i` iterates from 0 to N(exclusively)
+                // and thus is never out of bounds
+                let result_address = base.add(i);
+
+                // SAFETY: safe because address is guaranteed to be correct(see previous `SAFETY`)
+                // and value does not need to be dropped(because it is not currently initialized)
+                core::ptr::write(result_address, calculated);
+            }
+            i += 1
+        }
+        // SAFETY: all `N` elements are written in the loop above
+        unsafe { result.assume_init() }
     }
 
     ///
@@ -318,6 +416,7 @@ impl <T: Copy, const N: usize> vec <T, N> {
     pub fn modify_binary <U: Copy, R: Into <T>, F: Fn(T, U) -> R + Copy> (&mut self, rhs: vec <U, N>, op: F) {
         let mut i = 0;
         while i < N {
+            // SAFETY: every step is justified below
             unsafe {
                 // SAFETY: safe because `i` iterates from 0 to N(exclusively)
                 // and thus is never out of bounds
@@ -362,6 +461,7 @@ impl <T: Copy, const N: usize> vec <T, N> {
     pub fn modify_binary_single <U: Copy, R: Into <T>, F: Fn(T, U) -> R + Copy> (&mut self, rhs: U, op: F) {
         let mut i = 0;
         while i < N {
+            // SAFETY: every step is justified below
             unsafe {
                 // SAFETY: safe because `i` iterates from 0 to N(exclusively)
                 // and thus is never out of bounds
@@ -401,6 +501,7 @@ impl <T: Copy, const N: usize> vec <T, N> {
     pub fn modify_unary <R: Into <T>, F: Fn(T) -> R + Copy> (&mut self, op: F) {
         let mut i = 0;
         while i < N {
+            // SAFETY: every step is justified below
             unsafe {
                 // SAFETY: safe because `i` iterates from 0 to N(exclusively)
                 // and thus is never out of bounds
@@ -441,6 +542,7 @@ impl <T: Copy, const N: usize> vec <T, N> {
     pub fn apply_binary_bool <U: Copy, F: Fn(T, U) -> bool + Copy> (self, rhs: vec <U, N>, op: F) -> bool {
         let mut i = 0;
         while i < N {
+            // SAFETY: every step is justified below
             unsafe {
                 // SAFETY: safe because `i` iterates from 0 to N(exclusively)
                 // and thus is never out of bounds
@@ -483,6 +585,7 @@ impl <T: Copy, const N: usize> vec <T, N> {
     pub fn apply_unary_bool <F: Fn(T) -> bool + Copy> (self, op: F) -> bool {
         let mut i = 0;
         while i < N {
+            // SAFETY: every step is justified below
             unsafe {
                 // SAFETY: safe because `i` iterates from 0 to N(exclusively)
                 // and thus is never out of bounds
@@ -570,8 +673,47 @@ fn eq <T: PartialEq <T> + Copy> (a: T, b: T) -> bool {
 impl <T: Copy + PartialEq <T>, const N: usize> PartialEq for vec <T, N> {
     #[inline]
     fn eq(&self, other: &Self) -> bool {
-        self.apply_binary_bool(*other, eq)
+        vec_eq(*self, *other)
+    }
+}
+
+///
+/// Returns `true` if all the corresponding elements of `a` and `b` are equal.
+///
+/// Same as `a == b`, but a plain function, so it can be called in `const` contexts,
+/// e.g. `const { assert!(...) }`, where the compiler does not accept `==` or methods taking
+/// closures yet.
+///
+/// # Constness
+///
+/// Const when `nightly` feature is enabled.
+///
+/// # Examples
+/// ```
+/// use rokoko::prelude::*;
+/// use rokoko::math::vec::vec_eq;
+///
+/// assert!(vec_eq(ivec3::ZERO, ivec3::single(0)));
+/// assert!(!vec_eq(ivec3::UNIT_X, ivec3::UNIT_Y));
+///
+/// // Just as `==`
+/// assert!(!vec_eq(vec2::single(f32::NAN), vec2::single(f32::NAN)));
+/// ```
+///
+#[inline]
+#[nightly(const(T: PartialEq <T>))]
+pub fn vec_eq <T: Copy + PartialEq <T>, const N: usize> (a: vec <T, N>, b: vec <T, N>) -> bool {
+    let mut i = 0;
+    while i < N {
+        // SAFETY: safe because `i` iterates from 0 to N(exclusively)
+        // and thus is never out of bounds
+        let (x, y) = unsafe { (*a.get_unchecked(i), *b.get_unchecked(i)) };
+        if !eq(x, y) {
+            return false
+        }
+        i += 1
     }
+    true
 }
 
 #[nightly(const)]
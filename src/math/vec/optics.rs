@@ -0,0 +1,140 @@
+//!
+//! This module provides optics functions for shading,
+//! similar to those of `GLSL`.
+//!
+//! All of them work for any dimension, though only `vec2` and `vec3`
+//! are meaningful in practice.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use rokoko::prelude::*;
+//!
+//! // A ray hitting a floor at 45 degrees
+//! let ray = vec3::from([1.0, -1.0, 0.0]) * std::f32::consts::FRAC_1_SQRT_2;
+//! let normal = vec3::from([0.0, 1.0, 0.0]);
+//!
+//! let reflected = ray.reflect(normal);
+//! assert!((reflected - vec3::from([1.0, 1.0, 0.0]) * std::f32::consts::FRAC_1_SQRT_2).into_array().iter().all(|x| x.abs() < 1e-6));
+//!
+//! // Entering water bends the ray towards the normal
+//! let refracted = ray.refract(normal, 1.0 / 1.33).unwrap();
+//! assert!(refracted[0] < ray[0]);
+//! ```
+//!
+
+use super::vec;
+use crate::math::scalar::{Scalar, Float};
+
+/// Sum of products of corresponding elements
+#[inline]
+pub(super) fn dot <T: Scalar, const N: usize> (a: vec <T, N>, b: vec <T, N>) -> T {
+    let mut sum = T::ZERO;
+    let mut i = 0;
+    while i < N {
+        // SAFETY: `i` < `N`
+        sum = sum + unsafe { *a.get_unchecked(i) * *b.get_unchecked(i) };
+        i += 1
+    }
+    sum
+}
+
+impl <T: Float, const N: usize> vec <T, N> {
+    ///
+    /// Reflects the incident direction `self` off a surface with the normal `n`.
+    ///
+    /// `n` must be normalized.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rokoko::prelude::*;
+    ///
+    /// let incident = vec2::from([0.6, -0.8]);
+    /// let normal = vec2::from([0.0, 1.0]);
+    /// let reflected = incident.reflect(normal);
+    ///
+    /// assert_eq!(reflected, vec2::from([0.6, 0.8]));
+    ///
+    /// // The angle of reflection equals the angle of incidence
+    /// let cos = |v: vec2| v[0] * normal[0] + v[1] * normal[1];
+    /// assert_eq!(cos(reflected), -cos(incident));
+    /// ```
+    ///
+    #[inline]
+    pub fn reflect(self, n: Self) -> Self {
+        let d = dot(n, self);
+        self.apply_binary(n, |i, n| i - n * (d + d))
+    }
+
+    ///
+    /// Refracts the incident direction `self` through a surface with the normal `n`,
+    /// where `eta` is the ratio of indices of refraction(from / to).
+    ///
+    /// Both `self` and `n` must be normalized.
+    ///
+    /// Returns `None` on total internal reflection.
+    ///
+    /// # Difference from `GLSL`
+    ///
+    /// `GLSL`'s `refract` returns a zero vector on total internal reflection,
+    /// which is easy to mistake for a valid direction; `None` cannot be.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rokoko::prelude::*;
+    ///
+    /// let normal = dvec3::from([0.0, 1.0, 0.0]);
+    ///
+    /// // Nothing changes if indices are equal
+    /// let incident = dvec3::from([0.6, -0.8, 0.0]);
+    /// let same = incident.refract(normal, 1.0).unwrap();
+    /// assert!((same - incident).into_array().iter().all(|x| x.abs() < 1e-12));
+    ///
+    /// // From glass to air the critical angle is `asin(1 / 1.5)`, about 41.8 degrees
+    /// let at = |degrees: f64| {
+    ///     let angle = degrees.to_radians();
+    ///     dvec3::from([angle.sin(), -angle.cos(), 0.0])
+    /// };
+    /// assert!(at(41.0).refract(normal, 1.5).is_some());
+    /// assert!(at(42.0).refract(normal, 1.5).is_none());
+    /// ```
+    ///
+    #[inline]
+    pub fn refract(self, n: Self, eta: T) -> Option <Self> {
+        let d = dot(n, self);
+        let k = T::ONE - eta * eta * (T::ONE - d * d);
+        if k < T::ZERO {
+            return None
+        }
+        let scale = eta * d + k.sqrt();
+        Some(self.apply_binary(n, |i, n| eta * i - scale * n))
+    }
+
+    ///
+    /// Returns Schlick's approximation of Fresnel reflectance,
+    /// where `cos_theta` is the cosine of the angle between the view direction and the normal
+    /// and `f0` is the reflectance at normal incidence(per color channel).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rokoko::prelude::*;
+    ///
+    /// let f0 = vec3::from([0.04, 0.5, 0.9]);
+    ///
+    /// // Looking straight at the surface
+    /// assert_eq!(vec3::fresnel_schlick(1.0, f0), f0);
+    ///
+    /// // At grazing angles everything becomes a mirror
+    /// assert!(vec3::fresnel_schlick(0.0, f0).into_array().iter().all(|x| (x - 1.0).abs() < 1e-6));
+    /// ```
+    ///
+    #[inline]
+    pub fn fresnel_schlick(cos_theta: T, f0: Self) -> Self {
+        let m = T::ONE - cos_theta;
+        let m5 = m * m * m * m * m;
+        f0.apply_unary(|f0| f0 + (T::ONE - f0) * m5)
+    }
+}
@@ -0,0 +1,239 @@
+//!
+//! This module provides packing of vecs into 32-bit GPU formats:
+//! `RGB10A2` for colors with a 2-bit alpha and normals, and
+//! `R11G11B10F` for HDR colors.
+//!
+//! # Layout
+//!
+//! The first element takes the lowest bits, as in Vulkan's `A2B10G10R10_UNORM_PACK32`
+//! and `B10G11R11_UFLOAT_PACK32` (DXGI's `R10G10B10A2_UNORM` and `R11G11B10_FLOAT`).
+//! The layout is of the `u32` value, not of its bytes: GPUs read it as a little-endian word,
+//! so a vertex buffer gets [`u32::to_le_bytes`] of it, on any host.
+//!
+//! # Conversions
+//!
+//! Following the Vulkan specification:
+//! - unorm elements are clamped to `[0, 1]` and rounded to the nearest code, `NaN` becomes `0`;
+//! - unsigned small floats are rounded to the nearest, ties to even; negatives become `0`,
+//!   finite values above the largest representable one become it, infinity stays infinity,
+//!   and `NaN` stays `NaN`, with all the mantissa bits set.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use rokoko::prelude::*;
+//!
+//! // An opaque color
+//! let color = vec4::from([1.0, 0.5, 0.0, 1.0]);
+//! let packed = color.to_rgb10a2();
+//! assert_eq!(packed, 0xC008_03FF);
+//! assert_eq!(packed.to_le_bytes(), [0xFF, 0x03, 0x08, 0xC0]);
+//! assert!((vec4::from_rgb10a2(packed) - color).into_array().iter().all(|e| e.abs() <= 1.0 / 1023.0));
+//!
+//! // An HDR color, far out of `[0, 1]`
+//! let hdr = vec3::from([64.0, 1.0, 0.5]);
+//! assert_eq!(vec3::from_r11g11b10f(hdr.to_r11g11b10f()), hdr);
+//! ```
+//!
+
+use super::vec;
+
+/// Largest code of an unorm of `bits` bits, as `f32`
+#[inline]
+fn unorm_max(bits: u32) -> f32 {
+    ((1u32 << bits) - 1) as f32
+}
+
+/// Converts `x` to an unorm of `bits` bits
+#[inline]
+fn to_unorm(x: f32, bits: u32) -> u32 {
+    if x.is_nan() {
+        return 0
+    }
+    (x.clamp(0.0, 1.0) * unorm_max(bits) + 0.5) as u32
+}
+
+/// Converts an unorm of `bits` bits to `f32`
+#[inline]
+fn from_unorm(x: u32, bits: u32) -> f32 {
+    x as f32 / unorm_max(bits)
+}
+
+/// Rounds `truncated`, which is `full` shifted right by `shift`, to the nearest, ties to even
+#[inline]
+fn round_shifted(truncated: u32, full: u32, shift: u32) -> u32 {
+    let rest = full & ((1 << shift) - 1);
+    let half = 1 << (shift - 1);
+    if rest > half || (rest == half && truncated & 1 == 1) {
+        truncated + 1
+    } else {
+        truncated
+    }
+}
+
+///
+/// Converts `x` to an unsigned float with a 5-bit exponent and a mantissa of `mantissa` bits,
+/// see [module documentation](self).
+///
+fn to_small_float(x: f32, mantissa: u32) -> u32 {
+    let ones = (1 << mantissa) - 1;
+    let infinity = 31 << mantissa;
+    let max = (30 << mantissa) | ones;
+
+    if x.is_nan() {
+        return infinity | ones
+    }
+    // Zeros and negatives, infinity included
+    if x <= 0.0 {
+        return 0
+    }
+    if x == f32::INFINITY {
+        return infinity
+    }
+
+    let bits = x.to_bits();
+    let exponent = (bits >> 23) as i32 - 127 + 15;
+    let significand = (1 << 23) | (bits & 0x7F_FFFF);
+
+    let rounded = if exponent >= 1 {
+        // A carry out of the mantissa increments the exponent, as it should
+        let shift = 23 - mantissa;
+        round_shifted(((exponent as u32) << mantissa) | ((significand >> shift) & ones), significand, shift)
+    } else {
+        // Denormal: the implicit one becomes explicit, shifted further the smaller the exponent is
+        let shift = 23 - mantissa + (1 - exponent) as u32;
+        if shift > 24 {
+            // Less than half of the smallest denormal
+            return 0
+        }
+        round_shifted(significand >> shift, significand, shift)
+    };
+
+    rounded.min(max)
+}
+
+/// Converts an unsigned float with a 5-bit exponent and a mantissa of `mantissa` bits to `f32`
+fn from_small_float(x: u32, mantissa: u32) -> f32 {
+    let exponent = x >> mantissa;
+    let m = x & ((1 << mantissa) - 1);
+    match exponent {
+        0 => m as f32 / (1u32 << (14 + mantissa)) as f32,
+        31 => if m == 0 { f32::INFINITY } else { f32::NAN },
+        _ => f32::from_bits(((exponent + 127 - 15) << 23) | (m << (23 - mantissa)))
+    }
+}
+
+impl vec <f32, 4> {
+    ///
+    /// Packs `self` into `RGB10A2`: three 10-bit unorms and a 2-bit unorm,
+    /// see [module documentation](self) for the layout and conversions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rokoko::prelude::*;
+    ///
+    /// assert_eq!(vec4::single(0.0).to_rgb10a2(), 0);
+    /// assert_eq!(vec4::single(1.0).to_rgb10a2(), 0xFFFF_FFFF);
+    /// // `0.5 * 1023` and `0.5 * 3` are ties, rounded up
+    /// assert_eq!(vec4::single(0.5).to_rgb10a2(), 0xA008_0200);
+    ///
+    /// // Clamped, `NaN` is zero
+    /// assert_eq!(vec4::from([-1.0, 2.0, f32::NAN, f32::INFINITY]).to_rgb10a2(), 0xC00F_FC00);
+    /// ```
+    ///
+    pub fn to_rgb10a2(self) -> u32 {
+        to_unorm(self[0], 10)
+            | to_unorm(self[1], 10) << 10
+            | to_unorm(self[2], 10) << 20
+            | to_unorm(self[3], 2) << 30
+    }
+
+    ///
+    /// Unpacks `RGB10A2`, see [`vec::to_rgb10a2`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rokoko::prelude::*;
+    ///
+    /// assert_eq!(vec4::from_rgb10a2(0xFFFF_FFFF), vec4::single(1.0));
+    /// assert_eq!(vec4::from_rgb10a2(0x4000_0000 | 1023 << 10), vec4::from([0.0, 1.0, 0.0, 1.0 / 3.0]));
+    /// ```
+    ///
+    pub fn from_rgb10a2(packed: u32) -> Self {
+        Self::from([
+            from_unorm(packed & 0x3FF, 10),
+            from_unorm(packed >> 10 & 0x3FF, 10),
+            from_unorm(packed >> 20 & 0x3FF, 10),
+            from_unorm(packed >> 30, 2)
+        ])
+    }
+}
+
+impl vec <f32, 3> {
+    ///
+    /// Packs `self` into `R11G11B10F`: two unsigned 11-bit floats and an unsigned 10-bit one,
+    /// each with a 5-bit exponent, see [module documentation](self) for the layout and conversions.
+    ///
+    /// The largest finite values are `65024` for the first two elements and `64512` for the last one;
+    /// the relative precision is `2^-7` and `2^-6` respectively.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rokoko::prelude::*;
+    ///
+    /// // Exponents of `1.0`: 15, of `0.5`: 14
+    /// assert_eq!(vec3::single(1.0).to_r11g11b10f(), 15 << 6 | 15 << 6 << 11 | 15 << 5 << 22);
+    /// assert_eq!(vec3::from([0.5, 0.0, 0.0]).to_r11g11b10f(), 14 << 6);
+    /// assert_eq!(vec3::single(0.0).to_r11g11b10f(), 0);
+    ///
+    /// // Negatives are zero, too big values are the largest ones, infinity and `NaN` stay
+    /// assert_eq!(vec3::from([-1.0, f32::NEG_INFINITY, -0.0]).to_r11g11b10f(), 0);
+    /// assert_eq!(vec3::from_r11g11b10f(vec3::from([1e6, 65024.0, 1e6]).to_r11g11b10f()), vec3::from([65024.0, 65024.0, 64512.0]));
+    /// assert_eq!(vec3::from([f32::INFINITY, 0.0, 0.0]).to_r11g11b10f(), 31 << 6);
+    /// assert_eq!(vec3::from([f32::NAN, 0.0, f32::NAN]).to_r11g11b10f(), 0x7FF | 0x3FF << 22);
+    ///
+    /// // Rounded to the nearest, ties to even
+    /// let unpack = |x: f32| vec3::from_r11g11b10f(vec3::from([x, 0.0, 0.0]).to_r11g11b10f())[0];
+    /// assert_eq!(unpack(1.0 + 1.0 / 128.0), 1.0);
+    /// assert_eq!(unpack(1.0 + 3.0 / 128.0), 1.0 + 2.0 / 64.0);
+    /// assert_eq!(unpack(1.0 + 1.0 / 64.0 + 1.0 / 1024.0), 1.0 + 1.0 / 64.0);
+    ///
+    /// // Denormals, the smallest one is `2^-20`
+    /// assert_eq!(unpack(2f32.powi(-20)), 2f32.powi(-20));
+    /// assert_eq!(unpack(2f32.powi(-21)), 0.0);
+    /// assert_eq!(unpack(1.5 * 2f32.powi(-21)), 2f32.powi(-20));
+    /// ```
+    ///
+    pub fn to_r11g11b10f(self) -> u32 {
+        to_small_float(self[0], 6)
+            | to_small_float(self[1], 6) << 11
+            | to_small_float(self[2], 5) << 22
+    }
+
+    ///
+    /// Unpacks `R11G11B10F`, see [`vec::to_r11g11b10f`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rokoko::prelude::*;
+    ///
+    /// assert_eq!(vec3::from_r11g11b10f(0), vec3::single(0.0));
+    /// assert_eq!(vec3::from_r11g11b10f(15 << 6 | 14 << 6 << 11 | 16 << 5 << 22), vec3::from([1.0, 0.5, 2.0]));
+    ///
+    /// let special = vec3::from_r11g11b10f(31 << 6 | (31 << 6 | 1) << 11);
+    /// assert_eq!(special[0], f32::INFINITY);
+    /// assert!(special[1].is_nan());
+    /// ```
+    ///
+    pub fn from_r11g11b10f(packed: u32) -> Self {
+        Self::from([
+            from_small_float(packed & 0x7FF, 6),
+            from_small_float(packed >> 11 & 0x7FF, 6),
+            from_small_float(packed >> 22, 5)
+        ])
+    }
+}
@@ -0,0 +1,206 @@
+//!
+//! This module provides reductions of `vec`s to a single value: [`fold`](vec::fold)
+//! and its common cases, [`sum`](vec::sum), [`product`](vec::product),
+//! [`min_element`](vec::min_element) and [`max_element`](vec::max_element).
+//!
+//! All of them are `const` with `nightly` feature, as long as the operations they use are,
+//! e.g. `ivec4::sum` is, since `i32` adds in `const` contexts.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use rokoko::prelude::*;
+//!
+//! let v = ivec4::from([3, -1, 4, 1]);
+//!
+//! assert_eq!(v.sum(), 7);
+//! assert_eq!(v.product(), -12);
+//! assert_eq!((v.min_element(), v.max_element()), (-1, 4));
+//!
+//! // Anything else, e.g. the number of positive elements
+//! assert_eq!(v.fold(0, |count, e| count + (e > 0) as usize), 3);
+//! ```
+//!
+
+use super::vec;
+use crate::{nightly, math::scalar::Scalar};
+use core::ops::{Add, Mul};
+
+impl <T: Copy, const N: usize> vec <T, N> {
+    ///
+    /// Combines all elements into one value, calling `op` with the value so far(`init` at first)
+    /// and every element, from the first one to the last one.
+    ///
+    /// # Constness
+    ///
+    /// Const when `nightly` feature is enabled.
+    ///
+    /// # Examples
+    /// ```
+    /// use rokoko::prelude::*;
+    ///
+    /// let digits = ivec3::from_array([1, 2, 3]);
+    /// assert_eq!(digits.fold(0, |number, digit| number * 10 + digit), 123);
+    ///
+    /// // `init` is returned as is for an empty vec
+    /// assert_eq!(ivec::<0>::ZERO.fold(42, |a, b| a + b), 42);
+    /// ```
+    ///
+    #[nightly(const(F: Fn(A, T) -> A))]
+    pub fn fold <A, F: Fn(A, T) -> A + Copy> (self, init: A, op: F) -> A {
+        let mut acc = init;
+        let mut i = 0;
+        while i < N {
+            // SAFETY: safe because `i` iterates from 0 to N(exclusively)
+            // and thus is never out of bounds
+            acc = op(acc, unsafe { *self.get_unchecked(i) });
+            i += 1
+        }
+        acc
+    }
+}
+
+#[nightly(const(T: Add <Output = T>))]
+impl <T: Scalar + Add <Output = T>, const N: usize> vec <T, N> {
+    ///
+    /// Returns the sum of all elements, [`Scalar::ZERO`] for an empty vec.
+    ///
+    /// # Constness
+    ///
+    /// Const when `T` is `~const Add` and `nightly` feature is enabled.
+    ///
+    /// # Examples
+    /// ```
+    /// use rokoko::prelude::*;
+    ///
+    /// assert_eq!(ivec4::from_array([1, 2, 3, 4]).sum(), 10);
+    /// assert_eq!(vec2::from_array([0.5, 0.25]).sum(), 0.75);
+    /// assert_eq!(ivec::<0>::ZERO.sum(), 0);
+    /// ```
+    ///
+    #[nightly(const)]
+    #[inline]
+    pub fn sum(self) -> T {
+        let mut sum = T::ZERO;
+        let mut i = 0;
+        while i < N {
+            // SAFETY: `i` < `N`
+            sum = sum + unsafe { *self.get_unchecked(i) };
+            i += 1
+        }
+        sum
+    }
+}
+
+#[nightly(const(T: Mul <Output = T>))]
+impl <T: Scalar + Mul <Output = T>, const N: usize> vec <T, N> {
+    ///
+    /// Returns the product of all elements, [`Scalar::ONE`] for an empty vec.
+    ///
+    /// # Constness
+    ///
+    /// Const when `T` is `~const Mul` and `nightly` feature is enabled.
+    ///
+    /// # Examples
+    /// ```
+    /// use rokoko::prelude::*;
+    ///
+    /// assert_eq!(ivec4::from_array([1, 2, 3, 4]).product(), 24);
+    /// assert_eq!(uvec3::from_array([640, 480, 0]).product(), 0);
+    /// assert_eq!(ivec::<0>::ZERO.product(), 1);
+    /// ```
+    ///
+    #[nightly(const)]
+    #[inline]
+    pub fn product(self) -> T {
+        let mut product = T::ONE;
+        let mut i = 0;
+        while i < N {
+            // SAFETY: `i` < `N`
+            product = product * unsafe { *self.get_unchecked(i) };
+            i += 1
+        }
+        product
+    }
+}
+
+#[nightly(const(T: PartialOrd))]
+impl <T: Copy + PartialOrd, const N: usize> vec <T, N> {
+    ///
+    /// Returns the least element, the first one of equal ones.
+    ///
+    /// Elements are compared with `<`, so `NaN` is returned only if it is the first element.
+    ///
+    /// # Panics
+    ///
+    /// If the vec is empty.
+    ///
+    /// # Constness
+    ///
+    /// Const when `T` is `~const PartialOrd` and `nightly` feature is enabled.
+    ///
+    /// # Examples
+    /// ```
+    /// use rokoko::prelude::*;
+    ///
+    /// assert_eq!(ivec4::from_array([3, -1, 4, -1]).min_element(), -1);
+    /// assert_eq!(vec3::from_array([2.0, f32::NAN, 1.0]).min_element(), 1.0);
+    /// ```
+    ///
+    #[nightly(const)]
+    #[inline]
+    pub fn min_element(self) -> T {
+        assert!(N > 0, "an empty vec has no least element");
+        // SAFETY: `N` > 0, checked above
+        let mut min = unsafe { *self.get_unchecked(0) };
+        let mut i = 1;
+        while i < N {
+            // SAFETY: `i` < `N`
+            let elem = unsafe { *self.get_unchecked(i) };
+            if elem < min {
+                min = elem
+            }
+            i += 1
+        }
+        min
+    }
+
+    ///
+    /// Returns the greatest element, the first one of equal ones.
+    ///
+    /// Elements are compared with `>`, so `NaN` is returned only if it is the first element.
+    ///
+    /// # Panics
+    ///
+    /// If the vec is empty.
+    ///
+    /// # Constness
+    ///
+    /// Const when `T` is `~const PartialOrd` and `nightly` feature is enabled.
+    ///
+    /// # Examples
+    /// ```
+    /// use rokoko::prelude::*;
+    ///
+    /// assert_eq!(ivec4::from_array([3, -1, 4, 1]).max_element(), 4);
+    /// assert_eq!(uvec2::from_array([7, 7]).max_element(), 7);
+    /// ```
+    ///
+    #[nightly(const)]
+    #[inline]
+    pub fn max_element(self) -> T {
+        assert!(N > 0, "an empty vec has no greatest element");
+        // SAFETY: `N` > 0, checked above
+        let mut max = unsafe { *self.get_unchecked(0) };
+        let mut i = 1;
+        while i < N {
+            // SAFETY: `i` < `N`
+            let elem = unsafe { *self.get_unchecked(i) };
+            if elem > max {
+                max = elem
+            }
+            i += 1
+        }
+        max
+    }
+}
@@ -0,0 +1,170 @@
+//!
+//! This module provides extraction of sub-vecs with
+//! bounds checked in compile-time.
+//!
+//! # Examples
+//!
+//! Perspective divide:
+//!
+//! ```rust
+//! use rokoko::prelude::*;
+//!
+//! let clip = vec4::from([2.0, -4.0, 1.0, 2.0]);
+//!
+//! let ndc = clip.first::<3>() / clip[3];
+//! assert_eq!(ndc, vec3::from([1.0, -2.0, 0.5]));
+//!
+//! // Or the screen position only
+//! let screen = clip.segment::<0, 2>() / clip.last::<1>()[0];
+//! assert_eq!(screen, vec2::from([1.0, -2.0]));
+//! ```
+//!
+//! Out of bounds segments do not compile:
+//!
+//! ```rust,compile_fail
+//! use rokoko::prelude::*;
+//!
+//! let _ = ivec3::from([1, 2, 3]).segment::<2, 2>();
+//! ```
+//!
+
+use super::vec;
+use core::mem::MaybeUninit;
+use crate::nightly;
+
+///
+/// Checks that `[START, START + K)` lies within `[0, N)`
+/// in compile-time.
+///
+struct Bounds <const START: usize, const K: usize, const N: usize>;
+
+impl <const START: usize, const K: usize, const N: usize> Bounds <START, K, N> {
+    const CHECK: () = assert!(START + K <= N, "segment is out of bounds");
+}
+
+impl <T: Copy, const N: usize> vec <T, N> {
+    ///
+    /// Returns `K` elements starting from `START`.
+    ///
+    /// Fails to compile if `START + K > N`.
+    ///
+    /// # Constness
+    ///
+    /// Const when `nightly` feature is enabled
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rokoko::prelude::*;
+    ///
+    /// let v = ivec4::from([1, 2, 3, 4]);
+    ///
+    /// assert_eq!(v.segment::<1, 1>(), ivec1::from([2]));
+    /// assert_eq!(v.segment::<1, 2>(), ivec2::from([2, 3]));
+    /// assert_eq!(v.segment::<1, 3>(), ivec3::from([2, 3, 4]));
+    /// assert_eq!(v.segment::<0, 4>(), v);
+    /// assert_eq!(v.segment::<4, 0>(), ivec::<0>::from([]));
+    /// ```
+    ///
+    #[inline]
+    #[nightly(const)]
+    pub fn segment <const START: usize, const K: usize> (self) -> vec <T, K> {
+        // Evaluated in compile-time
+        let () = Bounds::<START, K, N>::CHECK;
+
+        let mut result = MaybeUninit::<vec <T, K>>::uninit();
+        let base = &mut result as *mut MaybeUninit <vec <T, K>> as *mut T;
+        let mut i = 0;
+        while i < K {
+            // SAFETY: every step is justified below
+            unsafe {
+                // SAFETY: safe because `i` iterates from 0 to K(exclusively)
+                // and `START + K <= N`, so it is never out of bounds
+                let elem = core::ptr::read(self.get_unchecked(START + i));
+
+                // SAFETY: safe because `i` iterates from 0 to K(exclusively)
+                // and value does not need to be dropped(because it is not currently initialized)
+                core::ptr::write(base.add(i), elem)
+            }
+            i += 1
+        }
+        // SAFETY: all `K` elements are written in the loop above
+        unsafe { result.assume_init() }
+    }
+
+    ///
+    /// Returns the first `K` elements.
+    ///
+    /// Fails to compile if `K > N`.
+    ///
+    /// # Constness
+    ///
+    /// Const when `nightly` feature is enabled
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rokoko::prelude::*;
+    ///
+    /// let v = ivec4::from([1, 2, 3, 4]);
+    ///
+    /// assert_eq!(v.first::<1>(), ivec1::from([1]));
+    /// assert_eq!(v.first::<2>(), ivec2::from([1, 2]));
+    /// assert_eq!(v.first::<3>(), ivec3::from([1, 2, 3]));
+    /// assert_eq!(v.first::<4>(), v);
+    /// ```
+    ///
+    #[inline]
+    #[nightly(const)]
+    pub fn first <const K: usize> (self) -> vec <T, K> {
+        self.segment::<0, K>()
+    }
+
+    ///
+    /// Returns the last `K` elements.
+    ///
+    /// Fails to compile if `K > N`.
+    ///
+    /// # Constness
+    ///
+    /// Const when `nightly` feature is enabled
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rokoko::prelude::*;
+    ///
+    /// let v = ivec4::from([1, 2, 3, 4]);
+    ///
+    /// assert_eq!(v.last::<1>(), ivec1::from([4]));
+    /// assert_eq!(v.last::<2>(), ivec2::from([3, 4]));
+    /// assert_eq!(v.last::<3>(), ivec3::from([2, 3, 4]));
+    /// assert_eq!(v.last::<4>(), v);
+    /// ```
+    ///
+    #[inline]
+    #[nightly(const)]
+    pub fn last <const K: usize> (self) -> vec <T, K> {
+        // Evaluated in compile-time
+        let () = Bounds::<0, K, N>::CHECK;
+
+        let mut result = MaybeUninit::<vec <T, K>>::uninit();
+        let base = &mut result as *mut MaybeUninit <vec <T, K>> as *mut T;
+        let mut i = 0;
+        while i < K {
+            // SAFETY: every step is justified below
+            unsafe {
+                // SAFETY: safe because `i` iterates from 0 to K(exclusively)
+                // and `K <= N`, so it is never out of bounds
+                let elem = core::ptr::read(self.get_unchecked(N - K + i));
+
+                // SAFETY: safe because `i` iterates from 0 to K(exclusively)
+                // and value does not need to be dropped(because it is not currently initialized)
+                core::ptr::write(base.add(i), elem)
+            }
+            i += 1
+        }
+        // SAFETY: all `K` elements are written in the loop above
+        unsafe { result.assume_init() }
+    }
+}
@@ -0,0 +1,289 @@
+//!
+//! This module provides the remaining `GLSL` built-ins useful
+//! when porting shaders: `sign`, `step`, `smoothstep`, `saturate`, `mix`(as [`lerp`](vec::lerp)),
+//! `floor`, `ceil`, `round` and `fract`.
+//!
+//! [`lerp`](vec::lerp) only needs a [`Scalar`], so it is available without a float backend,
+//! while rounding needs a [`Float`], see [`scalar`](crate::math::scalar) module documentation.
+//!
+//! Functions taking edges have `_single` variants with the same edge for all the elements,
+//! like `GLSL`'s overloads taking a scalar edge.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use rokoko::prelude::*;
+//!
+//! // A soft circle mask
+//! let distances = vec4::from([0.0, 0.5, 0.75, 0.8]);
+//! let mask = vec4::single(1.0) - vec4::smoothstep_single(0.25, 0.75, distances);
+//! assert_eq!(mask, vec4::from([1.0, 0.5, 0.0, 0.0]));
+//!
+//! // A hard one
+//! assert_eq!(vec4::step_single(0.75, distances), vec4::from([0.0, 0.0, 1.0, 1.0]));
+//!
+//! // Tiling texture coordinates
+//! let uv = vec2::from([0.0, 0.5]).lerp(vec2::from([3.0, 2.5]), 0.75);
+//! assert_eq!((uv.floor(), uv.fract()), (vec2::from([2.0, 2.0]), vec2::from([0.25, 0.0])));
+//! ```
+//!
+
+use super::vec;
+use crate::math::scalar::{Scalar, SignedScalar, Float};
+
+impl <T: SignedScalar, const N: usize> vec <T, N> {
+    ///
+    /// Returns `1` for positive elements, `-1` for negative ones and the element itself otherwise.
+    ///
+    /// # Difference from `f32::signum`
+    ///
+    /// Just as `GLSL`'s `sign`, zero stays zero(keeping its sign, so `-0.0` stays `-0.0`),
+    /// while [`f32::signum`] returns `±1.0` for it. `NaN`s stay `NaN`s.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rokoko::prelude::*;
+    ///
+    /// assert_eq!(ivec4::from([-7, 0, 3, i32::MIN]).signum(), ivec4::from([-1, 0, 1, -1]));
+    ///
+    /// let s = vec4::from([-2.5, 0.0, -0.0, f32::INFINITY]).signum();
+    /// assert_eq!(s.into_array().map(f32::to_bits), [(-1.0f32).to_bits(), 0, (-0.0f32).to_bits(), 1.0f32.to_bits()]);
+    /// assert!(vec2::from([f32::NAN, 1.0]).signum()[0].is_nan());
+    /// ```
+    ///
+    #[inline]
+    pub fn signum(self) -> Self {
+        self.apply_unary(|x| if x > T::ZERO {
+            T::ONE
+        } else if x < T::ZERO {
+            -T::ONE
+        } else {
+            x
+        })
+    }
+}
+
+impl <T: Scalar, const N: usize> vec <T, N> {
+    ///
+    /// Returns `0` for elements of `x` less than the corresponding ones of `edge` and `1` otherwise.
+    ///
+    /// Elements equal to the edge give `1`, just as in `GLSL`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rokoko::prelude::*;
+    ///
+    /// let edge = vec3::from([0.5, 0.5, -1.0]);
+    /// assert_eq!(vec3::step(edge, vec3::from([0.4, 0.5, 0.0])), vec3::from([0.0, 1.0, 1.0]));
+    ///
+    /// assert_eq!(ivec3::step(ivec3::single(2), ivec3::from([1, 2, 3])), ivec3::from([0, 1, 1]));
+    /// ```
+    ///
+    #[inline]
+    pub fn step(edge: Self, x: Self) -> Self {
+        x.apply_binary(edge, |x, edge| if x < edge { T::ZERO } else { T::ONE })
+    }
+
+    ///
+    /// Same as [`step`](vec::step), but with the same `edge` for all the elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rokoko::prelude::*;
+    ///
+    /// assert_eq!(vec3::step_single(0.5, vec3::from([0.4, 0.5, 0.6])), vec3::from([0.0, 1.0, 1.0]));
+    /// ```
+    ///
+    #[inline]
+    pub fn step_single(edge: T, x: Self) -> Self {
+        Self::step(Self::single(edge), x)
+    }
+
+    ///
+    /// Linearly interpolates between `self` and `rhs`: `self` for `t = 0`, `rhs` for `t = 1`,
+    /// extrapolating for `t` out of `[0, 1]`. Same as `GLSL`'s `mix`.
+    ///
+    /// Computed as `self * (1 - t) + rhs * t`, so both ends are exact for floats,
+    /// unless the other one is infinite or `NaN`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rokoko::prelude::*;
+    ///
+    /// let a = vec2::from([1.0, -2.0]);
+    /// let b = vec2::from([3.0, 6.0]);
+    ///
+    /// assert_eq!(a.lerp(b, 0.0), a);
+    /// assert_eq!(a.lerp(b, 1.0), b);
+    /// assert_eq!(a.lerp(b, 0.25), vec2::from([1.5, 0.0]));
+    /// assert_eq!(a.lerp(b, 2.0), vec2::from([5.0, 14.0]));
+    /// ```
+    ///
+    #[inline]
+    pub fn lerp(self, rhs: Self, t: T) -> Self {
+        let s = T::ONE - t;
+        self.apply_binary(rhs, |a, b| a * s + b * t)
+    }
+}
+
+impl <T: Float, const N: usize> vec <T, N> {
+    ///
+    /// Returns `self` with signs of elements taken from `sign_source`, bit-exactly:
+    /// `-0.0` and negative `NaN`s count as negative.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rokoko::prelude::*;
+    ///
+    /// let magnitudes = vec4::from([1.5, -2.0, 0.0, 3.0]);
+    /// let signs = vec4::from([-1.0, 1.0, -0.0, 0.0]);
+    ///
+    /// let v = magnitudes.copysign(signs);
+    /// assert_eq!(v.into_array().map(f32::to_bits), [(-1.5f32).to_bits(), 2.0f32.to_bits(), (-0.0f32).to_bits(), 3.0f32.to_bits()]);
+    /// ```
+    ///
+    #[inline]
+    pub fn copysign(self, sign_source: Self) -> Self {
+        self.apply_binary(sign_source, T::copysign)
+    }
+
+    ///
+    /// Rounds elements down, to the largest integers less than or equal to them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rokoko::prelude::*;
+    ///
+    /// assert_eq!(vec4::from([1.5, -1.5, 2.0, -0.25]).floor(), vec4::from([1.0, -2.0, 2.0, -1.0]));
+    /// ```
+    ///
+    #[inline]
+    pub fn floor(self) -> Self {
+        self.apply_unary(T::floor)
+    }
+
+    ///
+    /// Rounds elements up, to the smallest integers greater than or equal to them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rokoko::prelude::*;
+    ///
+    /// assert_eq!(vec4::from([1.5, -1.5, 2.0, 0.25]).ceil(), vec4::from([2.0, -1.0, 2.0, 1.0]));
+    /// ```
+    ///
+    #[inline]
+    pub fn ceil(self) -> Self {
+        self.apply_unary(T::ceil)
+    }
+
+    ///
+    /// Rounds elements to the nearest integers, half-way cases away from `0.0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rokoko::prelude::*;
+    ///
+    /// assert_eq!(vec4::from([1.5, -1.5, 2.4, -0.25]).round(), vec4::from([2.0, -2.0, 2.0, -0.0]));
+    /// ```
+    ///
+    #[inline]
+    pub fn round(self) -> Self {
+        self.apply_unary(T::round)
+    }
+
+    ///
+    /// Returns the fractional parts of elements, i.e. `self - self.floor()`.
+    ///
+    /// # Difference from `f32::fract`
+    ///
+    /// Just as `GLSL`'s `fract`, the result is within `[0, 1]` for negative elements too,
+    /// e.g. `0.75` for `-1.25`, while [`f32::fract`] keeps the sign and returns `-0.25`.
+    /// It may round to `1.0` for tiny negative elements. Infinities and `NaN`s give `NaN`s.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rokoko::prelude::*;
+    ///
+    /// assert_eq!(vec4::from([1.5, -1.25, 3.0, 0.0]).fract(), vec4::from([0.5, 0.75, 0.0, 0.0]));
+    /// assert!(vec2::from([f32::INFINITY, 1.0]).fract()[0].is_nan());
+    /// ```
+    ///
+    #[inline]
+    pub fn fract(self) -> Self {
+        self - self.floor()
+    }
+
+    ///
+    /// Clamps elements to `[0, 1]`. `NaN`s stay `NaN`s.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rokoko::prelude::*;
+    ///
+    /// assert_eq!(vec4::from([-0.5, 0.25, 1.0, 7.0]).saturate(), vec4::from([0.0, 0.25, 1.0, 1.0]));
+    /// ```
+    ///
+    #[inline]
+    pub fn saturate(self) -> Self {
+        self.apply_unary(|x| if x < T::ZERO {
+            T::ZERO
+        } else if x > T::ONE {
+            T::ONE
+        } else {
+            x
+        })
+    }
+
+    ///
+    /// Returns smooth Hermite interpolation between `0` and `1` when elements of `x` are
+    /// between the corresponding ones of `edge0` and `edge1`, `0` before and `1` after them.
+    ///
+    /// Just as in `GLSL`, the result is undefined if `edge0 >= edge1`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rokoko::prelude::*;
+    ///
+    /// let edge0 = vec3::from([0.0, 0.0, 1.0]);
+    /// let edge1 = vec3::from([1.0, 2.0, 3.0]);
+    ///
+    /// assert_eq!(vec3::smoothstep(edge0, edge1, vec3::from([-1.0, 1.0, 4.0])), vec3::from([0.0, 0.5, 1.0]));
+    /// assert_eq!(vec3::smoothstep(edge0, edge1, vec3::from([0.25, 0.5, 1.5]))[0], 0.15625);
+    /// ```
+    ///
+    #[inline]
+    pub fn smoothstep(edge0: Self, edge1: Self, x: Self) -> Self {
+        let two = T::ONE + T::ONE;
+        let three = two + T::ONE;
+        let t = ((x - edge0) / (edge1 - edge0)).saturate();
+        t.apply_unary(|t| t * t * (three - two * t))
+    }
+
+    ///
+    /// Same as [`smoothstep`](vec::smoothstep), but with the same edges for all the elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rokoko::prelude::*;
+    ///
+    /// assert_eq!(vec3::smoothstep_single(1.0, 3.0, vec3::from([0.0, 2.0, 5.0])), vec3::from([0.0, 0.5, 1.0]));
+    /// ```
+    ///
+    #[inline]
+    pub fn smoothstep_single(edge0: T, edge1: T, x: Self) -> Self {
+        Self::smoothstep(Self::single(edge0), Self::single(edge1), x)
+    }
+}
@@ -0,0 +1,150 @@
+//!
+//! This module provides shuffles of vec elements by indices known at compile time,
+//! e.g. for reordering color channels, and interleaving of two vecs.
+//!
+//! Indices are checked at compile time: an index out of bounds is an error
+//! once the shuffle is instantiated, i.e. on `cargo build`, while `cargo check` may miss it.
+//!
+//! # Examples
+//!
+//! RGBA to BGRA and back, e.g. for a surface in BGRA order:
+//!
+//! ```rust
+//! use rokoko::prelude::*;
+//!
+//! let rgba = vec4::from([1.0, 0.5, 0.0, 1.0]);
+//! let bgra = rgba.shuffle4::<2, 1, 0, 3>();
+//! assert_eq!(bgra, vec4::from([0.0, 0.5, 1.0, 1.0]));
+//!
+//! // The same shuffle swaps back, so a BGRA color can be packed with the RGBA helpers
+//! assert_eq!(bgra.shuffle4::<2, 1, 0, 3>().to_rgb10a2(), rgba.to_rgb10a2());
+//! ```
+//!
+//! An index out of bounds does not compile:
+//!
+//! ```compile_fail
+//! use rokoko::prelude::*;
+//!
+//! let _ = vec3::from([1.0, 2.0, 3.0]).shuffle2::<0, 3>();
+//! ```
+//!
+
+use super::vec;
+
+/// Compile-time checks of shuffle indices
+struct Check <const N: usize, const I: usize>;
+
+impl <const N: usize, const I: usize> Check <N, I> {
+    const IN_BOUNDS: () = assert!(I < N, "shuffle index out of bounds");
+}
+
+/// Compile-time check of interleaving
+struct Even <const N: usize>;
+
+impl <const N: usize> Even <N> {
+    const EVEN: () = assert!(N & 1 == 0, "only vecs with an even number of elements can be interleaved");
+}
+
+impl <T: Copy, const N: usize> vec <T, N> {
+    ///
+    /// Returns `[self[A], self[B]]`, see [module documentation](self).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rokoko::prelude::*;
+    ///
+    /// let v = ivec4::from([1, 2, 3, 4]);
+    /// assert_eq!(v.shuffle2::<3, 0>(), ivec2::from([4, 1]));
+    /// assert_eq!(v.shuffle2::<1, 1>(), ivec2::from([2, 2]));
+    /// ```
+    ///
+    #[inline]
+    pub fn shuffle2 <const A: usize, const B: usize> (self) -> vec <T, 2> {
+        let ((), ()) = (Check::<N, A>::IN_BOUNDS, Check::<N, B>::IN_BOUNDS);
+        vec::from_array([self[A], self[B]])
+    }
+
+    ///
+    /// Returns `[self[A], self[B], self[C]]`, see [module documentation](self).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rokoko::prelude::*;
+    ///
+    /// let v = ivec4::from([1, 2, 3, 4]);
+    /// assert_eq!(v.shuffle3::<2, 1, 0>(), ivec3::from([3, 2, 1]));
+    /// ```
+    ///
+    #[inline]
+    pub fn shuffle3 <const A: usize, const B: usize, const C: usize> (self) -> vec <T, 3> {
+        let ((), (), ()) = (Check::<N, A>::IN_BOUNDS, Check::<N, B>::IN_BOUNDS, Check::<N, C>::IN_BOUNDS);
+        vec::from_array([self[A], self[B], self[C]])
+    }
+
+    ///
+    /// Returns `[self[A], self[B], self[C], self[D]]`, see [module documentation](self).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rokoko::prelude::*;
+    ///
+    /// // Widening works too
+    /// let v = ivec2::from([1, 2]);
+    /// assert_eq!(v.shuffle4::<0, 1, 0, 1>(), ivec4::from([1, 2, 1, 2]));
+    /// ```
+    ///
+    #[inline]
+    pub fn shuffle4 <const A: usize, const B: usize, const C: usize, const D: usize> (self) -> vec <T, 4> {
+        let ((), (), (), ()) = (
+            Check::<N, A>::IN_BOUNDS,
+            Check::<N, B>::IN_BOUNDS,
+            Check::<N, C>::IN_BOUNDS,
+            Check::<N, D>::IN_BOUNDS
+        );
+        vec::from_array([self[A], self[B], self[C], self[D]])
+    }
+
+    ///
+    /// Interleaves the elements of `self` and `other`, returning the lower and the upper halves
+    /// of the result, as SIMD `unpacklo`/`unpackhi` do.
+    ///
+    /// `N` must be even, which is checked at compile time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rokoko::prelude::*;
+    ///
+    /// let a = ivec4::from([0, 1, 2, 3]);
+    /// let b = ivec4::from([10, 11, 12, 13]);
+    ///
+    /// let (low, high) = a.interleave(b);
+    /// assert_eq!(low, ivec4::from([0, 10, 1, 11]));
+    /// assert_eq!(high, ivec4::from([2, 12, 3, 13]));
+    /// ```
+    ///
+    /// Odd `N` does not compile:
+    ///
+    /// ```compile_fail
+    /// use rokoko::prelude::*;
+    ///
+    /// let _ = ivec3::ZERO.interleave(ivec3::ZERO);
+    /// ```
+    ///
+    #[inline]
+    pub fn interleave(self, other: Self) -> (Self, Self) {
+        let () = Even::<N>::EVEN;
+        let (mut low, mut high) = (self, other);
+        let mut i = 0;
+        while i < N {
+            let from = if i % 2 == 0 { self } else { other };
+            low[i] = from[i / 2];
+            high[i] = from[(N + i) / 2];
+            i += 1
+        }
+        (low, high)
+    }
+}
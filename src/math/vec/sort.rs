@@ -0,0 +1,184 @@
+//!
+//! This module provides comparators of `vec`s as points, e.g. to build k-d trees or BVHs:
+//! [`cmp_by_axis`](vec::cmp_by_axis), [`cmp_by_distance_to`](vec::cmp_by_distance_to)
+//! and [`cmp_morton`](vec::cmp_morton), each along with a `sort_points_*` shorthand.
+//!
+//! All of them are total orders, suitable for `slice::sort_by` and alike:
+//! floats are compared by `total_cmp`, see [`TotalOrd`], so sorting never panics on `NaN`s,
+//! and integer distances are computed in a wide enough arithmetic not to overflow.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use rokoko::prelude::*;
+//!
+//! let mut points = [ivec2::from([3, 1]), ivec2::from([-2, 5]), ivec2::from([0, -4])];
+//!
+//! // Split along x, as a k-d tree does
+//! ivec2::sort_points_by_axis(&mut points, 0);
+//! assert_eq!(points, [ivec2::from([-2, 5]), ivec2::from([0, -4]), ivec2::from([3, 1])]);
+//!
+//! // The nearest first
+//! points.sort_by(ivec2::cmp_by_distance_to(ivec2::from([2, 2])));
+//! assert_eq!(points[0], ivec2::from([3, 1]));
+//! ```
+//!
+
+use super::vec;
+use crate::math::scalar::{TotalOrd, IntScalar};
+use core::cmp::Ordering;
+
+impl <T: TotalOrd, const N: usize> vec <T, N> {
+    ///
+    /// Returns a comparator of points by their element `axis`.
+    ///
+    /// Floats are compared by `total_cmp`, so negative `NaN`s go first, positive `NaN`s go last
+    /// and `-0.0` goes before `0.0`, see [`TotalOrd`].
+    ///
+    /// # Panics
+    ///
+    /// If `axis` is not less than `N`.
+    ///
+    /// # Examples
+    /// ```
+    /// use rokoko::prelude::*;
+    ///
+    /// let mut points = [vec2::from([1.0, f32::NAN]), vec2::from([2.0, -1.0]), vec2::from([3.0, -0.0])];
+    ///
+    /// points.sort_by(vec2::cmp_by_axis(1));
+    /// assert_eq!(points.map(|p| p[0]), [2.0, 3.0, 1.0]);
+    /// ```
+    ///
+    pub fn cmp_by_axis(axis: usize) -> impl Fn(&Self, &Self) -> Ordering {
+        assert!(axis < N, "axis {} is out of bounds for a vec of {} elements", axis, N);
+        move |a, b| a[axis].total_cmp(&b[axis])
+    }
+
+    ///
+    /// Returns a comparator of points by their distance to `origin`, the nearest first.
+    ///
+    /// Squared distances are compared, so there is no square root. For integers, they are exact
+    /// and never overflow, whatever the elements are. For floats, `NaN` distances go last.
+    ///
+    /// See [`TotalOrd::cmp_distances_squared`].
+    ///
+    /// # Examples
+    /// ```
+    /// use rokoko::prelude::*;
+    ///
+    /// let mut points = [ivec2::from([i32::MAX, 0]), ivec2::from([i32::MIN, i32::MIN]), ivec2::from([1, 1])];
+    ///
+    /// points.sort_by(ivec2::cmp_by_distance_to(ivec2::ZERO));
+    /// assert_eq!(points, [ivec2::from([1, 1]), ivec2::from([i32::MAX, 0]), ivec2::from([i32::MIN, i32::MIN])]);
+    /// ```
+    ///
+    pub fn cmp_by_distance_to(origin: Self) -> impl Fn(&Self, &Self) -> Ordering {
+        move |a, b| T::cmp_distances_squared(a.as_array(), b.as_array(), origin.as_array())
+    }
+
+    ///
+    /// Sorts `points` by their element `axis`, see [`cmp_by_axis`](vec::cmp_by_axis).
+    ///
+    /// The sort is unstable and does not allocate.
+    ///
+    /// # Panics
+    ///
+    /// If `axis` is not less than `N`.
+    ///
+    /// # Examples
+    /// ```
+    /// use rokoko::prelude::*;
+    ///
+    /// let mut points = [uvec3::from([0, 0, 9]), uvec3::from([0, 0, 4])];
+    ///
+    /// uvec3::sort_points_by_axis(&mut points, 2);
+    /// assert_eq!(points, [uvec3::from([0, 0, 4]), uvec3::from([0, 0, 9])]);
+    /// ```
+    ///
+    #[inline]
+    pub fn sort_points_by_axis(points: &mut [Self], axis: usize) {
+        points.sort_unstable_by(Self::cmp_by_axis(axis))
+    }
+
+    ///
+    /// Sorts `points` by their distance to `origin`, the nearest first,
+    /// see [`cmp_by_distance_to`](vec::cmp_by_distance_to).
+    ///
+    /// The sort is unstable and does not allocate.
+    ///
+    /// # Examples
+    /// ```
+    /// use rokoko::prelude::*;
+    ///
+    /// let mut points = [vec2::from([f32::NAN, 0.0]), vec2::from([4.0, 4.0]), vec2::from([1.0, 0.0])];
+    ///
+    /// vec2::sort_points_by_distance_to(&mut points, vec2::ZERO);
+    /// assert_eq!(points[..2], [vec2::from([1.0, 0.0]), vec2::from([4.0, 4.0])]);
+    /// assert!(points[2][0].is_nan());
+    /// ```
+    ///
+    #[inline]
+    pub fn sort_points_by_distance_to(points: &mut [Self], origin: Self) {
+        points.sort_unstable_by(Self::cmp_by_distance_to(origin))
+    }
+}
+
+impl <T: IntScalar, const N: usize> vec <T, N> {
+    ///
+    /// Returns a comparator of points by their Morton code, i.e. along the Z-order curve,
+    /// which keeps points that are near in space mostly near in order.
+    ///
+    /// The Morton code interleaves bits of the elements, most significant ones first,
+    /// with the last element the most significant one of each level, e.g. `...y1x1y0x0` for `[x, y]`.
+    /// Signed elements are taken with their sign bit flipped, see [`IntScalar::ordered_bits`],
+    /// so that the order agrees with the values along every axis.
+    ///
+    /// Codes are compared without being computed, so they never overflow, whatever `N` and `T` are.
+    ///
+    /// # Examples
+    /// ```
+    /// use rokoko::prelude::*;
+    ///
+    /// // The Z shape
+    /// let mut points = [uvec2::from([1, 1]), uvec2::from([0, 1]), uvec2::from([1, 0]), uvec2::from([0, 0])];
+    ///
+    /// points.sort_by(uvec2::cmp_morton());
+    /// assert_eq!(points, [uvec2::from([0, 0]), uvec2::from([1, 0]), uvec2::from([0, 1]), uvec2::from([1, 1])]);
+    /// ```
+    ///
+    pub fn cmp_morton() -> impl Fn(&Self, &Self) -> Ordering {
+        |a, b| {
+            // The axis of the most significant differing bit, the last one of equally significant ones
+            let (mut axis, mut top) = (None, 0u128);
+            for i in (0..N).rev() {
+                let diff = a[i].ordered_bits() ^ b[i].ordered_bits();
+                // Whether the most significant bit of `diff` is above the one of `top`
+                if top < diff && top < (top ^ diff) {
+                    axis = Some(i);
+                    top = diff
+                }
+            }
+            axis.map_or(Ordering::Equal, |i| a[i].cmp(&b[i]))
+        }
+    }
+
+    ///
+    /// Sorts `points` by their Morton code, see [`cmp_morton`](vec::cmp_morton).
+    ///
+    /// The sort is unstable and does not allocate.
+    ///
+    /// # Examples
+    /// ```
+    /// use rokoko::prelude::*;
+    ///
+    /// let mut points = [ivec2::from([0, 0]), ivec2::from([-1, -1])];
+    ///
+    /// ivec2::sort_points_morton(&mut points);
+    /// assert_eq!(points, [ivec2::from([-1, -1]), ivec2::from([0, 0])]);
+    /// ```
+    ///
+    #[inline]
+    pub fn sort_points_morton(points: &mut [Self]) {
+        points.sort_unstable_by(Self::cmp_morton())
+    }
+}
@@ -0,0 +1,48 @@
+//!
+//! This module provides `GLSL`-like swizzles: elements named `x`, `y`, `z` and `w`.
+//!
+//! - `x()`, `y()`, `z()`, `w()` return an element, and `x_mut()`, etc. a mutable reference to it;
+//! - `xy()`, `zyx()`, `xxzw()`, etc. return a vec of the elements in the given order,
+//!   every combination of 2 to 4 elements is there, repeated ones included.
+//!
+//! Only the elements a vec has can be named, e.g. `vec2` has no `z()`.
+//! Every swizzle is const when `nightly` feature is enabled.
+//!
+//! Shuffles by indices, see [`vec::shuffle2`], do the same for any `vec`.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use rokoko::prelude::*;
+//!
+//! let mut position = vec3::from([1.0, 2.0, 3.0]);
+//!
+//! assert_eq!(position.z(), 3.0);
+//! assert_eq!(position.xy(), vec2::from([1.0, 2.0]));
+//! assert_eq!(position.zyx(), vec3::from([3.0, 2.0, 1.0]));
+//! assert_eq!(position.xxyy(), vec4::from([1.0, 1.0, 2.0, 2.0]));
+//!
+//! *position.y_mut() += 5.0;
+//! assert_eq!(position, vec3::from([1.0, 7.0, 3.0]));
+//!
+//! // Dropping `w` of homogeneous coordinates
+//! assert_eq!(ivec4::from([4, 5, 6, 1]).xyz(), ivec3::from([4, 5, 6]));
+//! ```
+//!
+//! There are no elements past the size of a vec:
+//!
+//! ```compile_fail
+//! use rokoko::prelude::*;
+//!
+//! let _ = vec2::from([1.0, 2.0]).z();
+//! ```
+//!
+
+use super::vec;
+use crate::nightly;
+
+// Sole procedure macro and not `macro_rules!` because it requires
+// generating names of methods
+rokoko_macro::impl_swizzles_for_vec! {
+    x y z w
+}
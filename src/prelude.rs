@@ -4,11 +4,13 @@ cfg_if! {
     if #[cfg(feature = "math")] {
         pub use math::vec::vec;
         pub use math::vec::alias::*;
+        pub use math::fixed::{fix32, fix64};
+        pub use math::angle::{Deg, Rad};
     }
 }
 
 cfg_if! {
-    if #[cfg(feature = "window")] {
-        pub use window::Window;
+    if #[cfg(all(feature = "window", nightly))] {
+        pub use window::{Window, Windows};
     }
 }
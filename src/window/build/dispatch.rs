@@ -0,0 +1,267 @@
+//!
+//! This module provides [`Dispatch`] - how [`WindowBuilder::create`](super::WindowBuilder::create)
+//! looks callbacks up and calls them.
+//!
+//! By default the callbacks are kept in the type list like any other data,
+//! so whether one is registered is known in compile-time, see [`HasFn`](super::getters::HasFn) and [`CallAll`](super::getters::CallAll).
+//!
+//! With `dyn-callbacks` feature, `on_*` methods box the callbacks into `DynCallbacks` instead,
+//! a runtime table every builder starts with, keyed by event. Registering a callback then does not
+//! change the type of the builder, which makes builds faster and lets callbacks be registered
+//! conditionally, at the cost of a lookup and a virtual call per event.
+//! The data stays in the type list either way.
+//!
+//! # Examples
+//!
+//! ```
+//! use rokoko::window::{Window, build::{OnClose, OnInit}};
+//!
+//! let builder = Window::new()
+//!     .title("Dispatched")
+//!     .on_close(Window::close);
+//!
+//! assert!(builder.has_callback::<OnClose>());
+//! assert!(!builder.has_callback::<OnInit>());
+//! ```
+//!
+//! Only with `dyn-callbacks` feature:
+//! ```
+//! # #[cfg(feature = "dyn-callbacks")] {
+//! use rokoko::window::{Window, build::OnClose};
+//!
+//! let mut builder = Window::new().title("Conditional");
+//!
+//! // The same type with or without the callback
+//! if std::env::args().count() > 1 {
+//!     builder = builder.on_close(|_| println!("Not closing!"))
+//! }
+//! assert_eq!(builder.has_callback::<OnClose>(), std::env::args().count() > 1);
+//! # }
+//! ```
+//!
+
+use super::{Callback, Empty};
+use super::getters::Chain;
+#[cfg(not(feature = "dyn-callbacks"))]
+use super::getters::{HasFn, CallAll, has_fn};
+#[cfg(feature = "dyn-callbacks")]
+use super::{
+    With,
+    equality::NotEq,
+    fn_container::{NotFnContainer, NotFnOnceContainer},
+    user_data::NotUserData,
+    loader::NotLoader
+};
+#[cfg(feature = "dyn-callbacks")]
+use core::any::{Any, TypeId};
+
+///
+/// Looks up and calls the callbacks of `ID` in a type list.
+///
+/// Implemented by every list the generated `create` accepts, through [`HasFn`](super::getters::HasFn) and [`CallAll`](super::getters::CallAll)
+/// by default, or through `DynCallbacks` with `dyn-callbacks` feature.
+///
+pub trait Dispatch <ID: Callback> where ID::Output: Chain {
+    /// Whether any callback of `ID` is registered
+    fn has_callback(&self) -> bool;
+
+    /// Calls all the callbacks of `ID` in the order they were registered, see [`CallAll::call_all`](super::getters::CallAll::call_all)
+    fn call_callbacks(&mut self, args: ID::Args) -> ID::Output;
+}
+
+#[cfg(not(feature = "dyn-callbacks"))]
+impl <ID: Callback, C: HasFn <ID> + CallAll <ID>> Dispatch <ID> for C where ID::Output: Chain {
+    // Known in compile-time, so dead branches are optimized out
+    #[inline(always)]
+    fn has_callback(&self) -> bool {
+        has_fn::<ID, C>()
+    }
+
+    #[inline(always)]
+    fn call_callbacks(&mut self, args: ID::Args) -> ID::Output {
+        self.call_all(args)
+    }
+}
+
+#[cfg(feature = "dyn-callbacks")]
+impl <ID: Callback + 'static, C: FindDynCallbacks> Dispatch <ID> for C where ID::Args: Clone + 'static, ID::Output: Chain + 'static {
+    #[inline(always)]
+    fn has_callback(&self) -> bool {
+        self.dyn_callbacks().has::<ID>()
+    }
+
+    #[inline(always)]
+    fn call_callbacks(&mut self, args: ID::Args) -> ID::Output {
+        self.dyn_callbacks_mut().call_all::<ID>(args)
+    }
+}
+
+/// The list [`WindowBuilder::empty`](super::WindowBuilder::empty) starts with
+#[cfg(not(feature = "dyn-callbacks"))]
+pub type Initial = Empty;
+
+/// The list [`WindowBuilder::empty`](super::WindowBuilder::empty) starts with, holding the table of callbacks
+#[cfg(feature = "dyn-callbacks")]
+pub type Initial = With <DynCallbacks, Empty>;
+
+/// Returns the [`Initial`] list
+#[cfg(not(feature = "dyn-callbacks"))]
+pub const fn initial() -> Initial {
+    Empty
+}
+
+/// Returns the [`Initial`] list
+#[cfg(feature = "dyn-callbacks")]
+pub const fn initial() -> Initial {
+    With {
+        data: DynCallbacks::new(),
+        next: Empty
+    }
+}
+
+/// A callback of `ID` as stored in [`DynCallbacks`]
+#[cfg(feature = "dyn-callbacks")]
+pub type DynFn <ID> = Box <dyn FnMut <<ID as Callback>::Args, Output = <ID as Callback>::Output>>;
+
+///
+/// The runtime table of callbacks, keyed by event.
+///
+/// Callbacks of the same event are called in the order they were registered,
+/// their results combined with [`Chain`], just as [`CallAll`](super::getters::CallAll) does.
+///
+/// Requires `dyn-callbacks` feature.
+///
+/// # Examples
+///
+/// ```
+/// use rokoko::window::build::{
+///     dispatch::{Dispatch, DynCallbacks},
+///     type_list::{With, Empty},
+///     fn_container::Callback
+/// };
+///
+/// struct Vote;
+///
+/// impl Callback for Vote {
+///     type Output = bool;
+///     type Args = (u32,);
+/// }
+///
+/// let mut list = With { data: DynCallbacks::new(), next: Empty };
+/// assert!(!Dispatch::<Vote>::has_callback(&list));
+///
+/// list.data.push::<Vote>(Box::new(|n: u32| n > 1));
+/// list.data.push::<Vote>(Box::new(|n: u32| n > 2));
+///
+/// assert!(Dispatch::<Vote>::has_callback(&list));
+/// assert!(Dispatch::<Vote>::call_callbacks(&mut list, (3,)));
+/// assert!(!Dispatch::<Vote>::call_callbacks(&mut list, (2,)));
+/// ```
+///
+#[cfg(feature = "dyn-callbacks")]
+pub struct DynCallbacks {
+    /// `TypeId` of an event and a `Vec <DynFn <ID>>` of its callbacks
+    table: Vec <(TypeId, Box <dyn Any>)>
+}
+
+// The table is data like any other, whatever the boxed callbacks are
+#[cfg(feature = "dyn-callbacks")]
+impl NotEq for DynCallbacks {}
+
+#[cfg(feature = "dyn-callbacks")]
+impl NotFnContainer for DynCallbacks {}
+
+#[cfg(feature = "dyn-callbacks")]
+impl NotFnOnceContainer for DynCallbacks {}
+
+#[cfg(feature = "dyn-callbacks")]
+impl NotUserData for DynCallbacks {}
+
+#[cfg(feature = "dyn-callbacks")]
+impl NotLoader for DynCallbacks {}
+
+#[cfg(feature = "dyn-callbacks")]
+impl DynCallbacks {
+    /// Creates an empty table
+    pub const fn new() -> Self {
+        Self {
+            table: Vec::new()
+        }
+    }
+
+    /// Returns the callbacks of `ID`, if any was registered
+    fn get_mut <ID: Callback + 'static> (&mut self) -> Option <&mut Vec <DynFn <ID>>> where ID::Args: 'static, ID::Output: 'static {
+        self.table
+            .iter_mut()
+            .find(|(id, _)| *id == TypeId::of::<ID>())
+            .map(|(_, callbacks)| callbacks.downcast_mut().expect("callbacks are keyed by their event"))
+    }
+
+    /// Registers `cb` after all the callbacks of `ID` registered before
+    pub fn push <ID: Callback + 'static> (&mut self, cb: DynFn <ID>) where ID::Args: 'static, ID::Output: 'static {
+        match self.get_mut::<ID>() {
+            Some(callbacks) => callbacks.push(cb),
+            None => self.table.push((TypeId::of::<ID>(), Box::new(vec![cb])))
+        }
+    }
+
+    /// Whether any callback of `ID` is registered
+    pub fn has <ID: 'static> (&self) -> bool {
+        self.table.iter().any(|(id, _)| *id == TypeId::of::<ID>())
+    }
+
+    /// Calls all the callbacks of `ID`, each getting its own clone of `args`, and returns their chained results
+    pub fn call_all <ID: Callback + 'static> (&mut self, args: ID::Args) -> ID::Output where ID::Args: Clone + 'static, ID::Output: Chain + 'static {
+        match self.get_mut::<ID>().and_then(|callbacks| callbacks.split_last_mut()) {
+            Some((last, earlier)) => {
+                let mut output = ID::Output::NONE;
+                for cb in earlier {
+                    output = output.chain(cb.call_mut(args.clone()))
+                }
+                output.chain(last.call_mut(args))
+            },
+            None => ID::Output::NONE
+        }
+    }
+}
+
+///
+/// Finds [`DynCallbacks`] of a list that starts as [`Initial`].
+///
+/// Requires `dyn-callbacks` feature.
+///
+#[cfg(feature = "dyn-callbacks")]
+pub trait FindDynCallbacks {
+    /// Returns the table
+    fn dyn_callbacks(&self) -> &DynCallbacks;
+
+    /// Returns the table mutably
+    fn dyn_callbacks_mut(&mut self) -> &mut DynCallbacks;
+}
+
+#[cfg(feature = "dyn-callbacks")]
+impl FindDynCallbacks for Initial {
+    #[inline(always)]
+    fn dyn_callbacks(&self) -> &DynCallbacks {
+        &self.data
+    }
+
+    #[inline(always)]
+    fn dyn_callbacks_mut(&mut self) -> &mut DynCallbacks {
+        &mut self.data
+    }
+}
+
+// Does not overlap with the above as `Empty` has no table
+#[cfg(feature = "dyn-callbacks")]
+impl <T, N: FindDynCallbacks> FindDynCallbacks for With <T, N> {
+    #[inline(always)]
+    fn dyn_callbacks(&self) -> &DynCallbacks {
+        self.next.dyn_callbacks()
+    }
+
+    #[inline(always)]
+    fn dyn_callbacks_mut(&mut self) -> &mut DynCallbacks {
+        self.next.dyn_callbacks_mut()
+    }
+}
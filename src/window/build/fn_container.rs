@@ -32,3 +32,30 @@ pub trait Callback {
 
     type Args;
 }
+
+///
+/// Helper type, used to contain a callback that is called at most once.
+///
+/// Unlike [`FnContainer`], the callback can be moved out, see [`super::getters::TakeFn`].
+///
+pub struct FnOnceContainer <ID, Args, F: FnOnce <Args>> {
+    pub cb: Option <F>,
+    _marker: PhantomData <(ID, Args)>
+}
+
+impl <ID, Args, F: FnOnce <Args>> FnOnceContainer <ID, Args, F> {
+    pub const fn new(cb: F) -> Self {
+        Self {
+            cb: Some(cb),
+            _marker: PhantomData
+        }
+    }
+}
+
+/// Asserts that a type is not an [`FnOnceContainer`]
+pub auto trait NotFnOnceContainer {}
+
+impl <ID, Args, F: FnOnce <Args>> !NotFnOnceContainer for FnOnceContainer <ID, Args, F> {}
+
+/// Convenient alias
+pub type OnceFnContainer <E, F> = FnOnceContainer <E, <E as Callback>::Args, F>;
@@ -1,4 +1,4 @@
-use super::{Callback, FnContainer, NotFnContainer, With, Empty, Equality, NotEq, NotMatching};
+use super::{Callback, FnContainer, NotFnContainer, FnOnceContainer, NotFnOnceContainer, With, Empty, Equality, NotEq, NotMatching};
 
 /// Used to obtain an actual callback
 pub trait GetFn <ID: Callback> {
@@ -45,29 +45,336 @@ impl <ID: Callback, F: FnMut <ID::Args, Output = ID::Output>, N> const GetFn <ID
     }
 }
 
-// /// Represents `true`
-// pub struct True;
-//
-// /// Represents `false`
-// pub struct False;
-//
-// /// Does a type list contains a specified `ID`
-// pub trait HasFn <ID: Callback> {
-//     /// [`True`] if contains, [`False`] otherwise
-//     type Has;
-// }
-//
-// impl <ID: Callback> HasFn <ID> for Empty {
-//     type Has = False;
-// }
-//
-// impl <ID: Callback, CID, Args, F: FnMut <Args>, N: HasFn <ID>> const HasFn <ID> for With <FnContainer <CID, Args, F>, N> where Equality <ID, CID>: NotEq {
-//     type Has = N::Has;
-// }
-//
-// impl <ID: Callback, F: FnMut <ID::Args, Output = ID::Output>, N> HasFn <ID> for With <FnContainer <ID, ID::Args, F>, N> {
-//     type Has = True;
-// }
+///
+/// Used to move out a callback that is called at most once.
+///
+/// Unlike [`GetFn`], the callback is taken, so the second call returns `None`.
+///
+pub trait TakeFn <ID: Callback> {
+    /// The real type of a callback: `fn`, `{{closure}}` or a functor
+    type Type: FnOnce <ID::Args, Output = ID::Output>;
+
+    /// Takes(if is contained and not taken yet) a callback
+    fn take(&mut self) -> Option <Self::Type>;
+}
+
+impl <ID: Callback> TakeFn <ID> for Empty {
+    type Type = NotMatching <ID::Output>;
+
+    #[inline(always)]
+    fn take(&mut self) -> Option <Self::Type> {
+        None
+    }
+}
+
+impl <ID: Callback, T: NotFnOnceContainer, N: TakeFn <ID>> TakeFn <ID> for With <T, N> {
+    type Type = N::Type;
+
+    #[inline(always)]
+    fn take(&mut self) -> Option <Self::Type> {
+        self.next.take()
+    }
+}
+
+impl <ID: Callback, CID, Args, F: FnOnce <Args>, N: TakeFn <ID>> TakeFn <ID> for With <FnOnceContainer <CID, Args, F>, N> where Equality <ID, CID>: NotEq {
+    type Type = N::Type;
+
+    #[inline(always)]
+    fn take(&mut self) -> Option <Self::Type> {
+        self.next.take()
+    }
+}
+
+impl <ID: Callback, F: FnOnce <ID::Args, Output = ID::Output>, N> TakeFn <ID> for With <FnOnceContainer <ID, ID::Args, F>, N> {
+    type Type = F;
+
+    #[inline(always)]
+    fn take(&mut self) -> Option <Self::Type> {
+        self.data.cb.take()
+    }
+}
+
+///
+/// Used to call every contained callback with the specified `ID`.
+///
+/// Unlike [`GetFn`], which only finds the most recently registered callback,
+/// walks the whole type list, so that registering the same event several times
+/// chains the callbacks in the order they were registered.
+/// Their results are combined with [`Chain`].
+///
+/// # Examples
+///
+/// ```
+/// use std::cell::RefCell;
+/// use rokoko::window::build::{
+///     getters::CallAll,
+///     type_list::{With, Empty},
+///     fn_container::{Callback, OnEventFnContainer}
+/// };
+///
+/// struct Ping;
+///
+/// impl Callback for Ping {
+///     type Output = ();
+///     type Args = (&'static str,);
+/// }
+///
+/// let log = RefCell::new(Vec::new());
+/// let first = |s: &str| log.borrow_mut().push(format!("first {s}"));
+/// let second = |s: &str| log.borrow_mut().push(format!("second {s}"));
+///
+/// // `second` is registered last, so it is the head of the list
+/// let mut list = With {
+///     data: OnEventFnContainer::<Ping, _>::new(second),
+///     next: With { data: OnEventFnContainer::<Ping, _>::new(first), next: Empty }
+/// };
+/// CallAll::<Ping>::call_all(&mut list, ("ping",));
+///
+/// assert_eq!(*log.borrow(), ["first ping", "second ping"]);
+/// ```
+///
+pub trait CallAll <ID: Callback> where ID::Output: Chain {
+    /// Calls all the callbacks, each getting its own clone of `args`, and returns their chained results
+    fn call_all(&mut self, args: ID::Args) -> ID::Output;
+}
+
+impl <ID: Callback> CallAll <ID> for Empty where ID::Output: Chain {
+    #[inline(always)]
+    fn call_all(&mut self, _: ID::Args) -> ID::Output {
+        ID::Output::NONE
+    }
+}
+
+impl <ID: Callback, T: NotFnContainer, N: CallAll <ID>> CallAll <ID> for With <T, N> where ID::Output: Chain {
+    #[inline(always)]
+    fn call_all(&mut self, args: ID::Args) -> ID::Output {
+        self.next.call_all(args)
+    }
+}
+
+impl <ID: Callback, CID, Args, F: FnMut <Args>, N: CallAll <ID>> CallAll <ID> for With <FnContainer <CID, Args, F>, N> where ID::Output: Chain, Equality <ID, CID>: NotEq {
+    #[inline(always)]
+    fn call_all(&mut self, args: ID::Args) -> ID::Output {
+        self.next.call_all(args)
+    }
+}
+
+impl <ID: Callback, F: FnMut <ID::Args, Output = ID::Output>, N: CallAll <ID> + HasFn <ID>> CallAll <ID> for With <FnContainer <ID, ID::Args, F>, N> where ID::Output: Chain, ID::Args: Clone {
+    #[inline(always)]
+    fn call_all(&mut self, args: ID::Args) -> ID::Output {
+        // Earlier callbacks are deeper in the list; the clone is only made when there are any
+        let earlier = if has_fn::<ID, N>() {
+            self.next.call_all(args.clone())
+        } else {
+            ID::Output::NONE
+        };
+        earlier.chain(self.data.cb.call_mut(args))
+    }
+}
+
+///
+/// Combines the results of callbacks chained by [`CallAll`].
+///
+/// Every callback is called, whatever the results of the earlier ones are.
+///
+pub trait Chain {
+    /// The result of no callbacks
+    const NONE: Self;
+
+    /// Combines the result of earlier callbacks with the result of a later one
+    fn chain(self, later: Self) -> Self;
+}
+
+impl Chain for () {
+    const NONE: Self = ();
+
+    #[inline(always)]
+    fn chain(self, _: Self) {}
+}
+
+/// `true` only if all the callbacks returned `true`
+impl Chain for bool {
+    const NONE: Self = true;
+
+    #[inline(always)]
+    fn chain(self, later: Self) -> Self {
+        self && later
+    }
+}
+
+/// Type-level boolean
+pub trait Bool {
+    /// The value as a `bool`
+    const VALUE: bool;
+}
+
+/// Represents `true`
+pub struct True;
+
+impl Bool for True {
+    const VALUE: bool = true;
+}
+
+/// Represents `false`
+pub struct False;
+
+impl Bool for False {
+    const VALUE: bool = false;
+}
+
+///
+/// Does a type list contain a callback with the specified `ID`.
+///
+/// Unlike [`GetFn`], answers in compile-time, so that different code
+/// can be generated depending on whether a callback was set.
+///
+/// # Examples
+///
+/// ```
+/// use rokoko::window::{
+///     Window,
+///     build::{
+///         OnClose, OnInit, Title, WindowBuilder,
+///         getters::{HasFn, HasData, has_fn, has_data},
+///         type_list::{With, Empty},
+///         fn_container::OnEventFnContainer
+///     }
+/// };
+///
+/// type Closing = With <OnEventFnContainer <OnClose, fn(Window)>, With <Title <'static>, Empty>>;
+///
+/// const _: () = assert!(has_fn::<OnClose, Closing>());
+/// const _: () = assert!(!has_fn::<OnInit, Closing>());
+/// const _: () = assert!(has_data::<Title <'static>, Closing>());
+/// const _: () = assert!(!has_fn::<OnClose, Empty>());
+///
+/// // Branching in compile-time
+/// fn describe <C: HasFn <OnInit>> (_: &WindowBuilder <C>) -> &'static str {
+///     if has_fn::<OnInit, C>() {
+///         "initializes itself"
+///     } else {
+///         "does nothing on init"
+///     }
+/// }
+///
+/// assert_eq!(describe(&Window::new().on_close(Window::close)), "does nothing on init");
+///
+/// // Boxed into a table with `dyn-callbacks` feature, see `WindowBuilder::has_callback` for both
+/// #[cfg(not(feature = "dyn-callbacks"))]
+/// assert_eq!(describe(&Window::new().on_init(|_| ())), "initializes itself");
+/// ```
+///
+pub trait HasFn <ID: Callback> {
+    /// [`True`] if contains, [`False`] otherwise
+    type Has: Bool;
+}
+
+impl <ID: Callback> HasFn <ID> for Empty {
+    type Has = False;
+}
+
+impl <ID: Callback, T: NotFnContainer, N: HasFn <ID>> HasFn <ID> for With <T, N> {
+    type Has = N::Has;
+}
+
+impl <ID: Callback, CID, Args, F: FnMut <Args>, N: HasFn <ID>> HasFn <ID> for With <FnContainer <CID, Args, F>, N> where Equality <ID, CID>: NotEq {
+    type Has = N::Has;
+}
+
+impl <ID: Callback, F: FnMut <ID::Args, Output = ID::Output>, N> HasFn <ID> for With <FnContainer <ID, ID::Args, F>, N> {
+    type Has = True;
+}
+
+///
+/// Returns `true` if type list `C` contains a callback with the specified `ID`.
+///
+/// See [`HasFn`].
+///
+#[inline(always)]
+pub const fn has_fn <ID: Callback, C: HasFn <ID>> () -> bool {
+    <C::Has as Bool>::VALUE
+}
+
+///
+/// Does a type list contain data of type `T`.
+///
+/// See [`HasFn`] for examples.
+///
+pub trait HasData <T> {
+    /// [`True`] if contains, [`False`] otherwise
+    type Has: Bool;
+}
+
+impl <T> HasData <T> for Empty {
+    type Has = False;
+}
+
+impl <T, E, N: HasData <T>> HasData <T> for With <E, N> where Equality <T, E>: NotEq {
+    type Has = N::Has;
+}
+
+impl <T, N> HasData <T> for With <T, N> {
+    type Has = True;
+}
+
+///
+/// Returns `true` if type list `C` contains data of type `T`.
+///
+/// See [`HasData`].
+///
+#[inline(always)]
+pub const fn has_data <T, C: HasData <T>> () -> bool {
+    <C::Has as Bool>::VALUE
+}
+
+///
+/// Implemented for type lists containing data of type `T`, and only for them.
+///
+/// Unlike [`has_data`], which answers `false` for lists without `T`,
+/// it is meant to be a bound: an API requiring some data to be configured
+/// fails to compile for builders without it.
+///
+/// # Examples
+///
+/// A helper only available for builders with a position, turning the runtime requirement
+/// of [`WindowBuilder::position_is_logical`](super::WindowBuilder::position_is_logical) into a compile-time one:
+///
+/// ```
+/// use rokoko::window::{
+///     Window,
+///     build::{Position, PositionIsLogical, Size, Title, WindowBuilder, getters::{Contains, has_data}, type_list::{With, Empty}}
+/// };
+///
+/// trait Logical <C> {
+///     fn logical(self) -> WindowBuilder <With <PositionIsLogical, C>>;
+/// }
+///
+/// impl <C: Contains <Position>> Logical <C> for WindowBuilder <C> {
+///     fn logical(self) -> WindowBuilder <With <PositionIsLogical, C>> {
+///         self.position_is_logical()
+///     }
+/// }
+///
+/// let _ = Window::new().position((100.0, 200.0)).title("placed").logical();
+///
+/// // As a value
+/// const _: () = assert!(has_data::<Title <'static>, With <Title <'static>, Empty>>());
+/// const _: () = assert!(!has_data::<Size, With <Title <'static>, Empty>>());
+/// ```
+///
+/// Builders without a size are rejected at compile time:
+///
+/// ```compile_fail
+/// use rokoko::window::{Window, build::{Size, WindowBuilder, getters::Contains}};
+///
+/// fn requires_size <C: Contains <Size>> (_: &WindowBuilder <C>) {}
+///
+/// requires_size(&Window::new().title("unsized"))
+/// ```
+///
+pub trait Contains <T>: HasData <T, Has = True> {}
+
+impl <T, C: HasData <T, Has = True>> Contains <T> for C {}
 
 /// Used to obtain data-like info
 pub trait GetData <T> {
@@ -0,0 +1,63 @@
+//!
+//! This module provides [`Loader`] - the closure given to
+//! [`WindowBuilder::loading_phase`](super::WindowBuilder::loading_phase), kept in the type list until `create`.
+//!
+//! See [`loading`](crate::window::loading) for what happens to it then.
+//!
+
+use super::{With, Empty};
+use super::user_data::UserDataStore;
+use crate::window::loading::{LoadingPhase, LoadingProgress, LoadingCtx};
+use core::marker::PhantomData;
+use std::time::Instant;
+
+///
+/// A loader given with [`WindowBuilder::loading_phase`](super::WindowBuilder::loading_phase).
+///
+/// Empty once moved into the window by `create`.
+///
+pub struct Loader <T, F: FnMut(LoadingCtx) -> LoadingProgress <T>> (Option <F>, PhantomData <fn() -> T>);
+
+impl <T, F: FnMut(LoadingCtx) -> LoadingProgress <T>> Loader <T, F> {
+    pub const fn new(load: F) -> Self {
+        Self(Some(load), PhantomData)
+    }
+}
+
+/// Asserts that a type is not a [`Loader`]
+pub auto trait NotLoader {}
+
+impl <T, F: FnMut(LoadingCtx) -> LoadingProgress <T>> !NotLoader for Loader <T, F> {}
+
+///
+/// Moves the [`Loader`] of a type list, if any, into a [`LoadingPhase`].
+///
+pub trait TakeLoader {
+    ///
+    /// Takes the very last loader, reserving the place for its value in `store`.
+    ///
+    /// The earlier ones are dropped, as with any other data.
+    ///
+    fn take_loader(&mut self, store: &mut UserDataStore, start: Instant) -> Option <LoadingPhase>;
+}
+
+impl TakeLoader for Empty {
+    #[inline(always)]
+    fn take_loader(&mut self, _: &mut UserDataStore, _: Instant) -> Option <LoadingPhase> {
+        None
+    }
+}
+
+impl <T: NotLoader, N: TakeLoader> TakeLoader for With <T, N> {
+    #[inline(always)]
+    fn take_loader(&mut self, store: &mut UserDataStore, start: Instant) -> Option <LoadingPhase> {
+        self.next.take_loader(store, start)
+    }
+}
+
+impl <T: 'static, F: FnMut(LoadingCtx) -> LoadingProgress <T> + 'static, N> TakeLoader for With <Loader <T, F>, N> {
+    #[inline(always)]
+    fn take_loader(&mut self, store: &mut UserDataStore, start: Instant) -> Option <LoadingPhase> {
+        self.data.0.take().map(|load| LoadingPhase::new(store, start, load))
+    }
+}
@@ -0,0 +1,202 @@
+//!
+//! This module provides machine-readable description of all the
+//! options and events of [`super::WindowBuilder`], e.g. for tooling.
+//!
+//! Everything is generated by `window_builder_create!` from the very same
+//! declarations `WindowBuilder` methods are, so it is always up to date.
+//!
+//! # Examples
+//!
+//! ```
+//! use rokoko::window::build::meta::{OPTIONS, EVENTS};
+//!
+//! for option in OPTIONS {
+//!     println!(
+//!         "{:<20} {:<12} {:<16} {}",
+//!         option.name,
+//!         option.ty.unwrap_or("-"),
+//!         option.default.unwrap_or("-"),
+//!         option.doc
+//!     )
+//! }
+//!
+//! let title = OPTIONS.iter().find(|o| o.name == "title").unwrap();
+//! assert_eq!(title.ty, Some("&str"));
+//! assert_eq!(title.default, Some("\"rokoko window\""));
+//!
+//! let size = OPTIONS.iter().find(|o| o.name == "size").unwrap();
+//! assert!(size.conflicts.contains(&"maximized"));
+//! assert!(size.conflicts.contains(&"fullscreen"));
+//!
+//! let maximized = OPTIONS.iter().find(|o| o.name == "maximized").unwrap();
+//! assert_eq!(maximized.ty, None);
+//!
+//! let size_is_logical = OPTIONS.iter().find(|o| o.name == "size_is_logical").unwrap();
+//! assert_eq!(size_is_logical.requires, ["size"]);
+//!
+//! let on_close = EVENTS.iter().find(|e| e.name == "on_close").unwrap();
+//! assert_eq!(on_close.args, ["Window"]);
+//! assert_eq!(on_close.ret, "()");
+//! assert_eq!(on_close.unique, None);
+//! ```
+//!
+//! # Stability
+//!
+//! Options and events may be marked in their declarations with
+//! - `#[unstable(feature = "name")]`, so the method is only present with the `unstable-name` feature,
+//!   see [`OptionDesc::unstable`];
+//! - `#[deprecated_option(since = "version", note = "text")]`, so the method is `#[deprecated]`,
+//!   while `create` still honors the data given through it, see [`OptionDesc::deprecated`].
+//!
+//! So a deprecated method warns, failing the build with warnings denied:
+//! ```compile_fail
+//! #![deny(deprecated)]
+//! # use rokoko::window::Window;
+//! let _ = Window::new().size((800., 600.)).size_is_logical();
+//! ```
+//!
+//! And an unstable one is absent without its feature:
+#![cfg_attr(not(feature = "unstable-minimize-on-focus-loss"), doc = "```compile_fail")]
+#![cfg_attr(feature = "unstable-minimize-on-focus-loss", doc = "```")]
+//! # use rokoko::window::Window;
+//! let _ = Window::new().fullscreen().fullscreen_management().minimize_on_focus_loss();
+//! ```
+//!
+
+pub use super::{OPTIONS, EVENTS};
+#[cfg(feature = "config-trace")]
+use std::{fmt, sync::{Mutex, PoisonError}};
+
+///
+/// Description of an option(data) of `WindowBuilder`.
+///
+#[derive(Debug, Copy, Clone)]
+pub struct OptionDesc {
+    /// Name of the method, e.g. `"title"`
+    pub name: &'static str,
+
+    /// Type of the argument as written, e.g. `Some("&str")`, `None` if there is no argument
+    pub ty: Option <&'static str>,
+
+    /// Default expression as written, e.g. `Some("\"rokoko window\"")`
+    pub default: Option <&'static str>,
+
+    /// Options this one cannot be specified with
+    pub conflicts: &'static [&'static str],
+
+    /// Options this one requires to be specified
+    pub requires: &'static [&'static str],
+
+    /// `true` if the value is checked, by [`WindowBuilder::validate`](super::WindowBuilder::validate) and `create` at least
+    pub validated: bool,
+
+    /// The feature the method requires, e.g. `Some("unstable-name")`, `None` if it is stable
+    pub unstable: Option <&'static str>,
+
+    /// Why the method is deprecated and what to use instead, `None` if it is not
+    pub deprecated: Option <&'static str>,
+
+    /// The first line of the documentation that is neither empty nor a heading
+    pub doc: &'static str
+}
+
+///
+/// Description of an event(callback) of `WindowBuilder`.
+///
+#[derive(Debug, Copy, Clone)]
+pub struct EventDesc {
+    /// Name of the method, e.g. `"on_close"`
+    pub name: &'static str,
+
+    /// Types of arguments of the callback as written, e.g. `["Window"]`
+    pub args: &'static [&'static str],
+
+    /// Return type of the callback as written, e.g. `"()"`
+    pub ret: &'static str,
+
+    /// The event pattern the callback is called on, with tokens separated by spaces,
+    /// e.g. `Some("Event :: MainEventsCleared")`, `None` if it is only called by its [`unique`](Self::unique) code
+    pub on: Option <&'static str>,
+
+    /// The feature of `WindowBuilder` calling the callback itself, e.g. `Some("gestures")`, `None` if there is none
+    pub unique: Option <&'static str>,
+
+    /// The feature the method requires, e.g. `Some("unstable-name")`, `None` if it is stable
+    pub unstable: Option <&'static str>,
+
+    /// Why the method is deprecated and what to use instead, `None` if it is not
+    pub deprecated: Option <&'static str>,
+
+    /// The first line of the documentation that is neither empty nor a heading
+    pub doc: &'static str
+}
+
+///
+/// Which options and events a particular `WindowBuilder` type has,
+/// see [`WindowBuilder::config_trace`](super::WindowBuilder::config_trace).
+///
+/// Presence is decided by the type of the builder, i.e. statically:
+/// an absent callback is not just never called, there is no code calling it at all.
+/// That is the usual reason of a callback that "does not fire": it was attached
+/// to a builder other than the one created.
+///
+/// [`Display`](fmt::Display) writes one line per option, then one per event,
+/// e.g. `on_close: STATICALLY ABSENT`.
+///
+/// Requires `config-trace` feature.
+///
+#[cfg(feature = "config-trace")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConfigTrace {
+    /// Every option in the order of [`OPTIONS`], with `true` if present
+    pub options: Vec <(&'static str, bool)>,
+
+    /// Every event in the order of [`EVENTS`], with `true` if present
+    pub events: Vec <(&'static str, bool)>
+}
+
+#[cfg(feature = "config-trace")]
+impl ConfigTrace {
+    /// Returns `true` if the option or event `name` is present, `None` if there is no such
+    pub fn is_present(&self, name: &str) -> Option <bool> {
+        self.options.iter().chain(&self.events).find(|(n, _)| *n == name).map(|&(_, present)| present)
+    }
+}
+
+#[cfg(feature = "config-trace")]
+impl fmt::Display for ConfigTrace {
+    fn fmt(&self, f: &mut fmt::Formatter <'_>) -> fmt::Result {
+        for (i, (name, present)) in self.options.iter().chain(&self.events).enumerate() {
+            if i != 0 {
+                f.write_str("\n")?
+            }
+            write!(f, "{name}: STATICALLY {}", if *present { "PRESENT" } else { "ABSENT" })?
+        }
+        Ok(())
+    }
+}
+
+/// The trace of the last created window
+#[cfg(feature = "config-trace")]
+static LAST_TRACE: Mutex <Option <ConfigTrace>> = Mutex::new(None);
+
+///
+/// Prints `trace` to `stderr` and keeps it for [`last_trace`].
+///
+/// Called by `WindowBuilder::create`.
+///
+#[cfg(feature = "config-trace")]
+pub(crate) fn record_trace(trace: ConfigTrace) {
+    eprintln!("rokoko config trace:\n{trace}");
+    *LAST_TRACE.lock().unwrap_or_else(PoisonError::into_inner) = Some(trace)
+}
+
+///
+/// Returns the [`ConfigTrace`] of the last window created, `None` if none was.
+///
+/// Requires `config-trace` feature.
+///
+#[cfg(feature = "config-trace")]
+pub fn last_trace() -> Option <ConfigTrace> {
+    LAST_TRACE.lock().unwrap_or_else(PoisonError::into_inner).clone()
+}
@@ -4,7 +4,9 @@
 //!
 
 pub mod fn_container;
-use self::fn_container::{FnContainer, NotFnContainer, OnEventFnContainer, Callback};
+use self::fn_container::{FnContainer, NotFnContainer, FnOnceContainer, NotFnOnceContainer, Callback};
+#[cfg(not(feature = "dyn-callbacks"))]
+use self::fn_container::OnEventFnContainer;
 
 pub mod not_matching;
 use self::not_matching::NotMatching;
@@ -15,18 +17,46 @@ use self::equality::{Equality, NotEq};
 pub mod type_list;
 use self::type_list::{With, Empty};
 
+pub mod user_data;
+use self::user_data::{UserData, UserDataStore, CollectUserData};
+
+pub mod loader;
+use self::loader::{Loader, TakeLoader};
+
 pub mod getters;
-use self::getters::{GetFn, GetData};
+use self::getters::{GetFn, GetData, TakeFn, Chain};
+
+pub mod dispatch;
+use self::dispatch::{Dispatch, Initial};
+#[cfg(feature = "dyn-callbacks")]
+use self::dispatch::FindDynCallbacks;
+
+pub mod meta;
 
-use crate::math::vec::vec2;
+use crate::math::vec::{vec2, uvec2};
 use super::{
     Window, UserEvent,
-    data::{WindowData, WinitRef}
+    data::{WindowData, WinitRef, Tracked},
+    event_counters::EventCounters,
+    size_policy::SizePolicy,
+    panic_context::PanicContext,
+    pacing::{self, FrameLoop},
+    power,
+    shutdown::CloseSchedule,
+    loading::{LoadingCtx, LoadingProgress, Step},
+    drag_select::{self, DragPhase, Rect},
+    gestures::{self, Gesture},
+    fullscreen::{self, FullscreenManager},
+    input_map::{self, Key, MouseButton},
+    windows::{Handler, OpenAll},
+    event_loop
 };
+use core::{cell::{Cell, RefCell}, time::Duration};
+use std::path::PathBuf;
 use winit::{
     event_loop::{EventLoop, ControlFlow},
-    event::{Event, WindowEvent},
-    dpi::{PhysicalSize, LogicalSize}
+    event::{Event, StartCause, WindowEvent, KeyboardInput, ElementState},
+    dpi::{PhysicalSize, LogicalSize, PhysicalPosition, LogicalPosition}
 };
 
 ///
@@ -34,7 +64,7 @@ use winit::{
 ///
 /// All the explanations can be found in `window` module.
 ///
-pub struct WindowBuilder <C = Empty> (C);
+pub struct WindowBuilder <C = Initial> (C);
 
 rokoko_macro::window_builder_data! {
     ///
@@ -44,6 +74,24 @@ rokoko_macro::window_builder_data! {
     /// ## Default
     /// Default is `"rokoko window"`.
     ///
+    /// ## Note
+    /// Must not be empty nor contain control characters(e.g. newlines),
+    /// see [`WindowBuilder::try_title`] and [`WindowBuilder::validate`].
+    ///
+    /// Builders evaluated at compile time fail the build with an invalid title:
+    /// ```compile_fail
+    /// # use rokoko::window::Window;
+    /// const _: () = {
+    ///     let _ = Window::new().title("");
+    /// };
+    /// ```
+    /// ```compile_fail
+    /// # use rokoko::window::Window;
+    /// const _: () = {
+    ///     let _ = Window::new().title("two\nlines");
+    /// };
+    /// ```
+    ///
     /// ## Example
     /// ```
     /// # use rokoko::window::Window;
@@ -53,6 +101,8 @@ rokoko_macro::window_builder_data! {
     /// ```
     ///
     #[default = "rokoko window"]
+    #[validate_now = check_title]
+    #[const_validate = validate_title]
     #[usage = .with_title(title)]
     title: &str,
 
@@ -64,16 +114,18 @@ rokoko_macro::window_builder_data! {
     /// Default is some platform-dependent preset dimensions.
     ///
     /// # Compatibility
-    /// Not compatible with the [`WindowBuilder::maximized`]
+    /// Not compatible with the [`WindowBuilder::maximized`], [`WindowBuilder::auto_size`], [`WindowBuilder::logical_size`]
+    /// and [`WindowBuilder::fullscreen`]
     ///
     /// ## Note
-    /// The default type of specified `size` is [`winit::dpi::PhysicalSize`].
-    ///
-    /// You can change default [`winit::dpi::PhysicalSize`] to [`winit::dpi::LogicalSize`]
-    /// by specifying [`WindowBuilder::size_is_logical`].
+    /// The type of specified `size` is [`winit::dpi::PhysicalSize`],
+    /// use [`WindowBuilder::logical_size`] for a [`winit::dpi::LogicalSize`].
     ///
     /// See [`winit::dpi`] module documentation for more information.
     ///
+    /// ## Note
+    /// Must be finite and non-negative, see [`WindowBuilder::try_size`] and [`WindowBuilder::validate`].
+    ///
     /// ## Example
     /// ```
     /// # use rokoko::window::Window;
@@ -83,6 +135,10 @@ rokoko_macro::window_builder_data! {
     /// ```
     ///
     #[conflict = maximized]
+    #[conflict = auto_size]
+    #[conflict = logical_size]
+    #[conflict = fullscreen]
+    #[validate_now = check_size]
     #[usage = .with_inner_size(if data.size_is_logical().is_some() {
         winit::dpi::Size::Logical(LogicalSize::from(size).cast())
     } else {
@@ -98,7 +154,8 @@ rokoko_macro::window_builder_data! {
     /// `.maximized()` -> specifies that window should have the maximum possible size.
     ///
     /// ## Compatibility
-    /// Not compatible with the [`WindowBuilder::size`]
+    /// Not compatible with the [`WindowBuilder::size`], [`WindowBuilder::auto_size`], [`WindowBuilder::logical_size`]
+    /// and [`WindowBuilder::fullscreen`]
     ///
     /// ## Example
     /// ```
@@ -109,14 +166,53 @@ rokoko_macro::window_builder_data! {
     /// ```
     ///
     #[conflict = size]
+    #[conflict = auto_size]
+    #[conflict = logical_size]
+    #[conflict = fullscreen]
     #[usage = .with_maximized(true)]
     maximized,
 
+    ///
+    /// ## Signature
+    /// `.auto_size(SizePolicy)` -> specifies dimensions of the window relatively to the monitor.
+    ///
+    /// The actual size is computed after the window is built, see [`SizePolicy::compute`].
+    ///
+    /// There are shortcuts: [`WindowBuilder::size_720p`], [`WindowBuilder::size_1080p`]
+    /// and [`WindowBuilder::size_fraction`].
+    ///
+    /// ## Compatibility
+    /// Not compatible with the [`WindowBuilder::size`], [`WindowBuilder::logical_size`], [`WindowBuilder::maximized`]
+    /// and [`WindowBuilder::fullscreen`]
+    ///
+    /// ## Note
+    /// [`SizePolicy::Fraction`] must be in `(0, 1]`, otherwise `create` panics.
+    ///
+    /// ## Example
+    /// ```
+    /// # use rokoko::window::{Window, size_policy::SizePolicy};
+    ///
+    /// Window::new()
+    ///     .auto_size(SizePolicy::Fraction(0.6));
+    /// ```
+    ///
+    #[conflict = size]
+    #[conflict = logical_size]
+    #[conflict = maximized]
+    #[conflict = fullscreen]
+    #[validate = SizePolicy::validate]
+    #[after_build = auto_size.apply(&winit_window)]
+    auto_size: SizePolicy,
+
     ///
     /// ## Signature
     /// `.size_is_logical()` -> specifies that given [`WindowBuilder::size`] is in [`winit::dpi::LogicalSize`]
     /// instead of [`winit::dpi::PhysicalSize`]
     ///
+    /// ## Deprecated
+    /// Use [`WindowBuilder::logical_size`] instead, which is a single option for the same.
+    /// Builders using this one are still created as before.
+    ///
     /// ## Note
     /// Should always be used in pair with [`WindowBuilder::size`]
     ///
@@ -124,166 +220,1589 @@ rokoko_macro::window_builder_data! {
     /// ```
     /// # use rokoko::window::Window;
     ///
+    /// // Same as `.logical_size((1000., 1000.))`
+    /// # #[allow(deprecated)]
     /// Window::new()
     ///     .size((1000., 1000.))
     ///     .size_is_logical();
     /// ```
     ///
     #[require = size]
-    size_is_logical
-}
+    #[deprecated_option(since = "0.0.0", note = "use `WindowBuilder::logical_size` instead")]
+    size_is_logical,
 
-rokoko_macro::window_builder_events! {
     ///
     /// ## Signature
-    /// `.on_close <F: FnMut(Window)> (F)` -> sets a callback that would be called when user attempts to close the window,
-    /// *not* when it is actually closed.
+    /// `.logical_size(impl Into <vec2>)` -> specifies dimensions of the window
+    /// in [`winit::dpi::LogicalSize`], i.e. scaled by the scale factor of the monitor.
     ///
     /// ## Default
-    /// Default behaviour is that if `.on_close` is not specified then window will be simply closed
+    /// Default is some platform-dependent preset dimensions.
+    ///
+    /// # Compatibility
+    /// Not compatible with the [`WindowBuilder::size`], [`WindowBuilder::maximized`], [`WindowBuilder::auto_size`]
+    /// and [`WindowBuilder::fullscreen`]
+    ///
+    /// ## Note
+    /// Must be finite and non-negative, see [`WindowBuilder::try_logical_size`] and [`WindowBuilder::validate`].
+    ///
+    /// ## Example
+    /// ```
+    /// # use rokoko::window::Window;
+    ///
+    /// Window::new()
+    ///     .logical_size((800., 600.));
+    /// ```
+    ///
+    #[conflict = size]
+    #[conflict = maximized]
+    #[conflict = auto_size]
+    #[conflict = fullscreen]
+    #[validate_now = check_size]
+    #[usage = .with_inner_size(winit::dpi::Size::Logical(LogicalSize::from(logical_size).cast()))]
+    logical_size: vec2,
+
+    ///
+    /// ## Signature
+    /// `.position(impl Into <vec2>)` -> specifies the position of the top-left corner
+    /// of the window on the screen.
+    ///
+    /// ## Default
+    /// Default is some platform-dependent position.
     ///
     /// ## Note
-    /// If you specify `.on_close` multiple times only the very last one will be used
+    /// The default type of specified `position` is [`winit::dpi::PhysicalPosition`].
+    ///
+    /// You can change default [`winit::dpi::PhysicalPosition`] to [`winit::dpi::LogicalPosition`]
+    /// by specifying [`WindowBuilder::position_is_logical`].
     ///
     /// ## Note
-    /// See also [`WindowBuilder::on_exit`]
+    /// Must be finite, see [`WindowBuilder::try_position`] and [`WindowBuilder::validate`].
     ///
-    /// ## Examples
-    /// With logging:
+    /// ## Example
     /// ```
     /// # use rokoko::window::Window;
+    ///
     /// Window::new()
-    ///     .on_close(|w| {
-    ///         println!("Mama, I'm closing!");
-    ///         w.close()
-    ///     });
+    ///     .position((100., 200.));
     /// ```
-    /// With a counter:
+    ///
+    #[validate_now = check_position]
+    #[usage = .with_position(if data.position_is_logical().is_some() {
+        winit::dpi::Position::Logical(LogicalPosition::from(position).cast())
+    } else {
+        winit::dpi::Position::Physical(PhysicalPosition::from(position).cast())
+    })]
+    position: vec2,
+
+    ///
+    /// ## Signature
+    /// `.position_is_logical()` -> specifies that given [`WindowBuilder::position`] is in [`winit::dpi::LogicalPosition`]
+    /// instead of [`winit::dpi::PhysicalPosition`]
+    ///
+    /// ## Note
+    /// Should always be used in pair with [`WindowBuilder::position`]
+    ///
+    /// ## Example
     /// ```
     /// # use rokoko::window::Window;
-    /// let mut counter = 5;
     ///
     /// Window::new()
-    ///     .on_close(move |w| {
-    ///         counter -= 1;
-    ///         if counter == 0 {
-    ///             println!("Closing!");
-    ///             w.close()
-    ///         } else {
-    ///             println!("{counter} attempts to close the window remains!")
-    ///         }
-    ///     });
+    ///     .position((100., 200.))
+    ///     .position_is_logical();
     /// ```
-    /// Default:
+    ///
+    #[require = position]
+    position_is_logical,
+
+    ///
+    /// ## Signature
+    /// `.not_resizable()` -> specifies that the user should not be able to resize the window.
+    ///
+    /// ## Note
+    /// The window can still be resized by the code, e.g. with [`WindowBuilder::maximized`].
+    ///
+    /// ## Example
     /// ```
     /// # use rokoko::window::Window;
+    ///
     /// Window::new()
-    ///     .on_close(Window::close);
+    ///     .size((400., 300.))
+    ///     .not_resizable();
     /// ```
-    /// Without closing:
+    ///
+    #[usage = .with_resizable(false)]
+    not_resizable,
+
+    ///
+    /// ## Signature
+    /// `.no_decorations()` -> specifies that the window should have no title bar and borders.
+    ///
+    /// ## Example
+    /// A fixed-size splash window:
     /// ```
     /// # use rokoko::window::Window;
+    ///
     /// Window::new()
-    ///     .on_close(|_| println!("Haha, you cannot close me!"));
+    ///     .size((400., 300.))
+    ///     .not_resizable()
+    ///     .no_decorations();
     /// ```
     ///
-    #[on = Event::WindowEvent { event: WindowEvent::CloseRequested, .. }]
-    #[default = window.close()]
-    on_close(window: Window),
+    #[usage = .with_decorations(false)]
+    no_decorations,
 
     ///
     /// ## Signature
-    /// `.on_init <F: FnMut(Window)> (F)` -> sets a callback that will be called when the window is created.
+    /// `.transparent()` -> specifies that the background of the window should be transparent,
+    /// e.g. for overlays.
     ///
     /// ## Note
-    /// If you specify `.on_init` multiple times only the very last one will be used
+    /// Whether the transparency is visible depends on the platform and on what is drawn.
     ///
-    /// ## Examples
-    /// With logging:
+    /// ## Example
     /// ```
     /// # use rokoko::window::Window;
+    ///
     /// Window::new()
-    ///     .on_init(|_| println!("Initialized!"));
+    ///     .transparent()
+    ///     .always_on_top()
+    ///     .no_decorations();
     /// ```
-    /// Closing the window immediately:
+    ///
+    #[usage = .with_transparent(true)]
+    transparent,
+
+    ///
+    /// ## Signature
+    /// `.always_on_top()` -> specifies that the window should stay above other windows.
+    ///
+    /// ## Example
     /// ```
     /// # use rokoko::window::Window;
+    ///
     /// Window::new()
-    ///     .on_init(|w| {
-    ///         println!("Initialized.. Oops, sorry, already closing!");
-    ///         w.close()
-    ///     });
+    ///     .always_on_top();
     /// ```
     ///
-    #[unique = "init"]
-    on_init(window: Window),
+    #[usage = .with_always_on_top(true)]
+    always_on_top,
 
     ///
     /// ## Signature
-    /// `.on_exit <F: FnMut(Window)> (F)` -> sets a callback that will be called when the `Window::close` function
-    /// is called.
+    /// `.icon((&[u8], uvec2))` -> specifies an icon of the window as RGBA pixels,
+    /// row by row from the top left corner, and its dimensions.
     ///
     /// ## Note
-    /// No other callback is called after that one, so it is useful to work as a destructor
+    /// The pixels must be exactly `4 * width * height` bytes long,
+    /// see [`WindowBuilder::try_icon`] and [`WindowBuilder::validate`].
     ///
-    /// ## Note
-    /// If you specify `.on_exit` multiple times only the very last one will be used
+    /// Not every platform shows window icons, e.g. macOS does not.
+    ///
+    /// ## Example
+    /// ```
+    /// # use rokoko::window::Window;
+    /// use rokoko::prelude::*;
+    ///
+    /// // A 2x2 checkerboard
+    /// const ICON: [u8; 16] = [
+    ///     0, 0, 0, 255,        255, 255, 255, 255,
+    ///     255, 255, 255, 255,  0, 0, 0, 255
+    /// ];
+    ///
+    /// Window::new()
+    ///     .icon((&ICON[..], uvec2::from([2, 2])));
+    /// ```
+    ///
+    #[validate_now = check_icon]
+    #[usage = .with_window_icon(Some(window_icon(icon)))]
+    icon: (&[u8], uvec2),
+
+    ///
+    /// ## Signature
+    /// `.no_panic_context()` -> specifies that panics should not be appended
+    /// with the configuration of the window.
+    ///
+    /// ## Default
+    /// By default, while the window is running, panic messages are followed by a line like
+    /// `note: rokoko window configuration: title="X" size=vec<f32, 2>(800.0, 600.0) callbacks=[on_close,on_init]`.
+    ///
+    /// See [`super::panic_context`] for more information.
+    ///
+    /// ## Example
+    /// ```
+    /// # use rokoko::window::Window;
+    ///
+    /// Window::new()
+    ///     .no_panic_context();
+    /// ```
+    ///
+    #[flag]
+    no_panic_context,
+
+    ///
+    /// ## Signature
+    /// `.target_fps(f32)` -> caps the rate at which [`WindowBuilder::on_frame`] is called.
+    ///
+    /// ## Default
+    /// By default frames are run as fast as possible.
     ///
     /// ## Note
-    /// See also [`WindowBuilder::on_close`]
+    /// Must be positive and finite, otherwise `create` panics.
     ///
-    /// ## Examples
+    /// See [`super::pacing`] for more information.
+    ///
+    /// ## Example
     /// ```
     /// # use rokoko::window::Window;
-    /// struct DropMe;
     ///
-    /// impl Drop for DropMe {
-    ///     fn drop(&mut self) {
-    ///         println!("Dropping!")
-    ///     }
-    /// }
+    /// Window::new()
+    ///     .target_fps(60.0);
+    /// ```
     ///
-    /// let to_be_dropped = DropMe;
+    #[flag]
+    #[validate = pacing::validate_fps]
+    target_fps: f32,
+
+    ///
+    /// ## Signature
+    /// `.power_mode(PowerMode)` -> decides when [`WindowBuilder::on_frame`] keeps running,
+    /// and thus when the event loop may wait for events instead.
+    ///
+    /// ## Default
+    /// Default is [`PowerMode::Balanced`](power::PowerMode::Balanced): frames run while the window is visible.
+    ///
+    /// See [`super::power`] for more information.
+    ///
+    /// ## Example
+    /// ```
+    /// # use rokoko::window::Window;
+    /// use rokoko::window::power::PowerMode;
     ///
     /// Window::new()
-    ///     .on_exit(move |_| {
-    ///         // SAFETY: nothing else can use `to_be_dropped` after that callback
-    ///         // so dropping it here is safe
-    ///         drop(unsafe { core::ptr::read(&to_be_dropped) })
-    ///     });
+    ///     .power_mode(PowerMode::Efficiency)
+    ///     .on_frame(|_, _dt| ());
     /// ```
     ///
-    #[on = Event::UserEvent(UserEvent::Close)]
-    on_exit(window: Window)
-}
+    #[flag]
+    #[default = power::PowerMode::Balanced]
+    power_mode: power::PowerMode,
 
-rokoko_macro::window_builder_create!();
+    ///
+    /// ## Signature
+    /// `.drag_select()` -> enables selection rectangles made by dragging with the left mouse button,
+    /// reported to [`WindowBuilder::on_drag_select`].
+    ///
+    /// See [`super::drag_select`] for more information.
+    ///
+    /// ## Example
+    /// ```
+    /// # use rokoko::window::Window;
+    ///
+    /// Window::new()
+    ///     .drag_select()
+    ///     .on_drag_select(|_, rect, phase| println!("{phase:?}: {rect:?}"));
+    /// ```
+    ///
+    #[flag]
+    drag_select,
 
-impl WindowBuilder {
     ///
-    /// Creates an empty [`WindowBuilder`].
+    /// ## Signature
+    /// `.drag_select_threshold(f32)` -> specifies the distance in pixels the cursor has to move
+    /// with the button held before a drag starts, so that clicks do not produce selections.
     ///
-    pub const fn empty() -> Self {
-        Self(Empty)
-    }
-}
+    /// ## Default
+    /// Default is [`drag_select::DEFAULT_THRESHOLD`].
+    ///
+    /// ## Note
+    /// Should always be used in pair with [`WindowBuilder::drag_select`]
+    ///
+    /// ## Note
+    /// Must be non-negative and finite, otherwise `create` panics.
+    ///
+    /// ## Example
+    /// ```
+    /// # use rokoko::window::Window;
+    ///
+    /// Window::new()
+    ///     .drag_select()
+    ///     .drag_select_threshold(10.0);
+    /// ```
+    ///
+    #[require = drag_select]
+    #[validate = drag_select::validate_threshold]
+    drag_select_threshold: f32,
 
-impl <C> WindowBuilder <C> {
-    const fn on_event <ID: Callback, F: FnMut <ID::Args, Output = ID::Output>> (self, cb: F) -> WindowBuilder <With <OnEventFnContainer <ID, F>, C>> {
-        WindowBuilder(With {
-            data: FnContainer::new(cb),
-            next: self.to_inner()
-        })
-    }
+    ///
+    /// ## Signature
+    /// `.gestures()` -> enables recognition of touch gestures, reported to [`WindowBuilder::on_pinch`],
+    /// [`WindowBuilder::on_pan`] and [`WindowBuilder::on_tap`].
+    ///
+    /// See [`super::gestures`] for more information.
+    ///
+    /// ## Example
+    /// ```
+    /// # use rokoko::window::Window;
+    ///
+    /// Window::new()
+    ///     .gestures()
+    ///     .on_pinch(|_, scale_delta, _| println!("zooming by {scale_delta}"));
+    /// ```
+    ///
+    #[flag]
+    gestures,
 
     ///
-    /// Transforms the [`WindowBuilder`] into `C`.
+    /// ## Signature
+    /// `.double_tap_interval(Duration)` -> specifies the longest time between the end of a tap
+    /// and the start of the next one for them to be counted together, see [`WindowBuilder::on_tap`].
     ///
-    const fn to_inner(self) -> C {
-        // SAFETY: safe because [`WindowBuilder`] does contain the only field -> `C`,
-        // so its memory layout is just the same as of `C`, and because [`WindowBuilder`]
-        // does not have a [`Drop`] implemented(of course), it doesn't need to be dropped.
-        unsafe { transmute(self) }
+    /// ## Default
+    /// Default is [`gestures::DEFAULT_DOUBLE_TAP_INTERVAL`].
+    ///
+    /// ## Note
+    /// Should always be used in pair with [`WindowBuilder::gestures`]
+    ///
+    /// ## Example
+    /// ```
+    /// # use rokoko::window::Window;
+    /// use std::time::Duration;
+    ///
+    /// Window::new()
+    ///     .gestures()
+    ///     .double_tap_interval(Duration::from_millis(500));
+    /// ```
+    ///
+    #[require = gestures]
+    double_tap_interval: Duration,
+
+    ///
+    /// ## Signature
+    /// `.fullscreen()` -> specifies that window should be borderless fullscreen on the current monitor.
+    ///
+    /// ## Compatibility
+    /// Not compatible with the [`WindowBuilder::size`], [`WindowBuilder::logical_size`], [`WindowBuilder::maximized`]
+    /// and [`WindowBuilder::auto_size`]
+    ///
+    /// ## Note
+    /// With [`WindowBuilder::fullscreen_management`] the window is made fullscreen after it is built,
+    /// once the platform is ready for it.
+    ///
+    /// See also [`Window::set_fullscreen`].
+    ///
+    /// ## Example
+    /// ```
+    /// # use rokoko::window::Window;
+    ///
+    /// Window::new()
+    ///     .fullscreen()
+    ///     .on_close(Window::close);
+    /// ```
+    ///
+    /// Conflicting data is rejected by `create`:
+    /// ```should_panic
+    /// # use rokoko::window::Window;
+    ///
+    /// Window::new()
+    ///     .size((800., 600.))
+    ///     .fullscreen()
+    ///     .create()
+    ///     .unwrap()
+    /// ```
+    ///
+    #[conflict = size]
+    #[conflict = logical_size]
+    #[conflict = maximized]
+    #[conflict = auto_size]
+    #[usage = .with_fullscreen(if data.fullscreen_management().is_some() {
+        None
+    } else {
+        Some(winit::window::Fullscreen::Borderless(None))
+    })]
+    fullscreen,
+
+    ///
+    /// ## Signature
+    /// `.fullscreen_management()` -> specifies that entering and exiting fullscreen, both with
+    /// [`WindowBuilder::fullscreen`] and [`Window::set_fullscreen`], should be coordinated with
+    /// focus and with transitions still in progress.
+    ///
+    /// On macOS the initial request is deferred until the window is focused.
+    ///
+    /// See [`super::fullscreen`] for more information.
+    ///
+    /// ## Example
+    /// ```
+    /// # use rokoko::window::Window;
+    ///
+    /// Window::new()
+    ///     .fullscreen()
+    ///     .fullscreen_management();
+    /// ```
+    ///
+    #[flag]
+    fullscreen_management,
+
+    ///
+    /// ## Signature
+    /// `.minimize_on_focus_loss()` -> specifies that a fullscreen window should exit fullscreen and
+    /// get minimized when it loses focus, e.g. on Alt-Tab, and be fullscreen again once refocused.
+    ///
+    /// ## Note
+    /// Should always be used in pair with [`WindowBuilder::fullscreen_management`]
+    ///
+    /// ## Example
+    /// ```
+    /// # use rokoko::window::Window;
+    ///
+    /// # #[cfg(feature = "unstable-minimize-on-focus-loss")]
+    /// Window::new()
+    ///     .fullscreen()
+    ///     .fullscreen_management()
+    ///     .minimize_on_focus_loss();
+    /// ```
+    ///
+    #[require = fullscreen_management]
+    #[unstable(feature = "minimize-on-focus-loss")]
+    minimize_on_focus_loss,
+
+    ///
+    /// ## Signature
+    /// `.count_events()` -> specifies that dispatched events and callback runs should be counted,
+    /// see [`Window::event_counters`].
+    ///
+    /// ## Default
+    /// By default nothing is counted, at no cost.
+    ///
+    /// See [`super::event_counters`] for more information.
+    ///
+    /// ## Example
+    /// ```
+    /// # use rokoko::window::Window;
+    ///
+    /// Window::new()
+    ///     .count_events()
+    ///     .on_exit(|w| println!("{}", w.event_counters()));
+    /// ```
+    ///
+    #[flag]
+    count_events,
+
+    ///
+    /// ## Signature
+    /// `.action_map(ActionMap)` -> binds keys and mouse buttons to named actions,
+    /// reported to [`WindowBuilder::on_action`] and polled with [`Window::action_held`].
+    ///
+    /// The bindings can be changed later with [`Window::action_map_mut`].
+    ///
+    /// See [`super::input_map`] for more information.
+    ///
+    /// ## Example
+    /// ```
+    /// # use rokoko::window::Window;
+    /// use rokoko::window::input_map::{ActionMap, Bind, Key, Modifiers};
+    ///
+    /// let mut map = ActionMap::new();
+    /// map.bind("jump", Key::Space)
+    ///     .bind("save", Key::S.with(Modifiers::CTRL));
+    ///
+    /// Window::new()
+    ///     .action_map(map)
+    ///     .on_action(|_, action, pressed| println!("{action}: {pressed}"));
+    /// ```
+    ///
+    #[flag]
+    action_map: input_map::ActionMap
+}
+
+rokoko_macro::window_builder_events! {
+    ///
+    /// ## Signature
+    /// `.on_close_request <F: FnMut(Window) -> bool> (F)` -> sets a callback that decides whether
+    /// an attempt of user to close the window succeeds: `true` lets it close, `false` keeps it open.
+    ///
+    /// Once allowed, the close is handled by [`WindowBuilder::on_close`] as usual,
+    /// that is, the window is closed unless `.on_close` is specified.
+    ///
+    /// ## Note
+    /// If you specify `.on_close_request` multiple times, all of them are called in the order they were specified,
+    /// and the close is allowed only if all of them return `true`
+    ///
+    /// ## Examples
+    /// Asking for a confirmation:
+    /// ```
+    /// # use rokoko::window::Window;
+    /// let mut attempts = 0;
+    ///
+    /// Window::new()
+    ///     .on_close_request(move |_| {
+    ///         attempts += 1;
+    ///         attempts >= 2
+    ///     });
+    /// ```
+    /// Together with `.on_close`:
+    /// ```
+    /// # use rokoko::window::Window;
+    /// # let saved = || true;
+    /// Window::new()
+    ///     .on_close_request(move |_| saved())
+    ///     .on_close(|w| {
+    ///         println!("Bye!");
+    ///         w.close()
+    ///     });
+    /// ```
+    ///
+    #[on = Event::WindowEvent { event: WindowEvent::CloseRequested, .. }]
+    #[then = if output {
+        if Dispatch::<OnClose>::has_callback(&data) {
+            if counting {
+                window.data().counters.ran("on_close")
+            }
+            Dispatch::<OnClose>::call_callbacks(&mut data, (window,))
+        } else {
+            window.close()
+        }
+    }]
+    on_close_request(window: Window) -> bool,
+
+    ///
+    /// ## Signature
+    /// `.on_close <F: FnMut(Window)> (F)` -> sets a callback that would be called when user attempts to close the window,
+    /// *not* when it is actually closed.
+    ///
+    /// ## Default
+    /// Default behaviour is that if `.on_close` is not specified then window will be simply closed
+    ///
+    /// ## Note
+    /// If you specify `.on_close` multiple times, all of them are called in the order they were specified,
+    /// and the default is not used, so the window is closed only if one of them calls [`Window::close`]
+    ///
+    /// ## Note
+    /// See also [`WindowBuilder::on_exit`] and [`WindowBuilder::on_close_request`]
+    ///
+    /// ## Examples
+    /// With logging:
+    /// ```
+    /// # use rokoko::window::Window;
+    /// Window::new()
+    ///     .on_close(|w| {
+    ///         println!("Mama, I'm closing!");
+    ///         w.close()
+    ///     });
+    /// ```
+    /// With a counter:
+    /// ```
+    /// # use rokoko::window::Window;
+    /// let mut counter = 5;
+    ///
+    /// Window::new()
+    ///     .on_close(move |w| {
+    ///         counter -= 1;
+    ///         if counter == 0 {
+    ///             println!("Closing!");
+    ///             w.close()
+    ///         } else {
+    ///             println!("{counter} attempts to close the window remains!")
+    ///         }
+    ///     });
+    /// ```
+    /// Default:
+    /// ```
+    /// # use rokoko::window::Window;
+    /// Window::new()
+    ///     .on_close(Window::close);
+    /// ```
+    /// Without closing:
+    /// ```
+    /// # use rokoko::window::Window;
+    /// Window::new()
+    ///     .on_close(|_| println!("Haha, you cannot close me!"));
+    /// ```
+    /// Chained, printing before closing:
+    /// ```
+    /// # use rokoko::window::Window;
+    /// Window::new()
+    ///     .on_close(|_| println!("Closing!"))
+    ///     .on_close(Window::close);
+    /// ```
+    ///
+    #[on = Event::WindowEvent { event: WindowEvent::CloseRequested, .. }]
+    #[default = window.close()]
+    on_close(window: Window),
+
+    ///
+    /// ## Signature
+    /// `.on_init <F: FnMut(Window)> (F)` -> sets a callback that will be called when the window is created.
+    ///
+    /// ## Note
+    /// If you specify `.on_init` multiple times, all of them are called in the order they were specified
+    ///
+    /// ## Examples
+    /// With logging:
+    /// ```
+    /// # use rokoko::window::Window;
+    /// Window::new()
+    ///     .on_init(|_| println!("Initialized!"));
+    /// ```
+    /// Closing the window immediately:
+    /// ```
+    /// # use rokoko::window::Window;
+    /// Window::new()
+    ///     .on_init(|w| {
+    ///         println!("Initialized.. Oops, sorry, already closing!");
+    ///         w.close()
+    ///     });
+    /// ```
+    ///
+    #[unique = "init"]
+    on_init(window: Window),
+
+    ///
+    /// ## Signature
+    /// `.on_exit <F: FnMut(Window)> (F)` -> sets a callback that will be called when the `Window::close` function
+    /// is called.
+    ///
+    /// ## Note
+    /// No other callback is called after that one, so it is useful to work as a destructor
+    ///
+    /// ## Note
+    /// If you specify `.on_exit` multiple times, all of them are called in the order they were specified
+    ///
+    /// ## Note
+    /// See also [`WindowBuilder::on_close`]
+    ///
+    /// ## Examples
+    /// ```
+    /// # use rokoko::window::Window;
+    /// struct DropMe;
+    ///
+    /// impl Drop for DropMe {
+    ///     fn drop(&mut self) {
+    ///         println!("Dropping!")
+    ///     }
+    /// }
+    ///
+    /// let to_be_dropped = DropMe;
+    ///
+    /// Window::new()
+    ///     .on_exit(move |_| {
+    ///         // SAFETY: nothing else can use `to_be_dropped` after that callback
+    ///         // so dropping it here is safe
+    ///         drop(unsafe { core::ptr::read(&to_be_dropped) })
+    ///     });
+    /// ```
+    ///
+    #[on = Event::UserEvent(UserEvent::Close)]
+    on_exit(window: Window),
+
+    ///
+    /// ## Signature
+    /// `.on_close_scheduled <F: FnMut(Window, Duration)> (F)` -> sets a callback that will be called when
+    /// a close is scheduled by [`Window::close_after`], with the time left until it.
+    ///
+    /// ## Note
+    /// Called once per scheduling, on the next iteration of the loop, so not at all
+    /// if the close is cancelled or replaced before that.
+    ///
+    /// ## Note
+    /// If you specify `.on_close_scheduled` multiple times, all of them are called in the order they were specified
+    ///
+    /// ## Examples
+    /// ```
+    /// # use rokoko::window::Window;
+    /// # use std::time::Duration;
+    /// Window::new()
+    ///     .on_init(|w| { w.close_after(Duration::from_secs(3)); })
+    ///     .on_close_scheduled(|w, remaining| w.set_title(&format!("closing in {}s", remaining.as_secs())));
+    /// ```
+    ///
+    #[on = Event::UserEvent(UserEvent::CloseScheduled)]
+    on_close_scheduled(window: Window, remaining: Duration),
+
+    ///
+    /// ## Signature
+    /// `.on_frame <F: FnMut(Window, f32)> (F)` -> sets a callback that will be called once per
+    /// iteration of the event loop, after all the other events are handled, with the time
+    /// since the previous frame in seconds.
+    ///
+    /// That is an easy way to port an existing game loop: its body goes here.
+    /// Other libraries often call such a callback `on_update`.
+    ///
+    /// ## Note
+    /// If specified, the event loop does not wait for events anymore, but runs frames
+    /// as fast as possible, or at the rate given by [`WindowBuilder::target_fps`].
+    /// By default that is only while the window is visible, see [`WindowBuilder::power_mode`].
+    ///
+    /// Otherwise the event loop keeps waiting for events, and no time is measured at all.
+    ///
+    /// ## Note
+    /// Delta time is measured with a monotonic clock and never exceeds [`pacing::MAX_DT`].
+    /// It is `0` on the first frame.
+    ///
+    /// ## Note
+    /// If you specify `.on_frame` multiple times, all of them are called in the order they were specified
+    ///
+    /// ## Examples
+    /// ```
+    /// # use rokoko::window::Window;
+    /// let mut time = 0.0;
+    ///
+    /// Window::new()
+    ///     .target_fps(60.0)
+    ///     .on_frame(move |_, dt| {
+    ///         time += dt;
+    ///         println!("{time:.2}s since start")
+    ///     });
+    /// ```
+    ///
+    #[on = Event::MainEventsCleared]
+    on_frame(window: Window, dt: f32),
+
+    ///
+    /// ## Signature
+    /// `.on_resize <F: FnMut(Window, vec2)> (F)` -> sets a callback that will be called when
+    /// the window is resized, with the new size of its client area in physical pixels.
+    ///
+    /// ## Note
+    /// Also called for resizes caused by the data, e.g. [`WindowBuilder::maximized`] or
+    /// [`WindowBuilder::auto_size`], so it is a good place to recompute the layout.
+    ///
+    /// ## Note
+    /// If you specify `.on_resize` multiple times, all of them are called in the order they were specified
+    ///
+    /// ## Examples
+    /// With logging:
+    /// ```
+    /// # use rokoko::window::Window;
+    /// Window::new()
+    ///     .on_resize(|_, size| println!("now {}x{}", size[0], size[1]));
+    /// ```
+    /// Keeping the aspect ratio of a maximized window:
+    /// ```
+    /// # use rokoko::prelude::*;
+    /// let mut aspect = 1.0;
+    ///
+    /// Window::new()
+    ///     .maximized()
+    ///     .on_resize(move |_, size: vec2| if size[1] != 0.0 {
+    ///         aspect = size[0] / size[1]
+    ///     });
+    /// ```
+    ///
+    #[on = Event::WindowEvent { event: WindowEvent::Resized(new_size), .. }]
+    #[bind = let size = vec2::from([new_size.width as f32, new_size.height as f32])]
+    on_resize(window: Window, size: vec2),
+
+    ///
+    /// ## Signature
+    /// `.on_redraw <F: FnMut(Window)> (F)` -> sets a callback that will be called when
+    /// the window has to be redrawn.
+    ///
+    /// That happens when the platform asks for it, e.g. once the window is shown or uncovered,
+    /// and after [`Window::request_redraw`].
+    ///
+    /// ## Note
+    /// Within an iteration of the event loop it is called after [`WindowBuilder::on_frame`],
+    /// so a frame may update the state and request the redraw showing it.
+    ///
+    /// ## Note
+    /// If you specify `.on_redraw` multiple times, all of them are called in the order they were specified
+    ///
+    /// ## Examples
+    /// Redrawing only on changes:
+    /// ```
+    /// # use rokoko::window::Window;
+    /// Window::new()
+    ///     .on_cursor_entered(Window::request_redraw)
+    ///     .on_cursor_left(Window::request_redraw)
+    ///     .on_redraw(|w| {
+    ///         let clear_color = if w.cursor_inside() { [0.3; 3] } else { [0.1; 3] };
+    ///         let _ = clear_color;
+    ///     });
+    /// ```
+    ///
+    #[on = Event::RedrawRequested(_)]
+    on_redraw(window: Window),
+
+    ///
+    /// ## Signature
+    /// `.on_key <F: FnMut(Window, Key, bool)> (F)` -> sets a callback that will be called when
+    /// a key is pressed(`true`) or released(`false`).
+    ///
+    /// ## Note
+    /// Keys the platform cannot name(see [`Key`]) are skipped.
+    ///
+    /// ## Note
+    /// A held key is reported as pressed again on every auto-repeat,
+    /// [`WindowBuilder::action_map`] is an alternative that reports it only once.
+    ///
+    /// ## Note
+    /// If you specify `.on_key` multiple times, all of them are called in the order they were specified
+    ///
+    /// ## Examples
+    /// Closing on escape:
+    /// ```
+    /// # use rokoko::window::{Window, Key};
+    /// Window::new()
+    ///     .on_key(|w, key, pressed| if key == Key::Escape && pressed {
+    ///         w.close()
+    ///     });
+    /// ```
+    ///
+    #[on = Event::WindowEvent { event: WindowEvent::KeyboardInput { input: KeyboardInput { virtual_keycode: Some(key), state, .. }, .. }, .. }]
+    #[bind = let pressed = state == ElementState::Pressed]
+    on_key(window: Window, key: Key, pressed: bool),
+
+    ///
+    /// ## Signature
+    /// `.on_char <F: FnMut(Window, char)> (F)` -> sets a callback that will be called when
+    /// a character is typed, with the keyboard layout, dead keys and modifiers applied.
+    ///
+    /// ## Note
+    /// Control characters are reported too, e.g. `'\u{8}'` alongside [`Key::Back`],
+    /// [`TextEdit::char`](super::textedit::TextEdit::char) ignores them.
+    ///
+    /// ## Note
+    /// `winit` does not report IME composition yet, so composed text comes only
+    /// where the platform commits it as characters.
+    ///
+    /// ## Note
+    /// If you specify `.on_char` multiple times, all of them are called in the order they were specified
+    ///
+    /// ## Examples
+    /// ```
+    /// # use rokoko::window::Window;
+    /// # use std::cell::RefCell;
+    /// let typed = RefCell::new(String::new());
+    ///
+    /// Window::new()
+    ///     .on_char(|_, c| if !c.is_control() {
+    ///         typed.borrow_mut().push(c)
+    ///     });
+    /// ```
+    ///
+    #[on = Event::WindowEvent { event: WindowEvent::ReceivedCharacter(c), .. }]
+    on_char(window: Window, c: char),
+
+    ///
+    /// ## Signature
+    /// `.on_mouse_move <F: FnMut(Window, vec2)> (F)` -> sets a callback that will be called when
+    /// the cursor moves over the window, with its new position in physical window coordinates.
+    ///
+    /// ## Note
+    /// See [`Window::window_to_ndc`] and the like for other coordinate spaces.
+    ///
+    /// ## Note
+    /// If you specify `.on_mouse_move` multiple times, all of them are called in the order they were specified
+    ///
+    /// ## Examples
+    /// ```
+    /// # use rokoko::window::Window;
+    /// Window::new()
+    ///     .on_mouse_move(|w, position| println!("{:?} in NDC", w.window_to_ndc(position)));
+    /// ```
+    ///
+    #[on = Event::WindowEvent { event: WindowEvent::CursorMoved { position, .. }, .. }]
+    #[bind = let position = vec2::from(position.cast::<f32>())]
+    on_mouse_move(window: Window, position: vec2),
+
+    ///
+    /// ## Signature
+    /// `.on_mouse_button <F: FnMut(Window, MouseButton, bool)> (F)` -> sets a callback that will be called when
+    /// a mouse button is pressed(`true`) or released(`false`).
+    ///
+    /// ## Note
+    /// If you specify `.on_mouse_button` multiple times, all of them are called in the order they were specified
+    ///
+    /// ## Examples
+    /// Remembering where the left button was pressed:
+    /// ```
+    /// # use rokoko::prelude::*;
+    /// use rokoko::window::MouseButton;
+    /// use std::cell::Cell;
+    ///
+    /// let cursor = Cell::new(vec2::from([0.0, 0.0]));
+    ///
+    /// Window::new()
+    ///     .on_mouse_move(|_, position| cursor.set(position))
+    ///     .on_mouse_button(|_, button, pressed| if button == MouseButton::Left && pressed {
+    ///         println!("Clicked at {:?}", cursor.get())
+    ///     });
+    /// ```
+    ///
+    #[on = Event::WindowEvent { event: WindowEvent::MouseInput { state, button, .. }, .. }]
+    #[bind = let pressed = state == ElementState::Pressed]
+    on_mouse_button(window: Window, button: MouseButton, pressed: bool),
+
+    ///
+    /// ## Signature
+    /// `.on_scroll <F: FnMut(Window, vec2)> (F)` -> sets a callback that will be called when
+    /// the wheel or the touchpad is scrolled, with the delta in lines.
+    ///
+    /// Positive `x` is rightwards, positive `y` is forward, i.e. away from the user.
+    ///
+    /// ## Note
+    /// Touchpads report pixels, those are converted, see [`input_map::scroll_lines`].
+    ///
+    /// ## Note
+    /// If you specify `.on_scroll` multiple times, all of them are called in the order they were specified
+    ///
+    /// ## Examples
+    /// Zooming:
+    /// ```
+    /// # use rokoko::window::Window;
+    /// let mut zoom = 1.0f32;
+    ///
+    /// Window::new()
+    ///     .on_scroll(move |_, delta| {
+    ///         zoom = (zoom * 1.1f32.powf(delta[1])).clamp(0.1, 10.0);
+    ///         println!("zoom: {zoom:.2}")
+    ///     });
+    /// ```
+    ///
+    #[on = Event::WindowEvent { event: WindowEvent::MouseWheel { delta, .. }, .. }]
+    #[bind = let delta = input_map::scroll_lines(delta)]
+    on_scroll(window: Window, delta: vec2),
+
+    ///
+    /// ## Signature
+    /// `.on_cursor_entered <F: FnMut(Window)> (F)` -> sets a callback that will be called when
+    /// the cursor enters the window.
+    ///
+    /// ## Note
+    /// [`Window::cursor_inside`] is already `true` when it is called.
+    ///
+    /// ## Note
+    /// If you specify `.on_cursor_entered` multiple times, all of them are called in the order they were specified
+    ///
+    /// ## Examples
+    /// Highlighting the window while hovered:
+    /// ```
+    /// # use rokoko::window::Window;
+    /// Window::new()
+    ///     .on_cursor_entered(|_| println!("Hovered, clearing with a lighter color"))
+    ///     .on_cursor_left(|_| println!("Not hovered, clearing with the usual color"))
+    ///     .on_frame(|w, _| {
+    ///         let clear_color = if w.cursor_inside() { [0.3; 3] } else { [0.1; 3] };
+    ///         let _ = clear_color;
+    ///     });
+    /// ```
+    ///
+    #[on = Event::WindowEvent { event: WindowEvent::CursorEntered { .. }, .. }]
+    on_cursor_entered(window: Window),
+
+    ///
+    /// ## Signature
+    /// `.on_cursor_left <F: FnMut(Window)> (F)` -> sets a callback that will be called when
+    /// the cursor leaves the window.
+    ///
+    /// ## Note
+    /// [`Window::cursor_inside`] is already `false` when it is called.
+    ///
+    /// ## Note
+    /// If you specify `.on_cursor_left` multiple times, all of them are called in the order they were specified
+    ///
+    /// ## Examples
+    /// ```
+    /// # use rokoko::window::Window;
+    /// Window::new()
+    ///     .on_cursor_left(|w| assert!(!w.cursor_inside()));
+    /// ```
+    ///
+    #[on = Event::WindowEvent { event: WindowEvent::CursorLeft { .. }, .. }]
+    on_cursor_left(window: Window),
+
+    ///
+    /// ## Signature
+    /// `.on_focus <F: FnMut(Window, bool)> (F)` -> sets a callback that will be called when
+    /// the window gains(`true`) or loses(`false`) focus.
+    ///
+    /// ## Note
+    /// With [`WindowBuilder::fullscreen_management`] fullscreen has already been handled when it is called.
+    ///
+    /// ## Note
+    /// If you specify `.on_focus` multiple times, all of them are called in the order they were specified
+    ///
+    /// ## Examples
+    /// Pausing while in background:
+    /// ```
+    /// # use rokoko::window::Window;
+    /// use std::cell::Cell;
+    ///
+    /// let paused = Cell::new(false);
+    ///
+    /// Window::new()
+    ///     .on_focus(|_, focused| paused.set(!focused))
+    ///     .on_frame(|_, _dt| if !paused.get() {
+    ///         // Update the game
+    ///     });
+    /// ```
+    ///
+    #[on = Event::WindowEvent { event: WindowEvent::Focused(focused), .. }]
+    on_focus(window: Window, focused: bool),
+
+    ///
+    /// ## Signature
+    /// `.on_file_drop <F: FnMut(Window, PathBuf)> (F)` -> sets a callback that will be called when
+    /// a file is dropped onto the window, with its path.
+    ///
+    /// ## Note
+    /// The path is given by value, as the event owns it anyway.
+    /// Several files dropped at once come one call per file.
+    ///
+    /// ## Note
+    /// If you specify `.on_file_drop` multiple times, all of them are called in the order they were specified
+    ///
+    /// ## Examples
+    /// ```
+    /// # use rokoko::window::Window;
+    /// Window::new()
+    ///     .on_file_drop(|w, path| if let Some(name) = path.file_name() {
+    ///         w.set_title(&name.to_string_lossy())
+    ///     });
+    /// ```
+    ///
+    #[on = Event::WindowEvent { event: WindowEvent::DroppedFile(path), .. }]
+    on_file_drop(window: Window, path: PathBuf),
+
+    ///
+    /// ## Signature
+    /// `.on_file_hover <F: FnMut(Window, Option <PathBuf>)> (F)` -> sets a callback that will be called when
+    /// a file is dragged over the window(`Some(path)`), and when it leaves without being dropped(`None`).
+    ///
+    /// ## Note
+    /// The path is given by value, as the event owns it anyway.
+    /// Several files dragged at once come one call per file, while leaving is reported once for all of them.
+    ///
+    /// ## Note
+    /// If you specify `.on_file_hover` multiple times, all of them are called in the order they were specified
+    ///
+    /// ## Examples
+    /// Highlighting the drop target:
+    /// ```
+    /// # use rokoko::window::Window;
+    /// use std::cell::Cell;
+    ///
+    /// let hovered = Cell::new(false);
+    ///
+    /// Window::new()
+    ///     .on_file_hover(|w, path| {
+    ///         hovered.set(path.is_some());
+    ///         w.request_redraw()
+    ///     })
+    ///     .on_file_drop(|w, _path| {
+    ///         hovered.set(false);
+    ///         w.request_redraw()
+    ///     });
+    /// ```
+    ///
+    #[on = Event::WindowEvent { event: hover @ (WindowEvent::HoveredFile(_) | WindowEvent::HoveredFileCancelled), .. }]
+    #[bind = let path = if let WindowEvent::HoveredFile(path) = hover { Some(path) } else { None }]
+    on_file_hover(window: Window, path: Option <PathBuf>),
+
+    ///
+    /// ## Signature
+    /// `.on_drag_select <F: FnMut(Window, Rect <f32>, DragPhase)> (F)` -> sets a callback that will be called
+    /// as a selection rectangle is dragged, see [`WindowBuilder::drag_select`].
+    ///
+    /// The rectangle is normalized and in physical window coordinates.
+    ///
+    /// ## Note
+    /// Only called if [`WindowBuilder::drag_select`] is specified.
+    ///
+    /// ## Note
+    /// If you specify `.on_drag_select` multiple times, all of them are called in the order they were specified
+    ///
+    /// ## Examples
+    /// Selecting points:
+    /// ```
+    /// # use rokoko::prelude::*;
+    /// use rokoko::window::drag_select::DragPhase;
+    ///
+    /// let points = [vec2::from([10.0, 10.0]), vec2::from([200.0, 50.0])];
+    ///
+    /// Window::new()
+    ///     .drag_select()
+    ///     .on_drag_select(move |_, rect, phase| if phase == DragPhase::Finished {
+    ///         let selected = points.iter().filter(|&&p| rect.contains(p)).count();
+    ///         println!("{selected} points selected")
+    ///     });
+    /// ```
+    ///
+    #[unique = "drag_select"]
+    on_drag_select(window: Window, rect: Rect <f32>, phase: DragPhase),
+
+    ///
+    /// ## Signature
+    /// `.on_pinch <F: FnMut(Window, f32, vec2)> (F)` -> sets a callback that will be called
+    /// as two fingers are moved closer or apart, see [`WindowBuilder::gestures`].
+    ///
+    /// Called with the ratio of the new distance between the fingers to the previous one,
+    /// so that scales can be multiplied by it, and the middle between them in physical window coordinates.
+    ///
+    /// ## Note
+    /// Only called if [`WindowBuilder::gestures`] is specified.
+    ///
+    /// ## Note
+    /// If you specify `.on_pinch` multiple times, all of them are called in the order they were specified
+    ///
+    /// ## Examples
+    /// Zooming:
+    /// ```
+    /// # use rokoko::window::Window;
+    /// let mut zoom = 1.0;
+    ///
+    /// Window::new()
+    ///     .gestures()
+    ///     .on_pinch(move |_, scale_delta, _| zoom = (zoom * scale_delta).clamp(0.1, 10.0));
+    /// ```
+    ///
+    #[unique = "gestures"]
+    #[on = Gesture::Pinch { scale_delta, center }]
+    on_pinch(window: Window, scale_delta: f32, center: vec2),
+
+    ///
+    /// ## Signature
+    /// `.on_pan <F: FnMut(Window, vec2)> (F)` -> sets a callback that will be called
+    /// as a single finger is dragged, see [`WindowBuilder::gestures`].
+    ///
+    /// Called with the movement since the previous call, in physical pixels.
+    ///
+    /// ## Note
+    /// Only called if [`WindowBuilder::gestures`] is specified.
+    ///
+    /// ## Note
+    /// If you specify `.on_pan` multiple times, all of them are called in the order they were specified
+    ///
+    /// ## Examples
+    /// Scrolling a view:
+    /// ```
+    /// # use rokoko::prelude::*;
+    /// let mut offset = vec2::from([0.0, 0.0]);
+    ///
+    /// Window::new()
+    ///     .gestures()
+    ///     .on_pan(move |_, delta| offset += delta);
+    /// ```
+    ///
+    #[unique = "gestures"]
+    #[on = Gesture::Pan { delta }]
+    on_pan(window: Window, delta: vec2),
+
+    ///
+    /// ## Signature
+    /// `.on_tap <F: FnMut(Window, vec2, u8)> (F)` -> sets a callback that will be called
+    /// when a finger briefly touches the window without moving, see [`WindowBuilder::gestures`].
+    ///
+    /// Called with the position in physical window coordinates and the number of taps in a row,
+    /// `1` for a single tap, `2` for the second tap of a double tap and so on, see [`WindowBuilder::double_tap_interval`].
+    ///
+    /// ## Note
+    /// Only called if [`WindowBuilder::gestures`] is specified.
+    ///
+    /// ## Note
+    /// If you specify `.on_tap` multiple times, all of them are called in the order they were specified
+    ///
+    /// ## Examples
+    /// Double tap to toggle fullscreen:
+    /// ```
+    /// # use rokoko::window::Window;
+    /// Window::new()
+    ///     .gestures()
+    ///     .on_tap(|w, _, count| if count == 2 {
+    ///         w.set_fullscreen(!w.is_fullscreen())
+    ///     });
+    /// ```
+    ///
+    #[unique = "gestures"]
+    #[on = Gesture::Tap { position, count }]
+    on_tap(window: Window, position: vec2, count: u8),
+
+    ///
+    /// ## Signature
+    /// `.on_action <F: FnMut(Window, &'static str, bool)> (F)` -> sets a callback that will be called
+    /// when an action becomes held(`true`) or not held anymore(`false`).
+    ///
+    /// ## Note
+    /// Only called if [`WindowBuilder::action_map`] is specified.
+    ///
+    /// ## Note
+    /// If you specify `.on_action` multiple times, all of them are called in the order they were specified
+    ///
+    /// ## Examples
+    /// ```
+    /// # use rokoko::window::Window;
+    /// use rokoko::window::input_map::{ActionMap, Key};
+    ///
+    /// let mut map = ActionMap::new();
+    /// map.bind("quit", Key::Escape);
+    ///
+    /// Window::new()
+    ///     .action_map(map)
+    ///     .on_action(|w, action, pressed| if action == "quit" && pressed {
+    ///         w.close()
+    ///     });
+    /// ```
+    ///
+    #[unique = "action_map"]
+    on_action(window: Window, action: &'static str, pressed: bool)
+}
+
+///
+/// Identifies the closure given to [`WindowBuilder::winit_overrides`].
+///
+pub struct WinitOverrides;
+
+impl Callback for WinitOverrides {
+    type Output = winit::window::WindowBuilder;
+    type Args = (winit::window::WindowBuilder,);
+}
+
+rokoko_macro::window_builder_create!();
+
+impl <C> WindowBuilder <C> {
+    ///
+    /// ## Signature
+    /// `.size_720p()` -> shortcut for `.auto_size(SizePolicy::P720)`.
+    ///
+    /// See [`WindowBuilder::auto_size`].
+    ///
+    pub const fn size_720p(self) -> WindowBuilder <With <AutoSize, C>> {
+        self.auto_size(SizePolicy::P720)
+    }
+
+    ///
+    /// ## Signature
+    /// `.size_1080p()` -> shortcut for `.auto_size(SizePolicy::P1080)`.
+    ///
+    /// See [`WindowBuilder::auto_size`].
+    ///
+    pub const fn size_1080p(self) -> WindowBuilder <With <AutoSize, C>> {
+        self.auto_size(SizePolicy::P1080)
+    }
+
+    ///
+    /// ## Signature
+    /// `.size_fraction(f32)` -> shortcut for `.auto_size(SizePolicy::Fraction(fraction))`.
+    ///
+    /// See [`WindowBuilder::auto_size`].
+    ///
+    /// ## Example
+    /// ```
+    /// # use rokoko::window::Window;
+    ///
+    /// Window::new()
+    ///     .size_fraction(0.6);
+    /// ```
+    ///
+    pub const fn size_fraction(self, fraction: f32) -> WindowBuilder <With <AutoSize, C>> {
+        self.auto_size(SizePolicy::Fraction(fraction))
+    }
+
+    ///
+    /// ## Signature
+    /// `.data <T: 'static> (T)` -> attaches a value shared between the callbacks,
+    /// available through [`Window::user_data`].
+    ///
+    /// See [`user_data`] for more information.
+    ///
+    /// ## Note
+    /// Values of different types can be attached at once. If you specify `.data` with the same type
+    /// multiple times only the very last one will be used
+    ///
+    /// ## Example
+    /// Counting attempts to close the window:
+    /// ```
+    /// # use rokoko::window::Window;
+    /// struct Attempts(u32);
+    ///
+    /// Window::new()
+    ///     .data(Attempts(0))
+    ///     .on_init(|w| w.user_data::<Attempts>().unwrap().0 = 3)
+    ///     .on_close(|w| {
+    ///         let mut attempts = w.user_data::<Attempts>().unwrap();
+    ///         attempts.0 -= 1;
+    ///         println!("{} attempts to close the window remain", attempts.0);
+    ///         if attempts.0 == 0 {
+    ///             w.close()
+    ///         }
+    ///     });
+    /// ```
+    ///
+    pub const fn data <T: 'static> (self, value: T) -> WindowBuilder <With <UserData <T>, C>> {
+        WindowBuilder(With {
+            data: UserData::new(value),
+            next: self.to_inner()
+        })
+    }
+
+    ///
+    /// ## Signature
+    /// `.loading_phase <T: 'static, F: FnMut(LoadingCtx) -> LoadingProgress <T>> (F)` -> sets a loader
+    /// for slow startup work(e.g. loading assets), done while the window is already shown.
+    ///
+    /// The loader is called once per iteration of the loop, which keeps processing events meanwhile,
+    /// until it returns [`LoadingProgress::Done`]. The value is attached then, as with [`WindowBuilder::data`],
+    /// and [`WindowBuilder::on_init`] is called.
+    ///
+    /// See [`loading`](crate::window::loading) for more information.
+    ///
+    /// ## Note
+    /// Until the value is loaded, no callback is called, [`WindowBuilder::on_close_request`] included:
+    /// closing the window aborts the loading(see [`LoadingCtx::should_abort`]) and calls only [`WindowBuilder::on_exit`].
+    ///
+    /// ## Note
+    /// If you specify `.loading_phase` multiple times only the very last one will be used
+    ///
+    /// ## Example
+    /// Loading a level a chunk per iteration:
+    /// ```
+    /// # use rokoko::window::{Window, loading::LoadingProgress};
+    /// struct Level(Vec <u32>);
+    ///
+    /// const TILES: usize = 1000;
+    /// let mut tiles = Vec::new();
+    ///
+    /// Window::new()
+    ///     .loading_phase(move |_| {
+    ///         tiles.extend((tiles.len()..tiles.len() + 100).map(|i| i as u32 * 7));
+    ///         if tiles.len() < TILES {
+    ///             LoadingProgress::Pending(tiles.len() as f32 / TILES as f32)
+    ///         } else {
+    ///             LoadingProgress::Done(Level(std::mem::take(&mut tiles)))
+    ///         }
+    ///     })
+    ///     .on_init(|w| println!("{} tiles loaded", w.user_data::<Level>().unwrap().0.len()));
+    /// ```
+    ///
+    pub const fn loading_phase <T: 'static, F: FnMut(LoadingCtx) -> LoadingProgress <T> + 'static> (self, load: F) -> WindowBuilder <With <Loader <T, F>, C>> {
+        WindowBuilder(With {
+            data: Loader::new(load),
+            next: self.to_inner()
+        })
+    }
+}
+
+#[cfg(feature = "winit-interop")]
+impl <C> WindowBuilder <C> {
+    ///
+    /// ## Signature
+    /// `.winit_overrides <F: FnOnce(winit::window::WindowBuilder) -> winit::window::WindowBuilder> (F)` ->
+    /// sets a closure that configures the underlying [`winit::window::WindowBuilder`] directly,
+    /// e.g. options `rokoko` does not wrap.
+    ///
+    /// ## Note
+    /// It is applied after all the data, so it wins over it:
+    /// the order is the given builder(see [`WindowBuilder::apply_to_winit`]), then the data
+    /// (defaults included), then the overrides.
+    ///
+    /// ## Note
+    /// If you specify `.winit_overrides` multiple times only the very last one will be used
+    ///
+    /// ## Note
+    /// Requires `winit-interop` feature.
+    ///
+    /// ## Example
+    /// ```
+    /// # use rokoko::window::Window;
+    /// let builder = Window::new()
+    ///     .title("Overridden")
+    ///     .maximized()
+    ///     .winit_overrides(|b| b.with_always_on_top(true).with_title("Mine"))
+    ///     .apply_to_winit(winit::window::WindowBuilder::new().with_resizable(false));
+    ///
+    /// // The data
+    /// assert!(builder.window.maximized);
+    ///
+    /// // The overrides, applied last
+    /// assert!(builder.window.always_on_top);
+    /// assert_eq!(builder.window.title, "Mine");
+    ///
+    /// // The given builder
+    /// assert!(!builder.window.resizable);
+    /// ```
+    ///
+    pub const fn winit_overrides <F: FnOnce(winit::window::WindowBuilder) -> winit::window::WindowBuilder> (self, f: F)
+        -> WindowBuilder <With <fn_container::OnceFnContainer <WinitOverrides, F>, C>> {
+        WindowBuilder(With {
+            data: FnOnceContainer::new(f),
+            next: self.to_inner()
+        })
+    }
+}
+
+///
+/// An invalid value given to a `try_` setter, e.g. [`WindowBuilder::try_size`],
+/// or found by [`WindowBuilder::validate`].
+///
+/// # Examples
+///
+/// ```
+/// # use rokoko::window::Window;
+/// let invalid = Window::new().try_title("").unwrap_err();
+/// assert_eq!(invalid.field, "title");
+/// assert_eq!(invalid.to_string(), "invalid `title`: must not be empty");
+/// ```
+///
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct InvalidValue {
+    /// The name of the setter, e.g. `"size"`
+    pub field: &'static str,
+
+    /// Why the value is invalid
+    pub reason: &'static str
+}
+
+impl core::fmt::Display for InvalidValue {
+    fn fmt(&self, f: &mut core::fmt::Formatter <'_>) -> core::fmt::Result {
+        write!(f, "invalid `{}`: {}", self.field, self.reason)
+    }
+}
+
+impl std::error::Error for InvalidValue {}
+
+/// Checks [`WindowBuilder::title`], control characters(e.g. newlines) break titles on some window managers
+const fn check_title(title: &&str) -> Result <(), &'static str> {
+    let bytes = title.as_bytes();
+    if bytes.is_empty() {
+        return Err("must not be empty")
+    }
+
+    let mut i = 0;
+    while i < bytes.len() {
+        // C0 controls and DEL, then C1 controls(U+0080..=U+009F), encoded as `C2 80..=C2 9F`
+        if bytes[i] < 0x20 || bytes[i] == 0x7f || (bytes[i] == 0xc2 && i + 1 < bytes.len() && bytes[i + 1] < 0xa0) {
+            return Err("must not contain control characters")
+        }
+        i += 1
+    }
+    Ok(())
+}
+
+///
+/// Checks [`WindowBuilder::title`] given to the setter, see [`check_title`].
+///
+/// Fails the build if the builder is evaluated at compile time, e.g. in a `const` item,
+/// and does nothing at runtime, where titles are checked by `validate` and `create`.
+///
+const fn validate_title(title: &str) -> &str {
+    const fn in_const(title: &str) -> &str {
+        match check_title(&title) {
+            Ok(()) => title,
+            Err(reason) => panic!("{}", reason)
+        }
+    }
+
+    fn at_runtime(title: &str) -> &str {
+        title
+    }
+
+    // SAFETY: both return `title` unchanged; the `const` one may only fail the build instead
+    unsafe { core::intrinsics::const_eval_select((title,), in_const, at_runtime) }
+}
+
+/// Checks [`WindowBuilder::size`], `NaN` fails both comparisons
+const fn check_size(size: &vec2) -> Result <(), &'static str> {
+    let [width, height] = *size.as_array();
+    if width >= 0.0 && width <= f32::MAX && height >= 0.0 && height <= f32::MAX {
+        Ok(())
+    } else {
+        Err("must be finite and non-negative")
+    }
+}
+
+/// Checks [`WindowBuilder::position`], `NaN` fails both comparisons
+const fn check_position(position: &vec2) -> Result <(), &'static str> {
+    let [x, y] = *position.as_array();
+    if x >= -f32::MAX && x <= f32::MAX && y >= -f32::MAX && y <= f32::MAX {
+        Ok(())
+    } else {
+        Err("must be finite")
+    }
+}
+
+/// Checks [`WindowBuilder::icon`], the dimensions are widened so that the product cannot overflow
+const fn check_icon((rgba, size): &(&[u8], uvec2)) -> Result <(), &'static str> {
+    let [width, height] = *size.as_array();
+    if rgba.len() as u128 == 4 * width as u128 * height as u128 {
+        Ok(())
+    } else {
+        Err("must have exactly `4 * width * height` bytes of RGBA")
+    }
+}
+
+/// Converts [`WindowBuilder::icon`], checked by [`check_icon`]
+fn window_icon((rgba, size): (&[u8], uvec2)) -> winit::window::Icon {
+    let [width, height] = *size.as_array();
+    winit::window::Icon::from_rgba(rgba.to_vec(), width, height).expect("window icon is checked")
+}
+
+/// Maximum length of a value in the configuration summary, longer ones are cut
+const CONFIG_VALUE_LEN: usize = 64;
+
+/// Formats a value for the configuration summary, cutting long ones such as [`WindowBuilder::icon`] pixels
+fn config_value(value: &dyn core::fmt::Debug) -> String {
+    let mut result = format!("{value:?}");
+    if let Some((cut, _)) = result.char_indices().nth(CONFIG_VALUE_LEN) {
+        result.truncate(cut);
+        result.push_str("...")
+    }
+    result
+}
+
+impl WindowBuilder {
+    ///
+    /// Creates an empty [`WindowBuilder`].
+    ///
+    pub const fn empty() -> Self {
+        Self(dispatch::initial())
+    }
+}
+
+impl <C> WindowBuilder <C> {
+    ///
+    /// Returns whether any callback of `ID` is registered, e.g. whether
+    /// [`WindowBuilder::on_close`] replaces closing by default, see [`Dispatch`].
+    ///
+    /// ## Example
+    /// ```
+    /// # use rokoko::window::{Window, build::OnClose};
+    /// assert!(!Window::new().has_callback::<OnClose>());
+    /// assert!(Window::new().on_close(|_| ()).has_callback::<OnClose>());
+    /// ```
+    ///
+    pub fn has_callback <ID: Callback> (&self) -> bool where C: Dispatch <ID>, ID::Output: Chain {
+        let Self(data) = self;
+        Dispatch::<ID>::has_callback(data)
+    }
+
+    #[cfg(not(feature = "dyn-callbacks"))]
+    const fn on_event <ID: Callback, F: FnMut <ID::Args, Output = ID::Output>> (self, cb: F) -> WindowBuilder <With <OnEventFnContainer <ID, F>, C>> {
+        WindowBuilder(With {
+            data: FnContainer::new(cb),
+            next: self.to_inner()
+        })
+    }
+
+    ///
+    /// Transforms the [`WindowBuilder`] into `C`.
+    ///
+    const fn to_inner(self) -> C {
+        // SAFETY: safe because [`WindowBuilder`] does contain the only field -> `C`,
+        // so its memory layout is just the same as of `C`, and because [`WindowBuilder`]
+        // does not have a [`Drop`] implemented(of course), it doesn't need to be dropped.
+        unsafe { transmute(self) }
+    }
+}
+
+#[cfg(feature = "dyn-callbacks")]
+impl <C: FindDynCallbacks> WindowBuilder <C> {
+    /// Boxes `cb` into the table of callbacks, see [`dispatch::DynCallbacks`]
+    fn on_dyn_event <ID: Callback + 'static, F: FnMut <ID::Args, Output = ID::Output> + 'static> (mut self, cb: F) -> Self
+        where ID::Args: 'static, ID::Output: 'static {
+        self.0.dyn_callbacks_mut().push::<ID>(Box::new(cb));
+        self
     }
 }
 
@@ -298,5 +1817,7 @@ impl <C> WindowBuilder <C> {
 ///
 #[doc(hidden)]
 pub const unsafe fn transmute <F, T> (from: F) -> T {
-    core::ptr::read(&core::mem::ManuallyDrop::new(from) as *const _ as *const T)
+    // SAFETY: the caller guarantees `T` can be read from the bytes of `F`,
+    // and `from` is never dropped, so it is moved out exactly once
+    unsafe { core::ptr::read(&core::mem::ManuallyDrop::new(from) as *const _ as *const T) }
 }
@@ -1,3 +1,69 @@
+//!
+//! This module provides the type list [`WindowBuilder`](super::WindowBuilder) keeps its configuration in,
+//! see `window` module documentation.
+//!
+//! The list is a public type-level data structure, so crates building on top of `rokoko`
+//! can inspect it in compile-time:
+//! - [`ConfigList::LEN`] - the number of configured options;
+//! - [`ConfigList::visit`] - iterating over types of the options;
+//! - [`super::getters::Contains`], [`super::getters::has_data`] and [`super::getters::has_fn`] -
+//!   whether an option is configured.
+//!
+//! # Examples
+//!
+//! ```
+//! use rokoko::window::{
+//!     Window,
+//!     build::{
+//!         OnClose, Title, Maximized, WindowBuilder,
+//!         type_list::{ConfigList, Visitor, With, Empty},
+//!         fn_container::OnEventFnContainer
+//!     }
+//! };
+//!
+//! type Config = With <OnEventFnContainer <OnClose, fn(Window)>, With <Maximized, With <Title <'static>, Empty>>>;
+//!
+//! const _: () = assert!(Empty::LEN == 0);
+//! const _: () = assert!(Config::LEN == 3);
+//!
+//! fn len <C: ConfigList> (_: &WindowBuilder <C>) -> usize {
+//!     C::LEN
+//! }
+//!
+//! let builder = Window::new()
+//!     .title("four")
+//!     .maximized()
+//!     .on_init(|_| ())
+//!     .on_close(Window::close);
+//!
+//! // Callbacks are in the list too, unless boxed into the table the list
+//! // starts with by `dyn-callbacks` feature, see `dispatch`
+//! if cfg!(feature = "dyn-callbacks") {
+//!     assert_eq!(len(&builder), 3);
+//! } else {
+//!     assert_eq!(len(&builder), 4);
+//! }
+//!
+//! // Specifying an option twice adds it twice
+//! assert_eq!(len(&Window::new().title("a").title("b")) - len(&Window::new()), 2);
+//!
+//! /// Collects names of the types, the most recently configured first
+//! struct Names(Vec <&'static str>);
+//!
+//! impl Visitor for Names {
+//!     fn visit <T> (&mut self) {
+//!         self.0.push(core::any::type_name::<T>())
+//!     }
+//! }
+//!
+//! let mut names = Names(Vec::new());
+//! Config::visit(&mut names);
+//! assert_eq!(names.0.len(), 3);
+//! assert!(names.0[1].ends_with("::Maximized"));
+//! assert!(names.0[2].contains("::Title"));
+//! ```
+//!
+
 /// Terminator
 pub struct Empty;
 
@@ -6,3 +72,43 @@ pub struct With <T, N> {
     pub data: T,
     pub next: N
 }
+
+///
+/// Called by [`ConfigList::visit`] with every type of a list.
+///
+/// The dispatch is static: `visit` is monomorphized for every type.
+///
+pub trait Visitor {
+    /// Visits a type of the list
+    fn visit <T> (&mut self);
+}
+
+///
+/// A type list, i.e. [`Empty`] or [`With`].
+///
+/// See [module documentation](self) for examples.
+///
+pub trait ConfigList {
+    /// The number of types in the list
+    const LEN: usize;
+
+    /// Calls `visitor` with every type of the list, from the head to the tail
+    fn visit <V: Visitor> (visitor: &mut V);
+}
+
+impl ConfigList for Empty {
+    const LEN: usize = 0;
+
+    #[inline(always)]
+    fn visit <V: Visitor> (_: &mut V) {}
+}
+
+impl <T, N: ConfigList> ConfigList for With <T, N> {
+    const LEN: usize = N::LEN + 1;
+
+    #[inline(always)]
+    fn visit <V: Visitor> (visitor: &mut V) {
+        visitor.visit::<T>();
+        N::visit(visitor)
+    }
+}
@@ -0,0 +1,172 @@
+//!
+//! This module provides [`UserData`] - values of any type attached to a window
+//! with [`WindowBuilder::data`](super::WindowBuilder::data) and shared between its callbacks
+//! through [`Window::user_data`](super::Window::user_data).
+//!
+//! The values are kept in the type list until `create`, so whether a type is attached
+//! is known in compile-time, see [`super::getters::has_data`]. Then they are moved
+//! into the window once, and looked up by type, without allocating anything per call.
+//!
+//! Unlike `title` and other data, they are not looked up through [`GetData`](super::getters::GetData) afterwards:
+//! callbacks get a [`Window`](super::super::Window), which does not know the type list of its builder,
+//! so [`Window::user_data`](super::super::Window::user_data) finds the value by its `TypeId`
+//! and returns `None` for a type that is not attached.
+//!
+//! # Examples
+//!
+//! ```
+//! use rokoko::window::{
+//!     Window,
+//!     build::{getters::has_data, user_data::UserData, WindowBuilder}
+//! };
+//!
+//! struct Scene {
+//!     objects: Vec <&'static str>
+//! }
+//!
+//! fn has_scene <C: rokoko::window::build::getters::HasData <UserData <Scene>>> (_: &WindowBuilder <C>) -> bool {
+//!     has_data::<UserData <Scene>, C>()
+//! }
+//!
+//! let builder = Window::new().data(Scene { objects: vec!["cube"] });
+//! assert!(has_scene(&builder));
+//! assert!(!has_scene(&Window::new().data(0u32)));
+//! ```
+//!
+
+use super::{With, Empty};
+use core::{
+    any::{Any, TypeId},
+    cell::{RefCell, RefMut}
+};
+
+///
+/// A value attached with [`WindowBuilder::data`](super::WindowBuilder::data).
+///
+/// Empty once moved into the window by `create`.
+///
+pub struct UserData <T> (Option <T>);
+
+impl <T> UserData <T> {
+    pub const fn new(value: T) -> Self {
+        Self(Some(value))
+    }
+}
+
+/// Asserts that a type is not an [`UserData`]
+pub auto trait NotUserData {}
+
+impl <T> !NotUserData for UserData <T> {}
+
+///
+/// The values attached to a window, moved out of the type list by `create`.
+///
+/// Types attached several times are found by the very last value, as with any other data.
+///
+/// # Examples
+///
+/// ```
+/// use rokoko::window::build::user_data::UserDataStore;
+///
+/// let mut store = UserDataStore::default();
+/// store.push(1u32);
+/// store.push("first");
+/// store.push(2u32);
+///
+/// *store.get::<u32>().unwrap() += 10;
+/// assert_eq!(*store.get::<u32>().unwrap(), 12);
+/// assert_eq!(*store.get::<&str>().unwrap(), "first");
+/// assert!(store.get::<u64>().is_none());
+/// ```
+///
+#[derive(Default)]
+pub struct UserDataStore {
+    /// Later values go first
+    values: Vec <(TypeId, RefCell <Box <dyn Any>>)>
+}
+
+impl UserDataStore {
+    /// Attaches `value`, hiding any previous value of the same type
+    pub fn push <T: 'static> (&mut self, value: T) {
+        self.values.insert(0, (TypeId::of::<T>(), RefCell::new(Box::new(value))))
+    }
+
+    ///
+    /// Reserves the place for a value of type `T` to be attached later through a shared reference,
+    /// see [`UserDataStore::fill`], hiding any previous value of the same type.
+    ///
+    /// Used for the value loaded by [`WindowBuilder::loading_phase`](super::WindowBuilder::loading_phase).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rokoko::window::build::user_data::UserDataStore;
+    ///
+    /// let mut store = UserDataStore::default();
+    /// store.push(1u32);
+    /// store.reserve::<u32>();
+    /// assert!(store.get::<u32>().is_none());
+    ///
+    /// store.fill(2u32);
+    /// assert_eq!(*store.get::<u32>().unwrap(), 2);
+    /// ```
+    ///
+    pub fn reserve <T: 'static> (&mut self) {
+        self.values.insert(0, (TypeId::of::<T>(), RefCell::new(Box::new(Reserved))))
+    }
+
+    ///
+    /// Attaches `value` in the place reserved by [`UserDataStore::reserve`].
+    ///
+    /// Panics if there is no such place or it is borrowed at the moment.
+    ///
+    pub fn fill <T: 'static> (&self, value: T) {
+        let (_, place) = self.values.iter().find(|(id, _)| *id == TypeId::of::<T>()).expect("the place is reserved");
+        *place.borrow_mut() = Box::new(value)
+    }
+
+    ///
+    /// Returns the value of type `T`, if attached.
+    ///
+    /// `None` for a place that is reserved, but not filled yet,
+    /// and for a value that is borrowed at the moment.
+    ///
+    pub fn get <T: 'static> (&self) -> Option <RefMut <'_, T>> {
+        let (_, value) = self.values.iter().find(|(id, _)| *id == TypeId::of::<T>())?;
+        RefMut::filter_map(value.try_borrow_mut().ok()?, |value| value.downcast_mut::<T>()).ok()
+    }
+}
+
+/// Takes the place of a value reserved by [`UserDataStore::reserve`] until it is filled
+struct Reserved;
+
+///
+/// Moves every [`UserData`] of a type list into a [`UserDataStore`].
+///
+pub trait CollectUserData {
+    /// Moves the values, leaving the list with empty [`UserData`]s
+    fn collect(&mut self, store: &mut UserDataStore);
+}
+
+impl CollectUserData for Empty {
+    #[inline(always)]
+    fn collect(&mut self, _: &mut UserDataStore) {}
+}
+
+impl <T: NotUserData, N: CollectUserData> CollectUserData for With <T, N> {
+    #[inline(always)]
+    fn collect(&mut self, store: &mut UserDataStore) {
+        self.next.collect(store)
+    }
+}
+
+impl <T: 'static, N: CollectUserData> CollectUserData for With <UserData <T>, N> {
+    #[inline(always)]
+    fn collect(&mut self, store: &mut UserDataStore) {
+        // Earlier values are deeper in the list, so are pushed first
+        self.next.collect(store);
+        if let Some(value) = self.data.0.take() {
+            store.push(value)
+        }
+    }
+}
@@ -0,0 +1,481 @@
+//!
+//! This module provides [`WindowConfigFile`] - window settings in a user-editable file,
+//! so that they survive across runs.
+//!
+//! # Format
+//!
+//! A subset of TOML: one `key = value` per line, `#` starts a comment,
+//! values are double-quoted strings(with `\"`, `\\`, `\n` and `\t` escapes),
+//! `true`/`false`, integers and pairs of integers `[x, y]`.
+//!
+//! ```toml
+//! version = 1
+//! title = "Editor"
+//! # Physical pixels
+//! size = [1280, 720]
+//! position = [100, 50]
+//! maximized = false
+//! fullscreen = false
+//! vsync = true
+//! ```
+//!
+//! Every key is optional. Keys that are not known are kept as they are and written back
+//! on save, so a file shared with newer versions(or other tools) loses nothing;
+//! comments are not kept though.
+//!
+//! # Versions
+//!
+//! `version` is the version of the format, [`VERSION`] when saved, `1` if missing.
+//! Files of older versions are migrated on load by the functions in `MIGRATIONS`,
+//! one version at a time, while files of newer versions are rejected,
+//! so that saving does not clobber settings this version does not understand.
+//!
+//! # Precedence
+//!
+//! [`WindowConfigFile::apply_to`] is meant for [`WindowBuilder::winit_overrides`],
+//! which is applied last, so the settings in the file win over the ones
+//! given to the builder in code, e.g. `.title(...)`: code gives defaults, the file overrides them.
+//!
+//! # Examples
+//!
+//! Requires `winit-interop` feature for [`WindowBuilder::winit_overrides`].
+//!
+#![cfg_attr(feature = "winit-interop", doc = "```no_run")]
+#![cfg_attr(not(feature = "winit-interop"), doc = "```ignore")]
+//! use rokoko::prelude::*;
+//! use rokoko::window::config_file::WindowConfigFile;
+//!
+//! const PATH: &str = "window.toml";
+//!
+//! // The very first run has no file yet
+//! let config = WindowConfigFile::load(PATH).unwrap_or_default();
+//!
+//! Window::new()
+//!     .title("Editor")
+//!     .winit_overrides(move |b| config.apply_to(b))
+//!     .on_close(|w| {
+//!         // Keeps the title and unknown keys of the file
+//!         let mut config = WindowConfigFile::load(PATH).unwrap_or_default();
+//!         config.update_from(&w);
+//!         if let Err(e) = config.save(PATH) {
+//!             eprintln!("cannot save window settings: {e}")
+//!         }
+//!         w.close()
+//!     })
+//!     .create()
+//!     .unwrap()
+//! ```
+//!
+//! [`WindowBuilder::winit_overrides`]: super::build::WindowBuilder::winit_overrides
+//!
+
+use core::{
+    fmt::{self, Display, Formatter, Write},
+    str::FromStr
+};
+use std::{io, fs, path::Path};
+use winit::{
+    dpi::{PhysicalSize, PhysicalPosition},
+    window::Fullscreen
+};
+use crate::math::vec::{uvec2, ivec2};
+use super::Window;
+
+/// Version of the format written by [`WindowConfigFile::save`]
+pub const VERSION: u32 = 1;
+
+///
+/// Migrations of raw entries, `MIGRATIONS[i]` turning version `i + 1` into `i + 2`,
+/// e.g. renaming keys or converting values.
+///
+/// Bumping [`VERSION`] requires adding one.
+///
+const MIGRATIONS: [fn(&mut Vec <Entry>); VERSION as usize - 1] = [];
+
+/// A `key = value` line: its number, the key and the value, unparsed
+type Entry = (usize, String, String);
+
+///
+/// Errors of loading a [`WindowConfigFile`].
+///
+/// Lines are numbered from 1.
+///
+#[derive(Debug)]
+pub enum Error {
+    /// The file cannot be read or written
+    Io(io::Error),
+
+    /// The line is neither `key = value`, nor empty, nor a comment
+    Syntax { line: usize },
+
+    /// The key has already been specified in an earlier line
+    DuplicateKey { line: usize, key: String },
+
+    /// The value is malformed, or of a wrong type for the key
+    InvalidValue { line: usize, key: String },
+
+    /// The file is of a newer version than [`VERSION`]
+    UnsupportedVersion(u32)
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter <'_>) -> fmt::Result {
+        match self {
+            Error::Io(e) => write!(f, "cannot access the window config file: {e}"),
+            Error::Syntax { line } => write!(f, "line {line}: expected `key = value`"),
+            Error::DuplicateKey { line, key } => write!(f, "line {line}: `{key}` is already specified"),
+            Error::InvalidValue { line, key } => write!(f, "line {line}: invalid value of `{key}`"),
+            Error::UnsupportedVersion(v) => write!(f, "version {v} is newer than the supported {VERSION}")
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option <&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io(e) => Some(e),
+            _ => None
+        }
+    }
+}
+
+impl From <io::Error> for Error {
+    fn from(e: io::Error) -> Self {
+        Error::Io(e)
+    }
+}
+
+///
+/// Window settings of a config file, see [module documentation](self).
+///
+/// `None` means the setting is not in the file and is left as the builder has it.
+///
+/// # Examples
+///
+/// ```
+/// use rokoko::prelude::*;
+/// use rokoko::window::config_file::{WindowConfigFile, Error};
+///
+/// let config: WindowConfigFile = r#"
+///     ## Written by hand
+///     size = [800, 600]   # physical pixels
+///     title = "Quotes \" and # inside"
+///     maximized = true
+///     theme = "dark"
+///     plugins.sidebar = [1, 2]
+/// "#.parse().unwrap();
+///
+/// assert_eq!(config.size, Some(uvec2::from([800, 600])));
+/// assert_eq!(config.title.as_deref(), Some("Quotes \" and # inside"));
+/// assert_eq!(config.maximized, Some(true));
+/// assert_eq!(config.position, None);
+///
+/// // Unknown keys are kept, in the order of the file
+/// assert_eq!(config.unknown(), [("theme", "\"dark\""), ("plugins.sidebar", "[1, 2]")]);
+/// assert_eq!(config.to_string(), "\
+/// version = 1
+/// title = \"Quotes \\\" and # inside\"
+/// size = [800, 600]
+/// maximized = true
+/// theme = \"dark\"
+/// plugins.sidebar = [1, 2]
+/// ");
+///
+/// // Saved and loaded back, nothing is lost
+/// assert_eq!(config.to_string().parse::<WindowConfigFile>().unwrap(), config);
+///
+/// // Errors point at the line
+/// let err = |s: &str| s.parse::<WindowConfigFile>().unwrap_err().to_string();
+/// assert_eq!(err("size = [1, 2]\n\nsize = [3, 4]"), "line 3: `size` is already specified");
+/// assert_eq!(err("theme = 1\ntheme = 2"), "line 2: `theme` is already specified");
+/// assert_eq!(err("maximized"), "line 1: expected `key = value`");
+/// assert_eq!(err("= 1"), "line 1: expected `key = value`");
+/// assert_eq!(err("[window]"), "line 1: expected `key = value`");
+/// assert_eq!(err("size = [1, -2]"), "line 1: invalid value of `size`");
+/// assert_eq!(err("size = [1, 2, 3]"), "line 1: invalid value of `size`");
+/// assert_eq!(err("title = \"unterminated"), "line 1: invalid value of `title`");
+/// assert_eq!(err("title = \"a\" \"b\""), "line 1: invalid value of `title`");
+/// assert_eq!(err("vsync = yes"), "line 1: invalid value of `vsync`");
+/// assert_eq!(err("theme ="), "line 1: invalid value of `theme`");
+///
+/// // Versions
+/// assert_eq!("version = 1".parse::<WindowConfigFile>().unwrap(), WindowConfigFile::default());
+/// assert_eq!(err("version = 0"), "line 1: invalid value of `version`");
+/// assert!(matches!("version = 2".parse::<WindowConfigFile>(), Err(Error::UnsupportedVersion(2))));
+/// ```
+///
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct WindowConfigFile {
+    /// Title of the window
+    pub title: Option <String>,
+
+    /// Inner size of the window, in physical pixels
+    pub size: Option <uvec2>,
+
+    /// Position of the top-left corner of the window on the desktop, in physical pixels
+    pub position: Option <ivec2>,
+
+    /// Whether the window is maximized
+    pub maximized: Option <bool>,
+
+    /// Whether the window is borderless fullscreen on its current monitor
+    pub fullscreen: Option <bool>,
+
+    ///
+    /// Whether presenting waits for the vertical blank.
+    ///
+    /// Only stored for the renderer to read, `winit` has no such setting, so [`WindowConfigFile::apply_to`] ignores it.
+    ///
+    pub vsync: Option <bool>,
+
+    /// Entries with unknown keys, the key and the value as written
+    unknown: Vec <(String, String)>
+}
+
+impl WindowConfigFile {
+    ///
+    /// Reads and parses the file at `path`.
+    ///
+    /// A missing file is an [`Error::Io`] of [`io::ErrorKind::NotFound`],
+    /// e.g. on the first run, `unwrap_or_default` is fine then.
+    ///
+    pub fn load(path: impl AsRef <Path>) -> Result <Self, Error> {
+        fs::read_to_string(path)?.parse()
+    }
+
+    /// Writes the settings to the file at `path`, replacing it
+    pub fn save(&self, path: impl AsRef <Path>) -> Result <(), Error> {
+        Ok(fs::write(path, self.to_string())?)
+    }
+
+    ///
+    /// Returns the current settings of `window`.
+    ///
+    /// `winit` cannot tell the title nor vsync, so those are `None`;
+    /// see [`WindowConfigFile::update_from`] to keep the ones of a loaded file.
+    ///
+    pub fn capture_from(window: &Window) -> Self {
+        let mut config = Self::default();
+        config.update_from(window);
+        config
+    }
+
+    /// Replaces the settings `window` can tell with its current ones, keeping the rest
+    pub fn update_from(&mut self, window: &Window) {
+        let winit = window.data().winit.get();
+        let size = winit.inner_size();
+        self.size = Some(uvec2::from([size.width, size.height]));
+        // Not supported e.g. on Wayland
+        if let Ok(pos) = winit.outer_position() {
+            self.position = Some(ivec2::from([pos.x, pos.y]))
+        }
+        self.maximized = Some(winit.is_maximized());
+        self.fullscreen = Some(winit.fullscreen().is_some())
+    }
+
+    ///
+    /// Applies the settings to `builder`, meant for [`WindowBuilder::winit_overrides`](super::build::WindowBuilder::winit_overrides).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # extern crate winit;
+    /// use rokoko::window::config_file::WindowConfigFile;
+    /// use winit::{window::WindowBuilder, dpi::{Size, PhysicalSize}};
+    ///
+    /// let config: WindowConfigFile = "title = \"From file\"\nsize = [300, 200]\nvsync = false".parse().unwrap();
+    ///
+    /// let builder = config.apply_to(WindowBuilder::new().with_title("From code").with_resizable(false));
+    /// assert_eq!(builder.window.title, "From file");
+    /// assert_eq!(builder.window.inner_size, Some(Size::Physical(PhysicalSize::new(300, 200))));
+    /// // Not in the file
+    /// assert!(!builder.window.resizable);
+    /// assert!(builder.window.position.is_none());
+    /// ```
+    ///
+    pub fn apply_to(&self, mut builder: winit::window::WindowBuilder) -> winit::window::WindowBuilder {
+        if let Some(title) = &self.title {
+            builder = builder.with_title(title.as_str())
+        }
+        if let Some(size) = self.size {
+            builder = builder.with_inner_size(PhysicalSize::new(size[0], size[1]))
+        }
+        if let Some(pos) = self.position {
+            builder = builder.with_position(PhysicalPosition::new(pos[0], pos[1]))
+        }
+        if let Some(maximized) = self.maximized {
+            builder = builder.with_maximized(maximized)
+        }
+        if let Some(fullscreen) = self.fullscreen {
+            builder = builder.with_fullscreen(fullscreen.then(|| Fullscreen::Borderless(None)))
+        }
+        builder
+    }
+
+    /// Returns the entries with unknown keys, the key and the value as written
+    pub fn unknown(&self) -> Vec <(&str, &str)> {
+        self.unknown.iter().map(|(k, v)| (k.as_str(), v.as_str())).collect()
+    }
+}
+
+/// Returns `line` without the comment, `#` inside strings does not start one
+fn strip_comment(line: &str) -> &str {
+    let (mut quoted, mut escaped) = (false, false);
+    for (i, c) in line.char_indices() {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' if quoted => escaped = true,
+            '"' => quoted = !quoted,
+            '#' if !quoted => return &line[..i],
+            _ => ()
+        }
+    }
+    line
+}
+
+/// Parses a double-quoted string with escapes
+fn parse_string(s: &str) -> Option <String> {
+    let inner = s.strip_prefix('"')?.strip_suffix('"')?;
+    let mut result = String::with_capacity(inner.len());
+    let mut chars = inner.chars();
+    while let Some(c) = chars.next() {
+        result.push(match c {
+            '\\' => match chars.next()? {
+                '"' => '"',
+                '\\' => '\\',
+                'n' => '\n',
+                't' => '\t',
+                _ => return None
+            },
+            // Only escaped within the string
+            '"' => return None,
+            c => c
+        })
+    }
+    Some(result)
+}
+
+/// Writes `s` double-quoted, escaping what [`parse_string`] unescapes
+fn write_string(f: &mut Formatter <'_>, s: &str) -> fmt::Result {
+    f.write_char('"')?;
+    for c in s.chars() {
+        match c {
+            '"' => f.write_str("\\\"")?,
+            '\\' => f.write_str("\\\\")?,
+            '\n' => f.write_str("\\n")?,
+            '\t' => f.write_str("\\t")?,
+            c => f.write_char(c)?
+        }
+    }
+    f.write_char('"')
+}
+
+/// Parses `true` or `false`
+fn parse_bool(s: &str) -> Option <bool> {
+    match s {
+        "true" => Some(true),
+        "false" => Some(false),
+        _ => None
+    }
+}
+
+/// Parses a pair of integers, `[x, y]`
+fn parse_pair <T: FromStr> (s: &str) -> Option <[T; 2]> {
+    let (x, y) = s.strip_prefix('[')?.strip_suffix(']')?.split_once(',')?;
+    Some([x.trim().parse().ok()?, y.trim().parse().ok()?])
+}
+
+/// Returns `true` if `key` is a bare key: letters, digits, `_`, `-` and `.`
+fn is_key(key: &str) -> bool {
+    !key.is_empty() && key.chars().all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | '.'))
+}
+
+impl FromStr for WindowConfigFile {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result <Self, Error> {
+        let mut entries = Vec::<Entry>::new();
+        for (i, line) in s.lines().enumerate() {
+            let line_no = i + 1;
+            let line = strip_comment(line).trim();
+            if line.is_empty() {
+                continue
+            }
+            let (key, value) = match line.split_once('=') {
+                Some((key, value)) if is_key(key.trim()) => (key.trim(), value.trim()),
+                _ => return Err(Error::Syntax { line: line_no })
+            };
+            if entries.iter().any(|(_, k, _)| k == key) {
+                return Err(Error::DuplicateKey { line: line_no, key: key.to_string() })
+            }
+            if value.is_empty() {
+                return Err(Error::InvalidValue { line: line_no, key: key.to_string() })
+            }
+            entries.push((line_no, key.to_string(), value.to_string()))
+        }
+
+        let version = match entries.iter().position(|(_, k, _)| k == "version") {
+            Some(idx) => {
+                let (line, key, value) = entries.remove(idx);
+                match value.parse::<u32>() {
+                    Ok(0) | Err(_) => return Err(Error::InvalidValue { line, key }),
+                    Ok(v) => v
+                }
+            },
+            None => 1
+        };
+        if version > VERSION {
+            return Err(Error::UnsupportedVersion(version))
+        }
+        for migrate in &MIGRATIONS[version as usize - 1..] {
+            migrate(&mut entries)
+        }
+
+        let mut config = Self::default();
+        for (line, key, value) in entries {
+            let parsed = match key.as_str() {
+                "title" => parse_string(&value).map(|v| config.title = Some(v)),
+                "size" => parse_pair(&value).map(|v| config.size = Some(uvec2::from(v))),
+                "position" => parse_pair(&value).map(|v| config.position = Some(ivec2::from(v))),
+                "maximized" => parse_bool(&value).map(|v| config.maximized = Some(v)),
+                "fullscreen" => parse_bool(&value).map(|v| config.fullscreen = Some(v)),
+                "vsync" => parse_bool(&value).map(|v| config.vsync = Some(v)),
+                _ => {
+                    config.unknown.push((key, value));
+                    continue
+                }
+            };
+            if parsed.is_none() {
+                return Err(Error::InvalidValue { line, key })
+            }
+        }
+        Ok(config)
+    }
+}
+
+/// Writes the file, see [module documentation](self) for the format
+impl Display for WindowConfigFile {
+    fn fmt(&self, f: &mut Formatter <'_>) -> fmt::Result {
+        writeln!(f, "version = {VERSION}")?;
+        if let Some(title) = &self.title {
+            f.write_str("title = ")?;
+            write_string(f, title)?;
+            f.write_char('\n')?
+        }
+        if let Some(size) = self.size {
+            writeln!(f, "size = [{}, {}]", size[0], size[1])?
+        }
+        if let Some(pos) = self.position {
+            writeln!(f, "position = [{}, {}]", pos[0], pos[1])?
+        }
+        for (key, value) in [("maximized", self.maximized), ("fullscreen", self.fullscreen), ("vsync", self.vsync)] {
+            if let Some(value) = value {
+                writeln!(f, "{key} = {value}")?
+            }
+        }
+        for (key, value) in &self.unknown {
+            writeln!(f, "{key} = {value}")?
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,233 @@
+//!
+//! This module provides [`Damage`] - the accumulator of regions of a frame
+//! changed since it was last presented, so that only those have to be presented again.
+//!
+//! Rectangles are in physical pixels and half-open: `min` is the first pixel
+//! of the region, `max` is one past the last one, so a rectangle with
+//! `min == max` in any dimension is empty.
+//!
+//! Overlapping and adjacent rectangles are merged into their bounding box,
+//! which may cover a few unchanged pixels, but keeps the list short.
+//! Once there are more rectangles than the cap, the damage degrades to the full frame,
+//! since presenting many small regions costs more than presenting it all.
+//!
+//! # Examples
+//!
+//! ```
+//! use rokoko::prelude::*;
+//! use rokoko::window::{damage::Damage, drag_select::Rect};
+//!
+//! let rect = |min: [u32; 2], max: [u32; 2]| Rect { min: uvec2::from(min), max: uvec2::from(max) };
+//!
+//! // A new frame has to be presented fully
+//! let mut damage = Damage::new(uvec2::from([640, 480]));
+//! assert!(damage.is_full());
+//! damage.clear();
+//! assert!(damage.is_empty());
+//!
+//! // A blinking cursor and a clock in the corner
+//! damage.mark(rect([10, 10], [12, 30]));
+//! damage.mark(rect([600, 0], [640, 16]));
+//! assert_eq!(damage.rects(), [rect([10, 10], [12, 30]), rect([600, 0], [640, 16])]);
+//! assert_eq!(damage.area(), 2 * 20 + 40 * 16);
+//! ```
+//!
+
+use crate::math::vec::uvec2;
+use super::drag_select::Rect;
+
+/// Cap used by [`Damage::new`]
+pub const DEFAULT_CAP: usize = 16;
+
+/// Returns `true` if `a` and `b` overlap or share an edge or a corner
+fn touches(a: &Rect <u32>, b: &Rect <u32>) -> bool {
+    a.min[0] <= b.max[0] && b.min[0] <= a.max[0] && a.min[1] <= b.max[1] && b.min[1] <= a.max[1]
+}
+
+/// Returns the bounding box of `a` and `b`
+fn union(a: &Rect <u32>, b: &Rect <u32>) -> Rect <u32> {
+    Rect {
+        min: uvec2::from([a.min[0].min(b.min[0]), a.min[1].min(b.min[1])]),
+        max: uvec2::from([a.max[0].max(b.max[0]), a.max[1].max(b.max[1])])
+    }
+}
+
+/// Returns the number of pixels in `rect`
+fn area(rect: &Rect <u32>) -> u64 {
+    (rect.max[0] - rect.min[0]) as u64 * (rect.max[1] - rect.min[1]) as u64
+}
+
+///
+/// Regions of a frame changed since it was last presented, see [module documentation](self).
+///
+/// # Examples
+///
+/// ```
+/// use rokoko::prelude::*;
+/// use rokoko::window::{damage::Damage, drag_select::Rect};
+///
+/// let rect = |min: [u32; 2], max: [u32; 2]| Rect { min: uvec2::from(min), max: uvec2::from(max) };
+///
+/// let mut damage = Damage::with_cap(uvec2::from([100, 100]), 3);
+/// damage.clear();
+///
+/// // Overlapping: merged
+/// damage.mark(rect([10, 10], [20, 20]));
+/// damage.mark(rect([15, 15], [30, 25]));
+/// assert_eq!(damage.rects(), [rect([10, 10], [30, 25])]);
+///
+/// // Contained: nothing changes
+/// damage.mark(rect([12, 12], [14, 14]));
+/// assert_eq!(damage.rects(), [rect([10, 10], [30, 25])]);
+///
+/// // Adjacent, e.g. pixels of a line: merged
+/// damage.mark(rect([30, 10], [31, 11]));
+/// damage.mark(rect([31, 10], [32, 11]));
+/// assert_eq!(damage.rects(), [rect([10, 10], [32, 25])]);
+///
+/// // Disjoint: kept apart
+/// damage.mark(rect([50, 50], [60, 60]));
+/// damage.mark(rect([0, 90], [5, 95]));
+/// assert_eq!(damage.rects().len(), 3);
+///
+/// // A rect bridging two others merges all three
+/// damage.mark(rect([30, 20], [50, 50]));
+/// assert_eq!(damage.rects(), [rect([0, 90], [5, 95]), rect([10, 10], [60, 60])]);
+///
+/// // Clipped to the frame, empty ones ignored
+/// damage.mark(rect([95, 0], [200, 3]));
+/// damage.mark(rect([70, 70], [70, 80]));
+/// assert_eq!(damage.rects().last(), Some(&rect([95, 0], [100, 3])));
+/// assert_eq!(damage.rects().len(), 3);
+///
+/// // Over the cap: the full frame
+/// damage.mark(rect([80, 80], [81, 81]));
+/// assert!(damage.is_full());
+/// assert_eq!(damage.rects(), [rect([0, 0], [100, 100])]);
+/// assert_eq!(damage.bounds(), Some(rect([0, 0], [100, 100])));
+///
+/// // Stays so until cleared
+/// damage.mark(rect([1, 1], [2, 2]));
+/// assert!(damage.is_full());
+/// damage.clear();
+/// assert_eq!(damage.rects(), []);
+/// assert_eq!(damage.bounds(), None);
+///
+/// // A resized frame is new and has to be presented fully
+/// damage.resize(uvec2::from([200, 50]));
+/// assert_eq!(damage.rects(), [rect([0, 0], [200, 50])]);
+/// ```
+///
+#[derive(Debug, Clone, PartialEq)]
+pub struct Damage {
+    /// Size of the frame
+    size: uvec2,
+
+    /// Maximal number of rectangles before degrading to the full frame
+    cap: usize,
+
+    /// Pairwise disjoint and not adjacent, in the order of marking
+    rects: Vec <Rect <u32>>,
+
+    /// `rects` is the full frame and the marks are ignored
+    full: bool
+}
+
+impl Damage {
+    /// Creates the damage of a new frame of `size`, i.e. full, with [`DEFAULT_CAP`]
+    pub fn new(size: uvec2) -> Self {
+        Self::with_cap(size, DEFAULT_CAP)
+    }
+
+    ///
+    /// Creates the damage of a new frame of `size`, i.e. full,
+    /// degrading to the full frame once there are more than `cap` rectangles.
+    ///
+    pub fn with_cap(size: uvec2, cap: usize) -> Self {
+        let mut damage = Self {
+            size,
+            cap,
+            rects: Vec::new(),
+            full: false
+        };
+        damage.mark_all();
+        damage
+    }
+
+    /// Returns the size of the frame
+    pub fn size(&self) -> uvec2 {
+        self.size
+    }
+
+    /// Changes the size of the frame, marking all of it
+    pub fn resize(&mut self, size: uvec2) {
+        self.size = size;
+        self.mark_all()
+    }
+
+    /// Marks `rect` as changed, the part of it outside the frame is ignored
+    pub fn mark(&mut self, rect: Rect <u32>) {
+        if self.full {
+            return
+        }
+
+        let mut rect = Rect {
+            min: uvec2::from([rect.min[0].min(self.size[0]), rect.min[1].min(self.size[1])]),
+            max: uvec2::from([rect.max[0].min(self.size[0]), rect.max[1].min(self.size[1])])
+        };
+        if rect.min[0] >= rect.max[0] || rect.min[1] >= rect.max[1] {
+            return
+        }
+
+        // A merged rect may touch the ones it did not before, so until nothing is merged
+        while let Some(idx) = self.rects.iter().position(|r| touches(r, &rect)) {
+            rect = union(&self.rects.remove(idx), &rect)
+        }
+        self.rects.push(rect);
+
+        if self.rects.len() > self.cap {
+            self.mark_all()
+        }
+    }
+
+    /// Marks the full frame as changed
+    pub fn mark_all(&mut self) {
+        self.rects.clear();
+        self.full = self.size[0] != 0 && self.size[1] != 0;
+        if self.full {
+            self.rects.push(Rect { min: uvec2::from([0, 0]), max: self.size })
+        }
+    }
+
+    /// Forgets all the marks, e.g. once the frame is presented
+    pub fn clear(&mut self) {
+        self.rects.clear();
+        self.full = false
+    }
+
+    /// Returns `true` if nothing is changed
+    pub fn is_empty(&self) -> bool {
+        self.rects.is_empty()
+    }
+
+    /// Returns `true` if the full frame is changed
+    pub fn is_full(&self) -> bool {
+        self.full
+    }
+
+    /// Returns the changed regions, disjoint, the full frame if [`Damage::is_full`]
+    pub fn rects(&self) -> &[Rect <u32>] {
+        &self.rects
+    }
+
+    /// Returns the bounding box of the changed regions, `None` if nothing is changed
+    pub fn bounds(&self) -> Option <Rect <u32>> {
+        let (first, rest) = self.rects.split_first()?;
+        Some(rest.iter().fold(*first, |acc, r| union(&acc, r)))
+    }
+
+    /// Returns the number of changed pixels, counting those merged in by bounding boxes
+    pub fn area(&self) -> u64 {
+        self.rects.iter().map(area).sum()
+    }
+}
@@ -1,13 +1,31 @@
 use winit::{
     event_loop::EventLoopProxy,
+    event::WindowEvent,
     window::Window as Winit
 };
-use core::num::NonZeroUsize;
+use core::{
+    num::NonZeroUsize,
+    cell::{Cell, RefCell}
+};
+use super::{
+    event_counters::EventCounters,
+    input_map::ActionMap,
+    fullscreen::FullscreenManager,
+    shutdown::CloseSchedule,
+    build::user_data::UserDataStore
+};
 
 #[derive(Debug, Copy, Clone)]
 #[repr(u8)]
 pub enum UserEvent {
-    Close
+    Close,
+
+    /// Sent by [`super::Window::close_after`]
+    CloseScheduled,
+
+    /// Sent by [`super::global_hotkeys::Forwarder`], with the id of the hotkey pressed
+    #[cfg(feature = "global-hotkeys")]
+    GlobalHotkey(u32)
 }
 
 /// This dirty and highly unsafe structure is needed
@@ -27,7 +45,116 @@ impl WinitRef {
     }
 }
 
+///
+/// State of the window tracked by the event loop itself.
+///
+/// It is updated before callbacks of an event are called,
+/// so they already see the new state.
+///
+/// # Examples
+///
+/// ```
+/// use rokoko::window::data::Tracked;
+/// # extern crate winit;
+/// use winit::{event::{WindowEvent, DeviceId, ModifiersState}, dpi::{PhysicalPosition, PhysicalSize}};
+///
+/// // SAFETY: only used for comparison, never passed to `winit`
+/// let device_id = unsafe { DeviceId::dummy() };
+/// #[allow(deprecated)]
+/// let moved = WindowEvent::CursorMoved {
+///     device_id,
+///     position: PhysicalPosition::new(1.0, 2.0),
+///     modifiers: ModifiersState::empty()
+/// };
+///
+/// let tracked = Tracked::default();
+/// assert!(!tracked.cursor_inside.get());
+///
+/// tracked.update(&WindowEvent::CursorEntered { device_id });
+/// assert!(tracked.cursor_inside.get());
+///
+/// tracked.update(&WindowEvent::CursorLeft { device_id });
+/// assert!(!tracked.cursor_inside.get());
+///
+/// // Some platforms deliver `CursorMoved` before `CursorEntered`
+/// tracked.update(&moved);
+/// assert!(tracked.cursor_inside.get());
+/// tracked.update(&WindowEvent::CursorEntered { device_id });
+/// assert!(tracked.cursor_inside.get());
+///
+/// // A new window is assumed to be shown and focused
+/// assert!(tracked.visible.get() && tracked.focused.get());
+/// tracked.update(&WindowEvent::Resized(PhysicalSize::new(0, 0)));
+/// assert!(!tracked.visible.get());
+/// tracked.update(&WindowEvent::Focused(false));
+/// assert!(!tracked.focused.get());
+/// ```
+///
+#[derive(Debug)]
+pub struct Tracked {
+    /// Whether the cursor is over the window, see [`super::Window::cursor_inside`]
+    pub cursor_inside: Cell <bool>,
+
+    /// Whether the window is not minimized, see [`super::power`]
+    pub visible: Cell <bool>,
+
+    /// Whether the window has the keyboard focus, see [`super::power`]
+    pub focused: Cell <bool>
+}
+
+impl Default for Tracked {
+    fn default() -> Self {
+        Self {
+            cursor_inside: Cell::new(false),
+            visible: Cell::new(true),
+            focused: Cell::new(true)
+        }
+    }
+}
+
+impl Tracked {
+    /// Updates the state according to `event`
+    pub fn update(&self, event: &WindowEvent <'_>) {
+        match event {
+            // A position within the window implies the cursor is inside,
+            // even if `CursorEntered` has not been delivered yet
+            WindowEvent::CursorEntered { .. } | WindowEvent::CursorMoved { .. } => self.cursor_inside.set(true),
+            WindowEvent::CursorLeft { .. } => self.cursor_inside.set(false),
+            // Minimizing is reported as resizing to zero
+            WindowEvent::Resized(size) => self.visible.set(size.width != 0 && size.height != 0),
+            WindowEvent::Focused(focused) => self.focused.set(*focused),
+            _ => ()
+        }
+    }
+}
+
 pub struct WindowData {
     pub proxy: EventLoopProxy <UserEvent>,
-    pub winit: WinitRef
+    pub winit: WinitRef,
+
+    /// A compact one-line summary of the configuration of the window
+    pub config: String,
+
+    pub tracked: Tracked,
+
+    /// See [`super::Window::event_counters`]
+    pub counters: EventCounters,
+
+    /// See [`super::Window::action_map`]
+    pub actions: RefCell <ActionMap>,
+
+    /// See [`super::build::WindowBuilder::fullscreen_management`]
+    pub fullscreen: RefCell <Option <FullscreenManager>>,
+
+    /// See [`super::WeakWindow`]
+    pub generation: usize,
+
+    /// Set by [`super::Window::close`], so that the request is not lost before the event loop runs
+    pub close_requested: Cell <bool>,
+
+    /// See [`super::Window::close_after`]
+    pub close_schedule: RefCell <CloseSchedule>,
+
+    /// See [`super::Window::user_data`]
+    pub user_data: UserDataStore
 }
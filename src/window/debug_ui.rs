@@ -0,0 +1,496 @@
+//!
+//! This module provides a tiny immediate-mode UI for tweaking values at runtime.
+//!
+//! It is deliberately minimal: widgets have fixed sizes, are laid out vertically
+//! and have no styling.
+//!
+//! The UI does not draw anything itself - it produces [`DrawCommand`]s
+//! (rectangles and text for an 8x8 bitmap font) to be rendered by any backend.
+//!
+//! # How it works
+//!
+//! [`UiCtx`] is kept by the user across frames. Each frame:
+//! - input is fed via [`UiCtx::set_cursor`] and [`UiCtx::set_mouse_down`],
+//!   and for text fields via [`UiCtx::input_key`], [`UiCtx::input_char`] and [`UiCtx::set_time`],
+//! - [`UiCtx::begin`] starts a panel,
+//! - widgets are called, changing given values in-place,
+//! - [`UiCtx::end`] finishes the frame and returns commands to be drawn.
+//!
+//! Widgets are identified by their labels, so labels must be unique within a panel.
+//!
+//! # Text fields
+//!
+//! [`UiCtx::text_field`] is focused by clicking it, and loses the focus on a click elsewhere,
+//! `Return` or `Escape`. Keys and characters fed while it is focused edit the value
+//! as described in [`textedit`](super::textedit), while the others are dropped at [`UiCtx::end`].
+//!
+//! The clipboard is kept by the context itself, see [`UiCtx::clipboard`],
+//! as there is no access to the one of the system yet.
+//!
+//! # Examples
+//!
+//! ```
+//! use rokoko::prelude::*;
+//! use rokoko::window::debug_ui::UiCtx;
+//!
+//! let mut ui = UiCtx::new();
+//! let mut speed = 0.0;
+//! let mut paused = false;
+//!
+//! let mut frame = |ui: &mut UiCtx, speed: &mut f32, paused: &mut bool| {
+//!     ui.begin(vec2::ZERO);
+//!     ui.slider("speed", speed, 0.0..=10.0);
+//!     ui.checkbox("paused", paused);
+//!     let reset = ui.button("reset");
+//!     ui.end();
+//!     reset
+//! };
+//!
+//! // Drag the slider to its middle
+//! let slider = UiCtx::widget_rect(vec2::ZERO, 0);
+//! ui.set_cursor(slider.0 + vec2::from([1.0, 1.0]));
+//! ui.set_mouse_down(true);
+//! frame(&mut ui, &mut speed, &mut paused);
+//! ui.set_cursor(slider.0 + slider.1 * 0.5);
+//! frame(&mut ui, &mut speed, &mut paused);
+//! ui.set_mouse_down(false);
+//! frame(&mut ui, &mut speed, &mut paused);
+//! assert_eq!(speed, 5.0);
+//!
+//! // Click the checkbox
+//! let checkbox = UiCtx::widget_rect(vec2::ZERO, 1);
+//! ui.set_cursor(checkbox.0 + vec2::from([1.0, 1.0]));
+//! ui.set_mouse_down(true);
+//! frame(&mut ui, &mut speed, &mut paused);
+//! assert!(!paused);
+//! ui.set_mouse_down(false);
+//! frame(&mut ui, &mut speed, &mut paused);
+//! assert!(paused);
+//!
+//! // Click the button
+//! let button = UiCtx::widget_rect(vec2::ZERO, 2);
+//! ui.set_cursor(button.0 + vec2::from([1.0, 1.0]));
+//! ui.set_mouse_down(true);
+//! assert!(!frame(&mut ui, &mut speed, &mut paused));
+//! ui.set_mouse_down(false);
+//! assert!(frame(&mut ui, &mut speed, &mut paused));
+//! assert!(!frame(&mut ui, &mut speed, &mut paused));
+//! ```
+//!
+//! Typing into a text field:
+//!
+//! ```
+//! use rokoko::prelude::*;
+//! use rokoko::window::debug_ui::UiCtx;
+//! use rokoko::window::input_map::{Key, Modifiers};
+//!
+//! let mut ui = UiCtx::new();
+//! let mut name = String::from("player");
+//!
+//! let mut frame = |ui: &mut UiCtx, name: &mut String| {
+//!     ui.begin(vec2::ZERO);
+//!     let changed = ui.text_field("name", name);
+//!     ui.end();
+//!     changed
+//! };
+//!
+//! // Focus it, clicking right of the text puts the cursor at the end
+//! let field = UiCtx::widget_rect(vec2::ZERO, 0);
+//! ui.set_cursor(field.0 + vec2::from([field.1[0] - 1.0, 1.0]));
+//! ui.set_mouse_down(true);
+//! frame(&mut ui, &mut name);
+//! ui.set_mouse_down(false);
+//!
+//! // Platforms report `Back` as a character too, which is ignored
+//! ui.input_key(Key::Back, Modifiers::NONE);
+//! ui.input_char('\u{8}');
+//! ui.input_char('r');
+//! ui.input_text("s");
+//! assert!(frame(&mut ui, &mut name));
+//! assert_eq!(name, "players");
+//!
+//! // `Return` drops the focus, so input is ignored after it
+//! ui.input_key(Key::Return, Modifiers::NONE);
+//! ui.input_char('!');
+//! assert!(!frame(&mut ui, &mut name));
+//! assert_eq!(name, "players");
+//! ```
+//!
+
+use crate::math::vec::vec2;
+use super::{
+    input_map::{Key, Modifiers},
+    textedit::TextEdit
+};
+use core::{ops::RangeInclusive, time::Duration};
+
+/// Width of a widget in pixels
+pub const WIDGET_WIDTH: f32 = 200.0;
+
+/// Height of a widget in pixels
+pub const WIDGET_HEIGHT: f32 = 16.0;
+
+/// Space between widgets in pixels
+pub const PADDING: f32 = 4.0;
+
+/// Size of a glyph of the bitmap font in pixels
+pub const GLYPH_SIZE: f32 = 8.0;
+
+/// How long the cursor of a text field is shown, and then hidden, while blinking
+pub const BLINK_INTERVAL: Duration = Duration::from_millis(500);
+
+///
+/// RGBA color.
+///
+pub type Color = [u8; 4];
+
+const BACKGROUND: Color = [40, 40, 40, 255];
+const HOT: Color = [70, 70, 70, 255];
+const ACTIVE: Color = [100, 100, 100, 255];
+const FILL: Color = [60, 120, 200, 255];
+const TEXT: Color = [230, 230, 230, 255];
+const SELECTION: Color = [60, 90, 140, 255];
+
+///
+/// A primitive to be drawn.
+///
+#[derive(Debug, Clone, PartialEq)]
+pub enum DrawCommand {
+    /// A filled rectangle with the top-left corner at `pos`
+    Rect {
+        pos: vec2,
+        size: vec2,
+        color: Color
+    },
+
+    /// A line of text with the top-left corner at `pos`
+    Text {
+        pos: vec2,
+        text: String,
+        color: Color
+    }
+}
+
+///
+/// State of the UI kept across frames.
+///
+/// See module documentation for more information.
+///
+#[derive(Debug, Default)]
+pub struct UiCtx {
+    cursor: vec2,
+    down: bool,
+    was_down: bool,
+
+    /// The widget under the cursor
+    hot: Option <u64>,
+
+    /// The widget being interacted with
+    active: Option <u64>,
+
+    /// The text field having the keyboard focus
+    focused: Option <u64>,
+
+    /// Editing state of the focused text field
+    edit: TextEdit,
+
+    /// Keys and characters fed since the last frame
+    input: Vec <TextInput>,
+
+    clipboard: String,
+    time: Duration,
+
+    /// When the cursor last started blinking, i.e. was shown
+    blink_start: Duration,
+
+    origin: vec2,
+    row: usize,
+    commands: Vec <DrawCommand>
+}
+
+/// A key or characters fed to [`UiCtx`]
+#[derive(Debug)]
+enum TextInput {
+    Key(Key, Modifiers),
+    Text(String)
+}
+
+impl UiCtx {
+    /// Creates a new context
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the cursor position in pixels, relatively to the top-left corner of the window
+    pub fn set_cursor(&mut self, pos: vec2) {
+        self.cursor = pos
+    }
+
+    /// Sets whether the left mouse button is held
+    pub fn set_mouse_down(&mut self, down: bool) {
+        self.down = down
+    }
+
+    /// Feeds a press(or an auto-repeat) of `key` with `modifiers` held, e.g. from `on_key`
+    pub fn input_key(&mut self, key: Key, modifiers: Modifiers) {
+        self.input.push(TextInput::Key(key, modifiers))
+    }
+
+    /// Feeds a typed character, e.g. from `on_char`
+    pub fn input_char(&mut self, c: char) {
+        self.input.push(TextInput::Text(c.to_string()))
+    }
+
+    /// Feeds typed text at once, e.g. an IME commit string
+    pub fn input_text(&mut self, text: &str) {
+        self.input.push(TextInput::Text(String::from(text)))
+    }
+
+    /// Sets the time passed since an arbitrary start, the cursor of a text field blinks with it
+    pub fn set_time(&mut self, time: Duration) {
+        self.time = time
+    }
+
+    /// Returns the contents of the clipboard text fields copy to and paste from
+    pub fn clipboard(&self) -> &str {
+        &self.clipboard
+    }
+
+    /// Replaces the contents of the clipboard, e.g. to paste from elsewhere
+    pub fn set_clipboard(&mut self, text: String) {
+        self.clipboard = text
+    }
+
+    ///
+    /// Returns the rectangle(position, size) of the `row`-th widget of a panel
+    /// with the top-left corner at `origin`.
+    ///
+    pub fn widget_rect(origin: vec2, row: usize) -> (vec2, vec2) {
+        let pos = origin + vec2::from([PADDING, PADDING + row as f32 * (WIDGET_HEIGHT + PADDING)]);
+        (pos, vec2::from([WIDGET_WIDTH, WIDGET_HEIGHT]))
+    }
+
+    ///
+    /// Begins a panel with the top-left corner at `origin`.
+    ///
+    pub fn begin(&mut self, origin: vec2) {
+        self.origin = origin;
+        self.row = 0;
+        self.hot = None;
+        self.commands.clear()
+    }
+
+    ///
+    /// Finishes the frame and returns commands to be drawn, the panel background first.
+    ///
+    pub fn end(&mut self) -> &[DrawCommand] {
+        // A press outside of the focused field, which would be hot otherwise
+        if self.down && !self.was_down && self.hot != self.focused {
+            self.focused = None
+        }
+        self.input.clear();
+
+        if !self.down {
+            self.active = None
+        }
+        self.was_down = self.down;
+
+        let size = vec2::from([
+            WIDGET_WIDTH + 2.0 * PADDING,
+            PADDING + self.row as f32 * (WIDGET_HEIGHT + PADDING)
+        ]);
+        self.commands.insert(0, DrawCommand::Rect {
+            pos: self.origin,
+            size,
+            color: BACKGROUND
+        });
+
+        &self.commands
+    }
+
+    ///
+    /// A slider changing `value` within `range` while dragged.
+    ///
+    /// Returns `true` if `value` changed.
+    ///
+    pub fn slider(&mut self, label: &str, value: &mut f32, range: RangeInclusive <f32>) -> bool {
+        let (id, pos, size) = self.widget(label);
+        let (min, max) = (*range.start(), *range.end());
+
+        let mut changed = false;
+        if self.active == Some(id) {
+            let t = ((self.cursor[0] - pos[0]) / size[0]).clamp(0.0, 1.0);
+            let new = min + (max - min) * t;
+            changed = new != *value;
+            *value = new
+        }
+
+        let t = if max > min { ((*value - min) / (max - min)).clamp(0.0, 1.0) } else { 0.0 };
+        self.rect(id, pos, size);
+        self.commands.push(DrawCommand::Rect {
+            pos,
+            size: vec2::from([size[0] * t, size[1]]),
+            color: FILL
+        });
+        self.text(pos, format!("{}: {:.2}", label, value));
+
+        changed
+    }
+
+    ///
+    /// A checkbox toggling `value` when clicked.
+    ///
+    /// Returns `true` if `value` changed.
+    ///
+    pub fn checkbox(&mut self, label: &str, value: &mut bool) -> bool {
+        let (clicked, pos) = self.clickable(label);
+        if clicked {
+            *value = !*value
+        }
+
+        if *value {
+            self.commands.push(DrawCommand::Rect {
+                pos,
+                size: vec2::single(WIDGET_HEIGHT),
+                color: FILL
+            })
+        }
+        self.text(pos + vec2::from([WIDGET_HEIGHT, 0.0]), label.to_string());
+
+        clicked
+    }
+
+    ///
+    /// A button.
+    ///
+    /// Returns `true` if it was clicked, i.e. pressed and released
+    /// while the cursor was over it.
+    ///
+    pub fn button(&mut self, label: &str) -> bool {
+        let (clicked, pos) = self.clickable(label);
+        self.text(pos, label.to_string());
+        clicked
+    }
+
+    ///
+    /// A single-line text field editing `value`, see the [module documentation](self).
+    ///
+    /// Returns `true` if `value` changed.
+    ///
+    pub fn text_field(&mut self, label: &str, value: &mut String) -> bool {
+        let (id, pos, size) = self.widget(label);
+        let text_pos = pos + vec2::from([(label.chars().count() + 2) as f32 * GLYPH_SIZE, 0.0]);
+
+        if self.hot == Some(id) && self.down && !self.was_down {
+            // Clicking puts the cursor at the nearest gap between chars
+            let clicked = ((self.cursor[0] - text_pos[0] - 2.0) / GLYPH_SIZE).round().max(0.0) as usize;
+            self.focused = Some(id);
+            self.edit.set_cursor(value, clicked);
+            self.blink_start = self.time
+        }
+
+        let mut changed = false;
+        if self.focused == Some(id) {
+            for input in core::mem::take(&mut self.input) {
+                match input {
+                    TextInput::Key(Key::Return | Key::Escape, _) => {
+                        self.focused = None;
+                        break
+                    },
+                    TextInput::Key(key, modifiers) => changed |= self.edit.key(value, key, modifiers, &mut self.clipboard),
+                    TextInput::Text(text) => changed |= self.edit.insert(value, &text)
+                }
+                self.blink_start = self.time
+            }
+        }
+
+        self.rect(id, pos, size);
+        if self.focused == Some(id) {
+            let at = |i: usize| text_pos + vec2::from([2.0 + i as f32 * GLYPH_SIZE, (WIDGET_HEIGHT - GLYPH_SIZE) / 2.0]);
+            let selection = self.edit.selection();
+            if !selection.is_empty() {
+                self.commands.push(DrawCommand::Rect {
+                    pos: at(selection.start),
+                    size: vec2::from([selection.len() as f32 * GLYPH_SIZE, GLYPH_SIZE]),
+                    color: SELECTION
+                })
+            }
+
+            let blinking = self.time.saturating_sub(self.blink_start).as_millis();
+            if blinking % (2 * BLINK_INTERVAL.as_millis()) < BLINK_INTERVAL.as_millis() {
+                self.commands.push(DrawCommand::Rect {
+                    pos: at(self.edit.cursor()),
+                    size: vec2::from([1.0, GLYPH_SIZE]),
+                    color: TEXT
+                })
+            }
+        }
+        self.text(pos, format!("{}: {}", label, value));
+
+        changed
+    }
+
+    ///
+    /// Lays out a widget that is clicked on release and draws its background.
+    ///
+    /// Returns whether it was clicked and its position.
+    ///
+    fn clickable(&mut self, label: &str) -> (bool, vec2) {
+        let (id, pos, size) = self.widget(label);
+        let clicked = self.active == Some(id) && !self.down && self.hot == Some(id);
+        self.rect(id, pos, size);
+        (clicked, pos)
+    }
+
+    ///
+    /// Lays out the next widget and updates interaction state.
+    ///
+    /// Returns its id and rectangle.
+    ///
+    fn widget(&mut self, label: &str) -> (u64, vec2, vec2) {
+        let id = Self::id(label);
+        let (pos, size) = Self::widget_rect(self.origin, self.row);
+        self.row += 1;
+
+        let inside = self.cursor[0] >= pos[0] && self.cursor[0] < pos[0] + size[0]
+            && self.cursor[1] >= pos[1] && self.cursor[1] < pos[1] + size[1];
+        if inside {
+            self.hot = Some(id);
+            if self.down && !self.was_down {
+                self.active = Some(id)
+            }
+        }
+
+        (id, pos, size)
+    }
+
+    /// Pushes the background of a widget
+    fn rect(&mut self, id: u64, pos: vec2, size: vec2) {
+        let color = if self.active == Some(id) {
+            ACTIVE
+        } else if self.hot == Some(id) {
+            HOT
+        } else {
+            BACKGROUND
+        };
+        self.commands.push(DrawCommand::Rect { pos, size, color })
+    }
+
+    /// Pushes a text vertically centered in a widget
+    fn text(&mut self, pos: vec2, text: String) {
+        self.commands.push(DrawCommand::Text {
+            pos: pos + vec2::from([2.0, (WIDGET_HEIGHT - GLYPH_SIZE) / 2.0]),
+            text,
+            color: TEXT
+        })
+    }
+
+    /// FNV-1a hash of a label
+    fn id(label: &str) -> u64 {
+        let mut hash = 0xcbf29ce484222325u64;
+        for byte in label.bytes() {
+            hash = (hash ^ byte as u64).wrapping_mul(0x100000001b3)
+        }
+        hash
+    }
+}
@@ -0,0 +1,311 @@
+//!
+//! This module provides [`Script`] - a human-written timeline of input for demos and manual testing,
+//! and [`Scheduler`], which tells which of its commands are due as time goes.
+//!
+//! # Format
+//!
+//! One command per line, or several separated with `;`, `#` starts a comment.
+//! Commands take effect right after the previous one, unless separated with `wait`.
+//!
+//! - `wait <duration>` - pauses the script, the duration is in `ms` or `s`, e.g. `500ms` or `2s`
+//! - `key <input> down|up` - presses or releases a key or a mouse button,
+//!   named as in [`input_map::Input`](super::input_map::Input), e.g. `Space` or `MouseLeft`
+//! - `resize <width> <height>` - resizes the window, in physical pixels
+//! - `screenshot` - captures the current frame
+//! - `close` - closes the window, must be the last command
+//!
+//! ```text
+//! # Jumps once, then closes
+//! wait 500ms; key Space down
+//! wait 100ms; key Space up
+//! resize 1024 768
+//! screenshot
+//! wait 1s
+//! close
+//! ```
+//!
+//! # Examples
+//!
+//! ```
+//! use rokoko::window::{Key, demo_script::{Script, Scheduler, Command}};
+//! use std::time::Duration;
+//!
+//! let script: Script = "wait 500ms; key Space down; key Space up\nclose".parse().unwrap();
+//! let mut scheduler = Scheduler::new(script);
+//!
+//! assert!(scheduler.due(Duration::from_millis(499)).is_empty());
+//! assert_eq!(scheduler.next_deadline(), Some(Duration::from_millis(500)));
+//!
+//! let due = scheduler.due(Duration::from_millis(510));
+//! assert_eq!(due.len(), 3);
+//! assert_eq!(due[0].command, Command::Key { input: Key::Space.into(), pressed: true });
+//! assert_eq!(due[2].command, Command::Close);
+//! assert!(scheduler.is_finished());
+//! ```
+//!
+
+use core::{
+    fmt::{self, Display, Formatter},
+    str::FromStr,
+    time::Duration
+};
+use std::{io, fs, path::Path};
+use crate::math::vec::uvec2;
+use super::input_map::Input;
+
+/// What a line of a [`Script`] does
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Command {
+    /// `key <input> down|up`
+    Key { input: Input, pressed: bool },
+
+    /// `resize <width> <height>`
+    Resize(uvec2),
+
+    /// `screenshot`
+    Screenshot,
+
+    /// `close`
+    Close
+}
+
+/// A [`Command`] and when it is due
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Step {
+    /// Time since the start of the script
+    pub at: Duration,
+
+    pub command: Command,
+
+    /// The line of the command, numbered from 1
+    pub line: usize
+}
+
+///
+/// Errors of loading a [`Script`].
+///
+/// Lines and columns are numbered from 1, columns are counted in characters.
+///
+#[derive(Debug)]
+pub enum Error {
+    /// The file cannot be read
+    Io(io::Error),
+
+    /// The script is malformed at the position
+    Syntax { line: usize, column: usize, message: String }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter <'_>) -> fmt::Result {
+        match self {
+            Error::Io(e) => write!(f, "cannot read the script: {e}"),
+            Error::Syntax { line, column, message } => write!(f, "{line}:{column}: {message}")
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option <&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io(e) => Some(e),
+            _ => None
+        }
+    }
+}
+
+impl From <io::Error> for Error {
+    fn from(e: io::Error) -> Self {
+        Error::Io(e)
+    }
+}
+
+///
+/// A parsed script, see the [module documentation](self) for the format.
+///
+/// # Examples
+///
+/// ```
+/// use rokoko::window::demo_script::{Script, Error};
+///
+/// let error = "wait 1s\nresize 800 six hundred".parse::<Script>().unwrap_err();
+/// assert!(matches!(&error, Error::Syntax { line: 2, column: 12, .. }));
+/// assert_eq!(error.to_string(), "2:12: expected a number of pixels, found `six`");
+/// ```
+///
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Script {
+    steps: Vec <Step>
+}
+
+impl Script {
+    /// Reads and parses the script at `path`
+    pub fn load(path: impl AsRef <Path>) -> Result <Self, Error> {
+        fs::read_to_string(path)?.parse()
+    }
+
+    /// The commands, in the order of the script and thus of time
+    pub fn steps(&self) -> &[Step] {
+        &self.steps
+    }
+}
+
+impl FromStr for Script {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result <Self, Error> {
+        let mut steps = Vec::new();
+        let mut at = Duration::ZERO;
+        let mut closed = None;
+
+        for (i, line) in s.lines().enumerate() {
+            let line_number = i + 1;
+            let code = line.split('#').next().unwrap_or_default();
+
+            let mut start = 0;
+            for command in code.split(';') {
+                let mut tokens = Tokens { line: code, end: start + command.len(), rest: command, offset: start };
+                start += command.len() + 1;
+
+                let error = |(column, message)| Error::Syntax { line: line_number, column, message };
+                let (column, name) = match tokens.next() {
+                    Some(name) => name,
+                    None => continue
+                };
+                if let Some(close) = closed {
+                    return Err(error((column, format!("nothing can follow `close` at line {close}"))))
+                }
+
+                let command = match name {
+                    "wait" => {
+                        at += tokens.parse("a duration, e.g. `500ms` or `2s`", parse_duration).map_err(error)?;
+                        None
+                    },
+                    "key" => {
+                        let (column, name) = tokens.expect("a key or a mouse button").map_err(error)?;
+                        let input = name.parse::<Input>().map_err(|e| error((column, e.to_string())))?;
+                        let pressed = tokens.parse("`down` or `up`", |s| match s {
+                            "down" => Some(true),
+                            "up" => Some(false),
+                            _ => None
+                        }).map_err(error)?;
+                        Some(Command::Key { input, pressed })
+                    },
+                    "resize" => {
+                        let width = tokens.parse("a number of pixels", |s| s.parse().ok()).map_err(error)?;
+                        let height = tokens.parse("a number of pixels", |s| s.parse().ok()).map_err(error)?;
+                        Some(Command::Resize(uvec2::from([width, height])))
+                    },
+                    "screenshot" => Some(Command::Screenshot),
+                    "close" => {
+                        closed = Some(line_number);
+                        Some(Command::Close)
+                    },
+                    _ => return Err(error((
+                        column,
+                        format!("unknown command `{name}`, expected one of `wait`, `key`, `resize`, `screenshot`, `close`")
+                    )))
+                };
+
+                if let Some((column, extra)) = tokens.next() {
+                    return Err(error((column, format!("unexpected `{extra}`, commands are separated with `;` or new lines"))))
+                }
+                if let Some(command) = command {
+                    steps.push(Step { at, command, line: line_number })
+                }
+            }
+        }
+
+        Ok(Self { steps })
+    }
+}
+
+/// Parses `500ms` or `2s`
+fn parse_duration(s: &str) -> Option <Duration> {
+    if let Some(ms) = s.strip_suffix("ms") {
+        ms.parse().ok().map(Duration::from_millis)
+    } else {
+        s.strip_suffix('s')?.parse().ok().map(Duration::from_secs)
+    }
+}
+
+/// Whitespace-separated words of a command with their columns
+struct Tokens <'a> {
+    /// The whole line, to count columns in characters
+    line: &'a str,
+
+    /// The byte offset of the end of the command in `line`
+    end: usize,
+
+    rest: &'a str,
+
+    /// The byte offset of `rest` in `line`
+    offset: usize
+}
+
+impl <'a> Tokens <'a> {
+    fn column(&self, offset: usize) -> usize {
+        self.line[..offset].chars().count() + 1
+    }
+
+    fn next(&mut self) -> Option <(usize, &'a str)> {
+        let trimmed = self.rest.trim_start();
+        self.offset += self.rest.len() - trimmed.len();
+        if trimmed.is_empty() {
+            return None
+        }
+
+        let len = trimmed.find(char::is_whitespace).unwrap_or(trimmed.len());
+        let token = (self.column(self.offset), &trimmed[..len]);
+        self.rest = &trimmed[len..];
+        self.offset += len;
+        Some(token)
+    }
+
+    /// Takes the next word, or points at the end of the command if there is none
+    fn expect(&mut self, what: &str) -> Result <(usize, &'a str), (usize, String)> {
+        self.next().ok_or_else(|| (self.column(self.end), format!("expected {what}")))
+    }
+
+    fn parse <T> (&mut self, what: &str, f: impl FnOnce(&str) -> Option <T>) -> Result <T, (usize, String)> {
+        let (column, token) = self.expect(what)?;
+        f(token).ok_or_else(|| (column, format!("expected {what}, found `{token}`")))
+    }
+}
+
+///
+/// Plays a [`Script`] back: tells which commands are due as time goes.
+///
+/// Commands due at the same time, i.e. not separated with `wait`, are returned together
+/// in the order of the script. The time is counted from any start, e.g. `create`,
+/// and [`Scheduler::next_deadline`] is when the loop should wake up next for the script.
+///
+#[derive(Debug, Clone)]
+pub struct Scheduler {
+    script: Script,
+
+    /// The index of the first step that is not due yet
+    next: usize
+}
+
+impl Scheduler {
+    pub fn new(script: Script) -> Self {
+        Self { script, next: 0 }
+    }
+
+    /// Returns the steps that became due by `elapsed` since the start, each only once
+    pub fn due(&mut self, elapsed: Duration) -> &[Step] {
+        let start = self.next;
+        self.next += self.script.steps[start..].iter().take_while(|step| step.at <= elapsed).count();
+        &self.script.steps[start..self.next]
+    }
+
+    /// Returns the time since the start when the next step is due, `None` if all of them are done
+    pub fn next_deadline(&self) -> Option <Duration> {
+        self.script.steps.get(self.next).map(|step| step.at)
+    }
+
+    /// Returns `true` if all the steps are done
+    pub fn is_finished(&self) -> bool {
+        self.next == self.script.steps.len()
+    }
+}
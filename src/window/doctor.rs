@@ -0,0 +1,377 @@
+//!
+//! This module provides [`doctor`] - a report of the environment windows
+//! are created in, for bug reports like "the window does not open".
+//!
+//! The report contains:
+//! - the version of `rokoko`, the target, enabled features and `cfg`s;
+//! - the `winit` backend;
+//! - environment variables affecting the backend selection;
+//! - monitors;
+//! - whether an event loop has already been created;
+//! - with `config-trace` feature, which options and callbacks the last created window has,
+//!   see [`WindowBuilder::config_trace`].
+//!
+//! Every probe may fail on its own(e.g. without a display server in a headless CI),
+//! which is recorded in the report instead of panicking.
+//!
+//! Probing the backend and monitors needs an event loop, so `doctor` creates it
+//! if no window has been created yet. The loop is kept for the next [`WindowBuilder::create`],
+//! so calling `doctor` first does not prevent creating a window.
+//!
+//! # Examples
+//!
+//! Printing the report when a window cannot be created:
+//!
+//! ```no_run
+//! use rokoko::prelude::*;
+//! use rokoko::window::doctor;
+//!
+//! let report = doctor();
+//!
+//! if let Err(e) = Window::new().title("app").on_close(Window::close).create() {
+//!     eprintln!("cannot create the window: {e}");
+//!     eprintln!("please attach this to the bug report:\n{report}");
+//!     std::process::exit(1)
+//! }
+//! ```
+//!
+//! [`WindowBuilder::create`]: super::build::WindowBuilder::create
+//! [`WindowBuilder::config_trace`]: super::build::WindowBuilder::config_trace
+//!
+
+use super::event_loop;
+#[cfg(feature = "config-trace")]
+use super::build::meta::{self, ConfigTrace};
+use core::fmt;
+
+/// Environment variables reported by [`doctor`]
+pub const ENV_VARS: [&str; 3] = ["WAYLAND_DISPLAY", "DISPLAY", "WINIT_UNIX_BACKEND"];
+
+///
+/// A source of the facts [`DoctorReport`] is made of.
+///
+/// [`doctor`] uses the real environment, while other implementations
+/// make it possible to test the report.
+///
+/// Fallible probes return the reason of the failure as `Err`.
+///
+pub trait Probe {
+    /// Returns names of enabled features of `rokoko`
+    fn features(&self) -> Vec <&'static str>;
+
+    /// Returns names of `rokoko`'s own `cfg`s that are set, e.g. `nightly`
+    fn cfgs(&self) -> Vec <&'static str>;
+
+    /// Returns the value of an environment variable, `None` if it is unset
+    fn env(&self, name: &str) -> Option <String>;
+
+    /// Returns the name of the `winit` backend
+    fn backend(&self) -> Result <String, String>;
+
+    /// Returns available monitors
+    fn monitors(&self) -> Result <Vec <MonitorInfo>, String>;
+
+    /// Returns `true` if an event loop had been created before the report
+    fn event_loop_created(&self) -> bool;
+
+    ///
+    /// Returns the trace of the last created window, `None` if none was.
+    ///
+    /// Requires `config-trace` feature.
+    ///
+    #[cfg(feature = "config-trace")]
+    fn config_trace(&self) -> Option <ConfigTrace> {
+        None
+    }
+}
+
+///
+/// A monitor as seen by [`doctor`].
+///
+#[derive(Debug, Clone, PartialEq)]
+pub struct MonitorInfo {
+    /// Name of the monitor, if the platform provides it
+    pub name: Option <String>,
+
+    /// Resolution in physical pixels
+    pub size: (u32, u32),
+
+    /// Scale factor, i.e. physical pixels per logical one
+    pub scale_factor: f64,
+
+    /// `true` if the monitor is the primary one
+    pub primary: bool
+}
+
+///
+/// A report of the environment, see [module documentation](self).
+///
+/// [`Display`](fmt::Display) produces a block ready to be pasted to a bug report.
+///
+/// # Examples
+///
+/// ```
+/// use rokoko::window::doctor::{DoctorReport, Probe, MonitorInfo};
+///
+/// /// A headless CI
+/// struct Fake;
+///
+/// impl Probe for Fake {
+///     fn features(&self) -> Vec <&'static str> { vec!["math", "window"] }
+///     fn cfgs(&self) -> Vec <&'static str> { vec!["nightly", "std"] }
+///     fn env(&self, name: &str) -> Option <String> { (name == "DISPLAY").then(|| String::from(":99")) }
+///     fn backend(&self) -> Result <String, String> { Err(String::from("no display")) }
+///     fn monitors(&self) -> Result <Vec <MonitorInfo>, String> { Err(String::from("no display")) }
+///     fn event_loop_created(&self) -> bool { false }
+/// }
+///
+/// let report = DoctorReport::gather(&Fake);
+/// assert_eq!(report.env[1], ("DISPLAY", Some(String::from(":99"))));
+/// assert!(report.monitors.is_err());
+///
+/// let text = report.to_string();
+/// assert!(text.starts_with("```text\nrokoko doctor report\n"));
+/// assert!(text.contains("\nfeatures:    math, window\n"));
+/// assert!(text.contains("\ncfg:         nightly, std\n"));
+/// assert!(text.contains("\nbackend:     unavailable (no display)\n"));
+/// assert!(text.contains("\nevent loop:  not created\n"));
+/// assert!(text.contains("\n    WAYLAND_DISPLAY=<unset>\n    DISPLAY=:99\n    WINIT_UNIX_BACKEND=<unset>\n"));
+/// assert!(text.contains("\nmonitors:    unavailable (no display)\n"));
+/// assert!(text.ends_with("\n```"));
+///
+/// // Monitors
+/// let mut report = report;
+/// report.backend = Ok(String::from("x11"));
+/// report.monitors = Ok(vec![
+///     MonitorInfo { name: Some(String::from("DP-1")), size: (2560, 1440), scale_factor: 1.5, primary: true },
+///     MonitorInfo { name: None, size: (1920, 1080), scale_factor: 1.0, primary: false }
+/// ]);
+/// report.features.clear();
+///
+/// let text = report.to_string();
+/// assert!(text.contains("\nfeatures:    <none>\n"));
+/// assert!(text.contains("\nbackend:     x11\n"));
+/// assert!(text.contains("\nmonitors:    2\n    #0 \"DP-1\" 2560x1440 @ 1.5 (primary)\n    #1 <unnamed> 1920x1080 @ 1\n"));
+///
+/// // No monitors at all
+/// report.monitors = Ok(vec![]);
+/// assert!(report.to_string().contains("\nmonitors:    0\n```"));
+/// ```
+///
+#[derive(Debug, Clone, PartialEq)]
+pub struct DoctorReport {
+    /// Version of `rokoko`
+    pub version: &'static str,
+
+    /// Operating system the crate was compiled for
+    pub target: &'static str,
+
+    /// See [`Probe::features`]
+    pub features: Vec <&'static str>,
+
+    /// See [`Probe::cfgs`]
+    pub cfgs: Vec <&'static str>,
+
+    /// See [`Probe::backend`]
+    pub backend: Result <String, String>,
+
+    /// Values of [`ENV_VARS`], in the same order
+    pub env: Vec <(&'static str, Option <String>)>,
+
+    /// See [`Probe::monitors`]
+    pub monitors: Result <Vec <MonitorInfo>, String>,
+
+    /// See [`Probe::event_loop_created`]
+    pub event_loop_created: bool,
+
+    /// See [`Probe::config_trace`]
+    #[cfg(feature = "config-trace")]
+    pub config_trace: Option <ConfigTrace>
+}
+
+impl DoctorReport {
+    ///
+    /// Makes a report from the facts of `probe`.
+    ///
+    /// Whether an event loop was created is asked first, since
+    /// probing the backend or monitors may create one.
+    ///
+    pub fn gather(probe: &impl Probe) -> Self {
+        let event_loop_created = probe.event_loop_created();
+        Self {
+            version: env!("CARGO_PKG_VERSION"),
+            target: std::env::consts::OS,
+            features: probe.features(),
+            cfgs: probe.cfgs(),
+            backend: probe.backend(),
+            env: ENV_VARS.iter().map(|&name| (name, probe.env(name))).collect(),
+            monitors: probe.monitors(),
+            event_loop_created,
+            #[cfg(feature = "config-trace")]
+            config_trace: probe.config_trace()
+        }
+    }
+}
+
+/// Writes a list as `a, b, c` or `<none>`
+fn list(f: &mut fmt::Formatter <'_>, items: &[&str]) -> fmt::Result {
+    if items.is_empty() {
+        f.write_str("<none>\n")
+    } else {
+        writeln!(f, "{}", items.join(", "))
+    }
+}
+
+impl fmt::Display for DoctorReport {
+    fn fmt(&self, f: &mut fmt::Formatter <'_>) -> fmt::Result {
+        f.write_str("```text\nrokoko doctor report\n")?;
+        writeln!(f, "version:     {}", self.version)?;
+        writeln!(f, "target:      {}", self.target)?;
+        f.write_str("features:    ")?;
+        list(f, &self.features)?;
+        f.write_str("cfg:         ")?;
+        list(f, &self.cfgs)?;
+
+        match &self.backend {
+            Ok(backend) => writeln!(f, "backend:     {backend}")?,
+            Err(e) => writeln!(f, "backend:     unavailable ({e})")?
+        }
+
+        writeln!(f, "event loop:  {}", if self.event_loop_created { "already created" } else { "not created" })?;
+
+        #[cfg(feature = "config-trace")]
+        match &self.config_trace {
+            Some(trace) => {
+                f.write_str("config trace:\n")?;
+                for line in trace.to_string().lines() {
+                    writeln!(f, "    {line}")?
+                }
+            },
+            None => f.write_str("config trace: no window created\n")?
+        }
+
+        f.write_str("environment:\n")?;
+        for (name, value) in &self.env {
+            writeln!(f, "    {name}={}", value.as_deref().unwrap_or("<unset>"))?
+        }
+
+        match &self.monitors {
+            Ok(monitors) => {
+                write!(f, "monitors:    {}", monitors.len())?;
+                for (i, monitor) in monitors.iter().enumerate() {
+                    write!(f, "\n    #{i} ")?;
+                    match &monitor.name {
+                        Some(name) => write!(f, "{name:?}")?,
+                        None => f.write_str("<unnamed>")?
+                    }
+                    write!(f, " {}x{} @ {}", monitor.size.0, monitor.size.1, monitor.scale_factor)?;
+                    if monitor.primary {
+                        f.write_str(" (primary)")?
+                    }
+                }
+            },
+            Err(e) => write!(f, "monitors:    unavailable ({e})")?
+        }
+
+        f.write_str("\n```")
+    }
+}
+
+/// The real environment
+struct System;
+
+impl Probe for System {
+    fn features(&self) -> Vec <&'static str> {
+        [
+            ("math", cfg!(feature = "math")),
+            ("libm", cfg!(feature = "libm")),
+            ("alloc", cfg!(feature = "alloc")),
+            ("window", cfg!(feature = "window")),
+            ("dialogs", cfg!(feature = "dialogs")),
+            ("debug-ui", cfg!(feature = "debug-ui")),
+            ("menus", cfg!(feature = "menus")),
+            ("global-hotkeys", cfg!(feature = "global-hotkeys")),
+            ("winit-interop", cfg!(feature = "winit-interop")),
+            ("config-trace", cfg!(feature = "config-trace")),
+            ("dyn-callbacks", cfg!(feature = "dyn-callbacks"))
+        ].iter().filter_map(|&(name, enabled)| enabled.then(|| name)).collect()
+    }
+
+    fn cfgs(&self) -> Vec <&'static str> {
+        [
+            ("nightly", cfg!(nightly)),
+            ("std", cfg!(std)),
+            ("reusable_event_loop", cfg!(reusable_event_loop)),
+            ("debug_assertions", cfg!(debug_assertions))
+        ].iter().filter_map(|&(name, set)| set.then(|| name)).collect()
+    }
+
+    fn env(&self, name: &str) -> Option <String> {
+        std::env::var_os(name).map(|value| value.to_string_lossy().into_owned())
+    }
+
+    fn backend(&self) -> Result <String, String> {
+        cfg_if::cfg_if! {
+            if #[cfg(any(
+                target_os = "linux",
+                target_os = "dragonfly",
+                target_os = "freebsd",
+                target_os = "netbsd",
+                target_os = "openbsd"
+            ))] {
+                use winit::platform::unix::EventLoopWindowTargetExtUnix;
+
+                event_loop::inspect(|target| String::from(if target.is_wayland() { "wayland" } else { "x11" }))
+            } else {
+                Ok(String::from(match std::env::consts::OS {
+                    "windows" => "win32",
+                    "macos" => "appkit",
+                    "ios" => "uikit",
+                    "android" => "android-ndk",
+                    other => other
+                }))
+            }
+        }
+    }
+
+    fn monitors(&self) -> Result <Vec <MonitorInfo>, String> {
+        event_loop::inspect(|target| {
+            let primary = target.primary_monitor();
+            target.available_monitors().map(|monitor| {
+                let size = monitor.size();
+                MonitorInfo {
+                    name: monitor.name(),
+                    size: (size.width, size.height),
+                    scale_factor: monitor.scale_factor(),
+                    primary: primary.as_ref() == Some(&monitor)
+                }
+            }).collect()
+        })
+    }
+
+    fn event_loop_created(&self) -> bool {
+        event_loop::created()
+    }
+
+    #[cfg(feature = "config-trace")]
+    fn config_trace(&self) -> Option <ConfigTrace> {
+        meta::last_trace()
+    }
+}
+
+///
+/// Returns a report of the environment, see [module documentation](self).
+///
+/// Never panics, even without a display server, though the message of a failure
+/// to create the event loop is still printed by the panic hook.
+///
+pub fn doctor() -> DoctorReport {
+    DoctorReport::gather(&System)
+}
+
+///
+/// Prints [`doctor`]'s report to `stderr`.
+///
+pub fn doctor_print() {
+    eprintln!("{}", doctor())
+}
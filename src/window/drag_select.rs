@@ -0,0 +1,331 @@
+//!
+//! This module provides [`DragSelect`] - the state machine behind
+//! [`WindowBuilder::drag_select`], turning click-drags with the left mouse button
+//! into selection rectangles.
+//!
+//! The machine is:
+//! - idle, until the left button is pressed;
+//! - pending, until the cursor moves farther than the threshold from the press position:
+//!   releasing the button here is just a click and produces nothing;
+//! - dragging, reporting [`DragPhase::Started`], [`DragPhase::Updated`] on every move, and
+//!   then [`DragPhase::Finished`] once the button is released, or [`DragPhase::Cancelled`]
+//!   if `Escape` is pressed or the window loses focus.
+//!
+//! Rectangles are in physical window coordinates and always normalized,
+//! whatever direction the cursor is dragged in.
+//!
+//! # Examples
+//!
+//! ```no_run
+//! use rokoko::prelude::*;
+//! use rokoko::window::drag_select::DragPhase;
+//!
+//! Window::new()
+//!     .drag_select()
+//!     .drag_select_threshold(8.0)
+//!     .on_drag_select(|_, rect, phase| match phase {
+//!         DragPhase::Started | DragPhase::Updated => println!("selecting {:?}..{:?}", rect.min, rect.max),
+//!         DragPhase::Finished => println!("selected {:?}", rect.size()),
+//!         DragPhase::Cancelled => println!("selection cancelled")
+//!     })
+//!     .create()
+//!     .unwrap()
+//! ```
+//!
+//! [`WindowBuilder::drag_select`]: super::build::WindowBuilder::drag_select
+//!
+
+use crate::math::vec::{vec, vec2};
+use winit::event::{WindowEvent, ElementState, MouseButton, VirtualKeyCode};
+
+/// Threshold used unless [`WindowBuilder::drag_select_threshold`](super::build::WindowBuilder::drag_select_threshold) is specified, in pixels
+pub const DEFAULT_THRESHOLD: f32 = 4.0;
+
+///
+/// An axis-aligned rectangle, `min` being not greater than `max` in both dimensions.
+///
+#[derive(Debug, Copy, Clone)]
+pub struct Rect <T> {
+    pub min: vec <T, 2>,
+    pub max: vec <T, 2>
+}
+
+// Not derived, as comparing `vec`s requires `T: Copy` as well
+impl <T: Copy + PartialEq> PartialEq for Rect <T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.min == other.min && self.max == other.max
+    }
+}
+
+impl Rect <f32> {
+    ///
+    /// Creates a rectangle from any two opposite corners.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rokoko::prelude::*;
+    /// use rokoko::window::drag_select::Rect;
+    ///
+    /// let rect = Rect::from_corners(vec2::from([30.0, 10.0]), vec2::from([10.0, 20.0]));
+    /// assert_eq!(rect.min, vec2::from([10.0, 10.0]));
+    /// assert_eq!(rect.max, vec2::from([30.0, 20.0]));
+    /// assert_eq!(rect.size(), vec2::from([20.0, 10.0]));
+    /// ```
+    ///
+    pub fn from_corners(a: vec2, b: vec2) -> Self {
+        Self {
+            min: vec2::from([a[0].min(b[0]), a[1].min(b[1])]),
+            max: vec2::from([a[0].max(b[0]), a[1].max(b[1])])
+        }
+    }
+
+    /// Returns width and height of the rectangle
+    pub fn size(&self) -> vec2 {
+        vec2::from([self.max[0] - self.min[0], self.max[1] - self.min[1]])
+    }
+
+    /// Returns `true` if `point` is inside the rectangle, boundary included
+    pub fn contains(&self, point: vec2) -> bool {
+        self.min[0] <= point[0] && point[0] <= self.max[0] && self.min[1] <= point[1] && point[1] <= self.max[1]
+    }
+}
+
+///
+/// A phase of a drag-select, see [module documentation](self).
+///
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum DragPhase {
+    /// The cursor has moved farther than the threshold with the button held
+    Started,
+
+    /// The cursor has moved while dragging
+    Updated,
+
+    /// The button has been released, the rectangle is the final selection
+    Finished,
+
+    /// `Escape` has been pressed or the window has lost focus, the rectangle is the last one reported
+    Cancelled
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+enum State {
+    Idle,
+    Pending { origin: vec2 },
+    Dragging { origin: vec2 }
+}
+
+///
+/// The drag-select state machine, see [module documentation](self).
+///
+/// # Examples
+///
+/// ```
+/// use rokoko::prelude::*;
+/// use rokoko::window::drag_select::{DragSelect, DragPhase, Rect};
+///
+/// let rect = |a: [f32; 2], b: [f32; 2]| Rect::from_corners(vec2::from(a), vec2::from(b));
+///
+/// let mut drag = DragSelect::new(5.0);
+///
+/// // Pressing with unknown cursor position does nothing
+/// assert_eq!(drag.button(true), None);
+/// assert!(!drag.is_dragging());
+/// assert_eq!(drag.button(false), None);
+///
+/// // A click, even with a small jitter, is not a selection
+/// assert_eq!(drag.cursor_moved(vec2::from([100.0, 100.0])), None);
+/// assert_eq!(drag.button(true), None);
+/// assert_eq!(drag.cursor_moved(vec2::from([103.0, 104.0])), None);
+/// assert_eq!(drag.button(false), None);
+///
+/// // Exactly at the threshold is still a click
+/// assert_eq!(drag.button(true), None);
+/// assert_eq!(drag.cursor_moved(vec2::from([106.0, 108.0])), None);
+/// assert_eq!(drag.button(false), None);
+///
+/// // A drag up and to the left: normalized
+/// assert_eq!(drag.button(true), None);
+/// assert_eq!(drag.cursor_moved(vec2::from([90.0, 100.0])), Some((rect([90.0, 100.0], [106.0, 108.0]), DragPhase::Started)));
+/// assert!(drag.is_dragging());
+/// assert_eq!(drag.cursor_moved(vec2::from([50.0, 60.0])), Some((rect([50.0, 60.0], [106.0, 108.0]), DragPhase::Updated)));
+///
+/// // Moving back within the threshold keeps dragging
+/// assert_eq!(drag.cursor_moved(vec2::from([106.0, 108.0])), Some((rect([106.0, 108.0], [106.0, 108.0]), DragPhase::Updated)));
+/// assert_eq!(drag.cursor_moved(vec2::from([200.0, 10.0])), Some((rect([106.0, 10.0], [200.0, 108.0]), DragPhase::Updated)));
+///
+/// // Pressing again while dragging is ignored
+/// assert_eq!(drag.button(true), None);
+/// assert_eq!(drag.button(false), Some((rect([106.0, 10.0], [200.0, 108.0]), DragPhase::Finished)));
+/// assert!(!drag.is_dragging());
+///
+/// // Moving while idle does nothing
+/// assert_eq!(drag.cursor_moved(vec2::from([0.0, 0.0])), None);
+///
+/// // Cancelling
+/// assert_eq!(drag.cancel(), None);
+/// assert_eq!(drag.button(true), None);
+/// assert_eq!(drag.cancel(), None);
+/// assert_eq!(drag.cursor_moved(vec2::from([10.0, 0.0])), None);
+///
+/// assert_eq!(drag.button(true), None);
+/// assert_eq!(drag.cursor_moved(vec2::from([0.0, 0.0])), Some((rect([0.0, 0.0], [10.0, 0.0]), DragPhase::Started)));
+/// assert_eq!(drag.cancel(), Some((rect([0.0, 0.0], [10.0, 0.0]), DragPhase::Cancelled)));
+/// assert!(!drag.is_dragging());
+///
+/// // The release after a cancellation is ignored
+/// assert_eq!(drag.cursor_moved(vec2::from([50.0, 50.0])), None);
+/// assert_eq!(drag.button(false), None);
+///
+/// // Zero threshold starts on any movement
+/// let mut drag = DragSelect::new(0.0);
+/// assert_eq!(drag.cursor_moved(vec2::from([1.0, 1.0])), None);
+/// assert_eq!(drag.button(true), None);
+/// assert_eq!(drag.cursor_moved(vec2::from([1.0, 1.0])), None);
+/// assert_eq!(drag.cursor_moved(vec2::from([1.0, 1.5])), Some((rect([1.0, 1.0], [1.0, 1.5]), DragPhase::Started)));
+/// ```
+///
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct DragSelect {
+    threshold: f32,
+    cursor: Option <vec2>,
+    state: State
+}
+
+impl DragSelect {
+    ///
+    /// Creates an idle machine.
+    ///
+    /// The drag starts once the cursor is farther than `threshold` pixels from where the button was pressed.
+    ///
+    pub const fn new(threshold: f32) -> Self {
+        Self {
+            threshold,
+            cursor: None,
+            state: State::Idle
+        }
+    }
+
+    /// Returns `true` if a drag has started and has not been finished or cancelled yet
+    pub const fn is_dragging(&self) -> bool {
+        matches!(self.state, State::Dragging { .. })
+    }
+
+    /// Handles movement of the cursor to `position`
+    pub fn cursor_moved(&mut self, position: vec2) -> Option <(Rect <f32>, DragPhase)> {
+        self.cursor = Some(position);
+        match self.state {
+            State::Idle => None,
+            State::Pending { origin } => {
+                let d = [position[0] - origin[0], position[1] - origin[1]];
+                if d[0] * d[0] + d[1] * d[1] > self.threshold * self.threshold {
+                    self.state = State::Dragging { origin };
+                    Some((Rect::from_corners(origin, position), DragPhase::Started))
+                } else {
+                    None
+                }
+            },
+            State::Dragging { origin } => Some((Rect::from_corners(origin, position), DragPhase::Updated))
+        }
+    }
+
+    /// Handles press(`pressed == true`) or release of the left mouse button
+    pub fn button(&mut self, pressed: bool) -> Option <(Rect <f32>, DragPhase)> {
+        match (self.state, pressed, self.cursor) {
+            (State::Idle, true, Some(cursor)) => {
+                self.state = State::Pending { origin: cursor };
+                None
+            },
+            (State::Pending { .. }, false, _) => {
+                self.state = State::Idle;
+                None
+            },
+            (State::Dragging { origin }, false, Some(cursor)) => {
+                self.state = State::Idle;
+                Some((Rect::from_corners(origin, cursor), DragPhase::Finished))
+            },
+            _ => None
+        }
+    }
+
+    /// Cancels the drag, if any
+    pub fn cancel(&mut self) -> Option <(Rect <f32>, DragPhase)> {
+        let state = core::mem::replace(&mut self.state, State::Idle);
+        match (state, self.cursor) {
+            (State::Dragging { origin }, Some(cursor)) => Some((Rect::from_corners(origin, cursor), DragPhase::Cancelled)),
+            _ => None
+        }
+    }
+
+    ///
+    /// Handles a `winit` event, ignoring unrelated ones.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rokoko::prelude::*;
+    /// use rokoko::window::drag_select::{DragSelect, DragPhase, Rect};
+    /// # extern crate winit;
+    /// use winit::{event::*, dpi::PhysicalPosition};
+    ///
+    /// // SAFETY: only used for comparison, never passed to `winit`
+    /// let device_id = unsafe { DeviceId::dummy() };
+    /// #[allow(deprecated)]
+    /// let moved = |x, y| WindowEvent::CursorMoved {
+    ///     device_id,
+    ///     position: PhysicalPosition::new(x, y),
+    ///     modifiers: ModifiersState::empty()
+    /// };
+    /// #[allow(deprecated)]
+    /// let mouse = |state, button| WindowEvent::MouseInput { device_id, state, button, modifiers: ModifiersState::empty() };
+    /// #[allow(deprecated)]
+    /// let key = |state, key| WindowEvent::KeyboardInput {
+    ///     device_id,
+    ///     input: KeyboardInput { scancode: 0, state, virtual_keycode: Some(key), modifiers: ModifiersState::empty() },
+    ///     is_synthetic: false
+    /// };
+    ///
+    /// let mut drag = DragSelect::new(2.0);
+    ///
+    /// drag.update(&moved(10.0, 10.0));
+    /// // Other buttons are ignored
+    /// assert_eq!(drag.update(&mouse(ElementState::Pressed, MouseButton::Right)), None);
+    /// assert_eq!(drag.update(&moved(20.0, 20.0)), None);
+    ///
+    /// drag.update(&mouse(ElementState::Pressed, MouseButton::Left));
+    /// assert_eq!(drag.update(&moved(30.0, 10.0)).map(|(_, phase)| phase), Some(DragPhase::Started));
+    ///
+    /// // Other keys and releases of `Escape` are ignored
+    /// assert_eq!(drag.update(&key(ElementState::Pressed, VirtualKeyCode::A)), None);
+    /// assert_eq!(drag.update(&key(ElementState::Released, VirtualKeyCode::Escape)), None);
+    /// assert!(drag.is_dragging());
+    ///
+    /// let rect = Rect::from_corners(vec2::from([20.0, 20.0]), vec2::from([30.0, 10.0]));
+    /// assert_eq!(drag.update(&key(ElementState::Pressed, VirtualKeyCode::Escape)), Some((rect, DragPhase::Cancelled)));
+    ///
+    /// // Losing focus cancels as well
+    /// drag.update(&mouse(ElementState::Released, MouseButton::Left));
+    /// drag.update(&mouse(ElementState::Pressed, MouseButton::Left));
+    /// drag.update(&moved(0.0, 0.0));
+    /// assert_eq!(drag.update(&WindowEvent::Focused(false)).map(|(_, phase)| phase), Some(DragPhase::Cancelled));
+    /// ```
+    ///
+    pub fn update(&mut self, event: &WindowEvent <'_>) -> Option <(Rect <f32>, DragPhase)> {
+        match event {
+            WindowEvent::CursorMoved { position, .. } => self.cursor_moved(vec2::from([position.x as f32, position.y as f32])),
+            WindowEvent::MouseInput { state, button: MouseButton::Left, .. } => self.button(*state == ElementState::Pressed),
+            WindowEvent::KeyboardInput { input, .. }
+                if input.state == ElementState::Pressed && input.virtual_keycode == Some(VirtualKeyCode::Escape) => self.cancel(),
+            WindowEvent::Focused(false) => self.cancel(),
+            _ => None
+        }
+    }
+}
+
+///
+/// Panics if `threshold` is not a valid drag-select threshold, i.e. negative or not finite.
+///
+pub fn validate_threshold(threshold: &f32) {
+    assert!(*threshold >= 0.0 && threshold.is_finite(), "drag-select threshold must be non-negative and finite, but is {}", threshold)
+}
@@ -0,0 +1,166 @@
+//!
+//! This module provides [`EventCounters`], the numbers of events dispatched
+//! by the event loop and of callbacks run, enabled by [`WindowBuilder::count_events`](super::build::WindowBuilder::count_events).
+//!
+//! Useful both in bug reports(next to [`doctor`](super::doctor())) and in tests.
+//!
+//! # Examples
+//!
+//! ```no_run
+//! # use rokoko::prelude::*;
+//! Window::new()
+//!     .count_events()
+//!     .on_close(|w| {
+//!         // e.g. `resized=1 key=0 mouse=42 redraw=1 user=0 close_requested=1 | on_close=1`
+//!         println!("{}", w.event_counters());
+//!         assert_eq!(w.event_counters().runs("on_close"), Some(1));
+//!         w.close()
+//!     })
+//!     .create()
+//!     .unwrap()
+//! ```
+//!
+
+use core::{
+    cell::Cell,
+    fmt::{self, Display, Formatter}
+};
+use winit::event::{Event, WindowEvent};
+use super::data::UserEvent;
+
+///
+/// Numbers of events dispatched by the event loop, by category, and of callbacks run.
+///
+/// All zero and without callbacks unless [`WindowBuilder::count_events`](super::build::WindowBuilder::count_events)
+/// is specified, in which case the loop does not count anything at all.
+///
+/// Both are updated before callbacks of an event are called, so they already see the new numbers.
+///
+/// # Examples
+///
+/// ```
+/// use rokoko::window::{event_counters::EventCounters, data::UserEvent};
+/// # extern crate winit;
+/// use winit::{event::{Event, WindowEvent}, window::WindowId, dpi::PhysicalSize};
+///
+/// // SAFETY: only used for comparison, never passed to `winit`
+/// let window_id = unsafe { WindowId::dummy() };
+///
+/// let counters = EventCounters::new(&["on_close", "on_frame"]);
+///
+/// counters.count(&Event::WindowEvent { window_id, event: WindowEvent::Resized(PhysicalSize::new(1, 1)) });
+/// counters.count(&Event::WindowEvent { window_id, event: WindowEvent::CloseRequested });
+/// counters.count(&Event::RedrawRequested(window_id));
+/// counters.count(&Event::UserEvent(UserEvent::Close));
+/// // Not in any category
+/// counters.count(&Event::MainEventsCleared);
+/// counters.count(&Event::WindowEvent { window_id, event: WindowEvent::Focused(true) });
+///
+/// counters.ran("on_frame");
+/// counters.ran("on_frame");
+/// // Not configured, ignored
+/// counters.ran("on_init");
+///
+/// assert_eq!(counters.resized.get(), 1);
+/// assert_eq!(counters.close_requested.get(), 1);
+/// assert_eq!(counters.redraw.get(), 1);
+/// assert_eq!(counters.user.get(), 1);
+/// assert_eq!(counters.key.get() + counters.mouse.get(), 0);
+///
+/// assert_eq!(counters.runs("on_frame"), Some(2));
+/// assert_eq!(counters.runs("on_close"), Some(0));
+/// assert_eq!(counters.runs("on_init"), None);
+///
+/// assert_eq!(
+///     counters.to_string(),
+///     "resized=1 key=0 mouse=0 redraw=1 user=1 close_requested=1 | on_close=0 on_frame=2"
+/// );
+///
+/// // Disabled
+/// let counters = EventCounters::default();
+/// counters.ran("on_frame");
+/// assert_eq!(counters.runs("on_frame"), None);
+/// assert_eq!(counters.to_string(), "resized=0 key=0 mouse=0 redraw=0 user=0 close_requested=0 |");
+/// ```
+///
+#[derive(Debug, Default)]
+pub struct EventCounters {
+    /// `WindowEvent::Resized`
+    pub resized: Cell <u64>,
+
+    /// `WindowEvent::KeyboardInput`
+    pub key: Cell <u64>,
+
+    /// `WindowEvent::CursorMoved`, `WindowEvent::MouseInput` and `WindowEvent::MouseWheel`
+    pub mouse: Cell <u64>,
+
+    /// `Event::RedrawRequested`
+    pub redraw: Cell <u64>,
+
+    /// `Event::UserEvent`, e.g. sent by [`super::Window::close`]
+    pub user: Cell <u64>,
+
+    /// `WindowEvent::CloseRequested`
+    pub close_requested: Cell <u64>,
+
+    /// Names of the configured callbacks and how many times they ran
+    callbacks: Vec <(&'static str, Cell <u64>)>
+}
+
+impl EventCounters {
+    /// Creates zeroed counters of the given callbacks
+    pub fn new(callbacks: &[&'static str]) -> Self {
+        Self {
+            callbacks: callbacks.iter().map(|&name| (name, Cell::new(0))).collect(),
+            ..Self::default()
+        }
+    }
+
+    /// Counts `event` into its category, if any
+    pub fn count(&self, event: &Event <'_, UserEvent>) {
+        let counter = match event {
+            Event::WindowEvent { event, .. } => match event {
+                WindowEvent::Resized(_) => &self.resized,
+                WindowEvent::KeyboardInput { .. } => &self.key,
+                WindowEvent::CursorMoved { .. } | WindowEvent::MouseInput { .. } | WindowEvent::MouseWheel { .. } => &self.mouse,
+                WindowEvent::CloseRequested => &self.close_requested,
+                _ => return
+            },
+            Event::RedrawRequested(_) => &self.redraw,
+            Event::UserEvent(_) => &self.user,
+            _ => return
+        };
+        counter.set(counter.get() + 1)
+    }
+
+    /// Counts a run of `callback`, ignored if it is not configured
+    pub fn ran(&self, callback: &str) {
+        if let Some((_, counter)) = self.callbacks.iter().find(|(name, _)| *name == callback) {
+            counter.set(counter.get() + 1)
+        }
+    }
+
+    /// Returns how many times `callback` ran, or `None` if it is not configured or nothing is counted
+    pub fn runs(&self, callback: &str) -> Option <u64> {
+        self.callbacks.iter().find(|(name, _)| *name == callback).map(|(_, counter)| counter.get())
+    }
+}
+
+impl Display for EventCounters {
+    fn fmt(&self, f: &mut Formatter <'_>) -> fmt::Result {
+        write!(
+            f,
+            "resized={} key={} mouse={} redraw={} user={} close_requested={} |",
+            self.resized.get(),
+            self.key.get(),
+            self.mouse.get(),
+            self.redraw.get(),
+            self.user.get(),
+            self.close_requested.get()
+        )?;
+        for (name, counter) in &self.callbacks {
+            write!(f, " {name}={}", counter.get())?
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,229 @@
+//!
+//! This module manages the `winit` event loop behind [`WindowBuilder::create`].
+//!
+//! `winit` allows only one event loop per process, and on some platforms
+//! it cannot be recreated even after the previous one has finished.
+//! So the loop is created by the first `create` and then:
+//! - where `winit` supports `run_return`(Windows, macOS, Linux, BSDs and Android) it is run with it
+//!   and kept for the next `create`, which thus returns once the window is closed,
+//!   and windows can be opened one after another;
+//! - elsewhere(iOS and the web, where the loop cannot give control back) it is run with `run`,
+//!   so `create` never returns, and any other `create` fails with [`Error::EventLoopAlreadyUsed`].
+//!
+//! The loop is kept per thread, since `winit` requires it to be used on the thread
+//! it was created on(the main one on most platforms), so `create` on any other thread
+//! fails with [`Error::EventLoopAlreadyUsed`] as well.
+//!
+//! [`REUSABLE`] tells which case applies, and `WindowBuilder::create_returning`
+//! is available only in the first one.
+//!
+//! Several windows can also be run by the same loop at once, see [`super::Windows`].
+//!
+//! # Examples
+//!
+//! Sequential windows:
+//!
+//! ```no_run
+//! use rokoko::window::{Window, event_loop::REUSABLE};
+//! use std::cell::Cell;
+//!
+//! let inits = Cell::new(0);
+//! let exits = Cell::new(0);
+//!
+//! for title in ["first", "second"] {
+//!     let inits = &inits as *const Cell <u32>;
+//!     let exits = &exits as *const Cell <u32>;
+//!
+//!     // SAFETY: `create` returns only after the callbacks are dropped, so counters outlive them
+//!     Window::new()
+//!         .title(title)
+//!         .on_init(move |w| {
+//!             unsafe { (*inits).set((*inits).get() + 1) };
+//!             w.close()
+//!         })
+//!         .on_exit(move |_| unsafe { (*exits).set((*exits).get() + 1) })
+//!         .create()
+//!         .unwrap();
+//! }
+//!
+//! // Only reached where the loop is reusable
+//! assert!(REUSABLE);
+//! assert_eq!((inits.get(), exits.get()), (2, 2));
+//! ```
+//!
+//! [`WindowBuilder::create`]: super::build::WindowBuilder::create
+//!
+
+use super::data::UserEvent;
+use core::fmt;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use winit::{
+    error::OsError,
+    event::Event,
+    event_loop::{EventLoop, EventLoopWindowTarget, ControlFlow}
+};
+
+///
+/// `true` if the event loop is reused, so `create` returns and can be called again.
+///
+/// Set by the build script for the target, along with `cfg(reusable_event_loop)` gating the code that relies on it,
+/// e.g. [`WindowBuilder::create_returning`](super::build::WindowBuilder::create_returning).
+///
+pub const REUSABLE: bool = cfg!(reusable_event_loop);
+
+///
+/// Errors of [`WindowBuilder::create`](super::build::WindowBuilder::create).
+///
+#[derive(Debug)]
+pub enum Error {
+    /// The platform failed to create the window
+    Os(OsError),
+
+    ///
+    /// The event loop has already been used by a previous `create`
+    /// and cannot be reused here.
+    ///
+    /// See module documentation for more information.
+    ///
+    EventLoopAlreadyUsed
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter <'_>) -> fmt::Result {
+        match self {
+            Error::Os(e) => write!(f, "cannot create the window: {e}"),
+            Error::EventLoopAlreadyUsed => f.write_str("the event loop has already been used by a previous `create()`; \
+                it can only be reused where `winit` supports `run_return`(not on iOS or the web), \
+                on the thread that created it, so only one window can be created per process here")
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option <&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Os(e) => Some(e),
+            Error::EventLoopAlreadyUsed => None
+        }
+    }
+}
+
+impl From <OsError> for Error {
+    fn from(e: OsError) -> Self {
+        Error::Os(e)
+    }
+}
+
+/// Whether an event loop has ever been created in the process
+static CREATED: AtomicBool = AtomicBool::new(false);
+
+/// The last generation started
+static GENERATIONS: AtomicUsize = AtomicUsize::new(0);
+
+///
+/// Starts the generation of a window being created.
+///
+pub(crate) fn begin_generation() -> usize {
+    let generation = GENERATIONS.fetch_add(1, Ordering::SeqCst) + 1;
+    LIVE.with(|live| live.borrow_mut().push(generation));
+    generation
+}
+
+///
+/// Ends `generation`, so that handles to its window are invalidated.
+///
+/// Does nothing if it has already been ended.
+///
+pub(crate) fn end_generation(generation: usize) {
+    LIVE.with(|live| live.borrow_mut().retain(|g| *g != generation))
+}
+
+/// Returns `true` if the window of `generation` is being run
+pub(crate) fn is_live(generation: usize) -> bool {
+    LIVE.with(|live| live.borrow().contains(&generation))
+}
+
+thread_local! {
+    ///
+    /// The generations of the windows being run, see [`super::WeakWindow`].
+    ///
+    /// Kept per thread, as windows are run on the thread of their loop.
+    ///
+    static LIVE: core::cell::RefCell <Vec <usize>> = core::cell::RefCell::new(Vec::new());
+
+    /// The loop kept for the next `create`, see [`REUSABLE`]
+    static STASH: core::cell::RefCell <Option <EventLoop <UserEvent>>> = core::cell::RefCell::new(None);
+}
+
+///
+/// Returns the kept event loop or creates the first one.
+///
+pub(crate) fn acquire() -> Result <EventLoop <UserEvent>, Error> {
+    if let Some(event_loop) = STASH.with(|s| s.borrow_mut().take()) {
+        return Ok(event_loop)
+    }
+    if CREATED.swap(true, Ordering::SeqCst) {
+        return Err(Error::EventLoopAlreadyUsed)
+    }
+    Ok(EventLoop::with_user_event())
+}
+
+/// Returns `true` if an event loop has ever been created in the process
+pub(crate) fn created() -> bool {
+    CREATED.load(Ordering::SeqCst)
+}
+
+///
+/// Calls `f` with the kept event loop, creating it if none has been created yet,
+/// and keeps the loop for the next `create`.
+///
+/// Unlike [`acquire`], failure of the platform to create the loop(e.g. without a display server)
+/// is reported as an error instead of a panic, so that a later `create` still panics with the cause.
+/// The panic message is still printed by the panic hook.
+///
+pub(crate) fn inspect <R> (f: impl FnOnce(&EventLoopWindowTarget <UserEvent>) -> R) -> Result <R, String> {
+    let event_loop = match STASH.with(|s| s.borrow_mut().take()) {
+        Some(event_loop) => event_loop,
+        None => {
+            if CREATED.swap(true, Ordering::SeqCst) {
+                return Err(Error::EventLoopAlreadyUsed.to_string())
+            }
+            match std::panic::catch_unwind(EventLoop::with_user_event) {
+                Ok(event_loop) => event_loop,
+                Err(payload) => {
+                    CREATED.store(false, Ordering::SeqCst);
+                    return Err(match payload.downcast::<String>() {
+                        Ok(message) => *message,
+                        Err(payload) => payload.downcast_ref::<&str>().map_or("cannot create the event loop", |m| m).to_string()
+                    })
+                }
+            }
+        }
+    };
+
+    let result = f(&event_loop);
+    STASH.with(|s| *s.borrow_mut() = Some(event_loop));
+    Ok(result)
+}
+
+///
+/// Runs `event_loop` until [`ControlFlow::Exit`].
+///
+/// Returns afterwards and keeps the loop for the next `create` if it is [`REUSABLE`],
+/// never returns otherwise.
+///
+pub(crate) fn run <F> (event_loop: EventLoop <UserEvent>, handler: F) -> Result <(), Error>
+where F: 'static + FnMut(Event <'_, UserEvent>, &EventLoopWindowTarget <UserEvent>, &mut ControlFlow) {
+    cfg_if::cfg_if! {
+        if #[cfg(reusable_event_loop)] {
+            use winit::platform::run_return::EventLoopExtRunReturn;
+
+            let mut event_loop = event_loop;
+            event_loop.run_return(handler);
+            STASH.with(|s| *s.borrow_mut() = Some(event_loop));
+            Ok(())
+        } else {
+            event_loop.run(handler)
+        }
+    }
+}
@@ -0,0 +1,266 @@
+//!
+//! This module provides [`EventQueue`] - a bounded queue of events
+//! sent to the event loop from other threads.
+//!
+//! `winit`'s proxy is unbounded, so a fast producer can flood the event loop.
+//! Instead, events are kept in an [`EventQueue`] and only a wake-up signal
+//! is sent through the proxy, once the queue becomes non-empty.
+//! The loop then drains the whole queue at once.
+//!
+//! What happens when the queue is full is decided by [`Backpressure`].
+//!
+//! # Implementation
+//!
+//! The queue is a [`VecDeque`] behind a [`Mutex`], not a lock-free ring:
+//! there may be any number of producers, [`Backpressure::Block`] needs
+//! a [`Condvar`] anyway, and the loop takes the lock only once per drain.
+//!
+//! # Examples
+//!
+//! ```
+//! use rokoko::window::event_queue::{EventQueue, Backpressure};
+//! use std::{sync::Arc, thread};
+//!
+//! let queue = Arc::new(EventQueue::new(16, Backpressure::Block));
+//!
+//! let producer = {
+//!     let queue = queue.clone();
+//!     thread::spawn(move || for i in 0..100 {
+//!         queue.push(i);
+//!     })
+//! };
+//!
+//! // The event loop
+//! let mut received = Vec::new();
+//! while received.len() < 100 {
+//!     received.extend(queue.drain());
+//!     thread::yield_now()
+//! }
+//! producer.join().unwrap();
+//!
+//! // Nothing is lost and the order is kept
+//! assert_eq!(received, (0..100).collect::<Vec <_>>());
+//! assert_eq!(queue.dropped(), 0);
+//! ```
+//!
+
+use std::{
+    collections::VecDeque,
+    sync::{Mutex, MutexGuard, Condvar}
+};
+
+///
+/// What to do when an event is pushed into a full [`EventQueue`].
+///
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Backpressure {
+    /// Drop the pushed event
+    DropNewest,
+
+    /// Drop the oldest event in the queue to make room for the pushed one
+    DropOldest,
+
+    ///
+    /// Block the producer until there is room.
+    ///
+    /// Must not be used to push from the event loop thread itself,
+    /// since nothing would drain the queue then.
+    ///
+    Block
+}
+
+struct State <T> {
+    events: VecDeque <T>,
+    dropped: u64,
+    closed: bool
+}
+
+///
+/// A bounded multi-producer queue of events.
+///
+/// See module documentation for more information.
+///
+pub struct EventQueue <T> {
+    state: Mutex <State <T>>,
+    not_full: Condvar,
+    capacity: usize,
+    policy: Backpressure
+}
+
+impl <T> EventQueue <T> {
+    ///
+    /// Creates an empty queue.
+    ///
+    /// # Panics
+    /// Panics if `capacity` is `0`.
+    ///
+    pub fn new(capacity: usize, policy: Backpressure) -> Self {
+        assert!(capacity > 0, "event queue capacity must be positive");
+        Self {
+            state: Mutex::new(State {
+                events: VecDeque::with_capacity(capacity),
+                dropped: 0,
+                closed: false
+            }),
+            not_full: Condvar::new(),
+            capacity,
+            policy
+        }
+    }
+
+    /// Returns the maximum number of events in the queue
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Returns what happens when the queue is full
+    pub fn policy(&self) -> Backpressure {
+        self.policy
+    }
+
+    /// Returns the number of events in the queue
+    pub fn len(&self) -> usize {
+        self.lock().events.len()
+    }
+
+    /// Returns `true` if there are no events in the queue
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the number of events dropped so far, either due to backpressure or closing
+    pub fn dropped(&self) -> u64 {
+        self.lock().dropped
+    }
+
+    ///
+    /// Pushes `event` into the queue according to the policy.
+    ///
+    /// Returns `true` if the queue was empty, i.e. the consumer needs to be woken up.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rokoko::window::event_queue::{EventQueue, Backpressure};
+    ///
+    /// // Newest are dropped
+    /// let queue = EventQueue::new(2, Backpressure::DropNewest);
+    /// assert!(queue.push(1));
+    /// assert!(!queue.push(2));
+    /// assert!(!queue.push(3));
+    /// assert_eq!(queue.drain(), [1, 2]);
+    /// assert_eq!(queue.dropped(), 1);
+    ///
+    /// // Oldest are dropped
+    /// let queue = EventQueue::new(2, Backpressure::DropOldest);
+    /// for i in 1..=5 {
+    ///     queue.push(i);
+    /// }
+    /// assert_eq!(queue.drain(), [4, 5]);
+    /// assert_eq!(queue.dropped(), 3);
+    ///
+    /// // Empty again, so the next push wakes the consumer up
+    /// assert!(queue.push(6));
+    /// ```
+    ///
+    /// Blocking:
+    ///
+    /// ```
+    /// use rokoko::window::event_queue::{EventQueue, Backpressure};
+    /// use std::{sync::Arc, thread, time::Duration};
+    ///
+    /// let queue = Arc::new(EventQueue::new(1, Backpressure::Block));
+    /// queue.push(1);
+    ///
+    /// let producer = {
+    ///     let queue = queue.clone();
+    ///     thread::spawn(move || queue.push(2))
+    /// };
+    ///
+    /// // The producer waits for room
+    /// thread::sleep(Duration::from_millis(50));
+    /// assert_eq!(queue.len(), 1);
+    ///
+    /// assert_eq!(queue.drain(), [1]);
+    /// producer.join().unwrap();
+    /// assert_eq!(queue.drain(), [2]);
+    /// assert_eq!(queue.dropped(), 0);
+    /// ```
+    ///
+    pub fn push(&self, event: T) -> bool {
+        let mut state = self.lock();
+
+        if state.events.len() == self.capacity && !state.closed {
+            match self.policy {
+                Backpressure::DropNewest => {
+                    state.dropped += 1;
+                    return false
+                },
+                Backpressure::DropOldest => {
+                    state.events.pop_front();
+                    state.dropped += 1
+                },
+                Backpressure::Block => {
+                    state = self.not_full
+                        .wait_while(state, |s| s.events.len() == self.capacity && !s.closed)
+                        .unwrap_or_else(|e| e.into_inner())
+                }
+            }
+        }
+
+        if state.closed {
+            state.dropped += 1;
+            return false
+        }
+
+        let wake = state.events.is_empty();
+        state.events.push_back(event);
+        wake
+    }
+
+    ///
+    /// Takes all the events from the queue, oldest first.
+    ///
+    pub fn drain(&self) -> Vec <T> {
+        let events = self.lock().events.drain(..).collect();
+        self.not_full.notify_all();
+        events
+    }
+
+    ///
+    /// Closes the queue: events pushed afterwards are dropped
+    /// and blocked producers are released.
+    ///
+    /// Events already in the queue can still be drained.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rokoko::window::event_queue::{EventQueue, Backpressure};
+    /// use std::{sync::Arc, thread};
+    ///
+    /// let queue = Arc::new(EventQueue::new(1, Backpressure::Block));
+    /// queue.push(1);
+    ///
+    /// let producer = {
+    ///     let queue = queue.clone();
+    ///     thread::spawn(move || queue.push(2))
+    /// };
+    ///
+    /// queue.close();
+    /// producer.join().unwrap();
+    ///
+    /// assert_eq!(queue.drain(), [1]);
+    /// assert_eq!(queue.dropped(), 1);
+    /// ```
+    ///
+    pub fn close(&self) {
+        self.lock().closed = true;
+        self.not_full.notify_all()
+    }
+
+    /// Locks the state, ignoring poisoning since it is always consistent
+    fn lock(&self) -> MutexGuard <'_, State <T>> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
@@ -0,0 +1,209 @@
+//!
+//! This module provides [`FullscreenManager`] - the state machine behind
+//! [`WindowBuilder::fullscreen_management`], smoothing over platform quirks of fullscreen:
+//! - on macOS the space-based fullscreen transition races with requests made before the window
+//!   is focused, so the initial request is deferred until the first `Focused(true)`;
+//! - with [`WindowBuilder::minimize_on_focus_loss`](requires `unstable-minimize-on-focus-loss` feature),
+//!   losing focus (e.g. Alt-Tab on Windows) exits fullscreen and then minimizes the window,
+//!   and refocusing restores fullscreen.
+//!
+//! The machine tracks the desired and the actual states separately, and issues at most one
+//! transition at a time: requests and focus changes made while a transition is pending are
+//! only taken into account once the platform reports the transition completed,
+//! see [`FullscreenManager::observed`].
+//!
+//! A change of the actual state that was not requested, e.g. the user leaving fullscreen
+//! with the platform's own controls, becomes the desired state, unless fullscreen is exited for focus loss.
+//!
+//! # Examples
+//!
+//! ```no_run
+//! use rokoko::window::{Window, Key};
+//!
+//! Window::new()
+//!     .fullscreen()
+//!     .fullscreen_management()
+//!     .on_key(|w, key, pressed| if key == Key::F11 && pressed {
+//!         w.set_fullscreen(!w.is_fullscreen())
+//!     })
+//!     .create()
+//!     .unwrap()
+//! ```
+//!
+//! [`WindowBuilder::fullscreen_management`]: super::build::WindowBuilder::fullscreen_management
+//! [`WindowBuilder::minimize_on_focus_loss`]: super::build::WindowBuilder::minimize_on_focus_loss
+//!
+
+use winit::window::{Window as Winit, Fullscreen};
+
+/// Whether the initial request has to wait for the first `Focused(true)` on this platform
+pub const DEFER_UNTIL_FOCUS: bool = cfg!(target_os = "macos");
+
+///
+/// A platform call issued by [`FullscreenManager`].
+///
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Command {
+    /// Enter(`true`) or exit borderless fullscreen on the current monitor
+    SetFullscreen(bool),
+
+    /// Minimize the window
+    Minimize
+}
+
+impl Command {
+    /// Performs the call on `window`
+    pub fn apply(self, window: &Winit) {
+        match self {
+            Self::SetFullscreen(true) => window.set_fullscreen(Some(Fullscreen::Borderless(None))),
+            Self::SetFullscreen(false) => window.set_fullscreen(None),
+            Self::Minimize => window.set_minimized(true)
+        }
+    }
+}
+
+///
+/// The fullscreen state machine, see [module documentation](self).
+///
+/// # Examples
+///
+/// ```
+/// use rokoko::window::fullscreen::{FullscreenManager, Command};
+///
+/// let mut fullscreen = FullscreenManager::new(false, true);
+///
+/// // The request is issued at once, further ones wait for it to complete
+/// assert_eq!(fullscreen.request(true), Some(Command::SetFullscreen(true)));
+/// assert!(fullscreen.is_pending());
+/// assert_eq!(fullscreen.request(true), None);
+///
+/// // Intermediate sizes of an animated transition are not its completion
+/// assert_eq!(fullscreen.observed(false), None);
+/// assert_eq!(fullscreen.observed(true), None);
+/// assert!(!fullscreen.is_pending());
+///
+/// // Alt-Tab: exit, then minimize once exited
+/// assert_eq!(fullscreen.focused(false), Some(Command::SetFullscreen(false)));
+/// assert_eq!(fullscreen.observed(false), Some(Command::Minimize));
+/// assert_eq!(fullscreen.observed(false), None);
+///
+/// // Back: restored
+/// assert_eq!(fullscreen.focused(true), Some(Command::SetFullscreen(true)));
+/// assert_eq!(fullscreen.observed(true), None);
+/// assert!(fullscreen.is_desired());
+///
+/// // Leaving fullscreen with the platform's controls is respected
+/// assert_eq!(fullscreen.observed(false), None);
+/// assert!(!fullscreen.is_desired());
+/// assert_eq!(fullscreen.focused(false), None);
+/// assert_eq!(fullscreen.focused(true), None);
+/// ```
+///
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct FullscreenManager {
+    minimize_on_focus_loss: bool,
+
+    /// Waiting for the first `Focused(true)`
+    deferred: bool,
+
+    /// `None` until the first focus event
+    focused: Option <bool>,
+
+    desired: bool,
+    actual: bool,
+
+    /// The transition issued and not completed yet
+    pending: Option <bool>,
+
+    /// Minimized since the focus was lost
+    minimized: bool
+}
+
+impl FullscreenManager {
+    ///
+    /// Creates a machine of a window that is not fullscreen and is not desired to be.
+    ///
+    /// If `defer_until_focus` is `true`, nothing is issued until the first `Focused(true)`,
+    /// [`DEFER_UNTIL_FOCUS`] tells whether the current platform needs it.
+    ///
+    /// If `minimize_on_focus_loss` is `true`, fullscreen is exited and the window is minimized
+    /// on focus loss, and fullscreen is restored on refocus.
+    ///
+    pub const fn new(defer_until_focus: bool, minimize_on_focus_loss: bool) -> Self {
+        Self {
+            minimize_on_focus_loss,
+            deferred: defer_until_focus,
+            focused: None,
+            desired: false,
+            actual: false,
+            pending: None,
+            minimized: false
+        }
+    }
+
+    /// Returns `true` if fullscreen is desired, whatever the actual state is
+    pub const fn is_desired(&self) -> bool {
+        self.desired
+    }
+
+    /// Returns `true` if a transition has been issued and has not completed yet
+    pub const fn is_pending(&self) -> bool {
+        self.pending.is_some()
+    }
+
+    /// Handles a request to enter(`fullscreen == true`) or exit fullscreen
+    pub fn request(&mut self, fullscreen: bool) -> Option <Command> {
+        self.desired = fullscreen;
+        self.next()
+    }
+
+    /// Handles focus gain(`focused == true`) or loss
+    pub fn focused(&mut self, focused: bool) -> Option <Command> {
+        self.focused = Some(focused);
+        if focused {
+            self.deferred = false;
+            self.minimized = false
+        }
+        self.next()
+    }
+
+    ///
+    /// Handles the actual state reported by the platform, e.g. after every resize.
+    ///
+    /// Completes the pending transition if `fullscreen` is its target, and is ignored otherwise.
+    ///
+    pub fn observed(&mut self, fullscreen: bool) -> Option <Command> {
+        match self.pending {
+            Some(target) if target != fullscreen => return None,
+            Some(_) => self.pending = None,
+            None if fullscreen != self.actual && !self.suspended() && !self.deferred => self.desired = fullscreen,
+            None => ()
+        }
+        self.actual = fullscreen;
+        self.next()
+    }
+
+    /// Fullscreen is exited for focus loss
+    fn suspended(&self) -> bool {
+        self.minimize_on_focus_loss && self.focused == Some(false)
+    }
+
+    /// Issues the next command, if there is nothing pending
+    fn next(&mut self) -> Option <Command> {
+        if self.pending.is_some() || self.deferred {
+            return None
+        }
+
+        let suspended = self.suspended();
+        let target = self.desired && !suspended;
+        if target != self.actual {
+            self.pending = Some(target);
+            Some(Command::SetFullscreen(target))
+        } else if suspended && self.desired && !self.minimized {
+            self.minimized = true;
+            Some(Command::Minimize)
+        } else {
+            None
+        }
+    }
+}
@@ -0,0 +1,349 @@
+//!
+//! This module provides [`GestureRecognizer`] - the state machine behind
+//! [`WindowBuilder::gestures`], turning touches into pinches, pans and taps.
+//!
+//! Touches are tracked by their ids in the order they started, and the machine is:
+//! - idle, until a touch starts;
+//! - pending, while the only touch stays within [`TAP_SLOP`] of where it started:
+//!   ending it within [`TAP_TIMEOUT`] is a [`Gesture::Tap`], counted together with the previous taps
+//!   if it started within the double-tap interval after the previous one ended,
+//!   and not farther than [`DOUBLE_TAP_SLOP`] from it;
+//! - panning, once the only touch has moved farther, reporting a [`Gesture::Pan`] on every move;
+//! - pinching, once a second touch starts, reporting a [`Gesture::Pinch`] whenever one of
+//!   the two oldest touches moves, with the ratio of the new distance between them to the previous one;
+//! - ignoring, once a third touch starts or a touch of a pinch ends, until all the touches end,
+//!   so that lifting fingers one after another does not turn into a pan or a tap.
+//!
+//! Positions are in physical window coordinates.
+//!
+//! Touchpad magnification, which some platforms report instead of touches,
+//! can be fed with [`GestureRecognizer::magnify`]; the `winit` version used does not report it yet.
+//!
+//! # Examples
+//!
+//! ```no_run
+//! use rokoko::prelude::*;
+//! use std::time::Duration;
+//!
+//! Window::new()
+//!     .gestures()
+//!     .double_tap_interval(Duration::from_millis(250))
+//!     .on_pinch(|_, scale_delta, center| println!("zooming by {scale_delta} around {center:?}"))
+//!     .on_pan(|_, delta| println!("panning by {delta:?}"))
+//!     .on_tap(|_, position, count| if count == 2 {
+//!         println!("double tap at {position:?}")
+//!     })
+//!     .create()
+//!     .unwrap()
+//! ```
+//!
+//! [`WindowBuilder::gestures`]: super::build::WindowBuilder::gestures
+//!
+
+use crate::math::vec::vec2;
+use std::time::Duration;
+use winit::event::{WindowEvent, TouchPhase};
+
+/// Interval used unless [`WindowBuilder::double_tap_interval`](super::build::WindowBuilder::double_tap_interval) is specified
+pub const DEFAULT_DOUBLE_TAP_INTERVAL: Duration = Duration::from_millis(300);
+
+/// The longest a touch may last to be a tap
+pub const TAP_TIMEOUT: Duration = Duration::from_millis(500);
+
+/// The farthest a touch may move from where it started to be a tap, in pixels
+pub const TAP_SLOP: f32 = 10.0;
+
+/// The farthest a tap may be from the previous one to be counted together with it, in pixels
+pub const DOUBLE_TAP_SLOP: f32 = 40.0;
+
+/// The smallest distance between the touches of a pinch a scale is computed from, in pixels
+pub const MIN_PINCH_DISTANCE: f32 = 1.0;
+
+///
+/// A gesture recognized by [`GestureRecognizer`], see [module documentation](self).
+///
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Gesture {
+    ///
+    /// The distance between the two oldest touches has been multiplied by `scale_delta`,
+    /// `center` being the middle between them.
+    ///
+    Pinch { scale_delta: f32, center: vec2 },
+
+    /// The only touch has moved by `delta`
+    Pan { delta: vec2 },
+
+    /// A short touch at `position`, `count` being the number of taps in a row, `1` for a single tap
+    Tap { position: vec2, count: u8 }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+struct Touch {
+    id: u64,
+    position: vec2
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+enum State {
+    Idle,
+    Pending { origin: vec2, started: Duration, count: u8 },
+    Panning,
+    Pinching { distance: f32 },
+    Ignoring
+}
+
+///
+/// The gesture state machine, see [module documentation](self).
+///
+/// Times are given as durations since any fixed moment, e.g. the creation of the machine.
+///
+/// # Examples
+///
+/// ```
+/// use rokoko::prelude::*;
+/// use rokoko::window::gestures::{GestureRecognizer, Gesture};
+/// use std::time::Duration;
+///
+/// let ms = Duration::from_millis;
+/// let v = |x: f32, y: f32| vec2::from([x, y]);
+///
+/// let mut gestures = GestureRecognizer::new(ms(300));
+///
+/// // A tap and a double tap
+/// assert_eq!(gestures.start(0, v(100.0, 100.0), ms(0)), None);
+/// assert_eq!(gestures.end(0, ms(50)), Some(Gesture::Tap { position: v(100.0, 100.0), count: 1 }));
+/// assert_eq!(gestures.start(1, v(110.0, 100.0), ms(200)), None);
+/// assert_eq!(gestures.end(1, ms(250)), Some(Gesture::Tap { position: v(110.0, 100.0), count: 2 }));
+///
+/// // A pan
+/// assert_eq!(gestures.start(2, v(0.0, 0.0), ms(1000)), None);
+/// assert_eq!(gestures.moved(2, v(5.0, 0.0), ms(1010)), None);
+/// assert_eq!(gestures.moved(2, v(20.0, 0.0), ms(1020)), Some(Gesture::Pan { delta: v(20.0, 0.0) }));
+/// assert_eq!(gestures.moved(2, v(20.0, 5.0), ms(1030)), Some(Gesture::Pan { delta: v(0.0, 5.0) }));
+///
+/// // Turning into a pinch
+/// assert_eq!(gestures.start(3, v(40.0, 5.0), ms(1040)), None);
+/// assert_eq!(gestures.moved(3, v(60.0, 5.0), ms(1050)), Some(Gesture::Pinch { scale_delta: 2.0, center: v(40.0, 5.0) }));
+/// assert_eq!(gestures.end(2, ms(1060)), None);
+///
+/// // The remaining touch is ignored
+/// assert_eq!(gestures.moved(3, v(100.0, 100.0), ms(1070)), None);
+/// assert_eq!(gestures.end(3, ms(1080)), None);
+/// ```
+///
+#[derive(Debug, Clone, PartialEq)]
+pub struct GestureRecognizer {
+    double_tap_interval: Duration,
+    touches: Vec <Touch>,
+    state: State,
+
+    /// Where and when the last tap ended and the number of taps in a row
+    last_tap: Option <(vec2, Duration, u8)>
+}
+
+impl GestureRecognizer {
+    ///
+    /// Creates an idle machine.
+    ///
+    /// A tap is counted together with the previous one if it starts within `double_tap_interval`
+    /// after the previous one has ended.
+    ///
+    pub const fn new(double_tap_interval: Duration) -> Self {
+        Self {
+            double_tap_interval,
+            touches: Vec::new(),
+            state: State::Idle,
+            last_tap: None
+        }
+    }
+
+    /// Returns the number of touches in progress
+    pub fn touches(&self) -> usize {
+        self.touches.len()
+    }
+
+    /// Handles the start of a touch with `id` at `position`
+    pub fn start(&mut self, id: u64, position: vec2, time: Duration) -> Option <Gesture> {
+        if self.touches.iter().any(|t| t.id == id) {
+            return None
+        }
+        self.touches.push(Touch { id, position });
+
+        self.state = match (self.state, self.touches.len()) {
+            (State::Idle, 1) => {
+                let count = match self.last_tap {
+                    Some((last, ended, count)) if time.saturating_sub(ended) <= self.double_tap_interval
+                        && distance(last, position) <= DOUBLE_TAP_SLOP => count.saturating_add(1),
+                    _ => 1
+                };
+                State::Pending { origin: position, started: time, count }
+            },
+            (State::Pending { .. } | State::Panning, 2) => State::Pinching {
+                distance: distance(self.touches[0].position, self.touches[1].position)
+            },
+            _ => State::Ignoring
+        };
+        if !matches!(self.state, State::Pending { .. }) {
+            self.last_tap = None
+        }
+        None
+    }
+
+    /// Handles movement of the touch with `id` to `position`
+    pub fn moved(&mut self, id: u64, position: vec2, _time: Duration) -> Option <Gesture> {
+        let index = self.touches.iter().position(|t| t.id == id)?;
+        let previous = core::mem::replace(&mut self.touches[index].position, position);
+
+        match self.state {
+            State::Pending { origin, .. } => if distance(origin, position) > TAP_SLOP {
+                self.state = State::Panning;
+                self.last_tap = None;
+                Some(Gesture::Pan { delta: position - origin })
+            } else {
+                None
+            },
+            State::Panning => Some(Gesture::Pan { delta: position - previous }),
+            State::Pinching { distance: previous } if index < 2 => {
+                let [a, b] = [self.touches[0].position, self.touches[1].position];
+                let current = distance(a, b);
+                self.state = State::Pinching { distance: current };
+                if previous < MIN_PINCH_DISTANCE || current < MIN_PINCH_DISTANCE {
+                    None
+                } else {
+                    Some(Gesture::Pinch { scale_delta: current / previous, center: (a + b) * 0.5 })
+                }
+            },
+            _ => None
+        }
+    }
+
+    /// Handles the end(e.g. lifting the finger) of the touch with `id`
+    pub fn end(&mut self, id: u64, time: Duration) -> Option <Gesture> {
+        let index = self.touches.iter().position(|t| t.id == id)?;
+        let touch = self.touches.remove(index);
+
+        let gesture = match self.state {
+            State::Pending { origin, started, count } if time.saturating_sub(started) <= TAP_TIMEOUT => {
+                self.last_tap = Some((origin, time, count));
+                Some(Gesture::Tap { position: touch.position, count })
+            },
+            _ => None
+        };
+        self.state = if self.touches.is_empty() {
+            State::Idle
+        } else {
+            State::Ignoring
+        };
+        gesture
+    }
+
+    ///
+    /// Handles cancellation of the touch with `id` by the platform.
+    ///
+    /// Unlike [`end`](Self::end), never finishes a tap.
+    ///
+    pub fn cancel(&mut self, id: u64) -> Option <Gesture> {
+        let index = self.touches.iter().position(|t| t.id == id)?;
+        self.touches.remove(index);
+
+        self.last_tap = None;
+        self.state = if self.touches.is_empty() {
+            State::Idle
+        } else {
+            State::Ignoring
+        };
+        None
+    }
+
+    ///
+    /// Handles touchpad magnification by `delta` around `center`, e.g. the cursor position.
+    ///
+    /// `delta` is the change of the scale, `0.0` meaning none, as macOS reports it,
+    /// and is normalized to the `scale_delta` of [`Gesture::Pinch`]. Ignored while touches are in progress.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rokoko::prelude::*;
+    /// use rokoko::window::gestures::{GestureRecognizer, Gesture, DEFAULT_DOUBLE_TAP_INTERVAL};
+    ///
+    /// let center = vec2::from([10.0, 20.0]);
+    /// let mut gestures = GestureRecognizer::new(DEFAULT_DOUBLE_TAP_INTERVAL);
+    ///
+    /// assert_eq!(gestures.magnify(0.25, center), Some(Gesture::Pinch { scale_delta: 1.25, center }));
+    /// assert_eq!(gestures.magnify(-0.5, center), Some(Gesture::Pinch { scale_delta: 0.5, center }));
+    ///
+    /// // Shrinking to nothing or less is not a scale
+    /// assert_eq!(gestures.magnify(-1.0, center), None);
+    /// ```
+    ///
+    pub fn magnify(&mut self, delta: f64, center: vec2) -> Option <Gesture> {
+        let scale_delta = (1.0 + delta) as f32;
+        if !self.touches.is_empty() || !(scale_delta > 0.0 && scale_delta.is_finite()) {
+            return None
+        }
+        Some(Gesture::Pinch { scale_delta, center })
+    }
+
+    ///
+    /// Handles a `winit` event at `time`, ignoring unrelated ones.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rokoko::prelude::*;
+    /// use rokoko::window::gestures::{GestureRecognizer, Gesture, DEFAULT_DOUBLE_TAP_INTERVAL};
+    /// use std::time::Duration;
+    /// # extern crate winit;
+    /// use winit::{event::*, dpi::PhysicalPosition};
+    ///
+    /// // SAFETY: only used for comparison, never passed to `winit`
+    /// let device_id = unsafe { DeviceId::dummy() };
+    /// let touch = |id, phase, x, y| WindowEvent::Touch(Touch {
+    ///     device_id,
+    ///     phase,
+    ///     location: PhysicalPosition::new(x, y),
+    ///     force: None,
+    ///     id
+    /// });
+    ///
+    /// let mut gestures = GestureRecognizer::new(DEFAULT_DOUBLE_TAP_INTERVAL);
+    /// let at = Duration::from_millis;
+    ///
+    /// assert_eq!(gestures.update(&touch(7, TouchPhase::Started, 5.0, 5.0), at(0)), None);
+    /// assert_eq!(gestures.update(&WindowEvent::Focused(true), at(10)), None);
+    /// assert_eq!(gestures.update(&touch(7, TouchPhase::Ended, 6.0, 5.0), at(20)), Some(Gesture::Tap {
+    ///     position: vec2::from([6.0, 5.0]),
+    ///     count: 1
+    /// }));
+    ///
+    /// // A cancelled touch is not a tap
+    /// gestures.update(&touch(8, TouchPhase::Started, 5.0, 5.0), at(1000));
+    /// assert_eq!(gestures.update(&touch(8, TouchPhase::Cancelled, 5.0, 5.0), at(1010)), None);
+    /// assert_eq!(gestures.touches(), 0);
+    /// ```
+    ///
+    pub fn update(&mut self, event: &WindowEvent <'_>, time: Duration) -> Option <Gesture> {
+        match event {
+            WindowEvent::Touch(touch) => {
+                let position = vec2::from([touch.location.x as f32, touch.location.y as f32]);
+                match touch.phase {
+                    TouchPhase::Started => self.start(touch.id, position, time),
+                    TouchPhase::Moved => self.moved(touch.id, position, time),
+                    TouchPhase::Ended => {
+                        // The last position may differ from the last movement
+                        let moved = self.moved(touch.id, position, time);
+                        self.end(touch.id, time).or(moved)
+                    },
+                    TouchPhase::Cancelled => self.cancel(touch.id)
+                }
+            },
+            _ => None
+        }
+    }
+}
+
+fn distance(a: vec2, b: vec2) -> f32 {
+    let d = b - a;
+    (d[0] * d[0] + d[1] * d[1]).sqrt()
+}
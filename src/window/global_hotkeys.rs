@@ -0,0 +1,332 @@
+//!
+//! This module provides [`GlobalHotkeys`] - system-wide hotkeys, pressed even when
+//! the window is not focused, e.g. to toggle a "quake console".
+//!
+//! Hotkeys are registered through a [`HotkeyManager`], implemented for `global_hotkey::GlobalHotKeyManager`
+//! and by anything else able to register them, e.g. a mock in tests.
+//!
+//! - Each hotkey is registered with an id of your own, which presses are reported with.
+//! - An id or a binding registered twice is an [`Error`], just as a failure of the manager,
+//!   e.g. a binding taken by another app.
+//! - Presses come from the manager on a thread of its own, so they are turned into
+//!   [`UserEvent::GlobalHotkey`]s by a [`Forwarder`] and sent to the event loop through its proxy.
+//! - Everything still registered is unregistered on drop.
+//!
+//! Bindings are written as in [`input_map`](super::input_map), e.g. `Ctrl+Shift+K`, but must be keys, not mouse buttons.
+//!
+//! # Examples
+//!
+//! ```
+//! use rokoko::window::{data::UserEvent, global_hotkeys::{GlobalHotkeys, HotkeyManager, Error}, input_map::Binding};
+//!
+//! /// Reports presses of a binding with its index
+//! #[derive(Default)]
+//! struct Mock(Vec <Binding>);
+//!
+//! impl HotkeyManager for Mock {
+//!     fn register(&mut self, binding: Binding) -> Result <u32, Error> {
+//!         self.0.push(binding);
+//!         Ok(self.0.len() as u32 - 1)
+//!     }
+//!
+//!     fn unregister(&mut self, _: Binding) -> Result <(), Error> {
+//!         Ok(())
+//!     }
+//! }
+//!
+//! let mut hotkeys = GlobalHotkeys::new(Mock::default());
+//! hotkeys.register("Ctrl+Shift+K".parse()?, 7)?;
+//! assert_eq!(hotkeys.register("Alt+F4".parse()?, 7), Err(Error::DuplicateId(7)));
+//!
+//! // On the listener thread
+//! let mut sent = Vec::new();
+//! hotkeys.forwarder().forward([0, 1], |event| {
+//!     sent.push(event);
+//!     Ok::<_, ()>(())
+//! });
+//! assert!(matches!(sent[..], [UserEvent::GlobalHotkey(7)]));
+//! # Ok::<(), Error>(())
+//! ```
+//!
+
+use core::fmt::{self, Display, Formatter};
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex, PoisonError}
+};
+use super::{
+    data::UserEvent,
+    input_map::{Binding, Input, ParseError}
+};
+
+///
+/// Registers system-wide hotkeys, see the [module documentation](self).
+///
+pub trait HotkeyManager {
+    ///
+    /// Registers `binding` system-wide, returning the id its presses are reported with,
+    /// unique among the bindings registered.
+    ///
+    fn register(&mut self, binding: Binding) -> Result <u32, Error>;
+
+    /// Unregisters `binding`, previously registered with [`HotkeyManager::register`]
+    fn unregister(&mut self, binding: Binding) -> Result <(), Error>;
+}
+
+///
+/// An error of registering or unregistering a global hotkey.
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    /// The id is registered already
+    DuplicateId(u32),
+
+    /// The binding is registered already, with the id given
+    DuplicateBinding { binding: Binding, id: u32 },
+
+    /// The id is not registered
+    UnknownId(u32),
+
+    /// The binding cannot be a global hotkey, e.g. a mouse button
+    Unsupported(Binding),
+
+    /// The binding failed to parse
+    Parse(ParseError),
+
+    /// The manager failed, e.g. the binding is taken by another app
+    Manager(String)
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter <'_>) -> fmt::Result {
+        match self {
+            Self::DuplicateId(id) => write!(f, "global hotkey id {id} is registered already"),
+            Self::DuplicateBinding { binding, id } => write!(f, "global hotkey `{binding}` is registered already, with id {id}"),
+            Self::UnknownId(id) => write!(f, "global hotkey id {id} is not registered"),
+            Self::Unsupported(binding) => write!(f, "`{binding}` cannot be a global hotkey"),
+            Self::Parse(e) => Display::fmt(e, f),
+            Self::Manager(e) => write!(f, "failed to register a global hotkey: {e}")
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From <ParseError> for Error {
+    fn from(e: ParseError) -> Self {
+        Self::Parse(e)
+    }
+}
+
+/// Ids of the manager to ids of the user
+type Routes = Arc <Mutex <HashMap <u32, u32>>>;
+
+///
+/// Global hotkeys registered through `M`, see the [module documentation](self).
+///
+pub struct GlobalHotkeys <M: HotkeyManager> {
+    manager: M,
+
+    /// Ids of the user to the bindings and the ids of the manager
+    registered: HashMap <u32, (Binding, u32)>,
+
+    routes: Routes
+}
+
+impl <M: HotkeyManager> GlobalHotkeys <M> {
+    /// Creates an empty set of hotkeys registered through `manager`
+    pub fn new(manager: M) -> Self {
+        Self {
+            manager,
+            registered: HashMap::new(),
+            routes: Routes::default()
+        }
+    }
+
+    ///
+    /// Registers `binding` system-wide, reporting its presses with `id`.
+    ///
+    /// Nothing is registered on an error.
+    ///
+    /// # Errors
+    ///
+    /// [`Error::DuplicateId`] or [`Error::DuplicateBinding`] if either is registered already,
+    /// [`Error::Unsupported`] for a mouse button, or whatever the manager fails with.
+    ///
+    pub fn register(&mut self, binding: Binding, id: u32) -> Result <(), Error> {
+        if self.registered.contains_key(&id) {
+            return Err(Error::DuplicateId(id))
+        }
+        if let Some((&id, _)) = self.registered.iter().find(|(_, (b, _))| *b == binding) {
+            return Err(Error::DuplicateBinding { binding, id })
+        }
+        if let Input::Mouse(_) = binding.input {
+            return Err(Error::Unsupported(binding))
+        }
+
+        let manager_id = self.manager.register(binding)?;
+        self.registered.insert(id, (binding, manager_id));
+        self.routes().insert(manager_id, id);
+        Ok(())
+    }
+
+    ///
+    /// Unregisters the hotkey of `id`, returning its binding.
+    ///
+    /// Its presses are not forwarded anymore, even if the manager fails to unregister it.
+    ///
+    /// # Errors
+    ///
+    /// [`Error::UnknownId`] if `id` is not registered, or whatever the manager fails with.
+    ///
+    pub fn unregister(&mut self, id: u32) -> Result <Binding, Error> {
+        let (binding, manager_id) = self.registered.remove(&id).ok_or(Error::UnknownId(id))?;
+        self.routes().remove(&manager_id);
+        self.manager.unregister(binding).map(|()| binding)
+    }
+
+    /// Returns the binding registered with `id`, if any
+    pub fn binding(&self, id: u32) -> Option <Binding> {
+        self.registered.get(&id).map(|&(binding, _)| binding)
+    }
+
+    /// Returns the ids registered, in no particular order
+    pub fn ids(&self) -> impl Iterator <Item = u32> + '_ {
+        self.registered.keys().copied()
+    }
+
+    /// Returns a forwarder of presses, which sees later registrations as well
+    pub fn forwarder(&self) -> Forwarder {
+        Forwarder(self.routes.clone())
+    }
+
+    fn routes(&self) -> std::sync::MutexGuard <'_, HashMap <u32, u32>> {
+        self.routes.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl <M: HotkeyManager> Drop for GlobalHotkeys <M> {
+    fn drop(&mut self) {
+        self.routes().clear();
+        for (_, (binding, _)) in self.registered.drain() {
+            // Nowhere to report to
+            let _ = self.manager.unregister(binding);
+        }
+    }
+}
+
+///
+/// Turns presses reported by a [`HotkeyManager`] into [`UserEvent::GlobalHotkey`]s,
+/// see [`GlobalHotkeys::forwarder`].
+///
+/// Can be sent to the thread the manager reports presses on.
+///
+#[derive(Clone)]
+pub struct Forwarder(Routes);
+
+impl Forwarder {
+    ///
+    /// Returns the event for a press reported with `manager_id`,
+    /// `None` if it is not registered(anymore).
+    ///
+    pub fn event(&self, manager_id: u32) -> Option <UserEvent> {
+        let routes = self.0.lock().unwrap_or_else(PoisonError::into_inner);
+        routes.get(&manager_id).map(|&id| UserEvent::GlobalHotkey(id))
+    }
+
+    ///
+    /// Sends the events for `presses` reported by the manager, skipping those not registered,
+    /// until either `presses` end or `send` fails, e.g. once the event loop is closed.
+    ///
+    pub fn forward <E> (&self, presses: impl IntoIterator <Item = u32>, mut send: impl FnMut(UserEvent) -> Result <(), E>) {
+        for manager_id in presses {
+            if let Some(event) = self.event(manager_id) {
+                if send(event).is_err() {
+                    return
+                }
+            }
+        }
+    }
+
+    ///
+    /// Spawns a thread forwarding the presses of `global_hotkey` to `proxy`,
+    /// until the event loop is closed.
+    ///
+    pub fn spawn(self, proxy: winit::event_loop::EventLoopProxy <UserEvent>) -> std::thread::JoinHandle <()> {
+        std::thread::spawn(move || {
+            let presses = global_hotkey::GlobalHotKeyEvent::receiver().iter().map(|event| event.id);
+            self.forward(presses, |event| proxy.send_event(event))
+        })
+    }
+}
+
+impl HotkeyManager for global_hotkey::GlobalHotKeyManager {
+    fn register(&mut self, binding: Binding) -> Result <u32, Error> {
+        let hotkey = backend::hotkey(binding)?;
+        let id = hotkey.id();
+        global_hotkey::GlobalHotKeyManager::register(self, hotkey).map_err(|e| Error::Manager(e.to_string()))?;
+        Ok(id)
+    }
+
+    fn unregister(&mut self, binding: Binding) -> Result <(), Error> {
+        let hotkey = backend::hotkey(binding)?;
+        global_hotkey::GlobalHotKeyManager::unregister(self, hotkey).map_err(|e| Error::Manager(e.to_string()))
+    }
+}
+
+/// Conversions into `global_hotkey`'s types
+mod backend {
+    use global_hotkey::hotkey::{HotKey, Code, Modifiers as Mods};
+    use super::{Binding, Error, Input};
+    use super::super::input_map::{Key, Modifiers};
+
+    macro_rules! codes {
+        ($( $key:ident => $code:ident ),* $(,)?) => {
+            /// Returns the physical key code of `key`, if there is one
+            fn code(key: Key) -> Option <Code> {
+                Some(match key {
+                    $( Key::$key => Code::$code, )*
+                    _ => return None
+                })
+            }
+        };
+    }
+
+    codes! {
+        A => KeyA, B => KeyB, C => KeyC, D => KeyD, E => KeyE, F => KeyF, G => KeyG, H => KeyH, I => KeyI,
+        J => KeyJ, K => KeyK, L => KeyL, M => KeyM, N => KeyN, O => KeyO, P => KeyP, Q => KeyQ, R => KeyR,
+        S => KeyS, T => KeyT, U => KeyU, V => KeyV, W => KeyW, X => KeyX, Y => KeyY, Z => KeyZ,
+        Key0 => Digit0, Key1 => Digit1, Key2 => Digit2, Key3 => Digit3, Key4 => Digit4,
+        Key5 => Digit5, Key6 => Digit6, Key7 => Digit7, Key8 => Digit8, Key9 => Digit9,
+        F1 => F1, F2 => F2, F3 => F3, F4 => F4, F5 => F5, F6 => F6,
+        F7 => F7, F8 => F8, F9 => F9, F10 => F10, F11 => F11, F12 => F12,
+        Escape => Escape, Space => Space, Tab => Tab, Return => Enter, Back => Backspace,
+        Insert => Insert, Delete => Delete, Home => Home, End => End, PageUp => PageUp, PageDown => PageDown,
+        Left => ArrowLeft, Right => ArrowRight, Up => ArrowUp, Down => ArrowDown,
+        Grave => Backquote, Minus => Minus, Equals => Equal, Comma => Comma, Period => Period, Slash => Slash,
+        Semicolon => Semicolon, Apostrophe => Quote, LBracket => BracketLeft, RBracket => BracketRight, Backslash => Backslash,
+        Snapshot => PrintScreen, Pause => Pause
+    }
+
+    /// Returns the hotkey of `binding`
+    pub(super) fn hotkey(binding: Binding) -> Result <HotKey, Error> {
+        let key = match binding.input {
+            Input::Key(key) => key,
+            Input::Mouse(_) => return Err(Error::Unsupported(binding))
+        };
+        let code = code(key).ok_or(Error::Unsupported(binding))?;
+
+        let mut mods = Mods::empty();
+        for (modifier, to) in [
+            (Modifiers::CTRL, Mods::CONTROL),
+            (Modifiers::SHIFT, Mods::SHIFT),
+            (Modifiers::ALT, Mods::ALT),
+            (Modifiers::LOGO, Mods::META)
+        ] {
+            if binding.modifiers.contains(modifier) {
+                mods |= to
+            }
+        }
+        Ok(HotKey::new((!mods.is_empty()).then_some(mods), code))
+    }
+}
@@ -0,0 +1,734 @@
+//!
+//! This module provides [`ActionMap`], bindings of keys and mouse buttons
+//! to named actions, see [`WindowBuilder::action_map`](super::build::WindowBuilder::action_map).
+//!
+//! Instead of matching keys in callbacks, actions are bound once
+//! and then reported to [`WindowBuilder::on_action`](super::build::WindowBuilder::on_action)
+//! or polled with [`Window::action_held`](super::Window::action_held).
+//!
+//! It also brings scroll deltas to a single unit, see [`scroll_lines`].
+//!
+//! # Resolution
+//!
+//! - A binding matches a press of its input if all of its [`Modifiers`] are held,
+//!   other modifiers may be held too, e.g. `S` matches `Shift+S`.
+//! - If several bindings of the input match, only the most specific ones are used,
+//!   i.e. those with the most modifiers, e.g. `Ctrl+S` wins over `S`.
+//! - Modifiers are checked on press only: an action is held until the very input
+//!   that triggered it is released, even if modifiers are released first.
+//! - Key repeats are suppressed: an action is pressed once, however long the key is held.
+//! - An action held by several inputs at once is reported pressed on the first press
+//!   and released on the last release.
+//! - Everything is released when the window loses focus.
+//!
+//! # Format
+//!
+//! Bindings are written as modifiers and an input joined with `+`, e.g. `Ctrl+Shift+S`,
+//! see [`Binding`]'s `Display` and `FromStr`, so they can be stored in configuration files.
+//!
+//! # Examples
+//!
+//! ```
+//! use rokoko::window::input_map::{ActionMap, Bind, Key, Modifiers, MouseButton};
+//!
+//! let mut map = ActionMap::new();
+//! map.bind("jump", Key::Space)
+//!     .bind("jump", MouseButton::Right)
+//!     .bind("save", Key::S.with(Modifiers::CTRL))
+//!     .bind("back", Key::S)
+//!     .bind("quit", "Ctrl+Q".parse::<rokoko::window::input_map::Binding>().unwrap());
+//!
+//! assert_eq!(map.press(Key::Space), [("jump", true)]);
+//! // Repeats
+//! assert_eq!(map.press(Key::Space), []);
+//! assert!(map.held("jump"));
+//!
+//! // Held by the mouse as well
+//! assert_eq!(map.press(MouseButton::Right), []);
+//! assert_eq!(map.release(Key::Space), []);
+//! assert_eq!(map.release(MouseButton::Right), [("jump", false)]);
+//! assert!(!map.held("jump"));
+//!
+//! // The most specific binding wins
+//! assert_eq!(map.press(Key::S), [("back", true)]);
+//! assert_eq!(map.release(Key::S), [("back", false)]);
+//! map.set_modifiers(Modifiers::CTRL);
+//! assert_eq!(map.press(Key::S), [("save", true)]);
+//! assert_eq!(map.release(Key::S), [("save", false)]);
+//!
+//! // Rebinding
+//! map.rebind("jump", Key::J);
+//! assert_eq!(map.bindings("jump").collect::<Vec <_>>(), [Key::J.into()]);
+//! ```
+//!
+//! WASD movement, switched to the arrows and back with `Tab`:
+//!
+//! ```no_run
+//! # use rokoko::prelude::*;
+//! use rokoko::window::input_map::{ActionMap, Key};
+//!
+//! const MOVES: [&str; 4] = ["left", "right", "up", "down"];
+//! const WASD: [Key; 4] = [Key::A, Key::D, Key::W, Key::S];
+//! const ARROWS: [Key; 4] = [Key::Left, Key::Right, Key::Up, Key::Down];
+//!
+//! let mut map = ActionMap::new();
+//! for (&action, key) in MOVES.iter().zip(WASD) {
+//!     map.bind(action, key);
+//! }
+//! map.bind("switch", Key::Tab);
+//!
+//! let mut position = vec2::from([0.0, 0.0]);
+//! let mut wasd = true;
+//! Window::new()
+//!     .action_map(map)
+//!     .on_action(move |w, action, pressed| if action == "switch" && pressed {
+//!         wasd = !wasd;
+//!         let mut map = w.action_map_mut();
+//!         for (&action, key) in MOVES.iter().zip(if wasd { WASD } else { ARROWS }) {
+//!             map.rebind(action, key);
+//!         }
+//!         println!("{map:?}")
+//!     })
+//!     .on_frame(move |w, dt| {
+//!         let axis = |neg, pos| (w.action_held(pos) as i8 - w.action_held(neg) as i8) as f32;
+//!         position += vec2::from([axis("left", "right"), axis("down", "up")]) * dt;
+//!     })
+//!     .create()
+//!     .unwrap()
+//! ```
+//!
+
+use core::{
+    fmt::{self, Debug, Display, Formatter},
+    ops::BitOr,
+    str::FromStr
+};
+use winit::event::{WindowEvent, ElementState, ModifiersState, MouseScrollDelta};
+use crate::math::vec::vec2;
+
+pub use winit::event::{VirtualKeyCode as Key, MouseButton};
+
+///
+/// A set of modifier keys.
+///
+/// Written as names joined with `+`, e.g. `Ctrl+Shift`, in the order of the constants;
+/// parsing is case-insensitive and accepts `Control`, `Option`, `Super`, `Win`, `Cmd` and `Meta` as well.
+///
+/// # Examples
+///
+/// ```
+/// use rokoko::window::input_map::Modifiers;
+///
+/// let both = Modifiers::CTRL | Modifiers::SHIFT;
+/// assert!(both.contains(Modifiers::CTRL));
+/// assert!(!Modifiers::CTRL.contains(both));
+/// assert!(both.contains(Modifiers::NONE));
+/// assert_eq!(both.len(), 2);
+///
+/// assert_eq!(both.to_string(), "Ctrl+Shift");
+/// assert_eq!(Modifiers::NONE.to_string(), "");
+/// assert_eq!("shift + control".parse(), Ok(both));
+/// assert_eq!("Cmd".parse(), Ok(Modifiers::LOGO));
+/// assert_eq!("".parse(), Ok(Modifiers::NONE));
+/// assert!("Ctrl+Hyper".parse::<Modifiers>().is_err());
+/// ```
+///
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Default)]
+pub struct Modifiers(u8);
+
+impl Modifiers {
+    /// No modifiers
+    pub const NONE: Self = Self(0);
+
+    /// Either `Ctrl` key
+    pub const CTRL: Self = Self(1 << 0);
+
+    /// Either `Shift` key
+    pub const SHIFT: Self = Self(1 << 1);
+
+    /// Either `Alt`(`Option` on macOS) key
+    pub const ALT: Self = Self(1 << 2);
+
+    /// Either `Logo`(`Windows`, `Command` on macOS) key
+    pub const LOGO: Self = Self(1 << 3);
+
+    /// Names of the modifiers, in the order they are written
+    const NAMES: [(Self, &'static str); 4] = [
+        (Self::CTRL, "Ctrl"),
+        (Self::SHIFT, "Shift"),
+        (Self::ALT, "Alt"),
+        (Self::LOGO, "Logo")
+    ];
+
+    /// Alternative names accepted by parsing
+    const ALIASES: [(Self, &'static str); 6] = [
+        (Self::CTRL, "Control"),
+        (Self::ALT, "Option"),
+        (Self::LOGO, "Super"),
+        (Self::LOGO, "Win"),
+        (Self::LOGO, "Cmd"),
+        (Self::LOGO, "Meta")
+    ];
+
+    /// Returns `true` if all of `other` are in `self`
+    pub const fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    /// Returns `true` if there are no modifiers
+    pub const fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    /// Returns the number of modifiers
+    pub const fn len(self) -> u32 {
+        self.0.count_ones()
+    }
+}
+
+impl BitOr for Modifiers {
+    type Output = Self;
+
+    fn bitor(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+}
+
+impl From <ModifiersState> for Modifiers {
+    fn from(state: ModifiersState) -> Self {
+        [
+            (Self::CTRL, state.ctrl()),
+            (Self::SHIFT, state.shift()),
+            (Self::ALT, state.alt()),
+            (Self::LOGO, state.logo())
+        ].iter().filter(|(_, held)| *held).fold(Self::NONE, |acc, &(m, _)| acc | m)
+    }
+}
+
+impl Display for Modifiers {
+    fn fmt(&self, f: &mut Formatter <'_>) -> fmt::Result {
+        let mut first = true;
+        for (m, name) in Self::NAMES {
+            if self.contains(m) {
+                if !first {
+                    f.write_str("+")?
+                }
+                f.write_str(name)?;
+                first = false
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Debug for Modifiers {
+    fn fmt(&self, f: &mut Formatter <'_>) -> fmt::Result {
+        write!(f, "Modifiers({self})")
+    }
+}
+
+impl FromStr for Modifiers {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result <Self, ParseError> {
+        if s.trim().is_empty() {
+            return Ok(Self::NONE)
+        }
+        s.split('+').map(str::trim).try_fold(Self::NONE, |acc, name| {
+            Self::NAMES
+                .iter()
+                .chain(Self::ALIASES.iter())
+                .find(|(_, n)| n.eq_ignore_ascii_case(name))
+                .map(|(m, _)| acc | *m)
+                .ok_or_else(|| ParseError::UnknownModifier(String::from(name)))
+        })
+    }
+}
+
+///
+/// Something that can be pressed and released.
+///
+/// Written as the name of the key, e.g. `Space` or `1`, or as `MouseLeft`, `MouseRight`, `MouseMiddle`
+/// and `Mouse<N>` for the other buttons; parsing is case-insensitive and accepts
+/// `Enter`, `Backspace` and `Esc` as well.
+///
+/// # Examples
+///
+/// ```
+/// use rokoko::window::input_map::{Input, Key, MouseButton};
+///
+/// assert_eq!(Input::from(Key::Space).to_string(), "Space");
+/// assert_eq!(Input::from(Key::Key1).to_string(), "1");
+/// assert_eq!(Input::from(MouseButton::Other(4)).to_string(), "Mouse4");
+///
+/// assert_eq!("pageup".parse(), Ok(Input::Key(Key::PageUp)));
+/// assert_eq!("Enter".parse(), Ok(Input::Key(Key::Return)));
+/// assert_eq!("MouseLeft".parse(), Ok(Input::Mouse(MouseButton::Left)));
+/// assert_eq!("mouse7".parse(), Ok(Input::Mouse(MouseButton::Other(7))));
+/// assert!("Mouse".parse::<Input>().is_err());
+/// assert!("Hyper".parse::<Input>().is_err());
+///
+/// // Every key round-trips
+/// for key in Input::KEYS.iter().map(|(key, _)| Input::Key(*key)) {
+///     assert_eq!(key.to_string().parse(), Ok(key))
+/// }
+/// ```
+///
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Input {
+    Key(Key),
+    Mouse(MouseButton)
+}
+
+/// Generates [`Input::KEYS`], named after the variants unless specified
+macro_rules! keys {
+    ($($key:ident $(= $name:literal)?),*) => {
+        impl Input {
+            /// Every key with its name
+            pub const KEYS: &'static [(Key, &'static str)] = &[$((Key::$key, keys!(@name $key $($name)?))),*];
+        }
+    };
+    (@name $key:ident $name:literal) => { $name };
+    (@name $key:ident) => { stringify!($key) };
+}
+
+keys!(
+    Key1 = "1", Key2 = "2", Key3 = "3", Key4 = "4", Key5 = "5", Key6 = "6", Key7 = "7", Key8 = "8",
+    Key9 = "9", Key0 = "0", A, B, C, D, E, F, G, H, I, J, K, L, M, N, O, P, Q, R, S, T, U, V, W, X,
+    Y, Z, Escape, F1, F2, F3, F4, F5, F6, F7, F8, F9, F10, F11, F12, F13, F14, F15, F16, F17, F18,
+    F19, F20, F21, F22, F23, F24, Snapshot, Scroll, Pause, Insert, Home, Delete, End, PageDown,
+    PageUp, Left, Up, Right, Down, Back, Return, Space, Compose, Caret, Numlock, Numpad0, Numpad1,
+    Numpad2, Numpad3, Numpad4, Numpad5, Numpad6, Numpad7, Numpad8, Numpad9, NumpadAdd,
+    NumpadDivide, NumpadDecimal, NumpadComma, NumpadEnter, NumpadEquals, NumpadMultiply,
+    NumpadSubtract, AbntC1, AbntC2, Apostrophe, Apps, Asterisk, At, Ax, Backslash, Calculator,
+    Capital, Colon, Comma, Convert, Equals, Grave, Kana, Kanji, LAlt, LBracket, LControl, LShift,
+    LWin, Mail, MediaSelect, MediaStop, Minus, Mute, MyComputer, NavigateForward, NavigateBackward,
+    NextTrack, NoConvert, OEM102, Period, PlayPause, Plus, Power, PrevTrack, RAlt, RBracket,
+    RControl, RShift, RWin, Semicolon, Slash, Sleep, Stop, Sysrq, Tab, Underline, Unlabeled,
+    VolumeDown, VolumeUp, Wake, WebBack, WebFavorites, WebForward, WebHome, WebRefresh, WebSearch,
+    WebStop, Yen, Copy, Paste, Cut
+);
+
+impl Input {
+    /// Alternative names of keys accepted by parsing
+    const KEY_ALIASES: [(Key, &'static str); 3] = [
+        (Key::Return, "Enter"),
+        (Key::Back, "Backspace"),
+        (Key::Escape, "Esc")
+    ];
+}
+
+impl From <Key> for Input {
+    fn from(key: Key) -> Self {
+        Self::Key(key)
+    }
+}
+
+impl From <MouseButton> for Input {
+    fn from(button: MouseButton) -> Self {
+        Self::Mouse(button)
+    }
+}
+
+impl Display for Input {
+    fn fmt(&self, f: &mut Formatter <'_>) -> fmt::Result {
+        match self {
+            Self::Key(key) => match Self::KEYS.iter().find(|(k, _)| k == key) {
+                Some((_, name)) => f.write_str(name),
+                None => write!(f, "{key:?}")
+            },
+            Self::Mouse(MouseButton::Left) => f.write_str("MouseLeft"),
+            Self::Mouse(MouseButton::Right) => f.write_str("MouseRight"),
+            Self::Mouse(MouseButton::Middle) => f.write_str("MouseMiddle"),
+            Self::Mouse(MouseButton::Other(n)) => write!(f, "Mouse{n}")
+        }
+    }
+}
+
+impl FromStr for Input {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result <Self, ParseError> {
+        let s = s.trim();
+        let unknown = || ParseError::UnknownInput(String::from(s));
+
+        if let Some((key, _)) = Self::KEYS
+            .iter()
+            .chain(Self::KEY_ALIASES.iter())
+            .find(|(_, name)| name.eq_ignore_ascii_case(s)) {
+            return Ok(Self::Key(*key))
+        }
+
+        let button = s
+            .get(..5)
+            .filter(|prefix| prefix.eq_ignore_ascii_case("Mouse"))
+            .map(|_| &s[5..])
+            .ok_or_else(unknown)?;
+        Ok(Self::Mouse(match button.to_ascii_lowercase().as_str() {
+            "left" => MouseButton::Left,
+            "right" => MouseButton::Right,
+            "middle" => MouseButton::Middle,
+            n => MouseButton::Other(n.parse().map_err(|_| unknown())?)
+        }))
+    }
+}
+
+///
+/// An [`Input`] with [`Modifiers`] required for it to trigger an action.
+///
+/// Written as modifiers and the input joined with `+`, e.g. `Ctrl+Shift+S`,
+/// see [`Modifiers`] and [`Input`] for the names.
+///
+/// # Examples
+///
+/// ```
+/// use rokoko::window::input_map::{Binding, Bind, Key, Modifiers, MouseButton, ParseError};
+///
+/// let save = Key::S.with(Modifiers::CTRL | Modifiers::SHIFT);
+/// assert_eq!(save.to_string(), "Ctrl+Shift+S");
+/// assert_eq!("Ctrl+Shift+S".parse(), Ok(save));
+/// assert_eq!(" shift + ctrl + s ".parse(), Ok(save));
+///
+/// assert_eq!("Space".parse(), Ok(Binding::from(Key::Space)));
+/// assert_eq!("Alt+MouseLeft".parse(), Ok(MouseButton::Left.with(Modifiers::ALT)));
+///
+/// assert_eq!("".parse::<Binding>(), Err(ParseError::UnknownInput(String::new())));
+/// assert_eq!("Ctrl+".parse::<Binding>(), Err(ParseError::UnknownInput(String::new())));
+/// assert_eq!("Hyper+S".parse::<Binding>(), Err(ParseError::UnknownModifier(String::from("Hyper"))));
+/// ```
+///
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Binding {
+    pub input: Input,
+    pub modifiers: Modifiers
+}
+
+impl From <Input> for Binding {
+    fn from(input: Input) -> Self {
+        Self {
+            input,
+            modifiers: Modifiers::NONE
+        }
+    }
+}
+
+impl From <Key> for Binding {
+    fn from(key: Key) -> Self {
+        Input::Key(key).into()
+    }
+}
+
+impl From <MouseButton> for Binding {
+    fn from(button: MouseButton) -> Self {
+        Input::Mouse(button).into()
+    }
+}
+
+impl Display for Binding {
+    fn fmt(&self, f: &mut Formatter <'_>) -> fmt::Result {
+        if !self.modifiers.is_empty() {
+            write!(f, "{}+", self.modifiers)?
+        }
+        write!(f, "{}", self.input)
+    }
+}
+
+impl Debug for Binding {
+    fn fmt(&self, f: &mut Formatter <'_>) -> fmt::Result {
+        write!(f, "Binding({self})")
+    }
+}
+
+impl FromStr for Binding {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result <Self, ParseError> {
+        let (modifiers, input) = s.rsplit_once('+').unwrap_or(("", s));
+        Ok(Self {
+            input: input.parse()?,
+            modifiers: modifiers.parse()?
+        })
+    }
+}
+
+///
+/// Adds [`Modifiers`] to an [`Input`], e.g. `Key::S.with(Modifiers::CTRL)`.
+///
+pub trait Bind: Into <Input> {
+    /// Returns the binding of `self` requiring `modifiers`
+    fn with(self, modifiers: Modifiers) -> Binding {
+        Binding {
+            input: self.into(),
+            modifiers
+        }
+    }
+}
+
+impl <I: Into <Input>> Bind for I {}
+
+///
+/// An error of parsing [`Modifiers`], [`Input`] or [`Binding`].
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseError {
+    UnknownModifier(String),
+    UnknownInput(String)
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter <'_>) -> fmt::Result {
+        match self {
+            Self::UnknownModifier(name) => write!(f, "unknown modifier `{name}`, expected one of `Ctrl`, `Shift`, `Alt`, `Logo`"),
+            Self::UnknownInput(name) => write!(f, "unknown key or mouse button `{name}`")
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+///
+/// Bindings of inputs to named actions and the state of the actions.
+///
+/// See [module documentation](self) for the rules and examples.
+///
+#[derive(Clone, Default)]
+pub struct ActionMap {
+    /// In the order of binding
+    bindings: Vec <(&'static str, Binding)>,
+
+    /// Modifiers held at the moment
+    modifiers: Modifiers,
+
+    /// Inputs held at the moment and actions each of them triggered
+    pressed: Vec <(Input, Vec <&'static str>)>
+}
+
+impl ActionMap {
+    /// Creates a map without bindings
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    ///
+    /// Binds `action` to `binding`, in addition to its other bindings.
+    ///
+    /// Several actions may have the same binding, then all of them are triggered together.
+    ///
+    pub fn bind(&mut self, action: &'static str, binding: impl Into <Binding>) -> &mut Self {
+        let binding = binding.into();
+        if !self.bindings.contains(&(action, binding)) {
+            self.bindings.push((action, binding))
+        }
+        self
+    }
+
+    ///
+    /// Removes all the bindings of `action`.
+    ///
+    /// If the action is held it stays so until the input is released.
+    ///
+    pub fn unbind(&mut self, action: &str) -> &mut Self {
+        self.bindings.retain(|(a, _)| *a != action);
+        self
+    }
+
+    /// Replaces all the bindings of `action` with `binding`
+    pub fn rebind(&mut self, action: &'static str, binding: impl Into <Binding>) -> &mut Self {
+        self.unbind(action).bind(action, binding)
+    }
+
+    /// Returns the bindings of `action`, in the order of binding
+    pub fn bindings <'a> (&'a self, action: &'a str) -> impl Iterator <Item = Binding> + 'a {
+        self.bindings.iter().filter(move |(a, _)| *a == action).map(|(_, b)| *b)
+    }
+
+    /// Returns `true` if `action` is held by any input
+    pub fn held(&self, action: &str) -> bool {
+        self.pressed.iter().any(|(_, actions)| actions.contains(&action))
+    }
+
+    /// Sets the modifiers held at the moment, which following presses are checked against
+    pub fn set_modifiers(&mut self, modifiers: Modifiers) {
+        self.modifiers = modifiers
+    }
+
+    ///
+    /// Handles a press of `input`, returning actions that became held, as `(action, true)`.
+    ///
+    /// Repeats, i.e. presses of inputs already held, are ignored.
+    ///
+    pub fn press(&mut self, input: impl Into <Input>) -> Vec <(&'static str, bool)> {
+        let input = input.into();
+        if self.pressed.iter().any(|(i, _)| *i == input) {
+            return Vec::new()
+        }
+
+        let matching = || self.bindings
+            .iter()
+            .filter(|(_, b)| b.input == input && self.modifiers.contains(b.modifiers));
+        let specificity = matching().map(|(_, b)| b.modifiers.len()).max();
+
+        let mut triggered = Vec::new();
+        for (action, _) in matching().filter(|(_, b)| Some(b.modifiers.len()) == specificity) {
+            if !triggered.contains(action) {
+                triggered.push(*action)
+            }
+        }
+
+        let changes = triggered
+            .iter()
+            .filter(|action| !self.held(action))
+            .map(|action| (*action, true))
+            .collect();
+        self.pressed.push((input, triggered));
+        changes
+    }
+
+    /// Handles a release of `input`, returning actions that are not held anymore, as `(action, false)`
+    pub fn release(&mut self, input: impl Into <Input>) -> Vec <(&'static str, bool)> {
+        let input = input.into();
+        let idx = match self.pressed.iter().position(|(i, _)| *i == input) {
+            Some(idx) => idx,
+            None => return Vec::new()
+        };
+        let (_, triggered) = self.pressed.remove(idx);
+        triggered
+            .into_iter()
+            .filter(|action| !self.held(action))
+            .map(|action| (action, false))
+            .collect()
+    }
+
+    /// Releases all the inputs, returning actions that are not held anymore, as `(action, false)`
+    pub fn release_all(&mut self) -> Vec <(&'static str, bool)> {
+        let mut changes = Vec::<(&'static str, bool)>::new();
+        for (_, triggered) in self.pressed.drain(..) {
+            for action in triggered {
+                if !changes.contains(&(action, false)) {
+                    changes.push((action, false))
+                }
+            }
+        }
+        changes
+    }
+
+    ///
+    /// Handles `event`, returning actions that became held or not held anymore.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rokoko::window::input_map::{ActionMap, Key, MouseButton};
+    /// # extern crate winit;
+    /// use winit::event::{WindowEvent, DeviceId, ElementState, KeyboardInput, ModifiersState};
+    ///
+    /// // SAFETY: only used for comparison, never passed to `winit`
+    /// let device_id = unsafe { DeviceId::dummy() };
+    /// #[allow(deprecated)]
+    /// let key = |state, key| WindowEvent::KeyboardInput {
+    ///     device_id,
+    ///     input: KeyboardInput { scancode: 0, state, virtual_keycode: Some(key), modifiers: ModifiersState::empty() },
+    ///     is_synthetic: false
+    /// };
+    /// #[allow(deprecated)]
+    /// let mouse = |state, button| WindowEvent::MouseInput { device_id, state, button, modifiers: ModifiersState::empty() };
+    ///
+    /// let mut map = ActionMap::new();
+    /// map.bind("fire", MouseButton::Left)
+    ///     .bind("run", Key::LShift)
+    ///     .bind("save", "Ctrl+S".parse::<rokoko::window::input_map::Binding>().unwrap());
+    ///
+    /// assert_eq!(map.update(&mouse(ElementState::Pressed, MouseButton::Left)), [("fire", true)]);
+    /// assert_eq!(map.update(&key(ElementState::Pressed, Key::LShift)), [("run", true)]);
+    ///
+    /// // Modifiers are reported separately
+    /// assert_eq!(map.update(&key(ElementState::Pressed, Key::S)), []);
+    /// assert_eq!(map.update(&key(ElementState::Released, Key::S)), []);
+    /// assert_eq!(map.update(&WindowEvent::ModifiersChanged(ModifiersState::CTRL)), []);
+    /// assert_eq!(map.update(&key(ElementState::Pressed, Key::S)), [("save", true)]);
+    ///
+    /// // Losing focus releases everything
+    /// assert_eq!(map.update(&WindowEvent::Focused(false)), [("fire", false), ("run", false), ("save", false)]);
+    /// assert!(!map.held("fire"));
+    /// ```
+    ///
+    pub fn update(&mut self, event: &WindowEvent <'_>) -> Vec <(&'static str, bool)> {
+        let (input, state) = match event {
+            WindowEvent::ModifiersChanged(state) => {
+                self.set_modifiers((*state).into());
+                return Vec::new()
+            },
+            WindowEvent::Focused(false) => return self.release_all(),
+            WindowEvent::KeyboardInput { input, .. } => match input.virtual_keycode {
+                Some(key) => (Input::Key(key), input.state),
+                None => return Vec::new()
+            },
+            WindowEvent::MouseInput { state, button, .. } => (Input::Mouse(*button), *state),
+            _ => return Vec::new()
+        };
+        match state {
+            ElementState::Pressed => self.press(input),
+            ElementState::Released => self.release(input)
+        }
+    }
+}
+
+/// Bindings grouped by action, e.g. `{"jump": [Space, MouseRight], "save": [Ctrl+S]}`
+impl Debug for ActionMap {
+    fn fmt(&self, f: &mut Formatter <'_>) -> fmt::Result {
+        /// Displays bindings as a list
+        struct List <'a> (&'a ActionMap, &'a str);
+
+        impl Debug for List <'_> {
+            fn fmt(&self, f: &mut Formatter <'_>) -> fmt::Result {
+                f.write_str("[")?;
+                for (i, binding) in self.0.bindings(self.1).enumerate() {
+                    if i != 0 {
+                        f.write_str(", ")?
+                    }
+                    write!(f, "{binding}")?
+                }
+                f.write_str("]")
+            }
+        }
+
+        let mut map = f.debug_map();
+        for (i, (action, _)) in self.bindings.iter().enumerate() {
+            // Every action once, at its first binding
+            if self.bindings[..i].iter().all(|(a, _)| a != action) {
+                map.entry(action, &List(self, action));
+            }
+        }
+        map.finish()
+    }
+}
+
+/// Pixels scrolled by a touchpad counted as a line scrolled by a wheel, see [`scroll_lines`]
+pub const PIXELS_PER_LINE: f32 = 20.0;
+
+///
+/// Returns `delta` in lines, whether the platform reports a wheel in lines or a touchpad in pixels,
+/// see [`PIXELS_PER_LINE`].
+///
+/// Positive `x` is rightwards, positive `y` is forward, i.e. away from the user.
+///
+/// # Examples
+///
+/// ```
+/// use rokoko::prelude::*;
+/// # extern crate winit;
+/// use rokoko::window::input_map::{scroll_lines, PIXELS_PER_LINE};
+/// use winit::{event::MouseScrollDelta, dpi::PhysicalPosition};
+///
+/// assert_eq!(scroll_lines(MouseScrollDelta::LineDelta(0.0, -3.0)), vec2::from([0.0, -3.0]));
+///
+/// let pixels = PhysicalPosition::new(PIXELS_PER_LINE as f64 / 2.0, PIXELS_PER_LINE as f64 * 2.0);
+/// assert_eq!(scroll_lines(MouseScrollDelta::PixelDelta(pixels)), vec2::from([0.5, 2.0]));
+/// ```
+///
+pub fn scroll_lines(delta: MouseScrollDelta) -> vec2 {
+    match delta {
+        MouseScrollDelta::LineDelta(x, y) => vec2::from([x, y]),
+        MouseScrollDelta::PixelDelta(pixels) => vec2::from(pixels.cast::<f32>()) / PIXELS_PER_LINE
+    }
+}
@@ -0,0 +1,213 @@
+//!
+//! This module provides [`LoadingPhase`] - the bookkeeping behind [`WindowBuilder::loading_phase`],
+//! i.e. slow startup work(e.g. loading assets) done while the window is already shown and responsive.
+//!
+//! The generated event loop calls the loader once per iteration until it returns
+//! [`LoadingProgress::Done`], then attaches the loaded value to the window, see [`Window::user_data`],
+//! and calls [`WindowBuilder::on_init`]. Until then, no other callback is called.
+//!
+//! If the user closes the window while loading, the loader is called once more,
+//! with [`LoadingCtx::should_abort`] set, so it may stop its own work, e.g. background threads.
+//! Then the window closes without [`WindowBuilder::on_init`], calling [`WindowBuilder::on_exit`] as usual.
+//!
+//! # Examples
+//!
+//! ```
+//! use rokoko::window::loading::{LoadingPhase, LoadingProgress, Step};
+//! use rokoko::window::build::user_data::UserDataStore;
+//! use std::time::Instant;
+//!
+//! struct Assets(Vec <&'static str>);
+//!
+//! let mut store = UserDataStore::default();
+//! let mut left = vec!["a.png", "b.png"];
+//! let mut phase = LoadingPhase::new(&mut store, Instant::now(), move |_| match left.len() {
+//!     0 => LoadingProgress::Done(Assets(vec!["a.png", "b.png"])),
+//!     n => {
+//!         left.pop();
+//!         LoadingProgress::Pending(1.0 - (n - 1) as f32 / 2.0)
+//!     }
+//! });
+//!
+//! assert_eq!(phase.step(Instant::now(), &store), Some(Step::Pending));
+//! assert_eq!(phase.progress(), 0.5);
+//! assert!(store.get::<Assets>().is_none());
+//!
+//! assert_eq!(phase.step(Instant::now(), &store), Some(Step::Pending));
+//! assert_eq!(phase.step(Instant::now(), &store), Some(Step::Loaded));
+//! assert_eq!(store.get::<Assets>().unwrap().0.len(), 2);
+//! assert!(phase.is_done());
+//! ```
+//!
+//! [`WindowBuilder::loading_phase`]: super::build::WindowBuilder::loading_phase
+//! [`WindowBuilder::on_init`]: super::build::WindowBuilder::on_init
+//! [`WindowBuilder::on_exit`]: super::build::WindowBuilder::on_exit
+//! [`Window::user_data`]: super::Window::user_data
+//!
+
+use super::build::user_data::UserDataStore;
+use std::time::{Duration, Instant};
+
+///
+/// What a loader returns on every call, see [`WindowBuilder::loading_phase`](super::build::WindowBuilder::loading_phase).
+///
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum LoadingProgress <T> {
+    /// Not loaded yet, with the fraction done so far, from `0.0` to `1.0`
+    Pending(f32),
+
+    /// Loaded, with the value to attach to the window
+    Done(T)
+}
+
+///
+/// What a loader is given on every call.
+///
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct LoadingCtx {
+    elapsed: Duration,
+    should_abort: bool
+}
+
+impl LoadingCtx {
+    /// Returns the time since the loading phase began
+    pub fn elapsed(&self) -> Duration {
+        self.elapsed
+    }
+
+    ///
+    /// Returns `true` if the window is closing, so this is the last call
+    /// and whatever the loader returns is dropped.
+    ///
+    pub fn should_abort(&self) -> bool {
+        self.should_abort
+    }
+}
+
+/// What a call of [`LoadingPhase::step`] did
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Step {
+    /// The loader is not done yet
+    Pending,
+
+    /// The loader is done and its value is attached, so `on_init` is due
+    Loaded,
+
+    /// The loader was told to abort, so the window should close
+    Aborted
+}
+
+/// Where a [`LoadingPhase`] is
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum State {
+    Loading,
+    Aborting,
+    Aborted,
+    Done
+}
+
+///
+/// A loader run before `on_init`, see the [module documentation](self).
+///
+pub struct LoadingPhase {
+    /// The loader, attaching the value itself once done
+    load: Box <dyn FnMut(LoadingCtx, &UserDataStore) -> Option <f32>>,
+    start: Instant,
+    progress: f32,
+    state: State
+}
+
+impl LoadingPhase {
+    ///
+    /// Creates a phase with `load` as the loader, started at `start`.
+    ///
+    /// Reserves the place for the loaded value in `store`, see [`UserDataStore::reserve`],
+    /// which hides any value of the same type attached with [`WindowBuilder::data`](super::build::WindowBuilder::data).
+    ///
+    pub fn new <T: 'static, F: FnMut(LoadingCtx) -> LoadingProgress <T> + 'static> (store: &mut UserDataStore, start: Instant, mut load: F) -> Self {
+        store.reserve::<T>();
+        Self {
+            load: Box::new(move |ctx, store| match load(ctx) {
+                LoadingProgress::Pending(progress) => Some(progress),
+                LoadingProgress::Done(value) => {
+                    // Dropped if aborting, the place is left empty then
+                    if !ctx.should_abort {
+                        store.fill(value);
+                    }
+                    None
+                }
+            }),
+            start,
+            progress: 0.0,
+            state: State::Loading
+        }
+    }
+
+    /// Returns `true` until the loader is done or aborted
+    pub fn is_loading(&self) -> bool {
+        matches!(self.state, State::Loading | State::Aborting)
+    }
+
+    /// Returns `true` once the loader is done, i.e. callbacks are called from then on
+    pub fn is_done(&self) -> bool {
+        self.state == State::Done
+    }
+
+    /// Returns the fraction last reported by the loader, `0.0` before the first call
+    pub fn progress(&self) -> f32 {
+        self.progress
+    }
+
+    ///
+    /// Tells the loader to abort on the next [`step`](LoadingPhase::step),
+    /// e.g. because the user closes the window.
+    ///
+    /// Does nothing once the loader is done or aborted.
+    ///
+    pub fn abort(&mut self) {
+        if self.state == State::Loading {
+            self.state = State::Aborting
+        }
+    }
+
+    ///
+    /// Calls the loader, attaching the value to `store` once it is done.
+    ///
+    /// Returns `None` once the loader is done or aborted, without calling it.
+    ///
+    pub fn step(&mut self, now: Instant, store: &UserDataStore) -> Option <Step> {
+        if !self.is_loading() {
+            return None
+        }
+
+        let should_abort = self.state == State::Aborting;
+        let ctx = LoadingCtx { elapsed: now.saturating_duration_since(self.start), should_abort };
+        let progress = (self.load)(ctx, store);
+
+        Some(match (should_abort, progress) {
+            (true, _) => {
+                self.state = State::Aborted;
+                Step::Aborted
+            },
+            (false, Some(progress)) => {
+                self.progress = progress;
+                Step::Pending
+            },
+            (false, None) => {
+                self.progress = 1.0;
+                self.state = State::Done;
+                Step::Loaded
+            }
+        })
+    }
+}
+
+impl core::fmt::Debug for LoadingPhase {
+    fn fmt(&self, f: &mut core::fmt::Formatter <'_>) -> core::fmt::Result {
+        f.debug_struct("LoadingPhase")
+            .field("start", &self.start)
+            .field("progress", &self.progress)
+            .field("state", &self.state)
+            .finish_non_exhaustive()
+    }
+}
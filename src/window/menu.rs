@@ -0,0 +1,162 @@
+//!
+//! This module provides declarative description of menus.
+//!
+//! Menus are described by nested [`MenuDesc`]s; every clickable item
+//! gets a unique [`MenuId`] which is later reported to the callback.
+//!
+//! # Examples
+//!
+//! ```
+//! use rokoko::window::menu::{MenuDesc, MenuItem, MenuId};
+//!
+//! let quit = MenuId::next();
+//!
+//! let menu = MenuDesc::new("")
+//!     .submenu(MenuDesc::new("File")
+//!         .action("Open")
+//!         .separator()
+//!         .action_with_id(quit, "Quit"))
+//!     .submenu(MenuDesc::new("Help")
+//!         .action("About"));
+//!
+//! assert_eq!(menu.find(&["File", "Quit"]), Some(quit));
+//! assert!(menu.find(&["Help", "About"]).is_some());
+//! assert_eq!(menu.find(&["Help", "Quit"]), None);
+//!
+//! // Ids are unique
+//! let ids = menu.ids();
+//! assert_eq!(ids.len(), 3);
+//! assert!(ids.iter().enumerate().all(|(i, a)| ids[i + 1..].iter().all(|b| a != b)));
+//!
+//! // Labels of items
+//! let file = match &menu.items[0] {
+//!     MenuItem::Submenu(file) => file,
+//!     _ => unreachable!()
+//! };
+//! assert_eq!(file.label, "File");
+//! assert_eq!(file.items[1], MenuItem::Separator);
+//! ```
+//!
+
+use core::sync::atomic::{AtomicU32, Ordering};
+
+///
+/// Identifier of a clickable menu item, unique within the process.
+///
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct MenuId(u32);
+
+impl MenuId {
+    ///
+    /// Allocates a new unique id.
+    ///
+    pub fn next() -> Self {
+        static NEXT: AtomicU32 = AtomicU32::new(0);
+        Self(NEXT.fetch_add(1, Ordering::Relaxed))
+    }
+
+    /// Returns the id as a number
+    pub const fn get(self) -> u32 {
+        self.0
+    }
+}
+
+///
+/// An item of a menu.
+///
+#[derive(Debug, Clone, PartialEq)]
+pub enum MenuItem {
+    /// A clickable item
+    Action {
+        id: MenuId,
+        label: String
+    },
+
+    /// A nested menu
+    Submenu(MenuDesc),
+
+    /// A horizontal line between items
+    Separator
+}
+
+///
+/// Description of a menu.
+///
+/// See module documentation for more information.
+///
+#[derive(Debug, Clone, PartialEq)]
+pub struct MenuDesc {
+    /// Label of the menu, ignored for the top-level one
+    pub label: String,
+
+    /// Items of the menu in order
+    pub items: Vec <MenuItem>
+}
+
+impl MenuDesc {
+    /// Creates an empty menu
+    pub fn new(label: &str) -> Self {
+        Self {
+            label: label.to_string(),
+            items: Vec::new()
+        }
+    }
+
+    /// Appends a clickable item with a newly allocated id
+    pub fn action(self, label: &str) -> Self {
+        self.action_with_id(MenuId::next(), label)
+    }
+
+    /// Appends a clickable item with the given `id`
+    pub fn action_with_id(mut self, id: MenuId, label: &str) -> Self {
+        self.items.push(MenuItem::Action {
+            id,
+            label: label.to_string()
+        });
+        self
+    }
+
+    /// Appends a nested menu
+    pub fn submenu(mut self, menu: MenuDesc) -> Self {
+        self.items.push(MenuItem::Submenu(menu));
+        self
+    }
+
+    /// Appends a separator
+    pub fn separator(mut self) -> Self {
+        self.items.push(MenuItem::Separator);
+        self
+    }
+
+    ///
+    /// Finds the id of an item by labels of submenus leading to it
+    /// and its own label.
+    ///
+    pub fn find(&self, path: &[&str]) -> Option <MenuId> {
+        let (first, rest) = path.split_first()?;
+        self.items.iter().find_map(|item| match item {
+            MenuItem::Action { id, label } if rest.is_empty() && label == first => Some(*id),
+            MenuItem::Submenu(menu) if !rest.is_empty() && menu.label == *first => menu.find(rest),
+            _ => None
+        })
+    }
+
+    ///
+    /// Returns ids of all the clickable items, depth-first.
+    ///
+    pub fn ids(&self) -> Vec <MenuId> {
+        let mut ids = Vec::new();
+        self.collect_ids(&mut ids);
+        ids
+    }
+
+    fn collect_ids(&self, ids: &mut Vec <MenuId>) {
+        for item in &self.items {
+            match item {
+                MenuItem::Action { id, .. } => ids.push(*id),
+                MenuItem::Submenu(menu) => menu.collect_ids(ids),
+                MenuItem::Separator => ()
+            }
+        }
+    }
+}
@@ -8,6 +8,11 @@
 //! and yet highly optimized window-building model, with all the configurations being done
 //! in compile-time.
 //!
+//! Only the compiler has to be nightly though: crates using this module
+//! do not need to enable any `#![feature(...)]` of their own.
+//! Callbacks are accepted as plain `impl FnMut(Window)`-like closures,
+//! while unstable traits stay internal(`tests/downstream` makes sure of that).
+//!
 //! ## Let's dive into it.
 //!
 //! We will start that chapter from looking at this tiny example:
@@ -30,7 +35,7 @@
 //!         // Close the window
 //!         w.close()
 //!     })
-//!     // Create the window; that function never returns
+//!     // Create the window and run it until closed, see `event_loop`
 //!     .create()
 //!     .unwrap()
 //! ```
@@ -42,6 +47,18 @@
 //!
 //! That is the opportunity to write code `rokoko` offers you.
 //!
+//! For programs as simple as this one there is also [`run!`](crate::run),
+//! which spares even the builder chain:
+//! ```no_run
+//! rokoko::run!(
+//!     on_init: |_| println!("Initialized!"),
+//!     on_close: |w| {
+//!         println!("Closed!");
+//!         w.close()
+//!     }
+//! )
+//! ```
+//!
 //! # How does it work under the hood
 //!
 //! The first idea of creating something like this was born while thinking about
@@ -153,7 +170,7 @@
 //!         // Close the window
 //!         w.close()
 //!     })
-//!     // Create the window; that function never returns
+//!     // Create the window and run it until closed, see `event_loop`
 //!     .create()
 //!     .unwrap()
 //! ```
@@ -352,14 +369,14 @@
 //! ```no_run
 //! use rokoko::prelude::*;
 //! use rokoko::window::build::{*, getters::*};
-//! use winit::error::OsError;
+//! use rokoko::window::event_loop::Error;
 //!
 //! trait WindowBuildable {
-//!     fn create(self) -> Result <(), OsError>;
+//!     fn create(self) -> Result <(), Error>;
 //! }
 //!
 //! impl <'title, C: GetData <Title <'title>> + GetFn <OnClose> + /* lots of other traits */> WindowBuildable for WindowBuilder <C> {
-//!     fn create(self) -> Result<(), OsError> {
+//!     fn create(self) -> Result<(), Error> {
 //!        self.create()
 //!     }
 //! }
@@ -382,23 +399,94 @@
 //!
 //! <b>At least I hope to.</b>
 //!
+//! For callbacks there is `dyn-callbacks` feature: they are boxed into a runtime table then,
+//! so they do not change the type and can be registered conditionally, paying with a lookup per event,
+//! see [`build::dispatch`]. Data is kept in the type list anyway.
+//!
 //! Anyway, I think that advantages of such a model are much more important than the drawbacks.
 //!
 
-///
-/// Only `nightly` is supported for now.
-///
-#[cfg(not(nightly))]
-compile_error!("Current `window` implementation requires nightly Rust.");
-
 pub mod build;
 use self::build::WindowBuilder;
 
 pub mod data;
 use self::data::{WindowData, UserEvent};
 
-use core::ptr::NonNull;
+pub mod size_policy;
+
+pub mod panic_context;
+
+pub mod pacing;
+
+pub mod power;
+
+pub mod shutdown;
+
+pub mod loading;
+
+pub mod event_loop;
+
+pub mod doctor;
+pub use self::doctor::{doctor, doctor_print};
+
+pub mod event_queue;
+
+pub mod drag_select;
+
+pub mod gestures;
+
+pub mod fullscreen;
+
+pub mod viewport;
+use self::viewport::Viewport;
+
+pub mod event_counters;
+use self::event_counters::EventCounters;
+
+pub mod input_map;
+use self::input_map::ActionMap;
+
+pub mod textedit;
+
+pub mod damage;
+
+pub mod config_file;
+
+pub mod screenshot;
+
+pub mod windows;
+pub use self::windows::Windows;
+
+mod run;
+
+pub mod platform;
+
+#[cfg(feature = "debug-ui")]
+pub mod debug_ui;
+
+#[cfg(feature = "menus")]
+pub mod menu;
+
+#[cfg(feature = "demo-script")]
+pub mod demo_script;
+
+#[cfg(feature = "global-hotkeys")]
+pub mod global_hotkeys;
+
+pub use self::input_map::{Key, MouseButton};
+
+#[cfg(feature = "dialogs")]
+pub use self::platform::dialog::{message_box, MessageKind};
+
+use core::{
+    ptr::NonNull,
+    cell::{Ref, RefMut},
+    time::Duration
+};
+use std::time::Instant;
 use raw_window_handle::RawWindowHandle;
+use winit::dpi::PhysicalSize;
+use crate::math::vec::{vec2, ivec2, uvec2};
 
 ///
 /// The main type of the module.
@@ -409,8 +497,11 @@ use raw_window_handle::RawWindowHandle;
 ///
 /// are not easy to use when it comes to type list, so - we have what we have.
 ///
+/// It is only valid while the window is being run, so to be kept across callbacks
+/// (e.g. in application state) it should be [downgraded](Window::downgrade).
+///
 #[derive(Copy, Clone)]
-pub struct Window(NonNull <WindowData>);
+pub struct Window(NonNull <WindowData>, usize);
 
 impl Window {
     /// Creates a new `WindowBuilder`, ready to be customized
@@ -423,11 +514,380 @@ impl Window {
     ///
     /// Only [`WindowBuilder::on_exit`] is called after this function.
     ///
+    /// Can be called before the event loop starts, e.g. from [`WindowBuilder::on_init`],
+    /// then the window is closed on the first iteration of the loop.
+    ///
     pub fn close(self) {
-       self.data().proxy.send_event(UserEvent::Close).expect("window must be opened to be closed")
+        if !self.data().close_requested.replace(true) {
+            // The request is already recorded, so the loop not being able to
+            // receive the event yet is not an error, see the generated `create`
+            let _ = self.data().proxy.send_event(UserEvent::Close);
+        }
+    }
+
+    ///
+    /// Schedules the window to be [closed](Window::close) once `delay` passes,
+    /// unless the returned [`CloseToken`] is cancelled before.
+    ///
+    /// Scheduling again replaces the close scheduled before, cancelling its token does nothing then.
+    /// Once the close has begun, cancelling has no effect, see [`shutdown`].
+    ///
+    /// [`WindowBuilder::on_close_scheduled`] is called with the time left,
+    /// e.g. to show a countdown.
+    ///
+    /// # Examples
+    ///
+    /// Closing after 3 seconds without input:
+    /// ```
+    /// # use rokoko::window::{Window, CloseToken};
+    /// # use std::{cell::Cell, time::Duration};
+    /// let countdown = Cell::new(None::<CloseToken>);
+    ///
+    /// Window::new()
+    ///     .on_init(|w| countdown.set(Some(w.close_after(Duration::from_secs(3)))))
+    ///     .on_close_scheduled(|_, remaining| println!("closing in {remaining:?}"))
+    ///     .on_key(|_, _, _| if let Some(token) = countdown.take() {
+    ///         token.cancel();
+    ///     });
+    /// ```
+    ///
+    pub fn close_after(self, delay: Duration) -> CloseToken {
+        let id = self.data().close_schedule.borrow_mut().schedule(Instant::now() + delay);
+        // Wakes the loop, so that it waits by the deadline and announces it
+        let _ = self.data().proxy.send_event(UserEvent::CloseScheduled);
+        CloseToken { window: self.downgrade(), id }
+    }
+
+    ///
+    /// Returns `true` if the window is scheduled to be closed by [`Window::close_after`],
+    /// and the close has not begun yet.
+    ///
+    pub fn is_close_scheduled(&self) -> bool {
+        self.data().close_schedule.borrow().is_scheduled()
+    }
+
+    ///
+    /// Creates a [`WeakWindow`], which can be stored for longer than the window is run.
+    ///
+    pub fn downgrade(self) -> WeakWindow {
+        WeakWindow(self)
+    }
+
+    ///
+    /// Asks for [`WindowBuilder::on_redraw`] to be called.
+    ///
+    /// Requests made before the callback runs are merged into a single call.
+    ///
+    pub fn request_redraw(self) {
+        self.data().winit.get().request_redraw()
+    }
+
+    ///
+    /// Enters(`fullscreen == true`) or exits borderless fullscreen on the current monitor.
+    ///
+    /// With [`WindowBuilder::fullscreen_management`] the request goes through
+    /// [`fullscreen::FullscreenManager`], and may take effect later.
+    ///
+    pub fn set_fullscreen(self, fullscreen: bool) {
+        let winit = self.data().winit.get();
+        let command = match self.data().fullscreen.borrow_mut().as_mut() {
+            Some(manager) => manager.request(fullscreen),
+            None => Some(fullscreen::Command::SetFullscreen(fullscreen))
+        };
+        if let Some(command) = command {
+            command.apply(winit)
+        }
+    }
+
+    /// Returns `true` if the window is fullscreen at the moment
+    pub fn is_fullscreen(&self) -> bool {
+        self.data().winit.get().fullscreen().is_some()
+    }
+
+    ///
+    /// Sets the title of the window, see [`WindowBuilder::title`].
+    ///
+    /// Like the other setters, takes effect while the event loop runs,
+    /// so a change made in [`WindowBuilder::on_exit`] may never be shown.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use rokoko::prelude::*;
+    /// Window::new()
+    ///     .on_key(|w, _key, pressed| if pressed {
+    ///         w.set_title("pressed!")
+    ///     })
+    ///     .create()
+    ///     .unwrap()
+    /// ```
+    ///
+    pub fn set_title(&self, title: &str) {
+        self.data().winit.get().set_title(title)
+    }
+
+    ///
+    /// Sets the inner size of the window in physical pixels, see [`WindowBuilder::size`].
+    ///
+    /// [`WindowBuilder::on_resize`] is called once the platform applies it,
+    /// which may be with a different size, e.g. if the window is maximized.
+    ///
+    pub fn set_size(&self, size: impl Into <vec2>) {
+        let size = size.into();
+        self.data().winit.get().set_inner_size(PhysicalSize {
+            width: size[0] as u32,
+            height: size[1] as u32
+        })
+    }
+
+    /// Shows(`visible == true`) or hides the window
+    pub fn set_visible(&self, visible: bool) {
+        self.data().winit.get().set_visible(visible)
+    }
+
+    /// Maximizes(`maximized == true`) or restores the window, see [`WindowBuilder::maximized`]
+    pub fn set_maximized(&self, maximized: bool) {
+        self.data().winit.get().set_maximized(maximized)
+    }
+
+    ///
+    /// Returns the size of the client area of the window in physical pixels.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use rokoko::prelude::*;
+    /// Window::new()
+    ///     .on_resize(|w, _size| {
+    ///         let size = w.inner_size();
+    ///         println!("aspect ratio: {}", size[0] / size[1])
+    ///     })
+    ///     .create()
+    ///     .unwrap()
+    /// ```
+    ///
+    pub fn inner_size(&self) -> vec2 {
+        vec2::from(self.data().winit.get().inner_size().cast::<f32>())
+    }
+
+    /// Returns the size of the window in physical pixels, decorations included
+    pub fn outer_size(&self) -> vec2 {
+        vec2::from(self.data().winit.get().outer_size().cast::<f32>())
+    }
+
+    ///
+    /// Returns the position of the top left corner of the window, decorations included,
+    /// in physical pixels relatively to the desktop.
+    ///
+    /// `None` if the platform cannot report it, e.g. on Wayland.
+    ///
+    pub fn position(&self) -> Option <ivec2> {
+        self.data().winit.get().outer_position().ok().map(ivec2::from)
+    }
+
+    /// Returns the ratio of physical pixels to logical ones, see [`winit::dpi`]
+    pub fn scale_factor(&self) -> f64 {
+        self.data().winit.get().scale_factor()
+    }
+
+    ///
+    /// Returns a compact one-line summary of the configuration of the window,
+    /// e.g. `title="X" size=vec<f32, 2>(800.0, 600.0) callbacks=[on_close,on_init]`.
+    ///
+    /// The very same line is appended to panic messages, see [`WindowBuilder::no_panic_context`].
+    ///
+    pub fn config(&self) -> &str {
+        &self.data().config
+    }
+
+    ///
+    /// Returns `true` if the cursor is over the window.
+    ///
+    /// Already updated when [`WindowBuilder::on_cursor_entered`] and
+    /// [`WindowBuilder::on_cursor_left`] are called.
+    ///
+    /// Any cursor movement within the window counts as being inside,
+    /// even if the platform has not reported entering yet.
+    ///
+    pub fn cursor_inside(&self) -> bool {
+        self.data().tracked.cursor_inside.get()
+    }
+
+    ///
+    /// Returns the numbers of dispatched events and callback runs so far.
+    ///
+    /// All zero unless [`WindowBuilder::count_events`] is specified.
+    ///
+    /// See [`event_counters`] for more information.
+    ///
+    pub fn event_counters(&self) -> &EventCounters {
+        &self.data().counters
+    }
+
+    ///
+    /// Returns `true` if `action` is held, see [`WindowBuilder::action_map`].
+    ///
+    /// Already updated when [`WindowBuilder::on_action`] is called.
+    ///
+    pub fn action_held(&self, action: &str) -> bool {
+        self.data().actions.borrow().held(action)
+    }
+
+    ///
+    /// Returns the bindings of actions, see [`WindowBuilder::action_map`].
+    ///
+    /// Empty unless [`WindowBuilder::action_map`] is specified.
+    ///
+    /// ## Note
+    /// Panics if [`Window::action_map_mut`] is borrowed at the moment.
+    ///
+    pub fn action_map(&self) -> Ref <'_, ActionMap> {
+        self.data().actions.borrow()
+    }
+
+    ///
+    /// Returns the bindings of actions for changing them at runtime, see [`WindowBuilder::action_map`].
+    ///
+    /// Has no effect unless [`WindowBuilder::action_map`] is specified.
+    ///
+    /// ## Note
+    /// Panics if [`Window::action_map`] or [`Window::action_map_mut`] is borrowed at the moment.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use rokoko::prelude::*;
+    /// use rokoko::window::input_map::{ActionMap, Key};
+    ///
+    /// let mut map = ActionMap::new();
+    /// map.bind("jump", Key::Space)
+    ///     .bind("swap", Key::Tab);
+    ///
+    /// Window::new()
+    ///     .action_map(map)
+    ///     .on_action(|w, action, pressed| if action == "swap" && pressed {
+    ///         w.action_map_mut().rebind("jump", Key::J);
+    ///     })
+    ///     .create()
+    ///     .unwrap()
+    /// ```
+    ///
+    pub fn action_map_mut(&self) -> RefMut <'_, ActionMap> {
+        self.data().actions.borrow_mut()
+    }
+
+    ///
+    /// Returns the value of type `T` attached with [`WindowBuilder::data`], `None` if there is no such value.
+    ///
+    /// The value is borrowed mutably, as every callback gets its own copy of the window.
+    /// It is found by type in runtime, see [`build::user_data`] for why.
+    ///
+    /// ## Note
+    /// Also `None` if the value is borrowed at the moment, e.g. by the caller of the current callback.
+    ///
+    /// ## Example
+    /// ```no_run
+    /// use rokoko::window::Window;
+    ///
+    /// struct Closes(u32);
+    ///
+    /// // Closed on the third request only
+    /// Window::new()
+    ///     .data(Closes(u32::MAX))
+    ///     .on_init(|w| *w.user_data::<Closes>().unwrap() = Closes(0))
+    ///     .on_close(|w| {
+    ///         let mut closes = w.user_data::<Closes>().unwrap();
+    ///         closes.0 += 1;
+    ///         if closes.0 == 3 {
+    ///             w.close()
+    ///         }
+    ///     })
+    ///     .create()
+    ///     .unwrap()
+    /// ```
+    ///
+    /// ```no_run
+    /// use rokoko::window::Window;
+    ///
+    /// struct Frames(u64);
+    ///
+    /// Window::new()
+    ///     .data(Frames(0))
+    ///     .on_frame(|w, _dt| w.user_data::<Frames>().unwrap().0 += 1)
+    ///     .on_exit(|w| println!("{} frames", w.user_data::<Frames>().unwrap().0))
+    ///     .create()
+    ///     .unwrap()
+    /// ```
+    ///
+    pub fn user_data <T: 'static> (&self) -> Option <RefMut <'_, T>> {
+        self.data().user_data.get::<T>()
+    }
+
+    ///
+    /// Returns the current inner size and scale factor of the window,
+    /// converting between its coordinate spaces.
+    ///
+    /// See [`viewport`] for the conventions.
+    ///
+    pub fn viewport(&self) -> Viewport {
+        let winit = self.data().winit.get();
+        let size = winit.inner_size();
+        Viewport::new(uvec2::from([size.width, size.height]), winit.scale_factor() as f32)
+    }
+
+    ///
+    /// Converts `pos` from window space(e.g. a cursor position) to NDC.
+    ///
+    /// See [`Viewport::window_to_ndc`].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use rokoko::prelude::*;
+    /// Window::new()
+    ///     .on_init(|w| {
+    ///         let size = w.viewport().size;
+    ///         let center = vec2::from([size[0] as f32 / 2.0, size[1] as f32 / 2.0]);
+    ///         assert_eq!(w.window_to_ndc(center), vec2::from([0.0, 0.0]));
+    ///         w.close()
+    ///     })
+    ///     .create()
+    ///     .unwrap()
+    /// ```
+    ///
+    pub fn window_to_ndc(&self, pos: vec2) -> vec2 {
+        self.viewport().window_to_ndc(pos)
+    }
+
+    /// Converts `ndc` from NDC to window space, see [`Viewport::ndc_to_window`]
+    pub fn ndc_to_window(&self, ndc: vec2) -> vec2 {
+        self.viewport().ndc_to_window(ndc)
+    }
+
+    /// Returns the pixel containing `pos`, or `None` if it is outside, see [`Viewport::window_to_pixel`]
+    pub fn window_to_pixel(&self, pos: vec2) -> Option <uvec2> {
+        self.viewport().window_to_pixel(pos)
+    }
+
+    /// Returns the center of `pixel` in window space, see [`Viewport::pixel_to_window`]
+    pub fn pixel_to_window(&self, pixel: uvec2) -> vec2 {
+        self.viewport().pixel_to_window(pixel)
+    }
+
+    ///
+    /// Shows a blocking message box owned by the window.
+    ///
+    /// Blocks the event loop until closed.
+    ///
+    /// See [`platform::dialog`] for more information.
+    ///
+    #[cfg(feature = "dialogs")]
+    pub fn message_box(&self, title: &str, text: &str, kind: MessageKind) -> std::io::Result <()> {
+        platform::dialog::show(Some(raw_window_handle::HasRawWindowHandle::raw_window_handle(self)), title, text, kind)
     }
 }
 
+// SAFETY: the handle is taken from the `winit` window, which is valid for the lifetime of `self`
 unsafe impl raw_window_handle::HasRawWindowHandle for Window {
     fn raw_window_handle(&self) -> RawWindowHandle {
         self.data().winit.get().raw_window_handle()
@@ -437,13 +897,83 @@ unsafe impl raw_window_handle::HasRawWindowHandle for Window {
 impl Window {
     /// Creates a new reference to `WindowData`.
     const fn from(data: &mut WindowData) -> Self {
+        let generation = data.generation;
         // SAFETY: safe because reference cannot be null
-        Self(unsafe { NonNull::new_unchecked(data) })
+        Self(unsafe { NonNull::new_unchecked(data) }, generation)
     }
 
     /// Get actual window data.
-    const fn data(&self) -> &WindowData {
-        // SAFETY: safe because `self.0` is guaranteed to have a reference
+    fn data(&self) -> &WindowData {
+        debug_assert!(event_loop::is_live(self.1), "the window is used after it has been closed, see `Window::downgrade`");
+        // SAFETY: safe because `self.0` is guaranteed to have a reference while the window is run
         unsafe { &*self.0.as_ptr() }
     }
 }
+
+///
+/// A [`Window`] that can be kept after the window has been closed.
+///
+/// Obtained with [`Window::downgrade`], is as cheap to copy as a `Window` is,
+/// but has to be [upgraded](WeakWindow::upgrade) to be used,
+/// which fails once the window has been closed or its loop has exited.
+///
+/// # Examples
+///
+/// ```
+/// use rokoko::window::{Window, WeakWindow};
+/// use std::cell::Cell;
+///
+/// struct App {
+///     window: Cell <Option <WeakWindow>>
+/// }
+///
+/// let app = App { window: Cell::new(None) };
+///
+/// Window::new()
+///     .on_init(|w| app.window.set(Some(w.downgrade())))
+///     .on_key(|_, _, _| if let Some(w) = app.window.get().and_then(WeakWindow::upgrade) {
+///         w.set_title("still alive")
+///     });
+/// ```
+///
+#[derive(Copy, Clone)]
+pub struct WeakWindow(Window);
+
+impl WeakWindow {
+    ///
+    /// Returns the window if it is still being run, `None` otherwise.
+    ///
+    /// Only looks it up among the few windows being run, so can be called every frame.
+    ///
+    pub fn upgrade(self) -> Option <Window> {
+        if event_loop::is_live((self.0).1) {
+            Some(self.0)
+        } else {
+            None
+        }
+    }
+}
+
+///
+/// A close scheduled by [`Window::close_after`], which can be cancelled.
+///
+/// Can be kept after the window has been closed, as a [`WeakWindow`] can.
+///
+#[derive(Copy, Clone)]
+pub struct CloseToken {
+    window: WeakWindow,
+    id: u64
+}
+
+impl CloseToken {
+    ///
+    /// Cancels the close, so that the window keeps running.
+    ///
+    /// Returns `true` if it was cancelled, and `false` if it was not pending anymore:
+    /// replaced by a later [`Window::close_after`], cancelled before, or already begun,
+    /// when cancelling is a no-op.
+    ///
+    pub fn cancel(self) -> bool {
+        self.window.upgrade().map_or(false, |window| window.data().close_schedule.borrow_mut().cancel(self.id))
+    }
+}
@@ -0,0 +1,291 @@
+//!
+//! This module provides frame pacing for [`super::build::WindowBuilder::on_frame`].
+//!
+//! All the logic is done on timestamps given as [`Duration`]s since an arbitrary origin,
+//! so that it does not depend on the actual clock and can be driven by a synthetic one.
+//!
+//! # Examples
+//!
+//! A moving square, updated at a stable 60 fps:
+//!
+//! ```no_run
+//! # use rokoko::prelude::*;
+//! let mut pos = vec2::ZERO;
+//! let velocity = vec2::from([120.0, 60.0]);
+//!
+//! Window::new()
+//!     .target_fps(60.0)
+//!     .on_frame(move |_, dt| {
+//!         pos += velocity * dt;
+//!         println!("square is at {:?}, frame took {:.2}ms", pos, dt * 1000.0)
+//!     })
+//!     .create()
+//!     .unwrap()
+//! ```
+//!
+
+use std::time::{Duration, Instant};
+use winit::event_loop::ControlFlow;
+use super::power::{self, PowerMode, LoopState};
+
+///
+/// The maximum delta time reported to [`super::build::WindowBuilder::on_frame`], in seconds.
+///
+/// Longer frames(e.g. when the window was dragged or the process was suspended)
+/// are reported as this value, so that simulations do not explode.
+///
+pub const MAX_DT: f32 = 0.25;
+
+///
+/// How long before a deadline a [`FramePacer`] stops sleeping and starts spinning.
+///
+/// OS sleeps tend to overshoot by up to a millisecond or so,
+/// which is too much at high frame rates.
+///
+pub const SPIN_THRESHOLD: Duration = Duration::from_millis(1);
+
+///
+/// Computes deadlines of frames to cap the frame rate.
+///
+/// # Examples
+///
+/// ```
+/// use rokoko::window::pacing::FramePacer;
+/// use std::time::Duration;
+///
+/// let ms = Duration::from_millis;
+/// let mut pacer = FramePacer::new(ms(10));
+///
+/// // The first frame is due immediately
+/// assert!(pacer.is_due(ms(0)));
+/// assert_eq!(pacer.frame(ms(0)), ms(10));
+///
+/// // Not yet
+/// assert!(!pacer.is_due(ms(5)));
+///
+/// // A frame started a bit late does not shift the schedule
+/// assert_eq!(pacer.frame(ms(11)), ms(20));
+///
+/// // A frame that is way too late does not make the next ones hurry to catch up
+/// assert_eq!(pacer.frame(ms(45)), ms(55));
+///
+/// // If the clock jumps backwards, the schedule restarts from the new time
+/// assert!(pacer.is_due(ms(30)));
+/// assert_eq!(pacer.frame(ms(30)), ms(40));
+/// ```
+///
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct FramePacer {
+    period: Duration,
+    deadline: Option <Duration>
+}
+
+impl FramePacer {
+    /// Creates a pacer with the given minimal time between frames
+    pub const fn new(period: Duration) -> Self {
+        Self {
+            period,
+            deadline: None
+        }
+    }
+
+    ///
+    /// Creates a pacer for the given frame rate.
+    ///
+    /// # Panics
+    /// Panics if `fps` is not positive and finite.
+    ///
+    pub fn from_fps(fps: f32) -> Self {
+        validate_fps(&fps);
+        Self::new(Duration::from_secs_f64(1.0 / fps as f64))
+    }
+
+    /// Returns the minimal time between frames
+    pub const fn period(&self) -> Duration {
+        self.period
+    }
+
+    /// Returns the deadline of the next frame, `None` if no frame was started yet
+    pub const fn deadline(&self) -> Option <Duration> {
+        self.deadline
+    }
+
+    ///
+    /// Returns whether the next frame should be started at `now`.
+    ///
+    /// It is also the case when the clock has jumped backwards, i.e.
+    /// the deadline is more than a period away from `now`.
+    ///
+    pub fn is_due(&self, now: Duration) -> bool {
+        match self.deadline {
+            Some(deadline) => now >= deadline || deadline > now + self.period,
+            None => true
+        }
+    }
+
+    ///
+    /// Marks a frame as started at `now` and returns the deadline of the next one.
+    ///
+    pub fn frame(&mut self, now: Duration) -> Duration {
+        let deadline = match self.deadline {
+            // In time or slightly late: keep the schedule
+            Some(deadline) if deadline <= now && now < deadline + self.period => deadline + self.period,
+            // Too late, too early or the clock has jumped: restart the schedule
+            _ => now + self.period
+        };
+        self.deadline = Some(deadline);
+        deadline
+    }
+}
+
+///
+/// Panics if `fps` is not a valid target frame rate, i.e. not positive and finite.
+///
+pub fn validate_fps(fps: &f32) {
+    assert!(*fps > 0.0 && fps.is_finite(), "target fps must be positive and finite, but is {}", fps)
+}
+
+///
+/// Measures time between frames.
+///
+/// # Examples
+///
+/// ```
+/// use rokoko::window::pacing::{DeltaTimer, MAX_DT};
+/// use std::time::Duration;
+///
+/// let ms = Duration::from_millis;
+/// let mut timer = DeltaTimer::new();
+///
+/// // Nothing to measure on the first frame
+/// assert_eq!(timer.tick(ms(100)), 0.0);
+/// assert_eq!(timer.tick(ms(116)), 0.016);
+///
+/// // Clamped
+/// assert_eq!(timer.tick(ms(2000)), MAX_DT);
+///
+/// // Never negative
+/// assert_eq!(timer.tick(ms(1000)), 0.0);
+/// assert_eq!(timer.tick(ms(1010)), 0.01);
+/// ```
+///
+#[derive(Debug, Copy, Clone, Default, PartialEq)]
+pub struct DeltaTimer {
+    last: Option <Duration>
+}
+
+impl DeltaTimer {
+    /// Creates a new timer
+    pub const fn new() -> Self {
+        Self {
+            last: None
+        }
+    }
+
+    ///
+    /// Returns the time since the previous call in seconds, clamped to `[0, MAX_DT]`.
+    ///
+    pub fn tick(&mut self, now: Duration) -> f32 {
+        let dt = match self.last {
+            Some(last) => now.saturating_sub(last).as_secs_f32().min(MAX_DT),
+            None => 0.0
+        };
+        self.last = Some(now);
+        dt
+    }
+}
+
+///
+/// Blocks the current thread until `deadline`, sleeping for the most of the time
+/// and spinning for the last [`SPIN_THRESHOLD`].
+///
+/// # Examples
+///
+/// ```
+/// use rokoko::window::pacing::sleep_until;
+/// use std::time::{Duration, Instant};
+///
+/// let deadline = Instant::now() + Duration::from_millis(5);
+/// sleep_until(deadline);
+/// assert!(Instant::now() >= deadline);
+/// ```
+///
+pub fn sleep_until(deadline: Instant) {
+    let remaining = deadline.saturating_duration_since(Instant::now());
+    if remaining > SPIN_THRESHOLD {
+        std::thread::sleep(remaining - SPIN_THRESHOLD)
+    }
+    while Instant::now() < deadline {
+        core::hint::spin_loop()
+    }
+}
+
+///
+/// State of frames of the generated event loop.
+///
+/// Frames are run on `MainEventsCleared`. Without a target frame rate
+/// the loop polls, otherwise it waits until [`SPIN_THRESHOLD`] before the deadline
+/// and spins the rest, unless [`power::control_flow`] decides to wait for events instead.
+///
+pub(crate) struct FrameLoop {
+    start: Instant,
+    active: bool,
+    mode: PowerMode,
+    pacer: Option <FramePacer>,
+    timer: DeltaTimer
+}
+
+impl FrameLoop {
+    ///
+    /// Creates the state, `active` tells whether there is a frame callback at all.
+    ///
+    pub(crate) fn new(active: bool, target_fps: Option <f32>, mode: PowerMode) -> Self {
+        Self {
+            start: Instant::now(),
+            active,
+            mode,
+            pacer: target_fps.map(FramePacer::from_fps),
+            timer: DeltaTimer::new()
+        }
+    }
+
+    /// Returns the monotonic time since the creation
+    fn now(&self) -> Duration {
+        self.start.elapsed()
+    }
+
+    ///
+    /// Starts a frame if it is due, returning its delta time.
+    ///
+    pub(crate) fn begin(&mut self) -> Option <f32> {
+        if !self.active {
+            return None
+        }
+
+        let mut now = self.now();
+        if let Some(pacer) = &mut self.pacer {
+            if !pacer.is_due(now) {
+                let deadline = pacer.deadline()?;
+                if deadline - now > SPIN_THRESHOLD {
+                    return None
+                }
+                sleep_until(self.start + deadline);
+                // Not `self.now()`, as `pacer` still borrows `self`
+                now = self.start.elapsed()
+            }
+            pacer.frame(now);
+        }
+
+        Some(self.timer.tick(now))
+    }
+
+    /// Returns the control flow to be set after the current event, given the state of the window
+    pub(crate) fn control_flow(&self, visible: bool, focused: bool) -> ControlFlow {
+        power::control_flow(self.mode, LoopState {
+            continuous: self.active,
+            visible,
+            focused,
+            next_frame: self.pacer.map(|pacer| self.start + pacer.deadline().unwrap_or_default().saturating_sub(SPIN_THRESHOLD))
+        })
+    }
+}
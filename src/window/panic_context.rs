@@ -0,0 +1,213 @@
+//!
+//! This module provides [`PanicContext`] - a guard that appends
+//! a configuration of the window to panic messages.
+//!
+//! Panic hooks are global, so the hook is installed only once
+//! for all living contexts(of all threads) and the previous one is restored
+//! when the last context is dropped. Contexts are kept per thread,
+//! so a panic only reports windows of the thread it happened on.
+//!
+//! The previous hook is always called first, so user-installed hooks keep working.
+//! If the hook was replaced by someone else while contexts were alive,
+//! it is left untouched on uninstall.
+//!
+//! # Examples
+//!
+//! ```
+//! use rokoko::window::panic_context::{self, PanicContext};
+//! use std::{
+//!     panic,
+//!     sync::atomic::{AtomicUsize, Ordering}
+//! };
+//!
+//! static USER_HOOK_CALLS: AtomicUsize = AtomicUsize::new(0);
+//!
+//! // A pre-existing user hook
+//! panic::set_hook(Box::new(|_| { USER_HOOK_CALLS.fetch_add(1, Ordering::SeqCst); }));
+//!
+//! {
+//!     let _outer = PanicContext::install(String::from("title=\"outer\""));
+//!     assert!(panic_context::is_installed());
+//!
+//!     {
+//!         // Nested window
+//!         let _inner = PanicContext::install(String::from("title=\"inner\""));
+//!         assert_eq!(panic_context::current().as_deref(), Some("title=\"inner\""));
+//!
+//!         // The user hook is chained
+//!         let _ = panic::catch_unwind(|| panic!("boom"));
+//!         assert_eq!(USER_HOOK_CALLS.load(Ordering::SeqCst), 1);
+//!     }
+//!
+//!     assert_eq!(panic_context::current().as_deref(), Some("title=\"outer\""));
+//!     assert!(panic_context::is_installed());
+//! }
+//!
+//! assert_eq!(panic_context::current(), None);
+//! assert!(!panic_context::is_installed());
+//!
+//! // The user hook is restored
+//! let _ = panic::catch_unwind(|| panic!("boom"));
+//! assert_eq!(USER_HOOK_CALLS.load(Ordering::SeqCst), 2);
+//! ```
+//!
+
+use std::{
+    cell::RefCell,
+    panic::{self, PanicInfo},
+    sync::{Arc, Mutex, MutexGuard, PoisonError}
+};
+
+type Hook = dyn Fn(&PanicInfo <'_>) + Sync + Send + 'static;
+
+/// Global state of the hook
+struct State {
+    /// Number of living [`PanicContext`]s
+    installed: usize,
+
+    /// The hook that was set before ours
+    previous: Option <Arc <Box <Hook>>>,
+
+    ///
+    /// Address of our hook, used to find out
+    /// if the hook was replaced by someone else.
+    ///
+    address: usize
+}
+
+static STATE: Mutex <State> = Mutex::new(State {
+    installed: 0,
+    previous: None,
+    address: 0
+});
+
+thread_local! {
+    /// Contexts of the current thread, the innermost is the last
+    static CONTEXTS: RefCell <Vec <String>> = RefCell::new(Vec::new());
+}
+
+///
+/// Locks the state ignoring poisoning, since the state
+/// is never left inconsistent.
+///
+fn state() -> MutexGuard <'static, State> {
+    STATE.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+/// Returns the address of data of a hook
+fn address(hook: &Hook) -> usize {
+    hook as *const Hook as *const () as usize
+}
+
+///
+/// Returns the innermost context of the current thread.
+///
+pub fn current() -> Option <String> {
+    CONTEXTS.with(|c| c.borrow().last().cloned())
+}
+
+///
+/// Returns `true` if our hook is currently installed.
+///
+pub fn is_installed() -> bool {
+    state().installed != 0
+}
+
+///
+/// A guard that appends `context` to panic messages
+/// of the current thread until dropped.
+///
+/// See module documentation for more information.
+///
+/// # Examples
+///
+/// Guards may be dropped in any order, e.g. when windows run together are closed one by one:
+///
+/// ```
+/// use rokoko::window::panic_context::{self, PanicContext};
+///
+/// let first = PanicContext::install(String::from("title=\"first\""));
+/// let second = PanicContext::install(String::from("title=\"second\""));
+///
+/// drop(first);
+/// assert_eq!(panic_context::current().as_deref(), Some("title=\"second\""));
+///
+/// drop(second);
+/// assert_eq!(panic_context::current(), None);
+/// ```
+///
+pub struct PanicContext {
+    /// Removed on drop, which may be out of order when several windows are run
+    context: String,
+
+    /// Makes the guard `!Send`, since contexts are per thread
+    _not_send: core::marker::PhantomData <*const ()>
+}
+
+impl PanicContext {
+    ///
+    /// Pushes `context` and installs the hook if it is not yet installed.
+    ///
+    pub fn install(context: String) -> Self {
+        CONTEXTS.with(|c| c.borrow_mut().push(context.clone()));
+
+        let mut state = state();
+        if state.installed == 0 {
+            let previous = Arc::new(panic::take_hook());
+            let chained = previous.clone();
+            let hook: Box <Hook> = Box::new(move |info| {
+                chained(info);
+                // `try_with` because the thread may be being destroyed
+                let _ = CONTEXTS.try_with(|c| if let Ok(contexts) = c.try_borrow() {
+                    if let Some(context) = contexts.last() {
+                        eprintln!("note: rokoko window configuration: {}", context)
+                    }
+                });
+            });
+            state.previous = Some(previous);
+            state.address = address(&*hook);
+            panic::set_hook(hook)
+        }
+        state.installed += 1;
+
+        Self {
+            context,
+            _not_send: core::marker::PhantomData
+        }
+    }
+}
+
+impl Drop for PanicContext {
+    fn drop(&mut self) {
+        // Equal contexts are interchangeable, so whichever is removed
+        CONTEXTS.with(|c| {
+            let mut contexts = c.borrow_mut();
+            if let Some(i) = contexts.iter().rposition(|c| *c == self.context) {
+                contexts.remove(i);
+            }
+        });
+
+        // Hooks cannot be changed while panicking, so
+        // ours is left installed(it does nothing without contexts)
+        if std::thread::panicking() {
+            return
+        }
+
+        let mut state = state();
+        state.installed -= 1;
+        if state.installed == 0 {
+            let previous = state.previous.take().expect("hook must be installed");
+            let current = panic::take_hook();
+            if address(&*current) == state.address {
+                drop(current);
+                // The only other owner is our hook, which has just been dropped
+                match Arc::try_unwrap(previous) {
+                    Ok(previous) => panic::set_hook(previous),
+                    Err(previous) => panic::set_hook(Box::new(move |info| previous(info)))
+                }
+            } else {
+                panic::set_hook(current)
+            }
+        }
+    }
+}
@@ -0,0 +1,327 @@
+//!
+//! This module provides blocking message boxes, usable
+//! both before any window exists and from callbacks.
+//!
+//! # Backends
+//!
+//! - Windows: `MessageBoxW`.
+//! - macOS: `osascript`(`display alert`).
+//! - Other unix: `zenity` or, if it is not installed, `kdialog` subprocess.
+//! If neither is found, the message is printed to `stderr` instead.
+//!
+//! # Reentrancy
+//!
+//! Message boxes block the calling thread until closed, so calling one from
+//! a callback is allowed, but the event loop is not run until the box is closed.
+//!
+//! # Examples
+//!
+//! Validating a config on start:
+//!
+//! ```no_run
+//! use rokoko::window::{Window, platform::dialog::{message_box, MessageKind}};
+//!
+//! Window::new()
+//!     .on_init(|w| if let Err(e) = std::fs::read_to_string("config.toml") {
+//!         message_box("Cannot load config", &e.to_string(), MessageKind::Error).unwrap();
+//!         w.close()
+//!     })
+//!     .create()
+//!     .unwrap()
+//! ```
+//!
+
+use std::{
+    io,
+    process::Command
+};
+
+///
+/// Icon and sound of a message box.
+///
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum MessageKind {
+    Info,
+    Warning,
+    Error
+}
+
+///
+/// Shows a blocking message box.
+///
+/// See module documentation for more information.
+///
+pub fn message_box(title: &str, text: &str, kind: MessageKind) -> io::Result <()> {
+    show(None, title, text, kind)
+}
+
+///
+/// Shows a message box owned by a window whose handle is `parent`(if any).
+///
+/// Only Windows makes use of `parent`.
+///
+pub(crate) fn show(parent: Option <raw_window_handle::RawWindowHandle>, title: &str, text: &str, kind: MessageKind) -> io::Result <()> {
+    cfg_if::cfg_if! {
+        if #[cfg(windows)] {
+            windows::show(parent, title, text, kind)
+        } else if #[cfg(target_os = "macos")] {
+            let _ = parent;
+            let status = Command::new("osascript").args(osascript_args(title, text, kind)).status()?;
+            if status.success() {
+                Ok(())
+            } else {
+                Err(io::Error::new(io::ErrorKind::Other, "`osascript` failed"))
+            }
+        } else {
+            let _ = parent;
+            subprocess(&mut SystemRunner, title, text, kind)
+        }
+    }
+}
+
+///
+/// Runs external programs.
+///
+/// Abstracts over [`Command`] so that backend selection can be tested.
+///
+pub trait CommandRunner {
+    ///
+    /// Runs `program` with `args` and waits for it to finish.
+    ///
+    /// Returns `true` if it succeeded.
+    ///
+    /// Must return [`io::ErrorKind::NotFound`] if `program` does not exist.
+    ///
+    fn run(&mut self, program: &str, args: &[String]) -> io::Result <bool>;
+}
+
+/// The real [`CommandRunner`]
+pub struct SystemRunner;
+
+impl CommandRunner for SystemRunner {
+    fn run(&mut self, program: &str, args: &[String]) -> io::Result <bool> {
+        Command::new(program).args(args).status().map(|s| s.success())
+    }
+}
+
+///
+/// Shows a message box via the first available subprocess backend,
+/// falling back to `stderr`.
+///
+/// # Examples
+///
+/// ```
+/// use rokoko::window::platform::dialog::{subprocess, CommandRunner, MessageKind};
+/// use std::io;
+///
+/// /// Pretends only `installed` programs exist
+/// struct Mock {
+///     installed: &'static [&'static str],
+///     called: Vec <String>
+/// }
+///
+/// impl CommandRunner for Mock {
+///     fn run(&mut self, program: &str, _: &[String]) -> io::Result <bool> {
+///         self.called.push(program.to_string());
+///         if self.installed.contains(&program) {
+///             Ok(true)
+///         } else {
+///             Err(io::ErrorKind::NotFound.into())
+///         }
+///     }
+/// }
+///
+/// // `zenity` is preferred
+/// let mut mock = Mock { installed: &["zenity", "kdialog"], called: vec![] };
+/// subprocess(&mut mock, "t", "x", MessageKind::Info).unwrap();
+/// assert_eq!(mock.called, ["zenity"]);
+///
+/// // Then `kdialog`
+/// let mut mock = Mock { installed: &["kdialog"], called: vec![] };
+/// subprocess(&mut mock, "t", "x", MessageKind::Info).unwrap();
+/// assert_eq!(mock.called, ["zenity", "kdialog"]);
+///
+/// // Then `stderr`
+/// let mut mock = Mock { installed: &[], called: vec![] };
+/// subprocess(&mut mock, "t", "x", MessageKind::Info).unwrap();
+/// assert_eq!(mock.called, ["zenity", "kdialog"]);
+/// ```
+///
+pub fn subprocess(runner: &mut impl CommandRunner, title: &str, text: &str, kind: MessageKind) -> io::Result <()> {
+    let backends: [(&str, fn(&str, &str, MessageKind) -> Vec <String>); 2] = [
+        ("zenity", zenity_args),
+        ("kdialog", kdialog_args)
+    ];
+
+    for (program, args) in backends {
+        match runner.run(program, &args(title, text, kind)) {
+            // A non-zero exit code means the user closed the box without pressing OK,
+            // which is not an error for a message box
+            Ok(_) => return Ok(()),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => continue,
+            Err(e) => return Err(e)
+        }
+    }
+
+    eprintln!("{:?}: {}: {}", kind, title, text);
+    Ok(())
+}
+
+///
+/// Returns arguments for `zenity`.
+///
+/// `zenity` interprets Pango markup and backslash escapes in the text,
+/// so both are escaped.
+///
+/// # Examples
+///
+/// ```
+/// use rokoko::window::platform::dialog::{zenity_args, MessageKind};
+///
+/// assert_eq!(
+///     zenity_args("<Title>", "a < b & c\\n", MessageKind::Warning),
+///     ["--warning", "--title=<Title>", "--text=a &lt; b &amp; c\\\\n"]
+/// );
+/// ```
+///
+pub fn zenity_args(title: &str, text: &str, kind: MessageKind) -> Vec <String> {
+    let kind = match kind {
+        MessageKind::Info => "--info",
+        MessageKind::Warning => "--warning",
+        MessageKind::Error => "--error"
+    };
+
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '\\' => escaped.push_str("\\\\"),
+            _ => escape_html(c, &mut escaped)
+        }
+    }
+
+    vec![kind.to_string(), format!("--title={}", title), format!("--text={}", escaped)]
+}
+
+///
+/// Returns arguments for `kdialog`.
+///
+/// `kdialog` guesses whether the text is rich, so it is always
+/// passed as escaped rich text.
+///
+/// # Examples
+///
+/// ```
+/// use rokoko::window::platform::dialog::{kdialog_args, MessageKind};
+///
+/// assert_eq!(
+///     kdialog_args("-t", "<b>\nok", MessageKind::Error),
+///     ["--title", "-t", "--error", "<qt>&lt;b&gt;<br>ok</qt>"]
+/// );
+/// ```
+///
+pub fn kdialog_args(title: &str, text: &str, kind: MessageKind) -> Vec <String> {
+    let kind = match kind {
+        MessageKind::Info => "--msgbox",
+        MessageKind::Warning => "--sorry",
+        MessageKind::Error => "--error"
+    };
+
+    let mut escaped = String::from("<qt>");
+    for c in text.chars() {
+        match c {
+            '\n' => escaped.push_str("<br>"),
+            _ => escape_html(c, &mut escaped)
+        }
+    }
+    escaped.push_str("</qt>");
+
+    vec![String::from("--title"), title.to_string(), kind.to_string(), escaped]
+}
+
+///
+/// Returns arguments for `osascript`.
+///
+/// Texts are passed as arguments of the script instead of being embedded
+/// into it, so they need no escaping.
+///
+/// # Examples
+///
+/// ```
+/// use rokoko::window::platform::dialog::{osascript_args, MessageKind};
+///
+/// let args = osascript_args("\"t\"", "x", MessageKind::Info);
+/// assert_eq!(&args[args.len() - 2..], ["\"t\"", "x"]);
+/// ```
+///
+pub fn osascript_args(title: &str, text: &str, kind: MessageKind) -> Vec <String> {
+    let kind = match kind {
+        MessageKind::Info => "informational",
+        MessageKind::Warning => "warning",
+        MessageKind::Error => "critical"
+    };
+
+    vec![
+        String::from("-e"),
+        String::from("on run argv"),
+        String::from("-e"),
+        format!("display alert (item 1 of argv) message (item 2 of argv) as {}", kind),
+        String::from("-e"),
+        String::from("end run"),
+        title.to_string(),
+        text.to_string()
+    ]
+}
+
+/// Pushes `c` into `s`, escaping `&`, `<` and `>`
+fn escape_html(c: char, s: &mut String) {
+    match c {
+        '&' => s.push_str("&amp;"),
+        '<' => s.push_str("&lt;"),
+        '>' => s.push_str("&gt;"),
+        _ => s.push(c)
+    }
+}
+
+#[cfg(windows)]
+mod windows {
+    use super::MessageKind;
+    use std::{
+        io,
+        ffi::c_void,
+        os::windows::ffi::OsStrExt
+    };
+    use raw_window_handle::RawWindowHandle;
+
+    const MB_ICONERROR: u32 = 0x10;
+    const MB_ICONWARNING: u32 = 0x30;
+    const MB_ICONINFORMATION: u32 = 0x40;
+
+    #[link(name = "user32")]
+    extern "system" {
+        fn MessageBoxW(hwnd: *mut c_void, text: *const u16, caption: *const u16, flags: u32) -> i32;
+    }
+
+    /// Converts `s` into a null-terminated UTF-16 string
+    fn wide(s: &str) -> Vec <u16> {
+        std::ffi::OsStr::new(s).encode_wide().chain(Some(0)).collect()
+    }
+
+    pub fn show(parent: Option <RawWindowHandle>, title: &str, text: &str, kind: MessageKind) -> io::Result <()> {
+        let hwnd = match parent {
+            Some(RawWindowHandle::Win32(handle)) => handle.hwnd,
+            _ => core::ptr::null_mut()
+        };
+        let flags = match kind {
+            MessageKind::Info => MB_ICONINFORMATION,
+            MessageKind::Warning => MB_ICONWARNING,
+            MessageKind::Error => MB_ICONERROR
+        };
+
+        // SAFETY: both strings are null-terminated and outlive the call
+        if unsafe { MessageBoxW(hwnd, wide(text).as_ptr(), wide(title).as_ptr(), flags) } == 0 {
+            Err(io::Error::last_os_error())
+        } else {
+            Ok(())
+        }
+    }
+}
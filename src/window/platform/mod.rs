@@ -0,0 +1,7 @@
+//!
+//! This module provides platform-specific functionality
+//! that `winit` does not cover.
+//!
+
+#[cfg(feature = "dialogs")]
+pub mod dialog;
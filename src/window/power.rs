@@ -0,0 +1,109 @@
+//!
+//! This module provides [`PowerMode`] and [`control_flow`], which decides how the
+//! generated event loop waits between iterations, see [`WindowBuilder::power_mode`].
+//!
+//! The decision is made at the end of every iteration from:
+//! - whether a continuous callback is present, i.e. [`WindowBuilder::on_frame`].
+//!   That is known statically, from the type of the builder;
+//! - whether the window is visible and focused, as reported by the platform so far;
+//! - the deadline of the next frame, if they are paced with [`WindowBuilder::target_fps`];
+//! - the [`PowerMode`].
+//!
+//! | Continuous callback | [`PowerMode`]   | Frames run while      | Otherwise |
+//! |---------------------|-----------------|-----------------------|-----------|
+//! | absent              | any             | never                 | `Wait`    |
+//! | present             | `Performance`   | always                | -         |
+//! | present             | `Balanced`      | visible               | `Wait`    |
+//! | present             | `Efficiency`    | visible and focused   | `Wait`    |
+//!
+//! While frames run, the loop polls, or waits until the deadline of the next frame if they are paced.
+//! While they do not, the loop waits for events and runs a frame whenever one arrives.
+//!
+//! `winit` does not report occlusion yet, so a window counts as hidden only if it is
+//! minimized(which is reported as resizing to zero).
+//!
+//! # Examples
+//!
+//! ```
+//! # extern crate winit;
+//! use rokoko::window::power::{control_flow, LoopState, PowerMode};
+//! use winit::event_loop::ControlFlow;
+//!
+//! let minimized = LoopState { continuous: true, visible: false, focused: false, next_frame: None };
+//!
+//! assert_eq!(control_flow(PowerMode::Balanced, minimized), ControlFlow::Wait);
+//! assert_eq!(control_flow(PowerMode::Performance, minimized), ControlFlow::Poll);
+//! ```
+//!
+//! [`WindowBuilder::power_mode`]: super::build::WindowBuilder::power_mode
+//! [`WindowBuilder::on_frame`]: super::build::WindowBuilder::on_frame
+//! [`WindowBuilder::target_fps`]: super::build::WindowBuilder::target_fps
+//!
+
+use std::time::Instant;
+use winit::event_loop::ControlFlow;
+
+///
+/// When frames keep running, see the [module documentation](self).
+///
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+pub enum PowerMode {
+    /// Frames run while the window is visible, the default
+    #[default]
+    Balanced,
+
+    /// Frames always run, e.g. for a game server window or a benchmark
+    Performance,
+
+    /// Frames run only while the window is visible and focused, e.g. for an editor
+    Efficiency
+}
+
+/// Inputs of [`control_flow`] other than the [`PowerMode`]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct LoopState {
+    /// `true` if there is a continuous callback
+    pub continuous: bool,
+
+    /// `false` if the window is minimized
+    pub visible: bool,
+
+    /// `true` if the window has the keyboard focus
+    pub focused: bool,
+
+    /// When the next frame is due, `None` if frames are not paced
+    pub next_frame: Option <Instant>
+}
+
+///
+/// Returns how the loop should wait after the current iteration, see the [module documentation](self).
+///
+/// # Examples
+///
+/// ```
+/// # extern crate winit;
+/// use rokoko::window::power::{control_flow, LoopState, PowerMode};
+/// use winit::event_loop::ControlFlow;
+/// use std::time::Instant;
+///
+/// let deadline = Instant::now();
+/// let focused = LoopState { continuous: true, visible: true, focused: true, next_frame: Some(deadline) };
+///
+/// assert_eq!(control_flow(PowerMode::Efficiency, focused), ControlFlow::WaitUntil(deadline));
+/// assert_eq!(control_flow(PowerMode::Efficiency, LoopState { focused: false, ..focused }), ControlFlow::Wait);
+/// assert_eq!(control_flow(PowerMode::Performance, LoopState { continuous: false, ..focused }), ControlFlow::Wait);
+/// ```
+///
+pub fn control_flow(mode: PowerMode, state: LoopState) -> ControlFlow {
+    let running = state.continuous && match mode {
+        PowerMode::Performance => true,
+        PowerMode::Balanced => state.visible,
+        PowerMode::Efficiency => state.visible && state.focused
+    };
+
+    match (running, state.next_frame) {
+        (false, _) => ControlFlow::Wait,
+        (true, None) => ControlFlow::Poll,
+        (true, Some(deadline)) => ControlFlow::WaitUntil(deadline)
+    }
+}
@@ -0,0 +1,222 @@
+//!
+//! This module provides the [`run!`](crate::run) macro, a shorthand for the simplest programs.
+//!
+//! The macro is a thin veneer over [`WindowBuilder`](super::build::WindowBuilder):
+//! every key is a method of it, see the table of [`__run_key!`](crate::__run_key).
+//!
+
+///
+/// Creates a window and runs it until closed, i.e. a shorthand for
+/// `Window::new()`, a chain of builder methods and `.create().unwrap()`.
+///
+/// Accepts a comma-separated list of
+/// - `key: value` -> `.key(value)`, for options taking an argument and for callbacks;
+/// - `key` -> `.key()`, for flags.
+///
+/// The keys are exactly the methods of [`WindowBuilder`](crate::window::build::WindowBuilder),
+/// so their documentation, defaults and conflicts apply as is:
+///
+/// | Key                          | Kind     |
+/// |------------------------------|----------|
+/// | `title`                      | value    |
+/// | `size`                       | value    |
+/// | `maximized`                  | flag     |
+/// | `auto_size`                  | value    |
+/// | `size_is_logical`            | flag     |
+/// | `logical_size`               | value    |
+/// | `position`                   | value    |
+/// | `position_is_logical`        | flag     |
+/// | `not_resizable`              | flag     |
+/// | `no_decorations`             | flag     |
+/// | `transparent`                | flag     |
+/// | `always_on_top`              | flag     |
+/// | `icon`                       | value    |
+/// | `no_panic_context`           | flag     |
+/// | `target_fps`                 | value    |
+/// | `power_mode`                 | value    |
+/// | `drag_select`                | flag     |
+/// | `drag_select_threshold`      | value    |
+/// | `gestures`                   | flag     |
+/// | `double_tap_interval`        | value    |
+/// | `fullscreen`                 | flag     |
+/// | `fullscreen_management`      | flag     |
+/// | `minimize_on_focus_loss`     | flag     |
+/// | `count_events`               | flag     |
+/// | `action_map`                 | value    |
+/// | `on_close_request`           | callback |
+/// | `on_close`                   | callback |
+/// | `on_init`                    | callback |
+/// | `on_exit`                    | callback |
+/// | `on_close_scheduled`         | callback |
+/// | `on_frame`                   | callback |
+/// | `on_resize`                  | callback |
+/// | `on_redraw`                  | callback |
+/// | `on_key`                     | callback |
+/// | `on_char`                    | callback |
+/// | `on_mouse_move`              | callback |
+/// | `on_mouse_button`            | callback |
+/// | `on_scroll`                  | callback |
+/// | `on_cursor_entered`          | callback |
+/// | `on_cursor_left`             | callback |
+/// | `on_focus`                   | callback |
+/// | `on_file_drop`               | callback |
+/// | `on_file_hover`              | callback |
+/// | `on_drag_select`             | callback |
+/// | `on_pinch`                   | callback |
+/// | `on_pan`                     | callback |
+/// | `on_tap`                     | callback |
+/// | `on_action`                  | callback |
+///
+/// Anything else, as well as a value given to a flag or a flag given without one, is a compile error.
+///
+/// Keys of unstable options need their features, e.g. `minimize_on_focus_loss` needs
+/// `unstable-minimize-on-focus-loss`, just like the methods do.
+///
+/// # Examples
+///
+/// The example from the [`window`](crate::window) module documentation:
+/// ```no_run
+/// rokoko::run!(
+///     on_init: |_| println!("Initialized!"),
+///     on_close: |w| {
+///         println!("Closed!");
+///         w.close()
+///     }
+/// )
+/// ```
+/// Options, flags and callbacks with arguments:
+/// ```no_run
+/// rokoko::run!(
+///     title: "Demo",
+///     logical_size: (800., 600.),
+///     target_fps: 60.0,
+///     on_frame: |_, dt| println!("{dt}s since the previous frame"),
+/// )
+/// ```
+/// Which is the same as:
+/// ```no_run
+/// # use rokoko::window::Window;
+/// Window::new()
+///     .title("Demo")
+///     .logical_size((800., 600.))
+///     .target_fps(60.0)
+///     .on_frame(|_, dt| println!("{dt}s since the previous frame"))
+///     .create()
+///     .unwrap()
+/// ```
+/// Unknown keys are rejected:
+/// ```compile_fail
+/// rokoko::run!(on_touch: |_, _| ())
+/// ```
+/// As are flags given a value:
+/// ```compile_fail
+/// rokoko::run!(maximized: true)
+/// ```
+///
+#[macro_export]
+macro_rules! run {
+    ($($config:tt)*) => {
+        $crate::__run_builder!($($config)*).create().unwrap()
+    };
+}
+
+///
+/// Expands to the [`WindowBuilder`](crate::window::build::WindowBuilder) [`run!`](crate::run) would create,
+/// without creating it.
+///
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __run_builder {
+    (@ $builder:expr ;) => {
+        $builder
+    };
+    (@ $builder:expr ; $key:ident $(: $value:expr)? $(, $($rest:tt)*)?) => {
+        $crate::__run_builder!(@ $crate::__run_key!($key, $builder $(, $value)?) ; $($($rest)*)?)
+    };
+    (@ $builder:expr ; $($rest:tt)*) => {
+        compile_error!(concat!(
+            "expected `key: value` or `flag` in `rokoko::run!`, found `",
+            stringify!($($rest)*),
+            "`"
+        ))
+    };
+    ($($config:tt)*) => {
+        $crate::__run_builder!(@ $crate::window::Window::new() ; $($config)*)
+    };
+}
+
+///
+/// Maps a key of [`run!`](crate::run) to the method of [`WindowBuilder`](crate::window::build::WindowBuilder).
+///
+/// Must be kept in sync with the table in [`run!`](crate::run), `tests/run_keys.rs` checks every key.
+///
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __run_key {
+    // Data
+    (title, $builder:expr, $value:expr) => { $builder.title($value) };
+    (size, $builder:expr, $value:expr) => { $builder.size($value) };
+    (maximized, $builder:expr) => { $builder.maximized() };
+    (auto_size, $builder:expr, $value:expr) => { $builder.auto_size($value) };
+    (size_is_logical, $builder:expr) => { $builder.size_is_logical() };
+    (logical_size, $builder:expr, $value:expr) => { $builder.logical_size($value) };
+    (position, $builder:expr, $value:expr) => { $builder.position($value) };
+    (position_is_logical, $builder:expr) => { $builder.position_is_logical() };
+    (not_resizable, $builder:expr) => { $builder.not_resizable() };
+    (no_decorations, $builder:expr) => { $builder.no_decorations() };
+    (transparent, $builder:expr) => { $builder.transparent() };
+    (always_on_top, $builder:expr) => { $builder.always_on_top() };
+    (icon, $builder:expr, $value:expr) => { $builder.icon($value) };
+    (no_panic_context, $builder:expr) => { $builder.no_panic_context() };
+    (target_fps, $builder:expr, $value:expr) => { $builder.target_fps($value) };
+    (power_mode, $builder:expr, $value:expr) => { $builder.power_mode($value) };
+    (drag_select, $builder:expr) => { $builder.drag_select() };
+    (drag_select_threshold, $builder:expr, $value:expr) => { $builder.drag_select_threshold($value) };
+    (gestures, $builder:expr) => { $builder.gestures() };
+    (double_tap_interval, $builder:expr, $value:expr) => { $builder.double_tap_interval($value) };
+    (fullscreen, $builder:expr) => { $builder.fullscreen() };
+    (fullscreen_management, $builder:expr) => { $builder.fullscreen_management() };
+    (minimize_on_focus_loss, $builder:expr) => { $builder.minimize_on_focus_loss() };
+    (count_events, $builder:expr) => { $builder.count_events() };
+    (action_map, $builder:expr, $value:expr) => { $builder.action_map($value) };
+
+    // Events
+    (on_close_request, $builder:expr, $value:expr) => { $builder.on_close_request($value) };
+    (on_close, $builder:expr, $value:expr) => { $builder.on_close($value) };
+    (on_init, $builder:expr, $value:expr) => { $builder.on_init($value) };
+    (on_exit, $builder:expr, $value:expr) => { $builder.on_exit($value) };
+    (on_close_scheduled, $builder:expr, $value:expr) => { $builder.on_close_scheduled($value) };
+    (on_frame, $builder:expr, $value:expr) => { $builder.on_frame($value) };
+    (on_resize, $builder:expr, $value:expr) => { $builder.on_resize($value) };
+    (on_redraw, $builder:expr, $value:expr) => { $builder.on_redraw($value) };
+    (on_key, $builder:expr, $value:expr) => { $builder.on_key($value) };
+    (on_char, $builder:expr, $value:expr) => { $builder.on_char($value) };
+    (on_mouse_move, $builder:expr, $value:expr) => { $builder.on_mouse_move($value) };
+    (on_mouse_button, $builder:expr, $value:expr) => { $builder.on_mouse_button($value) };
+    (on_scroll, $builder:expr, $value:expr) => { $builder.on_scroll($value) };
+    (on_cursor_entered, $builder:expr, $value:expr) => { $builder.on_cursor_entered($value) };
+    (on_cursor_left, $builder:expr, $value:expr) => { $builder.on_cursor_left($value) };
+    (on_focus, $builder:expr, $value:expr) => { $builder.on_focus($value) };
+    (on_file_drop, $builder:expr, $value:expr) => { $builder.on_file_drop($value) };
+    (on_file_hover, $builder:expr, $value:expr) => { $builder.on_file_hover($value) };
+    (on_drag_select, $builder:expr, $value:expr) => { $builder.on_drag_select($value) };
+    (on_pinch, $builder:expr, $value:expr) => { $builder.on_pinch($value) };
+    (on_pan, $builder:expr, $value:expr) => { $builder.on_pan($value) };
+    (on_tap, $builder:expr, $value:expr) => { $builder.on_tap($value) };
+    (on_action, $builder:expr, $value:expr) => { $builder.on_action($value) };
+
+    ($key:ident, $builder:expr, $value:expr) => {
+        compile_error!(concat!(
+            "`rokoko::run!` has no key `", stringify!($key), "` taking a value, expected one of: ",
+            "`title`, `size`, `auto_size`, `logical_size`, `position`, `icon`, `target_fps`, `power_mode`, `drag_select_threshold`, `double_tap_interval`, `action_map`, ",
+            "`on_close_request`, `on_close`, `on_init`, `on_exit`, `on_close_scheduled`, `on_frame`, `on_resize`, `on_redraw`, `on_key`, `on_char`, `on_mouse_move`, `on_mouse_button`, `on_scroll`, `on_cursor_entered`, `on_cursor_left`, `on_focus`, `on_file_drop`, `on_file_hover`, `on_drag_select`, `on_pinch`, `on_pan`, `on_tap`, `on_action`"
+        ))
+    };
+    ($key:ident, $builder:expr) => {
+        compile_error!(concat!(
+            "`rokoko::run!` has no flag `", stringify!($key), "`, expected one of: ",
+            "`maximized`, `size_is_logical`, `position_is_logical`, `not_resizable`, `no_decorations`, `transparent`, `always_on_top`, `no_panic_context`, `drag_select`, `gestures`, `fullscreen`, `fullscreen_management`, `minimize_on_focus_loss`, `count_events`; ",
+            "other keys take a value, as in `", stringify!($key), ": value`"
+        ))
+    };
+}
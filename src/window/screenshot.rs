@@ -0,0 +1,179 @@
+//!
+//! This module provides saving of screenshots: collision-free timestamped file names
+//! in a directory created if missing, and writing of RGB frames as binary PPM.
+//!
+//! Files are named after the UTC time of the capture, e.g. `screenshot-2026-10-15_11-29-39.123.ppm`,
+//! with `-1`, `-2`, etc. appended if the name is already taken, e.g. by several captures in the same millisecond.
+//! A name is taken by creating the file, so neither another capture nor another process can get the same one.
+//!
+//! [`save`] never panics on an I/O error and only logs it, since a failed screenshot
+//! should not take the app down with it; [`try_save`] returns the error instead.
+//!
+//! # Examples
+//!
+//! ```no_run
+//! use rokoko::prelude::*;
+//! use rokoko::window::screenshot;
+//! use std::time::SystemTime;
+//!
+//! // A 2x1 frame: red, then blue
+//! let rgb = [[255, 0, 0], [0, 0, 255]];
+//!
+//! if let Some(path) = screenshot::save("screenshots".as_ref(), SystemTime::now(), uvec2::from([2, 1]), &rgb) {
+//!     println!("saved {}", path.display())
+//! }
+//! ```
+//!
+
+use std::{
+    io::{self, Write},
+    fs::{self, File, OpenOptions},
+    path::{Path, PathBuf},
+    time::{SystemTime, UNIX_EPOCH}
+};
+use crate::math::vec::uvec2;
+
+///
+/// Writes a frame of `size` as a binary PPM(`P6`), with `rgb` pixels row by row from the top-left corner.
+///
+/// # Errors
+///
+/// [`io::ErrorKind::InvalidInput`] if there are not exactly `size[0] * size[1]` pixels,
+/// or whatever `out` fails with.
+///
+/// # Examples
+///
+/// ```
+/// use rokoko::prelude::*;
+/// use rokoko::window::screenshot::write_ppm;
+///
+/// let mut ppm = Vec::new();
+/// write_ppm(&mut ppm, uvec2::from([2, 1]), &[[255, 0, 0], [0, 0, 255]]).unwrap();
+/// assert_eq!(ppm, b"P6\n2 1\n255\n\xff\x00\x00\x00\x00\xff");
+/// ```
+///
+pub fn write_ppm <W: Write> (mut out: W, size: uvec2, rgb: &[[u8; 3]]) -> io::Result <()> {
+    if size[0] as u64 * size[1] as u64 != rgb.len() as u64 {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("expected {}x{} pixels, got {}", size[0], size[1], rgb.len())
+        ))
+    }
+
+    write!(out, "P6\n{} {}\n255\n", size[0], size[1])?;
+    for pixel in rgb {
+        out.write_all(pixel)?
+    }
+    out.flush()
+}
+
+///
+/// Returns the name of a screenshot taken at `time`, with `-{n}` appended unless `n` is `0`.
+///
+/// Times before the Unix epoch are named as the epoch itself.
+///
+/// # Examples
+///
+/// ```
+/// use rokoko::window::screenshot::file_name;
+/// use std::time::{Duration, UNIX_EPOCH};
+///
+/// let time = UNIX_EPOCH + Duration::from_millis(1_792_065_779_123);
+/// assert_eq!(file_name(time, 0), "screenshot-2026-10-15_12-02-59.123.ppm");
+/// assert_eq!(file_name(time, 2), "screenshot-2026-10-15_12-02-59.123-2.ppm");
+/// ```
+///
+pub fn file_name(time: SystemTime, n: u32) -> String {
+    let since_epoch = time.duration_since(UNIX_EPOCH).unwrap_or_default();
+    let secs = since_epoch.as_secs();
+    let (year, month, day) = civil_from_days(secs / 86400);
+    let suffix = if n == 0 { String::new() } else { format!("-{n}") };
+
+    format!(
+        "screenshot-{year:04}-{month:02}-{day:02}_{:02}-{:02}-{:02}.{:03}{suffix}.ppm",
+        secs / 3600 % 24, secs / 60 % 60, secs % 60, since_epoch.subsec_millis()
+    )
+}
+
+///
+/// Returns the `(year, month, day)` of the Gregorian calendar `days` after the Unix epoch.
+///
+/// See <http://howardhinnant.github.io/date_algorithms.html#civil_from_days>.
+///
+fn civil_from_days(days: u64) -> (u64, u64, u64) {
+    // Shifted so that eras(400-year cycles) start on March 1st of the year 0
+    let days = days + 719468;
+    let era = days / 146097;
+    let day_of_era = days % 146097;
+    let year_of_era = (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    // From March
+    let month = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month + 2) / 5 + 1;
+    let month = if month < 10 { month + 3 } else { month - 9 };
+    let year = year_of_era + era * 400 + (month <= 2) as u64;
+    (year, month, day)
+}
+
+///
+/// Creates `dir` if missing and a new file in it named after `time` by [`file_name`],
+/// taking the first `n` whose name is not taken yet.
+///
+/// # Errors
+///
+/// Whatever creating the directory or the file fails with, except for the names already taken.
+///
+pub fn create_file(dir: &Path, time: SystemTime) -> io::Result <(PathBuf, File)> {
+    fs::create_dir_all(dir)?;
+    let mut n = 0;
+    loop {
+        let path = dir.join(file_name(time, n));
+        match OpenOptions::new().write(true).create_new(true).open(&path) {
+            Ok(file) => return Ok((path, file)),
+            Err(e) if e.kind() == io::ErrorKind::AlreadyExists => n += 1,
+            Err(e) => return Err(e)
+        }
+    }
+}
+
+///
+/// Saves a frame taken at `time` into a new file in `dir`, see [`create_file`] and [`write_ppm`],
+/// returning its path.
+///
+/// A file that failed to be written is removed.
+///
+/// # Errors
+///
+/// Whatever [`create_file`] or [`write_ppm`] fails with.
+///
+pub fn try_save(dir: &Path, time: SystemTime, size: uvec2, rgb: &[[u8; 3]]) -> io::Result <PathBuf> {
+    let (path, file) = create_file(dir, time)?;
+    match write_ppm(io::BufWriter::new(file), size, rgb) {
+        Ok(()) => Ok(path),
+        Err(e) => {
+            let _ = fs::remove_file(&path);
+            Err(e)
+        }
+    }
+}
+
+///
+/// Same as [`try_save`], but logs an error to `stderr` instead of returning it.
+///
+/// # Examples
+///
+/// ```
+/// use rokoko::prelude::*;
+/// use rokoko::window::screenshot;
+/// use std::time::SystemTime;
+///
+/// // Not a panic, even if the directory cannot be created
+/// let path = screenshot::save("\0".as_ref(), SystemTime::now(), uvec2::from([1, 1]), &[[0, 0, 0]]);
+/// assert_eq!(path, None);
+/// ```
+///
+pub fn save(dir: &Path, time: SystemTime, size: uvec2, rgb: &[[u8; 3]]) -> Option <PathBuf> {
+    try_save(dir, time, size, rgb)
+        .map_err(|e| eprintln!("rokoko: failed to save a screenshot into {}: {e}", dir.display()))
+        .ok()
+}
@@ -0,0 +1,176 @@
+//!
+//! This module provides [`CloseSchedule`] - the bookkeeping behind [`Window::close_after`],
+//! i.e. "close in 3 seconds unless the user interacts".
+//!
+//! A window has a single schedule, so scheduling again replaces the deadline:
+//! - every scheduling gets an id, which its [`CloseToken`] cancels by, so cancelling
+//!   through a token of a replaced scheduling does nothing;
+//! - once the deadline passes, the schedule fires and the window starts closing,
+//!   after which neither cancelling nor scheduling again has any effect.
+//!
+//! The generated event loop fires the schedule on every iteration and wakes up
+//! by the deadline, see [`CloseSchedule::control_flow`].
+//!
+//! # Examples
+//!
+//! ```
+//! # extern crate winit;
+//! use rokoko::window::shutdown::CloseSchedule;
+//! use std::time::{Duration, Instant};
+//!
+//! let start = Instant::now();
+//! let secs = |s| start + Duration::from_secs(s);
+//!
+//! let mut schedule = CloseSchedule::new();
+//! let id = schedule.schedule(secs(3));
+//!
+//! // The user interacts at t=1
+//! assert!(schedule.cancel(id));
+//! assert!(!schedule.fire(secs(3)));
+//!
+//! // Nobody does
+//! schedule.schedule(secs(3));
+//! assert!(!schedule.fire(secs(2)));
+//! assert!(schedule.fire(secs(3)));
+//! ```
+//!
+//! [`Window::close_after`]: super::Window::close_after
+//! [`CloseToken`]: super::CloseToken
+//!
+
+use std::time::{Duration, Instant};
+use winit::event_loop::ControlFlow;
+
+/// Where a [`CloseSchedule`] is
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum State {
+    Idle,
+    Scheduled { id: u64, deadline: Instant },
+    Fired
+}
+
+///
+/// A close of a window scheduled for later, see the [module documentation](self).
+///
+#[derive(Debug, Clone)]
+pub struct CloseSchedule {
+    state: State,
+    next_id: u64,
+
+    /// Whether the latest scheduling is yet to be reported to `on_close_scheduled`
+    unannounced: bool
+}
+
+impl CloseSchedule {
+    /// Creates a schedule with nothing scheduled
+    pub const fn new() -> Self {
+        Self { state: State::Idle, next_id: 0, unannounced: false }
+    }
+
+    ///
+    /// Schedules the close at `deadline`, replacing the scheduled one, if any.
+    ///
+    /// Returns the id to [cancel](CloseSchedule::cancel) it by.
+    ///
+    pub fn schedule(&mut self, deadline: Instant) -> u64 {
+        let id = self.next_id;
+        self.next_id += 1;
+        if self.state != State::Fired {
+            self.state = State::Scheduled { id, deadline };
+            self.unannounced = true
+        }
+        id
+    }
+
+    ///
+    /// Cancels the close scheduled with `id`.
+    ///
+    /// Returns `true` if it was cancelled, `false` if it has been replaced,
+    /// cancelled before, or has already fired.
+    ///
+    pub fn cancel(&mut self, id: u64) -> bool {
+        match self.state {
+            State::Scheduled { id: scheduled, .. } if scheduled == id => {
+                self.state = State::Idle;
+                self.unannounced = false;
+                true
+            },
+            _ => false
+        }
+    }
+
+    /// Returns `true` if a close is scheduled and has not fired yet
+    pub fn is_scheduled(&self) -> bool {
+        self.deadline().is_some()
+    }
+
+    /// Returns when the scheduled close is due, `None` if there is none
+    pub fn deadline(&self) -> Option <Instant> {
+        match self.state {
+            State::Scheduled { deadline, .. } => Some(deadline),
+            _ => None
+        }
+    }
+
+    ///
+    /// Returns `true` once, when the close is due at `now`, i.e. the window should start closing.
+    ///
+    pub fn fire(&mut self, now: Instant) -> bool {
+        match self.state {
+            State::Scheduled { deadline, .. } if now >= deadline => {
+                self.state = State::Fired;
+                self.unannounced = false;
+                true
+            },
+            _ => false
+        }
+    }
+
+    ///
+    /// Returns the time left until the close, if it is scheduled and has not been announced yet.
+    ///
+    /// Lets `on_close_scheduled` be called once per scheduling.
+    ///
+    pub fn announce(&mut self, now: Instant) -> Option <Duration> {
+        let deadline = self.deadline().filter(|_| self.unannounced)?;
+        self.unannounced = false;
+        Some(deadline.saturating_duration_since(now))
+    }
+
+    ///
+    /// Returns `flow`, waking the loop by the deadline if a close is scheduled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # extern crate winit;
+    /// use rokoko::window::shutdown::CloseSchedule;
+    /// use winit::event_loop::ControlFlow;
+    /// use std::time::{Duration, Instant};
+    ///
+    /// let soon = Instant::now();
+    /// let later = soon + Duration::from_secs(1);
+    ///
+    /// let mut schedule = CloseSchedule::new();
+    /// assert_eq!(schedule.control_flow(ControlFlow::Wait), ControlFlow::Wait);
+    ///
+    /// schedule.schedule(soon);
+    /// assert_eq!(schedule.control_flow(ControlFlow::Wait), ControlFlow::WaitUntil(soon));
+    /// assert_eq!(schedule.control_flow(ControlFlow::WaitUntil(later)), ControlFlow::WaitUntil(soon));
+    /// assert_eq!(schedule.control_flow(ControlFlow::Poll), ControlFlow::Poll);
+    /// ```
+    ///
+    pub fn control_flow(&self, flow: ControlFlow) -> ControlFlow {
+        match (flow, self.deadline()) {
+            (ControlFlow::Wait, Some(deadline)) => ControlFlow::WaitUntil(deadline),
+            (ControlFlow::WaitUntil(wake), Some(deadline)) => ControlFlow::WaitUntil(wake.min(deadline)),
+            (flow, _) => flow
+        }
+    }
+}
+
+impl Default for CloseSchedule {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,97 @@
+//!
+//! This module provides [`SizePolicy`] - a way to specify the size of a window
+//! relatively to the monitor it is shown on.
+//!
+
+use crate::math::vec::vec2;
+use winit::{
+    window::Window as Winit,
+    dpi::PhysicalSize
+};
+
+///
+/// Size of a window that cannot be known until the window is built,
+/// since it depends on the monitor.
+///
+/// See [`super::build::WindowBuilder::auto_size`].
+///
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum SizePolicy {
+    /// 1280x720 logical pixels
+    P720,
+
+    /// 1920x1080 logical pixels
+    P1080,
+
+    /// The given fraction of the monitor size, must be in `(0, 1]`
+    Fraction(f32)
+}
+
+impl SizePolicy {
+    ///
+    /// Panics if the policy is invalid, i.e. a fraction is not in `(0, 1]`.
+    ///
+    pub fn validate(&self) {
+        if let Self::Fraction(fraction) = *self {
+            assert!(fraction > 0.0 && fraction <= 1.0, "size fraction must be in (0, 1], but is {}", fraction)
+        }
+    }
+
+    ///
+    /// Computes the physical size of a window on a monitor
+    /// of `monitor` physical size with the given `scale_factor`.
+    ///
+    /// Presets are scaled by `scale_factor`, and then, if they do not fit the monitor,
+    /// are shrunk keeping the aspect ratio.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rokoko::prelude::*;
+    /// # use rokoko::window::size_policy::SizePolicy;
+    /// // Full HD monitor
+    /// assert_eq!(SizePolicy::P720.compute(vec2::from([1920., 1080.]), 1.0), vec2::from([1280., 720.]));
+    ///
+    /// // 4K monitor with 200% scaling
+    /// assert_eq!(SizePolicy::P720.compute(vec2::from([3840., 2160.]), 2.0), vec2::from([2560., 1440.]));
+    /// assert_eq!(SizePolicy::P1080.compute(vec2::from([3840., 2160.]), 2.0), vec2::from([3840., 2160.]));
+    ///
+    /// // Too small monitor
+    /// assert_eq!(SizePolicy::P1080.compute(vec2::from([1280., 1024.]), 1.0), vec2::from([1280., 720.]));
+    ///
+    /// // Fractions do not depend on the scale factor
+    /// assert_eq!(SizePolicy::Fraction(0.5).compute(vec2::from([1920., 1080.]), 1.5), vec2::from([960., 540.]));
+    /// ```
+    ///
+    pub fn compute(self, monitor: vec2, scale_factor: f64) -> vec2 {
+        let wanted = match self {
+            Self::P720 => vec2::from([1280., 720.]),
+            Self::P1080 => vec2::from([1920., 1080.]),
+            Self::Fraction(fraction) => return monitor * fraction
+        } * scale_factor as f32;
+
+        let ratio = (monitor[0] / wanted[0]).min(monitor[1] / wanted[1]);
+        if ratio < 1.0 {
+            wanted * ratio
+        } else {
+            wanted
+        }
+    }
+
+    ///
+    /// Resizes the window according to the policy.
+    ///
+    /// Uses the primary monitor, or, if it cannot be determined, the current one.
+    ///
+    /// Does nothing if no monitor is found.
+    ///
+    /// ## Note
+    /// `winit` does not provide the work area of a monitor, so the whole monitor size is used.
+    ///
+    pub fn apply(self, window: &Winit) {
+        if let Some(monitor) = window.primary_monitor().or_else(|| window.current_monitor()) {
+            let size = self.compute(monitor.size().cast::<f32>().into(), monitor.scale_factor());
+            window.set_inner_size(PhysicalSize::<f32>::from(size))
+        }
+    }
+}
@@ -0,0 +1,267 @@
+//!
+//! This module provides [`TextEdit`] - the editing state of a single-line text field,
+//! i.e. a cursor and a selection, changed by keys and typed characters.
+//!
+//! It does not own the text: every method takes the `String` being edited,
+//! so the same value can be shown and changed elsewhere, e.g. by
+//! [`UiCtx::text_field`](super::debug_ui::UiCtx::text_field) of the debug UI.
+//!
+//! Positions are counted in `char`s, not graphemes, since the crate has no segmentation data:
+//! e.g. `e` followed by a combining accent is crossed by two presses of `Left`,
+//! and `Back` removes the accent alone.
+//!
+//! | Key                   | Action                                                             |
+//! |-----------------------|--------------------------------------------------------------------|
+//! | `Left`, `Right`       | Moves the cursor by a char, or to the side of the selection        |
+//! | `Home`, `End`         | Moves the cursor to the start or the end                           |
+//! | `Shift` + any above   | Extends the selection instead                                      |
+//! | `Back`, `Delete`      | Removes the selection, or the char before or after the cursor      |
+//! | `Ctrl+A`              | Selects everything                                                 |
+//! | `Ctrl+C`, `Ctrl+X`    | Copies, or cuts, the selection into the [`Clipboard`]              |
+//! | `Ctrl+V`              | Pastes the [`Clipboard`] over the selection                        |
+//!
+//! `Logo`(`Command` on macOS) works as `Ctrl` does, so the usual shortcuts work everywhere.
+//!
+//! Typed characters, e.g. of [`WindowBuilder::on_char`], replace the selection.
+//! Control characters are ignored, either typed or pasted, since platforms report e.g. `Back`
+//! both as a key and as `'\u{8}'`, and the field has a single line.
+//!
+//! # Examples
+//!
+//! ```
+//! use rokoko::window::textedit::TextEdit;
+//! use rokoko::window::input_map::{Key, Modifiers};
+//!
+//! let mut text = String::from("hello");
+//! let mut edit = TextEdit::new();
+//! // An in-process clipboard
+//! let mut clipboard = String::new();
+//!
+//! edit.key(&mut text, Key::End, Modifiers::NONE, &mut clipboard);
+//! edit.key(&mut text, Key::Left, Modifiers::SHIFT, &mut clipboard);
+//! edit.key(&mut text, Key::Left, Modifiers::SHIFT, &mut clipboard);
+//! assert_eq!(edit.selected(&text), "lo");
+//!
+//! edit.key(&mut text, Key::X, Modifiers::CTRL, &mut clipboard);
+//! assert_eq!((text.as_str(), clipboard.as_str()), ("hel", "lo"));
+//!
+//! edit.char(&mut text, 'p');
+//! assert_eq!(text, "help");
+//! ```
+//!
+//! [`WindowBuilder::on_char`]: super::build::WindowBuilder::on_char
+//!
+
+use super::input_map::{Key, Modifiers};
+use core::ops::Range;
+
+///
+/// Where [`TextEdit`] copies to and pastes from.
+///
+/// Implemented for `String` as an in-process clipboard, while the one of the system
+/// can be bridged by implementing it for a handle to that.
+///
+pub trait Clipboard {
+    /// Returns the text in the clipboard, `None` if there is none
+    fn get(&mut self) -> Option <String>;
+
+    /// Replaces the contents of the clipboard with `text`
+    fn set(&mut self, text: String);
+}
+
+impl Clipboard for String {
+    fn get(&mut self) -> Option <String> {
+        Some(self.clone())
+    }
+
+    fn set(&mut self, text: String) {
+        *self = text
+    }
+}
+
+///
+/// A cursor and a selection in a single-line text, see the [module documentation](self).
+///
+/// The selection spans from the anchor, where it was started, to the cursor, and is empty
+/// if they are the same. Both are clamped to the text before every change, so the text
+/// may be changed elsewhere in between.
+///
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+pub struct TextEdit {
+    cursor: usize,
+    anchor: usize
+}
+
+impl TextEdit {
+    /// Creates a state with the cursor at the start and nothing selected
+    pub const fn new() -> Self {
+        Self { cursor: 0, anchor: 0 }
+    }
+
+    /// Returns the position of the cursor, in chars
+    pub const fn cursor(&self) -> usize {
+        self.cursor
+    }
+
+    /// Returns the selected chars, empty(at the cursor) if there is no selection
+    pub fn selection(&self) -> Range <usize> {
+        self.cursor.min(self.anchor)..self.cursor.max(self.anchor)
+    }
+
+    /// Returns the selected part of `text`
+    pub fn selected <'a> (&self, text: &'a str) -> &'a str {
+        let Range { start, end } = self.selection();
+        &text[offset(text, start)..offset(text, end)]
+    }
+
+    /// Moves the cursor to `pos`(clamped to `text`), dropping the selection
+    pub fn set_cursor(&mut self, text: &str, pos: usize) {
+        self.cursor = pos.min(text.chars().count());
+        self.anchor = self.cursor
+    }
+
+    /// Selects the whole `text`, leaving the cursor at the end
+    pub fn select_all(&mut self, text: &str) {
+        self.anchor = 0;
+        self.cursor = text.chars().count()
+    }
+
+    ///
+    /// Handles a press(or an auto-repeat) of `key` with `modifiers` held, see the [module documentation](self).
+    ///
+    /// Returns `true` if `text` changed. Keys without an action are ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rokoko::window::textedit::TextEdit;
+    /// use rokoko::window::input_map::{Key, Modifiers};
+    ///
+    /// let mut text = String::from("abc");
+    /// let mut edit = TextEdit::new();
+    /// let mut clipboard = String::from("x");
+    ///
+    /// assert!(!edit.key(&mut text, Key::Right, Modifiers::NONE, &mut clipboard));
+    /// assert!(edit.key(&mut text, Key::Delete, Modifiers::NONE, &mut clipboard));
+    /// assert!(edit.key(&mut text, Key::V, Modifiers::LOGO, &mut clipboard));
+    /// assert_eq!(text, "axc");
+    /// assert_eq!(edit.cursor(), 2);
+    /// ```
+    ///
+    pub fn key(&mut self, text: &mut String, key: Key, modifiers: Modifiers, clipboard: &mut impl Clipboard) -> bool {
+        self.clamp(text);
+        let len = text.chars().count();
+        let extend = modifiers.contains(Modifiers::SHIFT);
+        let shortcut = modifiers.contains(Modifiers::CTRL) || modifiers.contains(Modifiers::LOGO);
+        let selection = self.selection();
+
+        match key {
+            Key::Left if !extend && !selection.is_empty() => self.move_to(selection.start, false),
+            Key::Right if !extend && !selection.is_empty() => self.move_to(selection.end, false),
+            Key::Left => self.move_to(self.cursor.saturating_sub(1), extend),
+            Key::Right => self.move_to((self.cursor + 1).min(len), extend),
+            Key::Home => self.move_to(0, extend),
+            Key::End => self.move_to(len, extend),
+            Key::Back => {
+                if selection.is_empty() {
+                    self.anchor = self.cursor.saturating_sub(1)
+                }
+                return self.remove_selection(text)
+            },
+            Key::Delete => {
+                if selection.is_empty() {
+                    self.anchor = (self.cursor + 1).min(len)
+                }
+                return self.remove_selection(text)
+            },
+            Key::A if shortcut => self.select_all(text),
+            Key::C if shortcut => self.copy(text, clipboard),
+            Key::X if shortcut => {
+                self.copy(text, clipboard);
+                return self.remove_selection(text)
+            },
+            Key::V if shortcut => return match clipboard.get() {
+                Some(pasted) => self.insert(text, &pasted),
+                None => false
+            },
+            _ => ()
+        }
+        false
+    }
+
+    ///
+    /// Types `c` over the selection, unless it is a control character.
+    ///
+    /// Returns `true` if `text` changed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rokoko::window::textedit::TextEdit;
+    ///
+    /// let mut text = String::new();
+    /// let mut edit = TextEdit::new();
+    ///
+    /// assert!(edit.char(&mut text, 'ü'));
+    /// // E.g. reported alongside `Key::Back`
+    /// assert!(!edit.char(&mut text, '\u{8}'));
+    /// assert_eq!(text, "ü");
+    /// ```
+    ///
+    pub fn char(&mut self, text: &mut String, c: char) -> bool {
+        !c.is_control() && self.insert(text, c.encode_utf8(&mut [0; 4]))
+    }
+
+    ///
+    /// Inserts `s` over the selection, without its control characters, e.g. a pasted text
+    /// or an IME commit string.
+    ///
+    /// Returns `true` if `text` changed.
+    ///
+    pub fn insert(&mut self, text: &mut String, s: &str) -> bool {
+        self.clamp(text);
+        let inserted = s.chars().filter(|c| !c.is_control()).collect::<String>();
+        let removed = self.remove_selection(text);
+
+        text.insert_str(offset(text, self.cursor), &inserted);
+        self.cursor += inserted.chars().count();
+        self.anchor = self.cursor;
+
+        removed || !inserted.is_empty()
+    }
+
+    /// Moves the cursor to `pos`, keeping the anchor if `extend`
+    fn move_to(&mut self, pos: usize, extend: bool) {
+        self.cursor = pos;
+        if !extend {
+            self.anchor = pos
+        }
+    }
+
+    /// Copies the selection, leaving the clipboard as is if there is none
+    fn copy(&self, text: &str, clipboard: &mut impl Clipboard) {
+        if !self.selection().is_empty() {
+            clipboard.set(String::from(self.selected(text)))
+        }
+    }
+
+    /// Removes the selection, returns `true` if there was one
+    fn remove_selection(&mut self, text: &mut String) -> bool {
+        let Range { start, end } = self.selection();
+        text.replace_range(offset(text, start)..offset(text, end), "");
+        self.move_to(start, false);
+        start != end
+    }
+
+    /// Clamps the cursor and the anchor to `text`, which may have changed since
+    fn clamp(&mut self, text: &str) {
+        let len = text.chars().count();
+        self.cursor = self.cursor.min(len);
+        self.anchor = self.anchor.min(len)
+    }
+}
+
+/// Returns the byte offset of the `pos`-th char of `text`, its length if there are fewer
+fn offset(text: &str, pos: usize) -> usize {
+    text.char_indices().nth(pos).map_or(text.len(), |(i, _)| i)
+}
@@ -0,0 +1,260 @@
+//!
+//! This module provides [`Viewport`] - conversions between the coordinate spaces of a window.
+//!
+//! # Spaces
+//!
+//! - *Window* space: physical pixels from the top-left corner of the inner area, `y` down,
+//!   just as cursor positions reported by `winit`. It is continuous: pixel `(i, j)` covers
+//!   `[i, i + 1) × [j, j + 1)`, so its center is `(i + 0.5, j + 0.5)`.
+//! - *Pixel* space: integer indices of pixels, `(0, 0)` being the top-left one,
+//!   e.g. in a frame buffer.
+//! - *NDC*(normalized device coordinates): `[-1, 1]` in both axes, `y` up, as in OpenGL.
+//!   `(-1, 1)` is the top-left *corner* of the window, not the center of its top-left pixel,
+//!   and `(0, 0)` is the center of the window.
+//! - *Logical* space: window space divided by the scale factor, i.e. what
+//!   [`WindowBuilder::logical_size`] sizes are in.
+//!
+//! The window methods, e.g. [`Window::window_to_ndc`], use the current inner size
+//! and scale factor, see [`Window::viewport`].
+//!
+//! # Examples
+//!
+//! ```
+//! use rokoko::prelude::*;
+//! use rokoko::window::viewport::Viewport;
+//!
+//! let viewport = Viewport::new(uvec2::from([800, 600]), 1.0);
+//!
+//! // A click at the center
+//! assert_eq!(viewport.window_to_ndc(vec2::from([400.0, 300.0])), vec2::from([0.0, 0.0]));
+//!
+//! // The top-left pixel
+//! let center = viewport.pixel_to_window(uvec2::from([0, 0]));
+//! assert_eq!(center, vec2::from([0.5, 0.5]));
+//! assert_eq!(viewport.window_to_pixel(center), Some(uvec2::from([0, 0])));
+//! ```
+//!
+//! [`WindowBuilder::logical_size`]: super::build::WindowBuilder::logical_size
+//! [`Window::window_to_ndc`]: super::Window::window_to_ndc
+//! [`Window::viewport`]: super::Window::viewport
+//!
+
+use crate::math::vec::{vec2, uvec2};
+
+///
+/// Size and scale factor of a window, converting between its spaces,
+/// see [module documentation](self).
+///
+/// # Examples
+///
+/// Round trips at corners, edges and centers of windows of odd and even sizes:
+///
+/// ```
+/// use rokoko::prelude::*;
+/// use rokoko::window::viewport::Viewport;
+///
+/// for (w, h) in [(1, 1), (2, 2), (3, 5), (640, 480), (641, 481), (1, 1000)] {
+///     let viewport = Viewport::new(uvec2::from([w, h]), 1.0);
+///     let (wf, hf) = (w as f32, h as f32);
+///
+///     // Corners of the window
+///     assert_eq!(viewport.window_to_ndc(vec2::from([0.0, 0.0])), vec2::from([-1.0, 1.0]));
+///     assert_eq!(viewport.window_to_ndc(vec2::from([wf, hf])), vec2::from([1.0, -1.0]));
+///     assert_eq!(viewport.window_to_ndc(vec2::from([wf, 0.0])), vec2::from([1.0, 1.0]));
+///     assert_eq!(viewport.window_to_ndc(vec2::from([0.0, hf])), vec2::from([-1.0, -1.0]));
+///     assert_eq!(viewport.ndc_to_window(vec2::from([-1.0, 1.0])), vec2::from([0.0, 0.0]));
+///     assert_eq!(viewport.ndc_to_window(vec2::from([1.0, -1.0])), vec2::from([wf, hf]));
+///
+///     // The center
+///     assert_eq!(viewport.window_to_ndc(vec2::from([wf / 2.0, hf / 2.0])), vec2::from([0.0, 0.0]));
+///     assert_eq!(viewport.ndc_to_window(vec2::from([0.0, 0.0])), vec2::from([wf / 2.0, hf / 2.0]));
+///
+///     // Every corner and edge pixel, and the central one
+///     let mut pixels = vec![[0, 0], [w - 1, 0], [0, h - 1], [w - 1, h - 1], [w / 2, h / 2]];
+///     pixels.extend((0..w).flat_map(|x| [[x, 0], [x, h - 1]]));
+///     pixels.extend((0..h).flat_map(|y| [[0, y], [w - 1, y]]));
+///
+///     for pixel in pixels.into_iter().map(uvec2::from) {
+///         let center = viewport.pixel_to_window(pixel);
+///         assert_eq!(center, vec2::from([pixel[0] as f32 + 0.5, pixel[1] as f32 + 0.5]));
+///         assert_eq!(viewport.window_to_pixel(center), Some(pixel));
+///
+///         // Pixels are half-open: their top-left corner belongs to them, the bottom-right one does not
+///         let corner = vec2::from([pixel[0] as f32, pixel[1] as f32]);
+///         assert_eq!(viewport.window_to_pixel(corner), Some(pixel));
+///         let next = vec2::from([pixel[0] as f32 + 1.0, pixel[1] as f32 + 1.0]);
+///         assert_ne!(viewport.window_to_pixel(next), Some(pixel));
+///
+///         // Through NDC
+///         let ndc = viewport.window_to_ndc(center);
+///         assert!(ndc[0] > -1.0 && ndc[0] < 1.0 && ndc[1] > -1.0 && ndc[1] < 1.0);
+///         assert_eq!(viewport.window_to_pixel(viewport.ndc_to_window(ndc)), Some(pixel));
+///     }
+///
+///     // `y` is flipped: the top row is above the bottom one in NDC
+///     let top = viewport.window_to_ndc(viewport.pixel_to_window(uvec2::from([0, 0])));
+///     let bottom = viewport.window_to_ndc(viewport.pixel_to_window(uvec2::from([0, h - 1])));
+///     assert!(h == 1 || top[1] > bottom[1]);
+///
+///     // Outside
+///     assert_eq!(viewport.window_to_pixel(vec2::from([-0.001, 0.0])), None);
+///     assert_eq!(viewport.window_to_pixel(vec2::from([0.0, -0.001])), None);
+///     assert_eq!(viewport.window_to_pixel(vec2::from([wf, 0.0])), None);
+///     assert_eq!(viewport.window_to_pixel(vec2::from([0.0, hf])), None);
+///     assert_eq!(viewport.window_to_pixel(vec2::from([f32::NAN, 0.0])), None);
+/// }
+/// ```
+///
+/// A window created with a logical size of `400x300` under a scale factor of `2`:
+///
+/// ```
+/// use rokoko::prelude::*;
+/// use rokoko::window::viewport::Viewport;
+///
+/// let viewport = Viewport::new(uvec2::from([800, 600]), 2.0);
+///
+/// // The logical center is the physical center
+/// let center = viewport.logical_to_window(vec2::from([200.0, 150.0]));
+/// assert_eq!(center, vec2::from([400.0, 300.0]));
+/// assert_eq!(viewport.window_to_ndc(center), vec2::from([0.0, 0.0]));
+/// assert_eq!(viewport.window_to_logical(center), vec2::from([200.0, 150.0]));
+///
+/// // A logical pixel covers 2x2 physical ones
+/// let logical_pixel = vec2::from([10.0, 20.0]);
+/// assert_eq!(viewport.window_to_pixel(viewport.logical_to_window(logical_pixel)), Some(uvec2::from([20, 40])));
+/// assert_eq!(viewport.window_to_pixel(viewport.logical_to_window(vec2::from([10.5, 20.5]))), Some(uvec2::from([21, 41])));
+///
+/// // Logical corners
+/// assert_eq!(viewport.window_to_ndc(viewport.logical_to_window(vec2::from([0.0, 0.0]))), vec2::from([-1.0, 1.0]));
+/// assert_eq!(viewport.window_to_ndc(viewport.logical_to_window(vec2::from([400.0, 300.0]))), vec2::from([1.0, -1.0]));
+///
+/// // Round trip of every physical pixel of a small window
+/// let viewport = Viewport::new(uvec2::from([6, 5]), 2.0);
+/// for x in 0..6 {
+///     for y in 0..5 {
+///         let pixel = uvec2::from([x, y]);
+///         let logical = viewport.window_to_logical(viewport.pixel_to_window(pixel));
+///         assert_eq!(viewport.window_to_pixel(viewport.logical_to_window(logical)), Some(pixel));
+///     }
+/// }
+/// ```
+///
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Viewport {
+    /// Inner size of the window in physical pixels
+    pub size: uvec2,
+
+    /// Physical pixels per logical one
+    pub scale_factor: f32
+}
+
+impl Viewport {
+    /// Creates a viewport
+    #[inline]
+    pub const fn new(size: uvec2, scale_factor: f32) -> Self {
+        Self { size, scale_factor }
+    }
+
+    ///
+    /// Returns `true` if the area is zero, e.g. while the window is minimized on Windows.
+    ///
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.size[0] == 0 || self.size[1] == 0
+    }
+
+    ///
+    /// Returns width divided by height, or `1` if the viewport [is empty](Viewport::is_empty),
+    /// so that projections built from it never get `NaN`s.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rokoko::prelude::*;
+    /// use rokoko::window::viewport::Viewport;
+    ///
+    /// assert_eq!(Viewport::new(uvec2::from([800, 400]), 1.0).aspect_ratio(), 2.0);
+    ///
+    /// // Minimized
+    /// assert_eq!(Viewport::new(uvec2::from([0, 0]), 1.0).aspect_ratio(), 1.0);
+    /// assert_eq!(Viewport::new(uvec2::from([800, 0]), 1.0).aspect_ratio(), 1.0);
+    /// assert_eq!(Viewport::new(uvec2::from([0, 600]), 1.0).aspect_ratio(), 1.0);
+    /// ```
+    ///
+    pub fn aspect_ratio(&self) -> f32 {
+        if self.is_empty() {
+            1.0
+        } else {
+            self.size[0] as f32 / self.size[1] as f32
+        }
+    }
+
+    ///
+    /// Converts `pos` from window space to NDC.
+    ///
+    /// Returns the center, `(0, 0)`, if the viewport [is empty](Viewport::is_empty).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rokoko::prelude::*;
+    /// use rokoko::window::viewport::Viewport;
+    ///
+    /// for size in [[0, 0], [0, 600], [800, 0]] {
+    ///     let viewport = Viewport::new(uvec2::from(size), 1.0);
+    ///     assert!(viewport.is_empty());
+    ///
+    ///     assert_eq!(viewport.window_to_ndc(vec2::from([10.0, 20.0])), vec2::from([0.0, 0.0]));
+    ///     assert_eq!(viewport.window_to_pixel(vec2::from([0.0, 0.0])), None);
+    ///
+    ///     let window = viewport.ndc_to_window(vec2::from([1.0, -1.0]));
+    ///     assert!(window[0].is_finite() && window[1].is_finite());
+    /// }
+    /// ```
+    ///
+    pub fn window_to_ndc(&self, pos: vec2) -> vec2 {
+        if self.is_empty() {
+            return vec2::from([0.0, 0.0])
+        }
+        vec2::from([
+            2.0 * pos[0] / self.size[0] as f32 - 1.0,
+            1.0 - 2.0 * pos[1] / self.size[1] as f32
+        ])
+    }
+
+    /// Converts `ndc` from NDC to window space
+    pub fn ndc_to_window(&self, ndc: vec2) -> vec2 {
+        vec2::from([
+            (ndc[0] + 1.0) * 0.5 * self.size[0] as f32,
+            (1.0 - ndc[1]) * 0.5 * self.size[1] as f32
+        ])
+    }
+
+    ///
+    /// Returns the pixel containing `pos` in window space,
+    /// or `None` if it is outside the window.
+    ///
+    pub fn window_to_pixel(&self, pos: vec2) -> Option <uvec2> {
+        let inside = |x: f32, size: u32| x >= 0.0 && x < size as f32;
+        if inside(pos[0], self.size[0]) && inside(pos[1], self.size[1]) {
+            Some(uvec2::from([pos[0] as u32, pos[1] as u32]))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the center of `pixel` in window space
+    pub fn pixel_to_window(&self, pixel: uvec2) -> vec2 {
+        vec2::from([pixel[0] as f32 + 0.5, pixel[1] as f32 + 0.5])
+    }
+
+    /// Converts `pos` from logical space to window space
+    pub fn logical_to_window(&self, pos: vec2) -> vec2 {
+        vec2::from([pos[0] * self.scale_factor, pos[1] * self.scale_factor])
+    }
+
+    /// Converts `pos` from window space to logical space
+    pub fn window_to_logical(&self, pos: vec2) -> vec2 {
+        vec2::from([pos[0] / self.scale_factor, pos[1] / self.scale_factor])
+    }
+}
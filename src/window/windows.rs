@@ -0,0 +1,203 @@
+//!
+//! This module provides [`Windows`] - several windows run by one event loop,
+//! each with its own data and callbacks.
+//!
+//! The builders are kept in a type list, just as the data of a single [`WindowBuilder`] is,
+//! and [`Windows::create`] opens them in the order they were pushed, calling their
+//! [`WindowBuilder::on_init`]s one after another, then runs the loop:
+//! - events of a window(e.g. `WindowEvent`s) go to its callbacks only;
+//! - events of the loop(e.g. the ones [`WindowBuilder::on_frame`] is based on) go to all of them;
+//! - [`Window::close`](super::Window::close) closes only the window it is called for, while the others keep running,
+//!   and the loop ends once the last one is closed.
+//!
+//! As with [`WindowBuilder::create`], this returns only where the loop is
+//! [reusable](super::event_loop::REUSABLE), and never returns elsewhere.
+//!
+//! A panic in any of the callbacks is reported with the configuration
+//! of the last window opened, see [`panic_context`](super::panic_context).
+//!
+//! # Examples
+//!
+//! ```no_run
+//! use rokoko::window::{Window, Windows};
+//!
+//! Windows::new()
+//!     .push(Window::new()
+//!         .title("Scene")
+//!         .on_frame(|_, _dt| ()))
+//!     .push(Window::new()
+//!         .title("Inspector")
+//!         .size((300., 600.))
+//!         .on_key(|w, _key, _pressed| w.set_title("Inspector*")))
+//!     .create()
+//!     .unwrap();
+//! ```
+//!
+//! [`WindowBuilder`]: super::build::WindowBuilder
+//! [`WindowBuilder::on_init`]: super::build::WindowBuilder::on_init
+//! [`WindowBuilder::on_frame`]: super::build::WindowBuilder::on_frame
+//! [`WindowBuilder::create`]: super::build::WindowBuilder::create
+//!
+
+use super::{
+    UserEvent,
+    build::{WindowBuilder, transmute, type_list::{With, Empty}},
+    event_loop::{self, Error}
+};
+use winit::{
+    event::Event,
+    event_loop::{EventLoop, ControlFlow},
+    window::WindowId
+};
+
+///
+/// Builders of windows to be run together, see the [module documentation](self).
+///
+pub struct Windows <L = Empty> (L);
+
+impl Windows {
+    pub const fn new() -> Self {
+        Self(Empty)
+    }
+}
+
+impl Default for Windows {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl <L> Windows <L> {
+    ///
+    /// Adds a window to be opened after the ones pushed before.
+    ///
+    pub const fn push <C> (self, builder: WindowBuilder <C>) -> Windows <With <WindowBuilder <C>, L>> {
+        Windows(With {
+            data: builder,
+            // SAFETY: same as `WindowBuilder::to_inner`, `Windows` only contains `L`
+            next: unsafe { transmute(self) }
+        })
+    }
+}
+
+impl <L: OpenAll> Windows <L> {
+    ///
+    /// Opens all the windows and runs them until the last one is closed.
+    ///
+    /// Fails if any of them cannot be created, closing the ones opened before.
+    ///
+    pub fn create(self) -> Result <(), Error> {
+        let event_loop = event_loop::acquire()?;
+        let mut handlers = Vec::new();
+        self.0.open_all(&event_loop, &mut handlers)?;
+
+        event_loop::run(event_loop, move |event, _, cf| {
+            let target = match &event {
+                Event::WindowEvent { window_id, .. } | Event::RedrawRequested(window_id) => Some(*window_id),
+                _ => None
+            };
+
+            match target {
+                Some(id) => if let Some(handler) = handlers.iter_mut().find(|handler| handler.id == id) {
+                    handler.handle(event)
+                },
+                // Only window events borrow anything, so the rest can be copied for everyone
+                None => if let Some(event) = event.to_static() {
+                    for handler in &mut handlers {
+                        handler.handle(event.clone())
+                    }
+                }
+            }
+
+            // Dropping a handler closes its window right away
+            handlers.retain(|handler| handler.control_flow() != ControlFlow::Exit);
+            *cf = merge_control_flows(handlers.iter().map(Handler::control_flow))
+        })
+    }
+}
+
+///
+/// The running state of a window: the window itself, its data and callbacks.
+///
+/// Created by `WindowBuilder::create` and [`Windows::create`], and
+/// handles to the window are invalidated once it is dropped, see [`super::WeakWindow`].
+///
+pub struct Handler {
+    id: WindowId,
+    generation: usize,
+    control_flow: ControlFlow,
+    handle: Box <dyn FnMut(Event <'_, UserEvent>, &mut ControlFlow)>
+}
+
+impl Handler {
+    pub(crate) fn new(id: WindowId, generation: usize, handle: impl FnMut(Event <'_, UserEvent>, &mut ControlFlow) + 'static) -> Self {
+        Self {
+            id,
+            generation,
+            control_flow: ControlFlow::default(),
+            handle: Box::new(handle)
+        }
+    }
+
+    /// Passes `event` to the callbacks of the window
+    pub(crate) fn handle(&mut self, event: Event <'_, UserEvent>) {
+        (self.handle)(event, &mut self.control_flow)
+    }
+
+    /// Returns what the window asks the loop to do, [`ControlFlow::Exit`] once it is closed
+    pub(crate) fn control_flow(&self) -> ControlFlow {
+        self.control_flow
+    }
+}
+
+impl Drop for Handler {
+    fn drop(&mut self) {
+        event_loop::end_generation(self.generation)
+    }
+}
+
+///
+/// Opens every [`WindowBuilder`] of a type list, see [`Windows::create`].
+///
+pub trait OpenAll {
+    /// Opens the windows, appending their handlers to `handlers`
+    fn open_all(self, event_loop: &EventLoop <UserEvent>, handlers: &mut Vec <Handler>) -> Result <(), Error>;
+}
+
+impl OpenAll for Empty {
+    #[inline(always)]
+    fn open_all(self, _: &EventLoop <UserEvent>, _: &mut Vec <Handler>) -> Result <(), Error> {
+        Ok(())
+    }
+}
+
+///
+/// Merges what several windows ask the loop to do: it waits only
+/// as long as all of them allow, and exits only if all of them are closed.
+///
+/// # Examples
+///
+/// ```
+/// # extern crate winit;
+/// use rokoko::window::windows::merge_control_flows;
+/// use winit::event_loop::ControlFlow;
+/// use std::time::{Duration, Instant};
+///
+/// let soon = Instant::now();
+/// let later = soon + Duration::from_secs(1);
+///
+/// assert_eq!(merge_control_flows([]), ControlFlow::Exit);
+/// assert_eq!(merge_control_flows([ControlFlow::Wait, ControlFlow::Exit]), ControlFlow::Wait);
+/// assert_eq!(merge_control_flows([ControlFlow::WaitUntil(later), ControlFlow::Wait, ControlFlow::WaitUntil(soon)]), ControlFlow::WaitUntil(soon));
+/// assert_eq!(merge_control_flows([ControlFlow::WaitUntil(soon), ControlFlow::Poll]), ControlFlow::Poll);
+/// ```
+///
+pub fn merge_control_flows(flows: impl IntoIterator <Item = ControlFlow>) -> ControlFlow {
+    flows.into_iter().fold(ControlFlow::Exit, |merged, flow| match (merged, flow) {
+        (ControlFlow::Exit, flow) | (flow, ControlFlow::Exit) => flow,
+        (ControlFlow::Poll, _) | (_, ControlFlow::Poll) => ControlFlow::Poll,
+        (ControlFlow::WaitUntil(a), ControlFlow::WaitUntil(b)) => ControlFlow::WaitUntil(a.min(b)),
+        (ControlFlow::WaitUntil(t), ControlFlow::Wait) | (ControlFlow::Wait, ControlFlow::WaitUntil(t)) => ControlFlow::WaitUntil(t),
+        (ControlFlow::Wait, ControlFlow::Wait) => ControlFlow::Wait
+    })
+}
@@ -0,0 +1,76 @@
+//!
+//! Checks vecs of `Deg` and `Rad`: the operators, conversions between the units
+//! and the angle helpers on annotated vecs.
+//!
+
+extern crate rokoko;
+
+use rokoko::prelude::*;
+use rokoko::math::scalar::{Scalar, SignedScalar};
+use std::f64::consts::PI;
+
+fn close(a: f64, b: f64) -> bool {
+    (a - b).abs() < 1e-9
+}
+
+#[test]
+fn operators_on_degrees() {
+    let a = vec::from([Deg(10.0f32), Deg(-20.0), Deg(30.0)]);
+    let b = vec::from([Deg(5.0f32), Deg(5.0), Deg(5.0)]);
+
+    assert_eq!(a + b, vec::from([Deg(15.0), Deg(-15.0), Deg(35.0)]));
+    assert_eq!(a - b, vec::from([Deg(5.0), Deg(-25.0), Deg(25.0)]));
+    assert_eq!(-a, vec::from([Deg(-10.0), Deg(20.0), Deg(-30.0)]));
+    assert_eq!(a * Deg(2.0), vec::from([Deg(20.0), Deg(-40.0), Deg(60.0)]));
+
+    let mut c = a;
+    c += b;
+    c -= b;
+    assert_eq!(c, a);
+    assert_eq!(a + vec::ZERO, a);
+}
+
+#[test]
+fn scalar_constants_and_abs() {
+    assert_eq!(Rad::<f64>::ZERO, Rad(0.0));
+    assert_eq!(Deg::<f32>::ONE, Deg(1.0));
+    assert_eq!(Deg(-3.0f32).abs(), Deg(3.0));
+    assert!(Rad(1.0f64) < Rad(2.0));
+}
+
+#[test]
+fn conversions() {
+    assert!(close(Rad::from(Deg(180.0f64)).0, PI));
+    assert!(close(Deg::from(Rad(PI / 2.0)).0, 90.0));
+
+    let degrees = vec::from([Deg(0.0f64), Deg(90.0), Deg(-45.0), Deg(720.0)]);
+    let radians: vec <Rad <f64>, 4> = degrees.into();
+    assert!(close(radians[1].0, PI / 2.0));
+    assert!(close(radians[3].0, 4.0 * PI));
+
+    let back: vec <Deg <f64>, 4> = radians.into();
+    for i in 0..4 {
+        assert!(close(back[i].0, degrees[i].0), "{back:?}");
+    }
+}
+
+#[test]
+fn plain_round_trip() {
+    let v = dvec4::from([1.0, -2.5, 360.0, 0.0]);
+    let back = v.to_radians().to_degrees();
+    for i in 0..4 {
+        assert!(close(back[i], v[i]));
+    }
+    assert_eq!(vec3::from([180.0, 0.0, -180.0]).to_radians(), vec3::from([core::f32::consts::PI, 0.0, -core::f32::consts::PI]));
+}
+
+#[test]
+fn annotated_helpers_match_plain_ones() {
+    let a = dvec3::from([PI - 0.1, 0.3, -3.0 * PI]);
+    let b = dvec3::from([-PI + 0.1, 0.1, 0.5]);
+    let (ra, rb) = (a.apply_unary(Rad), b.apply_unary(Rad));
+
+    assert_eq!(ra.wrap_angles(), a.wrap_angles().apply_unary(Rad));
+    assert_eq!(ra.angle_difference(rb), a.angle_difference(b).apply_unary(Rad));
+    assert_eq!(ra.lerp_angles(rb, 0.25), a.lerp_angles(b, 0.25).apply_unary(Rad));
+}
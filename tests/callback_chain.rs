@@ -0,0 +1,174 @@
+//!
+//! Checks that callbacks registered several times for the same event are chained
+//! in the order of registration, see `getters::CallAll`, and how `on_close_request`
+//! interacts with `on_close`.
+//!
+//! Only walks hand-built type lists and builds(but does not create) windows, as there may be no display.
+//!
+//! Requires nightly and the `window` feature, compiled to nothing otherwise.
+//!
+
+#![cfg(all(feature = "window", nightly))]
+
+extern crate rokoko;
+
+use std::cell::RefCell;
+use rokoko::window::{
+    Window,
+    build::{
+        OnClose, OnCloseRequest, Maximized, WindowBuilder,
+        getters::{CallAll, Chain, has_fn},
+        dispatch::Dispatch,
+        meta::EVENTS,
+        type_list::{With, Empty},
+        fn_container::{Callback, OnEventFnContainer}
+    }
+};
+
+struct Ping;
+
+impl Callback for Ping {
+    type Output = ();
+    type Args = (u32,);
+}
+
+struct Pong;
+
+impl Callback for Pong {
+    type Output = ();
+    type Args = (String,);
+}
+
+struct Vote;
+
+impl Callback for Vote {
+    type Output = bool;
+    type Args = (u32,);
+}
+
+#[test]
+fn two_handlers() {
+    let log = RefCell::new(Vec::new());
+    let first = |n: u32| log.borrow_mut().push(format!("first {n}"));
+    let second = |n: u32| log.borrow_mut().push(format!("second {n}"));
+
+    let mut list = With {
+        data: OnEventFnContainer::<Ping, _>::new(second),
+        next: With { data: OnEventFnContainer::<Ping, _>::new(first), next: Empty }
+    };
+    CallAll::<Ping>::call_all(&mut list, (1,));
+    CallAll::<Ping>::call_all(&mut list, (2,));
+    drop(list);
+
+    assert_eq!(log.into_inner(), ["first 1", "second 1", "first 2", "second 2"]);
+}
+
+#[test]
+fn three_handlers_among_other_data() {
+    let log = RefCell::new(Vec::new());
+    let first = |s: String| log.borrow_mut().push(format!("first {s}"));
+    let second = |s: String| log.borrow_mut().push(format!("second {s}"));
+    let third = |s: String| log.borrow_mut().push(format!("third {s}"));
+    let other = |_: u32| log.borrow_mut().push("other".to_string());
+
+    let mut list = With {
+        data: OnEventFnContainer::<Pong, _>::new(third),
+        next: With {
+            data: OnEventFnContainer::<Ping, _>::new(other),
+            next: With {
+                data: OnEventFnContainer::<Pong, _>::new(second),
+                next: With {
+                    data: Maximized,
+                    next: With { data: OnEventFnContainer::<Pong, _>::new(first), next: Empty }
+                }
+            }
+        }
+    };
+    CallAll::<Pong>::call_all(&mut list, ("pong".to_string(),));
+    drop(list);
+
+    assert_eq!(log.into_inner(), ["first pong", "second pong", "third pong"]);
+}
+
+#[test]
+fn no_handlers() {
+    let mut list = With { data: Maximized, next: Empty };
+    CallAll::<Ping>::call_all(&mut list, (0,));
+
+    // Which is when the generated code falls back to the `#[default]`
+    assert!(!has_fn::<Ping, With <Maximized, Empty>>());
+}
+
+fn closes <C: Dispatch <OnClose>> (builder: &WindowBuilder <C>) -> bool {
+    builder.has_callback::<OnClose>()
+}
+
+#[test]
+fn default_is_replaced_by_any_handler() {
+    // The default closes the window
+    assert!(!closes(&Window::new()));
+
+    // A single handler replaces it, and so do chained ones, so closing has to be requested explicitly
+    assert!(closes(&Window::new().on_close(|_| ())));
+    assert!(closes(&Window::new()
+        .on_close(|_| println!("Closing!"))
+        .title("chained")
+        .on_close(Window::close)));
+}
+
+#[test]
+fn bool_results() {
+    assert!(bool::NONE);
+    assert!(true.chain(true));
+    assert!(!true.chain(false));
+    assert!(!false.chain(true));
+
+    let calls = RefCell::new(0);
+    let yes = |_: u32| { *calls.borrow_mut() += 1; true };
+    let no = |_: u32| { *calls.borrow_mut() += 1; false };
+
+    let mut agreeing = With {
+        data: OnEventFnContainer::<Vote, _>::new(yes),
+        next: With { data: OnEventFnContainer::<Vote, _>::new(yes), next: Empty }
+    };
+    assert!(CallAll::<Vote>::call_all(&mut agreeing, (0,)));
+
+    // A veto does not stop the later callbacks from being called
+    let mut vetoed = With {
+        data: OnEventFnContainer::<Vote, _>::new(yes),
+        next: With {
+            data: OnEventFnContainer::<Vote, _>::new(yes),
+            next: With { data: OnEventFnContainer::<Vote, _>::new(no), next: Empty }
+        }
+    };
+    assert!(!CallAll::<Vote>::call_all(&mut vetoed, (0,)));
+
+    drop((agreeing, vetoed));
+    assert_eq!(calls.into_inner(), 5);
+}
+
+fn requests <C: Dispatch <OnCloseRequest>> (builder: &WindowBuilder <C>) -> bool {
+    builder.has_callback::<OnCloseRequest>()
+}
+
+#[test]
+fn close_request() {
+    let close_request = EVENTS.iter().find(|e| e.name == "on_close_request").unwrap();
+    assert_eq!(close_request.ret, "bool");
+    assert_eq!(EVENTS.iter().find(|e| e.name == "on_close").unwrap().ret, "()");
+
+    // Deciding alone, closing with the default
+    let builder = Window::new().on_close_request(|_| false);
+    assert!(requests(&builder) && !closes(&builder));
+
+    // Deciding, then handling the close with `on_close`
+    let builder = Window::new()
+        .on_close_request(|_| true)
+        .on_close(Window::close)
+        .on_close_request(|_| false);
+    assert!(requests(&builder) && closes(&builder));
+
+    // `()`-returning closures keep working without a request
+    let builder = Window::new().on_close(|_| ());
+    assert!(!requests(&builder) && closes(&builder));
+}
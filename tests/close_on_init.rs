@@ -0,0 +1,47 @@
+//!
+//! Checks that `Window::close` called from `on_init`, before the event loop runs,
+//! closes the window instead of panicking, and that `create` returns afterwards.
+//!
+//! Creates a real window, so needs a display and the main thread, thus is run without the test harness.
+//! Skipped without a display or where `create` never returns, see `window::event_loop::REUSABLE`.
+//!
+//! Requires nightly and the `window` feature, does nothing otherwise.
+//!
+
+#[cfg(all(feature = "window", nightly))]
+fn main() {
+    use rokoko::window::{Window, event_loop::REUSABLE};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    static INITS: AtomicUsize = AtomicUsize::new(0);
+    static EXITS: AtomicUsize = AtomicUsize::new(0);
+
+    if !REUSABLE {
+        eprintln!("note: `create` never returns on this platform, skipping");
+        return
+    }
+    if cfg!(unix) && std::env::var_os("DISPLAY").is_none() && std::env::var_os("WAYLAND_DISPLAY").is_none() {
+        eprintln!("note: no display found, skipping");
+        return
+    }
+
+    for title in ["closed on init", "closed on init twice"] {
+        Window::new()
+            .title(title)
+            .on_init(|w| {
+                INITS.fetch_add(1, Ordering::SeqCst);
+                w.close();
+                // Repeated requests are merged
+                w.close()
+            })
+            .on_frame(|_, _| panic!("must be closed before the first frame"))
+            .on_exit(|_| { EXITS.fetch_add(1, Ordering::SeqCst); })
+            .create()
+            .unwrap();
+    }
+
+    assert_eq!((INITS.load(Ordering::SeqCst), EXITS.load(Ordering::SeqCst)), (2, 2));
+}
+
+#[cfg(not(all(feature = "window", nightly)))]
+fn main() {}
@@ -0,0 +1,116 @@
+//!
+//! Checks `WindowBuilder::config_trace` against known builder configurations,
+//! and that it gets to `doctor`'s report.
+//!
+//! Requires nightly and the `config-trace` feature, compiled to nothing otherwise.
+//!
+
+#![cfg(all(feature = "config-trace", nightly))]
+
+extern crate rokoko;
+
+use rokoko::window::{
+    Window,
+    build::meta::{ConfigTrace, OPTIONS, EVENTS},
+    doctor::{DoctorReport, Probe, MonitorInfo}
+};
+
+/// Names of the present ones
+fn present(trace: &ConfigTrace) -> Vec <&'static str> {
+    trace.options.iter().chain(&trace.events).filter(|(_, present)| *present).map(|&(name, _)| name).collect()
+}
+
+#[test]
+fn lists_everything_described() {
+    let trace = Window::new().config_trace();
+
+    let options: Vec <_> = trace.options.iter().map(|&(name, _)| name).collect();
+    let events: Vec <_> = trace.events.iter().map(|&(name, _)| name).collect();
+    assert_eq!(options, OPTIONS.iter().map(|o| o.name).collect::<Vec <_>>());
+    assert_eq!(events, EVENTS.iter().map(|e| e.name).collect::<Vec <_>>());
+
+    assert!(present(&trace).is_empty());
+    assert_eq!(trace.is_present("on_touch"), None);
+}
+
+#[test]
+fn known_configuration() {
+    let trace = Window::new()
+        .title("traced")
+        .logical_size((800., 600.))
+        .on_close(Window::close)
+        .on_resize(|_, _| ())
+        .config_trace();
+
+    assert_eq!(present(&trace), ["title", "logical_size", "on_close", "on_resize"]);
+
+    let text = trace.to_string();
+    let lines: Vec <_> = text.lines().collect();
+    assert_eq!(lines.len(), OPTIONS.len() + EVENTS.len());
+    assert_eq!(lines[0], "title: STATICALLY PRESENT");
+    assert!(lines.contains(&"maximized: STATICALLY ABSENT"));
+    assert!(lines.contains(&"on_close: STATICALLY PRESENT"));
+    assert!(lines.contains(&"on_init: STATICALLY ABSENT"));
+}
+
+#[test]
+fn callback_attached_to_another_builder() {
+    let builder = Window::new().title("created");
+    // By-value chaining: the callback ends up in a builder that is dropped
+    let _other = Window::new().title("created").on_init(|_| ());
+
+    let trace = builder.config_trace();
+    assert_eq!(trace.is_present("title"), Some(true));
+    assert_eq!(trace.is_present("on_init"), Some(false));
+}
+
+/// A headless CI, having created a window with the trace given
+struct Fake(Option <ConfigTrace>);
+
+impl Probe for Fake {
+    fn features(&self) -> Vec <&'static str> { vec!["window", "config-trace"] }
+    fn cfgs(&self) -> Vec <&'static str> { vec!["nightly"] }
+    fn env(&self, _: &str) -> Option <String> { None }
+    fn backend(&self) -> Result <String, String> { Err(String::from("no display")) }
+    fn monitors(&self) -> Result <Vec <MonitorInfo>, String> { Err(String::from("no display")) }
+    fn event_loop_created(&self) -> bool { self.0.is_some() }
+    fn config_trace(&self) -> Option <ConfigTrace> { self.0.clone() }
+}
+
+#[test]
+fn included_in_doctor_report() {
+    let trace = ConfigTrace {
+        options: vec![("title", true), ("size", false)],
+        events: vec![("on_close", false), ("on_init", true)]
+    };
+
+    let text = DoctorReport::gather(&Fake(Some(trace))).to_string();
+    assert!(text.contains(concat!(
+        "\nevent loop:  already created\n",
+        "config trace:\n",
+        "    title: STATICALLY PRESENT\n",
+        "    size: STATICALLY ABSENT\n",
+        "    on_close: STATICALLY ABSENT\n",
+        "    on_init: STATICALLY PRESENT\n",
+        "environment:\n"
+    )));
+
+    let text = DoctorReport::gather(&Fake(None)).to_string();
+    assert!(text.contains("\nconfig trace: no window created\nenvironment:\n"));
+}
+
+#[test]
+fn default_probe_has_no_trace() {
+    struct Minimal;
+
+    impl Probe for Minimal {
+        fn features(&self) -> Vec <&'static str> { vec![] }
+        fn cfgs(&self) -> Vec <&'static str> { vec![] }
+        fn env(&self, _: &str) -> Option <String> { None }
+        fn backend(&self) -> Result <String, String> { Err(String::new()) }
+        fn monitors(&self) -> Result <Vec <MonitorInfo>, String> { Ok(vec![]) }
+        fn event_loop_created(&self) -> bool { false }
+    }
+
+    assert_eq!(DoctorReport::gather(&Minimal).config_trace, None);
+}
@@ -0,0 +1,67 @@
+//!
+//! Checks that the data declared with `#[conflict]` is rejected together,
+//! e.g. `size` and `maximized`, while either one alone is fine.
+//!
+//! Goes through `WindowBuilder::apply_to_winit`, which applies the data
+//! just as `create` does, without opening a window.
+//!
+//! Requires nightly and the `winit-interop` feature, compiled to nothing otherwise.
+//!
+
+#![cfg(all(feature = "winit-interop", nightly))]
+
+extern crate rokoko;
+extern crate winit;
+
+use rokoko::window::Window;
+use winit::window::WindowBuilder;
+
+#[test]
+#[should_panic(expected = "cannot have both `maximized` and `size`")]
+fn size_and_maximized() {
+    Window::new()
+        .size((800., 600.))
+        .maximized()
+        .apply_to_winit(WindowBuilder::new());
+}
+
+#[test]
+#[should_panic(expected = "cannot have both `maximized` and `size`")]
+fn order_does_not_matter() {
+    Window::new()
+        .maximized()
+        .size((800., 600.))
+        .apply_to_winit(WindowBuilder::new());
+}
+
+#[test]
+#[should_panic(expected = "cannot have both `fullscreen` and `maximized`")]
+fn maximized_and_fullscreen() {
+    Window::new()
+        .fullscreen()
+        .maximized()
+        .apply_to_winit(WindowBuilder::new());
+}
+
+#[test]
+#[should_panic(expected = "cannot have both `fullscreen` and `auto_size`")]
+fn auto_size_and_fullscreen() {
+    Window::new()
+        .fullscreen()
+        .size_720p()
+        .apply_to_winit(WindowBuilder::new());
+}
+
+#[test]
+fn either_alone() {
+    let sized = Window::new()
+        .size((800., 600.))
+        .apply_to_winit(WindowBuilder::new());
+    assert!(sized.window.inner_size.is_some());
+    assert!(!sized.window.maximized);
+
+    let maximized = Window::new()
+        .maximized()
+        .apply_to_winit(WindowBuilder::new());
+    assert!(maximized.window.maximized);
+}
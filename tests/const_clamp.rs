@@ -0,0 +1,32 @@
+//!
+//! Checks that componentwise least, greatest, clamped and absolute values of `vec`s
+//! can be evaluated at compile time.
+//!
+//! Every assertion is a `const` item, so this file fails to compile
+//! if any of them loses its constness.
+//!
+//! Requires nightly, compiled to nothing otherwise.
+//!
+
+#![cfg(nightly)]
+
+extern crate rokoko;
+
+use rokoko::prelude::*;
+use rokoko::math::vec::vec_eq;
+
+const A: ivec3 = ivec3::from_array([1, -5, 3]);
+const B: ivec3 = ivec3::from_array([2, -7, 3]);
+
+const _: () = assert!(vec_eq(A.min(B), ivec3::from_array([1, -7, 3])));
+const _: () = assert!(vec_eq(A.max(B), ivec3::from_array([2, -5, 3])));
+
+const _: () = assert!(vec_eq(A.clamp(ivec3::ZERO, ivec3::single(2)), ivec3::from_array([1, 0, 2])));
+const _: () = assert!(vec_eq(A.clamp_scalar(-1, 1), ivec3::from_array([1, -1, 1])));
+
+const _: () = assert!(vec_eq(A.abs(), ivec3::from_array([1, 5, 3])));
+const _: () = assert!(fvec2::from_array([-0.0, -2.5]).abs().into_array()[1] == 2.5);
+
+/// The assertions above are checked by compiling this file
+#[test]
+fn const_clamps_compile() {}
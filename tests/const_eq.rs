@@ -0,0 +1,43 @@
+//!
+//! Checks that comparisons of `vec`s can be evaluated at compile time.
+//!
+//! Every assertion is a `const` item, so this file fails to compile
+//! if any of them loses its constness.
+//!
+//! Requires nightly, compiled to nothing otherwise.
+//!
+
+#![cfg(nightly)]
+
+extern crate rokoko;
+
+use rokoko::prelude::*;
+use rokoko::math::vec::vec_eq;
+
+/// A `const` replacement of a closure, which cannot be called in `const` contexts
+const fn equal(a: i32, b: i32) -> bool {
+    a == b
+}
+
+const fn positive(a: i32) -> bool {
+    a > 0
+}
+
+const _: () = assert!(!vec_eq(ivec2::UNIT_X, ivec2::UNIT_Y));
+const _: () = assert!(!vec_eq(vec3::UNIT_Z, vec3::ZERO));
+const _: () = assert!(vec_eq(ivec4::ZERO, ivec4::single(0)));
+const _: () = assert!(vec_eq(dvec2::ONE, dvec2::single(1.0)));
+
+// Through `PartialEq`
+const _: () = assert!(ivec3::ZERO == ivec3::single(0));
+const _: () = assert!(ivec3::UNIT_X != ivec3::UNIT_Y);
+
+// Through the methods
+const _: () = assert!(ivec4::UNIT_W.apply_binary_bool(ivec4::UNIT_W, equal));
+const _: () = assert!(!ivec4::UNIT_W.apply_binary_bool(ivec4::UNIT_X, equal));
+const _: () = assert!(ivec2::ONE.apply_unary_bool(positive));
+const _: () = assert!(!ivec2::UNIT_Y.apply_unary_bool(positive));
+
+/// The assertions above are checked by compiling this file
+#[test]
+fn const_comparisons_compile() {}
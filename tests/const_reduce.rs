@@ -0,0 +1,48 @@
+//!
+//! Checks that reductions and indexed maps of `vec`s can be evaluated at compile time.
+//!
+//! Every assertion is a `const` item, so this file fails to compile
+//! if any of them loses its constness.
+//!
+//! Requires nightly, compiled to nothing otherwise.
+//!
+
+#![cfg(nightly)]
+
+extern crate rokoko;
+
+use rokoko::prelude::*;
+use rokoko::math::vec::vec_eq;
+
+const V: ivec4 = ivec4::from_array([3, -1, 4, 1]);
+
+/// `const` replacements of closures, which cannot be called in `const` contexts
+const fn positive(count: i32, e: i32) -> i32 {
+    count + (e > 0) as i32
+}
+
+const fn digits(number: i32, digit: i32) -> i32 {
+    number * 10 + digit
+}
+
+const fn plus_index(i: usize, e: i32) -> i32 {
+    e + i as i32
+}
+
+const _: () = assert!(V.fold(0, positive) == 3);
+const _: () = assert!(ivec4::from_array([1, 2, 3, 4]).fold(0, digits) == 1234);
+
+const _: () = assert!(V.sum() == 7);
+const _: () = assert!(V.product() == -12);
+const _: () = assert!(ivec4::ZERO.sum() == 0);
+const _: () = assert!(ivec4::ONE.product() == 1);
+
+const _: () = assert!(V.min_element() == -1);
+const _: () = assert!(V.max_element() == 4);
+
+const _: () = assert!(vec_eq(V.map_indexed(plus_index), ivec4::from_array([3, 0, 6, 4])));
+const _: () = assert!(vec_eq(ivec4::ZERO.map_indexed(plus_index), ivec4::from_array([0, 1, 2, 3])));
+
+/// The assertions above are checked by compiling this file
+#[test]
+fn const_reductions_compile() {}
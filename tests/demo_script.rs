@@ -0,0 +1,120 @@
+//!
+//! Checks parsing of `window::demo_script::Script` and the order in which `Scheduler` plays it back.
+//!
+//! Requires nightly and the `demo-script` feature, compiled to nothing otherwise.
+//!
+
+#![cfg(all(feature = "demo-script", nightly))]
+
+extern crate rokoko;
+
+use rokoko::math::vec::uvec2;
+use rokoko::window::{
+    Key, MouseButton,
+    demo_script::{Script, Scheduler, Command, Step, Error}
+};
+use std::time::Duration;
+
+fn ms(ms: u64) -> Duration {
+    Duration::from_millis(ms)
+}
+
+fn parse(script: &str) -> Vec <Step> {
+    script.parse::<Script>().unwrap().steps().to_vec()
+}
+
+/// Returns the position and the message of the error
+fn error(script: &str) -> (usize, usize, String) {
+    match script.parse::<Script>() {
+        Err(Error::Syntax { line, column, message }) => (line, column, message),
+        other => panic!("expected a syntax error, got {other:?}")
+    }
+}
+
+#[test]
+fn commands() {
+    let steps = parse("
+        # Comments and empty lines are skipped
+        wait 500ms; key Space down
+        wait 100ms; key MouseLeft up # trailing comment
+        resize 1024 768 ; ; screenshot
+        wait 2s
+        close
+    ");
+    let commands: Vec <_> = steps.iter().map(|step| (step.at, step.command, step.line)).collect();
+
+    assert_eq!(commands, [
+        (ms(500), Command::Key { input: Key::Space.into(), pressed: true }, 3),
+        (ms(600), Command::Key { input: MouseButton::Left.into(), pressed: false }, 4),
+        (ms(600), Command::Resize(uvec2::from([1024, 768])), 5),
+        (ms(600), Command::Screenshot, 5),
+        (ms(2600), Command::Close, 7)
+    ]);
+
+    assert!(parse("").is_empty());
+    assert!(parse("wait 1s\n# nothing else").is_empty());
+}
+
+#[test]
+fn errors_with_positions() {
+    let expected_down_or_up = String::from("expected `down` or `up`");
+
+    assert_eq!(error("key Space"), (1, 10, expected_down_or_up.clone()));
+    assert_eq!(error("wait 1s\nkey Space sideways").1, 11);
+    assert_eq!(error("wait 1s;  jump").0, 1);
+    assert_eq!(error("wait 1s;  jump").1, 11);
+    assert_eq!(error("wait 5 ; close"), (1, 6, String::from("expected a duration, e.g. `500ms` or `2s`, found `5`")));
+    assert_eq!(error("resize 1 2 3").2, "unexpected `3`, commands are separated with `;` or new lines");
+    assert_eq!(error("key Hyper down").2, "unknown key or mouse button `Hyper`");
+    assert_eq!(error("close\n\nscreenshot"), (3, 1, String::from("nothing can follow `close` at line 1")));
+
+    // Columns are counted in characters, not bytes
+    assert_eq!(error("# é\nwait 1s # ✓\nkey Space down; résumé").1, 17);
+    assert_eq!(error("key Space up ;; wait 1ms; wait 10 ms").1, 32);
+
+    let error = "\nwait".parse::<Script>().unwrap_err();
+    assert_eq!(error.to_string(), "2:5: expected a duration, e.g. `500ms` or `2s`");
+}
+
+#[test]
+fn loading() {
+    let path = std::env::temp_dir().join(format!("rokoko-demo-script-{}.txt", std::process::id()));
+
+    assert!(matches!(Script::load(&path), Err(Error::Io(_))));
+
+    std::fs::write(&path, "wait 10ms\nclose\n").unwrap();
+    let script = Script::load(&path);
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(script.unwrap().steps(), [Step { at: ms(10), command: Command::Close, line: 2 }]);
+}
+
+#[test]
+fn scheduling() {
+    let mut scheduler = Scheduler::new("
+        key A down
+        wait 100ms; key A up; key B down
+        wait 50ms; wait 50ms; key B up
+        wait 1s
+        close
+    ".parse().unwrap());
+
+    let lines = |steps: &[Step]| steps.iter().map(|step| step.line).collect::<Vec <_>>();
+
+    assert_eq!(scheduler.next_deadline(), Some(ms(0)));
+    assert_eq!(lines(scheduler.due(ms(0))), [2]);
+
+    // Nothing is returned twice, nor ahead of time
+    assert_eq!(lines(scheduler.due(ms(0))), []);
+    assert_eq!(lines(scheduler.due(ms(99))), []);
+    assert_eq!(scheduler.next_deadline(), Some(ms(100)));
+
+    // Same-time commands keep the order of the script
+    assert_eq!(lines(scheduler.due(ms(100))), [3, 3]);
+    assert_eq!(scheduler.next_deadline(), Some(ms(200)));
+
+    // A late poll catches up on everything that became due
+    assert_eq!(lines(scheduler.due(ms(5000))), [4, 6]);
+    assert!(scheduler.is_finished());
+    assert_eq!(scheduler.next_deadline(), None);
+    assert_eq!(lines(scheduler.due(ms(10000))), []);
+}
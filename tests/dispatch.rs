@@ -0,0 +1,126 @@
+//!
+//! Checks `build::dispatch::Dispatch` the same way with and without `dyn-callbacks` feature:
+//! lists are built as `on_*` methods build them in either mode, then looked up and called.
+//!
+//! Only builds(but does not create) windows, as there may be no display.
+//!
+//! Requires nightly and the `window` feature, compiled to nothing otherwise.
+//!
+
+#![cfg(all(feature = "window", nightly))]
+
+extern crate rokoko;
+
+use std::{cell::RefCell, rc::Rc};
+use rokoko::window::{
+    Window,
+    build::{
+        OnClose, OnInit, OnFrame, WindowBuilder,
+        dispatch::{Dispatch, initial},
+        type_list::ConfigList,
+        fn_container::Callback
+    }
+};
+
+struct Vote;
+
+impl Callback for Vote {
+    type Output = bool;
+    type Args = (u32,);
+}
+
+type Log = Rc <RefCell <Vec <String>>>;
+
+/// A callback logging its `name` and voting for `n > min`
+fn voter(log: &Log, name: &'static str, min: u32) -> impl FnMut(u32) -> bool + 'static {
+    let log = log.clone();
+    move |n| {
+        log.borrow_mut().push(format!("{name} {n}"));
+        n > min
+    }
+}
+
+/// Registers `first`, then `second`, as two `on_*` calls of the same event would
+#[cfg(not(feature = "dyn-callbacks"))]
+fn registered <A, B> (first: A, second: B) -> impl Dispatch <Vote>
+    where A: FnMut(u32) -> bool + 'static, B: FnMut(u32) -> bool + 'static {
+    use rokoko::window::build::{type_list::With, fn_container::OnEventFnContainer};
+
+    // The most recently registered is the head of the list
+    With {
+        data: OnEventFnContainer::<Vote, _>::new(second),
+        next: With { data: OnEventFnContainer::<Vote, _>::new(first), next: initial() }
+    }
+}
+
+/// Registers `first`, then `second`, as two `on_*` calls of the same event would
+#[cfg(feature = "dyn-callbacks")]
+fn registered <A, B> (first: A, second: B) -> impl Dispatch <Vote>
+    where A: FnMut(u32) -> bool + 'static, B: FnMut(u32) -> bool + 'static {
+    let mut list = initial();
+    list.data.push::<Vote>(Box::new(first));
+    list.data.push::<Vote>(Box::new(second));
+    list
+}
+
+#[test]
+fn called_in_order_of_registration() {
+    let log = Log::default();
+    let mut list = registered(voter(&log, "first", 0), voter(&log, "second", 1));
+
+    assert!(Dispatch::<Vote>::has_callback(&list));
+    assert!(Dispatch::<Vote>::call_callbacks(&mut list, (2,)));
+    // A veto does not stop the later callbacks from being called
+    assert!(!Dispatch::<Vote>::call_callbacks(&mut list, (1,)));
+
+    assert_eq!(*log.borrow(), ["first 2", "second 2", "first 1", "second 1"]);
+}
+
+#[test]
+fn nothing_registered() {
+    let mut list = initial();
+    assert!(!Dispatch::<Vote>::has_callback(&list));
+    // The result of no callbacks
+    assert!(Dispatch::<Vote>::call_callbacks(&mut list, (0,)));
+    assert!(!Dispatch::<OnClose>::has_callback(&list));
+}
+
+#[test]
+fn builders() {
+    let builder = Window::new()
+        .title("dispatched")
+        .on_frame(|_, _| ())
+        .on_close(|_| println!("Closing!"))
+        .on_close(Window::close);
+
+    assert!(builder.has_callback::<OnFrame>());
+    assert!(builder.has_callback::<OnClose>());
+    assert!(!builder.has_callback::<OnInit>());
+    assert!(!Window::new().has_callback::<OnClose>());
+}
+
+fn len <C: ConfigList> (_: &WindowBuilder <C>) -> usize {
+    C::LEN
+}
+
+#[test]
+#[cfg(not(feature = "dyn-callbacks"))]
+fn callbacks_grow_the_type() {
+    assert_eq!(len(&Window::new().title("a").on_close(Window::close).on_close(Window::close)), 3);
+}
+
+#[test]
+#[cfg(feature = "dyn-callbacks")]
+fn callbacks_keep_the_type() {
+    let mut builder = Window::new().title("a");
+    let before = len(&builder);
+
+    for _ in 0..3 {
+        builder = builder.on_close(Window::close)
+    }
+    assert_eq!(len(&builder), before);
+    assert!(builder.has_callback::<OnClose>());
+
+    // Data is still in the list
+    assert_eq!(len(&builder.maximized()), before + 1);
+}
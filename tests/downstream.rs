@@ -0,0 +1,42 @@
+//!
+//! Checks that a crate using the `window` feature compiles on nightly
+//! without enabling any nightly features itself, see `tests/downstream`.
+//!
+//! Requires `rustup` with the nightly toolchain `rokoko` is developed on, so it is ignored by default
+//! and fails without the toolchain once asked for:
+//! ```text
+//! cargo test --test downstream -- --ignored
+//! ```
+//! Another toolchain can be chosen with `ROKOKO_NIGHTLY`.
+//!
+
+use std::process::Command;
+
+/// Same as pinned by `rokoko-template`
+const NIGHTLY: &str = "nightly-2022-11-01";
+
+#[test]
+#[ignore = "needs the pinned nightly toolchain via `rustup`, run with `--ignored`"]
+fn downstream_needs_no_feature_gates() {
+    let toolchain = std::env::var("ROKOKO_NIGHTLY").unwrap_or_else(|_| String::from(NIGHTLY));
+
+    let nightly = Command::new("rustup")
+        .args(["run", &toolchain, "cargo", "--version"])
+        .output()
+        .map_or(false, |o| o.status.success());
+    assert!(nightly, "no `{toolchain}` toolchain found via `rustup`, install it or choose another one with `ROKOKO_NIGHTLY`");
+
+    let output = Command::new("rustup")
+        .args(["run", &toolchain, "cargo", "check", "--color", "never", "--manifest-path"])
+        .arg(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/downstream/Cargo.toml"))
+        .arg("--target-dir")
+        .arg(concat!(env!("CARGO_TARGET_TMPDIR"), "/downstream"))
+        // Otherwise the toolchain of this very test would be used
+        .env_remove("RUSTUP_TOOLCHAIN")
+        .env_remove("RUSTC")
+        .env_remove("CARGO")
+        .output()
+        .unwrap();
+
+    assert!(output.status.success(), "must compile without feature gates:\n{}", String::from_utf8_lossy(&output.stderr))
+}
@@ -0,0 +1,41 @@
+//!
+//! Uses as much of `rokoko::window` as possible without any `#![feature(...)]`.
+//!
+//! Must keep compiling: user crates should only need a nightly compiler, not nightly features.
+//!
+
+use rokoko::prelude::*;
+use rokoko::window::size_policy::SizePolicy;
+
+pub fn open() -> Result <(), rokoko::window::event_loop::Error> {
+    let mut frames = 0;
+
+    Window::new()
+        .title("downstream")
+        .auto_size(SizePolicy::Fraction(0.5))
+        .target_fps(60.0)
+        .on_init(|w| println!("{}", w.config()))
+        .on_frame(move |w, dt| {
+            frames += 1;
+            if frames == 100 || dt > 1.0 {
+                w.close()
+            }
+        })
+        .on_cursor_entered(|w| assert!(w.cursor_inside()))
+        .on_close(Window::close)
+        .on_exit(|_| ())
+        .create()
+}
+
+/// Plain functions work as callbacks as well
+fn on_close(window: Window) {
+    window.close()
+}
+
+pub fn open_sized() -> Result <(), rokoko::window::event_loop::Error> {
+    Window::new()
+        .logical_size((640., 480.))
+        .on_close(on_close)
+        .create()
+}
+
@@ -0,0 +1,220 @@
+//!
+//! Checks `window::fullscreen::FullscreenManager` against orderings of focus events
+//! and transitions completing, see the module documentation.
+//!
+//! Requires nightly and the `window` feature, compiled to nothing otherwise.
+//!
+
+#![cfg(all(feature = "window", nightly))]
+
+extern crate rokoko;
+
+use rokoko::window::fullscreen::{FullscreenManager, Command::{self, SetFullscreen, Minimize}};
+
+/// An input of the machine
+#[derive(Debug, Copy, Clone)]
+enum Input {
+    Request(bool),
+    Focused(bool),
+    Observed(bool)
+}
+
+use self::Input::*;
+
+/// Feeds `inputs`, checking the command issued after each of them
+fn run(mut manager: FullscreenManager, steps: &[(Input, Option <Command>)]) -> FullscreenManager {
+    for (i, &(input, expected)) in steps.iter().enumerate() {
+        let command = match input {
+            Request(fullscreen) => manager.request(fullscreen),
+            Focused(focused) => manager.focused(focused),
+            Observed(fullscreen) => manager.observed(fullscreen)
+        };
+        assert_eq!(command, expected, "step {i}: {input:?}");
+    }
+    manager
+}
+
+fn minimizing() -> FullscreenManager {
+    FullscreenManager::new(false, true)
+}
+
+/// Fullscreen, focused and with nothing pending
+fn entered() -> FullscreenManager {
+    run(minimizing(), &[
+        (Focused(true), None),
+        (Request(true), Some(SetFullscreen(true))),
+        (Observed(true), None)
+    ])
+}
+
+#[test]
+fn deferred_until_first_focus() {
+    let manager = run(FullscreenManager::new(true, false), &[
+        (Request(true), None),
+        (Observed(false), None),
+        (Focused(false), None),
+        (Focused(true), Some(SetFullscreen(true))),
+        (Focused(true), None),
+        (Observed(true), None)
+    ]);
+    assert!(manager.is_desired() && !manager.is_pending());
+}
+
+#[test]
+fn deferred_request_changed_before_focus() {
+    run(FullscreenManager::new(true, false), &[
+        (Request(true), None),
+        (Request(false), None),
+        (Focused(true), None)
+    ]);
+}
+
+#[test]
+fn not_deferred_without_focus_events() {
+    run(FullscreenManager::new(false, true), &[
+        (Request(true), Some(SetFullscreen(true))),
+        (Observed(true), None)
+    ]);
+}
+
+#[test]
+fn focus_lost_after_transition() {
+    run(entered(), &[
+        (Focused(false), Some(SetFullscreen(false))),
+        (Observed(false), Some(Minimize)),
+        (Focused(true), Some(SetFullscreen(true))),
+        (Observed(true), None)
+    ]);
+}
+
+#[test]
+fn focus_lost_before_entering_completes() {
+    run(minimizing(), &[
+        (Focused(true), None),
+        (Request(true), Some(SetFullscreen(true))),
+        (Focused(false), None),
+        (Observed(true), Some(SetFullscreen(false))),
+        (Observed(false), Some(Minimize)),
+        (Focused(true), Some(SetFullscreen(true)))
+    ]);
+}
+
+#[test]
+fn focus_back_before_exiting_completes() {
+    let manager = run(entered(), &[
+        (Focused(false), Some(SetFullscreen(false))),
+        (Focused(true), None),
+        // Exited, but focused again: back to fullscreen without minimizing
+        (Observed(false), Some(SetFullscreen(true))),
+        (Observed(true), None)
+    ]);
+    assert!(manager.is_desired());
+}
+
+#[test]
+fn focus_lost_and_back_before_entering_completes() {
+    run(minimizing(), &[
+        (Request(true), Some(SetFullscreen(true))),
+        (Focused(false), None),
+        (Focused(true), None),
+        (Observed(true), None)
+    ]);
+}
+
+#[test]
+fn minimized_once_per_focus_loss() {
+    run(entered(), &[
+        (Focused(false), Some(SetFullscreen(false))),
+        (Observed(false), Some(Minimize)),
+        // A minimized window is resized on some platforms
+        (Observed(false), None),
+        (Focused(false), None),
+        (Focused(true), Some(SetFullscreen(true))),
+        (Observed(true), None),
+        (Focused(false), Some(SetFullscreen(false))),
+        (Observed(false), Some(Minimize))
+    ]);
+}
+
+#[test]
+fn not_minimized_if_not_desired() {
+    run(minimizing(), &[
+        (Focused(true), None),
+        (Focused(false), None),
+        (Observed(false), None),
+        (Focused(true), None)
+    ]);
+}
+
+#[test]
+fn exit_requested_while_unfocused() {
+    let manager = run(entered(), &[
+        (Focused(false), Some(SetFullscreen(false))),
+        (Request(false), None),
+        (Observed(false), None),
+        (Focused(true), None)
+    ]);
+    assert!(!manager.is_desired());
+}
+
+#[test]
+fn focus_loss_ignored_without_minimizing() {
+    run(FullscreenManager::new(false, false), &[
+        (Request(true), Some(SetFullscreen(true))),
+        (Observed(true), None),
+        (Focused(false), None),
+        (Observed(true), None),
+        (Focused(true), None)
+    ]);
+}
+
+#[test]
+fn requests_during_transition_are_coalesced() {
+    let manager = run(minimizing(), &[
+        (Request(true), Some(SetFullscreen(true))),
+        (Request(false), None),
+        (Request(true), None),
+        (Observed(true), None)
+    ]);
+    assert!(!manager.is_pending());
+
+    run(manager, &[
+        (Request(false), Some(SetFullscreen(false))),
+        (Request(true), None),
+        (Request(false), None),
+        (Observed(false), None)
+    ]);
+}
+
+#[test]
+fn latest_request_issued_after_transition() {
+    run(minimizing(), &[
+        (Request(true), Some(SetFullscreen(true))),
+        (Request(false), None),
+        (Observed(true), Some(SetFullscreen(false))),
+        (Observed(false), None)
+    ]);
+}
+
+#[test]
+fn intermediate_states_do_not_complete_transitions() {
+    let manager = run(minimizing(), &[
+        (Request(true), Some(SetFullscreen(true))),
+        (Observed(false), None),
+        (Observed(false), None)
+    ]);
+    assert!(manager.is_pending());
+}
+
+#[test]
+fn external_changes_become_desired() {
+    let manager = run(entered(), &[
+        (Observed(false), None)
+    ]);
+    assert!(!manager.is_desired());
+
+    let manager = run(manager, &[
+        (Observed(true), None)
+    ]);
+    assert!(manager.is_desired());
+}
@@ -0,0 +1,141 @@
+//!
+//! Property-based tests of `geometry` containment queries.
+//!
+//! Checked for each query:
+//! - `Inside` implies intersection;
+//! - `Inside` and `Outside` agree with sampled points of the classified shape;
+//! - shapes are symmetric in being `Outside` of each other.
+//!
+
+extern crate proptest;
+extern crate rokoko;
+
+use proptest::prelude::*;
+use proptest::array::uniform3;
+use rokoko::prelude::*;
+use rokoko::math::geometry::{Aabb, Sphere, Frustum, Plane, Containment};
+
+/// Small coordinates on a grid, so that touching shapes are generated often and computed exactly
+fn coord() -> impl Strategy <Value = f64> {
+    (-16i32..16).prop_map(|x| x as f64 / 4.0)
+}
+
+fn point() -> impl Strategy <Value = dvec3> {
+    uniform3(coord()).prop_map(dvec3::from)
+}
+
+fn aabb() -> impl Strategy <Value = Aabb <f64, 3>> {
+    (point(), point()).prop_map(|(a, b)| Aabb::from_corners(a, b))
+}
+
+fn sphere() -> impl Strategy <Value = Sphere <f64, 3>> {
+    (point(), 0i32..16).prop_map(|(center, r)| Sphere::new(center, r as f64 / 4.0))
+}
+
+/// Boxes as frusta, so that the answers are exact
+fn frustum() -> impl Strategy <Value = Frustum <f64>> {
+    aabb().prop_map(|b| {
+        let axis = |i| {
+            let mut v = dvec3::single(0.0);
+            v[i] = 1.0;
+            v
+        };
+        Frustum::from_planes([
+            Plane::new(axis(0), -b.min[0]), Plane::new(-axis(0), b.max[0]),
+            Plane::new(axis(1), -b.min[1]), Plane::new(-axis(1), b.max[1]),
+            Plane::new(axis(2), -b.min[2]), Plane::new(-axis(2), b.max[2])
+        ])
+    })
+}
+
+/// The box with the same planes as `frustum()` generates
+fn frustum_box(f: &Frustum <f64>) -> Aabb <f64, 3> {
+    Aabb::new(
+        dvec3::from([-f.planes[0].d, -f.planes[2].d, -f.planes[4].d]),
+        dvec3::from([f.planes[1].d, f.planes[3].d, f.planes[5].d])
+    )
+}
+
+/// Corners and the center of a box
+fn aabb_samples(b: &Aabb <f64, 3>) -> Vec <dvec3> {
+    let mut samples = vec![(b.min + b.max) / dvec3::single(2.0)];
+    for mask in 0..8 {
+        let mut p = b.min;
+        for i in 0..3 {
+            if mask & (1 << i) != 0 {
+                p[i] = b.max[i]
+            }
+        }
+        samples.push(p)
+    }
+    samples
+}
+
+/// Axis extremes and the center of a sphere
+fn sphere_samples(s: &Sphere <f64, 3>) -> Vec <dvec3> {
+    let mut samples = vec![s.center];
+    for i in 0..3 {
+        for sign in [-1.0, 1.0] {
+            let mut p = s.center;
+            p[i] += sign * s.radius;
+            samples.push(p)
+        }
+    }
+    samples
+}
+
+fn in_sphere(s: &Sphere <f64, 3>, p: dvec3) -> bool {
+    let d = p - s.center;
+    d[0] * d[0] + d[1] * d[1] + d[2] * d[2] <= s.radius * s.radius
+}
+
+/// Checks `Inside` and `Outside` against `samples` of the classified shape
+fn agrees(c: Containment, samples: &[dvec3], contains: impl Fn(dvec3) -> bool) -> bool {
+    match c {
+        Containment::Inside => c.intersects() && samples.iter().all(|&p| contains(p)),
+        Containment::Outside => !c.intersects() && !samples.iter().any(|&p| contains(p)),
+        Containment::Intersects => c.intersects()
+    }
+}
+
+proptest! {
+    #[test]
+    fn aabb_aabb(a in aabb(), b in aabb()) {
+        let c = a.classify_aabb(&b);
+        prop_assert!(agrees(c, &aabb_samples(&b), |p| a.contains_point(p)));
+        prop_assert_eq!(c == Containment::Outside, b.classify_aabb(&a) == Containment::Outside);
+    }
+
+    #[test]
+    fn aabb_sphere(a in aabb(), s in sphere()) {
+        let c = a.classify_sphere(&s);
+        prop_assert!(agrees(c, &sphere_samples(&s), |p| a.contains_point(p)));
+        prop_assert_eq!(c == Containment::Outside, s.classify_aabb(&a) == Containment::Outside);
+    }
+
+    #[test]
+    fn sphere_sphere(a in sphere(), b in sphere()) {
+        let c = a.classify_sphere(&b);
+        prop_assert!(agrees(c, &sphere_samples(&b), |p| in_sphere(&a, p)));
+        prop_assert_eq!(c == Containment::Outside, b.classify_sphere(&a) == Containment::Outside);
+    }
+
+    #[test]
+    fn sphere_aabb(s in sphere(), b in aabb()) {
+        let c = s.classify_aabb(&b);
+        prop_assert!(agrees(c, &aabb_samples(&b), |p| in_sphere(&s, p)));
+    }
+
+    #[test]
+    fn frustum_aabb(f in frustum(), b in aabb()) {
+        let c = f.classify_aabb(&b);
+        prop_assert!(agrees(c, &aabb_samples(&b), |p| frustum_box(&f).contains_point(p)));
+        prop_assert_eq!(c, frustum_box(&f).classify_aabb(&b));
+    }
+
+    #[test]
+    fn frustum_sphere(f in frustum(), s in sphere()) {
+        let c = f.classify_sphere(&s);
+        prop_assert!(agrees(c, &sphere_samples(&s), |p| frustum_box(&f).contains_point(p)));
+    }
+}
@@ -0,0 +1,208 @@
+//!
+//! Feeds synthetic touch sequences to `window::gestures::GestureRecognizer`,
+//! see the module documentation.
+//!
+//! Requires nightly and the `window` feature, compiled to nothing otherwise.
+//!
+
+#![cfg(all(feature = "window", nightly))]
+
+extern crate rokoko;
+
+use rokoko::math::vec::vec2;
+use rokoko::window::gestures::{
+    GestureRecognizer, Gesture,
+    DEFAULT_DOUBLE_TAP_INTERVAL, TAP_TIMEOUT, TAP_SLOP, DOUBLE_TAP_SLOP
+};
+use std::time::Duration;
+
+fn v(x: f32, y: f32) -> vec2 {
+    vec2::from([x, y])
+}
+
+fn ms(ms: u64) -> Duration {
+    Duration::from_millis(ms)
+}
+
+fn recognizer() -> GestureRecognizer {
+    GestureRecognizer::new(DEFAULT_DOUBLE_TAP_INTERVAL)
+}
+
+/// Taps with touch `id` at `position`, starting at `time`
+fn tap(gestures: &mut GestureRecognizer, id: u64, position: vec2, time: Duration) -> Option <Gesture> {
+    assert_eq!(gestures.start(id, position, time), None);
+    gestures.end(id, time + ms(20))
+}
+
+fn tap_count(gesture: Option <Gesture>) -> Option <u8> {
+    match gesture {
+        Some(Gesture::Tap { count, .. }) => Some(count),
+        _ => None
+    }
+}
+
+#[test]
+fn taps_within_slop() {
+    let mut gestures = recognizer();
+
+    assert_eq!(gestures.start(0, v(50.0, 50.0), ms(0)), None);
+    assert_eq!(gestures.moved(0, v(50.0 + TAP_SLOP, 50.0), ms(10)), None);
+    assert_eq!(gestures.end(0, ms(20)), Some(Gesture::Tap { position: v(50.0 + TAP_SLOP, 50.0), count: 1 }));
+    assert_eq!(gestures.touches(), 0);
+}
+
+#[test]
+fn long_press_is_not_a_tap() {
+    let mut gestures = recognizer();
+
+    assert_eq!(gestures.start(0, v(0.0, 0.0), ms(0)), None);
+    assert_eq!(gestures.end(0, TAP_TIMEOUT + ms(1)), None);
+
+    // Exactly at the timeout still is
+    assert_eq!(gestures.start(1, v(0.0, 0.0), ms(5000)), None);
+    assert_eq!(tap_count(gestures.end(1, ms(5000) + TAP_TIMEOUT)), Some(1));
+}
+
+#[test]
+fn multiple_taps() {
+    let mut gestures = recognizer();
+
+    assert_eq!(tap_count(tap(&mut gestures, 0, v(10.0, 10.0), ms(0))), Some(1));
+    assert_eq!(tap_count(tap(&mut gestures, 1, v(12.0, 10.0), ms(100))), Some(2));
+    assert_eq!(tap_count(tap(&mut gestures, 2, v(10.0, 12.0), ms(200))), Some(3));
+
+    // Too late
+    assert_eq!(tap_count(tap(&mut gestures, 3, v(10.0, 10.0), ms(220) + DEFAULT_DOUBLE_TAP_INTERVAL + ms(1))), Some(1));
+
+    // Too far
+    assert_eq!(tap_count(tap(&mut gestures, 4, v(10.0 + DOUBLE_TAP_SLOP + 1.0, 10.0), ms(600))), Some(1));
+}
+
+#[test]
+fn custom_double_tap_interval() {
+    let mut gestures = GestureRecognizer::new(ms(1000));
+
+    assert_eq!(tap_count(tap(&mut gestures, 0, v(0.0, 0.0), ms(0))), Some(1));
+    assert_eq!(tap_count(tap(&mut gestures, 0, v(0.0, 0.0), ms(1020))), Some(2));
+    assert_eq!(tap_count(tap(&mut gestures, 0, v(0.0, 0.0), ms(2041))), Some(1));
+}
+
+#[test]
+fn tap_count_saturates() {
+    let mut gestures = recognizer();
+
+    let mut last = None;
+    for i in 0..300 {
+        last = tap_count(tap(&mut gestures, i, v(0.0, 0.0), ms(i * 100)));
+    }
+    assert_eq!(last, Some(u8::MAX));
+}
+
+#[test]
+fn pan_breaks_taps() {
+    let mut gestures = recognizer();
+
+    assert_eq!(tap_count(tap(&mut gestures, 0, v(0.0, 0.0), ms(0))), Some(1));
+
+    assert_eq!(gestures.start(1, v(0.0, 0.0), ms(100)), None);
+    assert_eq!(gestures.moved(1, v(0.0, 30.0), ms(110)), Some(Gesture::Pan { delta: v(0.0, 30.0) }));
+
+    // Moving back does not make it a tap again
+    assert_eq!(gestures.moved(1, v(0.0, 0.0), ms(120)), Some(Gesture::Pan { delta: v(0.0, -30.0) }));
+    assert_eq!(gestures.end(1, ms(130)), None);
+
+    assert_eq!(tap_count(tap(&mut gestures, 2, v(0.0, 0.0), ms(200))), Some(1));
+}
+
+#[test]
+fn pinch() {
+    let mut gestures = recognizer();
+
+    assert_eq!(gestures.start(0, v(0.0, 0.0), ms(0)), None);
+    assert_eq!(gestures.start(1, v(10.0, 0.0), ms(10)), None);
+    assert_eq!(gestures.touches(), 2);
+
+    // Spreading
+    assert_eq!(gestures.moved(1, v(20.0, 0.0), ms(20)), Some(Gesture::Pinch { scale_delta: 2.0, center: v(10.0, 0.0) }));
+    assert_eq!(gestures.moved(0, v(-20.0, 0.0), ms(30)), Some(Gesture::Pinch { scale_delta: 2.0, center: v(0.0, 0.0) }));
+
+    // Squeezing
+    assert_eq!(gestures.moved(0, v(10.0, 0.0), ms(40)), Some(Gesture::Pinch { scale_delta: 0.25, center: v(15.0, 0.0) }));
+
+    // Too close to compute a scale
+    assert_eq!(gestures.moved(0, v(20.0, 0.5), ms(50)), None);
+    assert_eq!(gestures.moved(0, v(0.0, 0.0), ms(60)), None);
+    assert_eq!(gestures.moved(0, v(10.0, 0.0), ms(70)), Some(Gesture::Pinch { scale_delta: 0.5, center: v(15.0, 0.0) }));
+
+    // Not a tap, and the rest is ignored
+    assert_eq!(gestures.end(1, ms(80)), None);
+    assert_eq!(gestures.moved(0, v(100.0, 0.0), ms(90)), None);
+    assert_eq!(gestures.end(0, ms(100)), None);
+    assert_eq!(gestures.touches(), 0);
+
+    // Until all the touches end
+    assert_eq!(gestures.start(2, v(0.0, 0.0), ms(200)), None);
+    assert_eq!(gestures.moved(2, v(100.0, 0.0), ms(210)), Some(Gesture::Pan { delta: v(100.0, 0.0) }));
+}
+
+#[test]
+fn third_touch_cancels() {
+    let mut gestures = recognizer();
+
+    assert_eq!(gestures.start(0, v(0.0, 0.0), ms(0)), None);
+    assert_eq!(gestures.start(1, v(10.0, 0.0), ms(10)), None);
+    assert_eq!(gestures.start(2, v(20.0, 0.0), ms(20)), None);
+
+    assert_eq!(gestures.moved(1, v(50.0, 0.0), ms(30)), None);
+    assert_eq!(gestures.end(2, ms(40)), None);
+
+    // Even with two touches left
+    assert_eq!(gestures.moved(1, v(100.0, 0.0), ms(50)), None);
+    assert_eq!(gestures.end(0, ms(60)), None);
+    assert_eq!(gestures.end(1, ms(70)), None);
+}
+
+#[test]
+fn cancellation() {
+    let mut gestures = recognizer();
+
+    assert_eq!(tap_count(tap(&mut gestures, 0, v(0.0, 0.0), ms(0))), Some(1));
+
+    // A cancelled touch is neither a tap, nor a part of a multiple tap
+    assert_eq!(gestures.start(1, v(0.0, 0.0), ms(50)), None);
+    assert_eq!(gestures.cancel(1), None);
+    assert_eq!(tap_count(tap(&mut gestures, 2, v(0.0, 0.0), ms(100))), Some(1));
+
+    // Cancelling a touch of a pinch ignores the other one
+    assert_eq!(gestures.start(3, v(0.0, 0.0), ms(1000)), None);
+    assert_eq!(gestures.start(4, v(10.0, 0.0), ms(1010)), None);
+    assert_eq!(gestures.cancel(3), None);
+    assert_eq!(gestures.moved(4, v(50.0, 0.0), ms(1020)), None);
+    assert_eq!(gestures.end(4, ms(1030)), None);
+}
+
+#[test]
+fn unknown_touches() {
+    let mut gestures = recognizer();
+
+    assert_eq!(gestures.moved(9, v(0.0, 0.0), ms(0)), None);
+    assert_eq!(gestures.end(9, ms(0)), None);
+    assert_eq!(gestures.cancel(9), None);
+
+    // A repeated start is ignored
+    assert_eq!(gestures.start(0, v(0.0, 0.0), ms(0)), None);
+    assert_eq!(gestures.start(0, v(5.0, 5.0), ms(10)), None);
+    assert_eq!(gestures.touches(), 1);
+    assert_eq!(gestures.end(0, ms(20)), Some(Gesture::Tap { position: v(0.0, 0.0), count: 1 }));
+}
+
+#[test]
+fn magnify_is_ignored_while_touching() {
+    let mut gestures = recognizer();
+
+    assert_eq!(gestures.start(0, v(0.0, 0.0), ms(0)), None);
+    assert_eq!(gestures.magnify(0.5, v(0.0, 0.0)), None);
+    assert!(gestures.end(0, ms(10)).is_some());
+    assert_eq!(gestures.magnify(0.5, v(0.0, 0.0)), Some(Gesture::Pinch { scale_delta: 1.5, center: v(0.0, 0.0) }));
+    assert_eq!(gestures.magnify(f64::NAN, v(0.0, 0.0)), None);
+}
@@ -0,0 +1,207 @@
+//!
+//! Checks the bookkeeping of `window::global_hotkeys::GlobalHotkeys` and the forwarding of presses
+//! to the event loop against a mocked manager.
+//!
+//! Requires nightly and the `global-hotkeys` feature, compiled to nothing otherwise.
+//!
+
+#![cfg(all(feature = "global-hotkeys", nightly))]
+
+extern crate rokoko;
+
+use rokoko::window::{
+    data::UserEvent,
+    global_hotkeys::{GlobalHotkeys, HotkeyManager, Error},
+    input_map::Binding
+};
+use std::{
+    cell::RefCell,
+    rc::Rc,
+    sync::mpsc,
+    thread
+};
+
+/// What the mocked manager was asked to do, and the bindings taken by "other apps"
+#[derive(Default)]
+struct State {
+    registered: Vec <Binding>,
+    unregistered: Vec <Binding>,
+    taken: Vec <Binding>,
+    fail_unregister: bool
+}
+
+/// Reports presses of a binding with its index in `registered` plus 100
+#[derive(Clone, Default)]
+struct Mock(Rc <RefCell <State>>);
+
+impl HotkeyManager for Mock {
+    fn register(&mut self, binding: Binding) -> Result <u32, Error> {
+        let mut state = self.0.borrow_mut();
+        if state.taken.contains(&binding) {
+            return Err(Error::Manager(format!("`{binding}` is taken")))
+        }
+        state.registered.push(binding);
+        Ok(state.registered.len() as u32 + 99)
+    }
+
+    fn unregister(&mut self, binding: Binding) -> Result <(), Error> {
+        let mut state = self.0.borrow_mut();
+        state.unregistered.push(binding);
+        if state.fail_unregister {
+            Err(Error::Manager(String::from("cannot unregister")))
+        } else {
+            Ok(())
+        }
+    }
+}
+
+fn binding(s: &str) -> Binding {
+    s.parse().unwrap()
+}
+
+/// Ids of the events sent for `presses`
+fn forwarded <M: HotkeyManager> (hotkeys: &GlobalHotkeys <M>, presses: &[u32]) -> Vec <u32> {
+    let mut sent = Vec::new();
+    hotkeys.forwarder().forward(presses.iter().copied(), |event| {
+        match event {
+            UserEvent::GlobalHotkey(id) => sent.push(id),
+            other => panic!("unexpected {other:?}")
+        }
+        Ok::<_, ()>(())
+    });
+    sent
+}
+
+#[test]
+fn register_forward_unregister() {
+    let mock = Mock::default();
+    let mut hotkeys = GlobalHotkeys::new(mock.clone());
+
+    hotkeys.register(binding("Ctrl+Shift+K"), 1).unwrap();
+    hotkeys.register(binding("F12"), 2).unwrap();
+    assert_eq!(mock.0.borrow().registered, [binding("Ctrl+Shift+K"), binding("F12")]);
+    assert_eq!(hotkeys.binding(2), Some(binding("F12")));
+    let mut ids: Vec <_> = hotkeys.ids().collect();
+    ids.sort();
+    assert_eq!(ids, [1, 2]);
+
+    assert_eq!(forwarded(&hotkeys, &[101, 100, 101]), [2, 1, 2]);
+
+    assert_eq!(hotkeys.unregister(1), Ok(binding("Ctrl+Shift+K")));
+    assert_eq!(mock.0.borrow().unregistered, [binding("Ctrl+Shift+K")]);
+    assert_eq!(hotkeys.binding(1), None);
+    assert_eq!(forwarded(&hotkeys, &[100, 101]), [2]);
+
+    // The id is free again
+    hotkeys.register(binding("Alt+K"), 1).unwrap();
+    assert_eq!(forwarded(&hotkeys, &[102]), [1]);
+}
+
+#[test]
+fn duplicates_are_rejected_before_the_manager() {
+    let mock = Mock::default();
+    let mut hotkeys = GlobalHotkeys::new(mock.clone());
+    hotkeys.register(binding("Ctrl+K"), 1).unwrap();
+
+    assert_eq!(hotkeys.register(binding("Ctrl+J"), 1), Err(Error::DuplicateId(1)));
+    assert_eq!(hotkeys.register(binding("Ctrl+K"), 2), Err(Error::DuplicateBinding { binding: binding("Ctrl+K"), id: 1 }));
+    assert_eq!(hotkeys.register(binding("Ctrl+MouseLeft"), 3), Err(Error::Unsupported(binding("Ctrl+MouseLeft"))));
+
+    assert_eq!(mock.0.borrow().registered, [binding("Ctrl+K")]);
+    assert_eq!(hotkeys.binding(1), Some(binding("Ctrl+K")));
+    assert_eq!(hotkeys.binding(2), None);
+}
+
+#[test]
+fn manager_failures_register_nothing() {
+    let mock = Mock::default();
+    mock.0.borrow_mut().taken.push(binding("Ctrl+Alt+Delete"));
+    let mut hotkeys = GlobalHotkeys::new(mock.clone());
+
+    let error = hotkeys.register(binding("Ctrl+Alt+Delete"), 1).unwrap_err();
+    assert_eq!(error, Error::Manager(String::from("`Ctrl+Alt+Delete` is taken")));
+    assert_eq!(error.to_string(), "failed to register a global hotkey: `Ctrl+Alt+Delete` is taken");
+    assert_eq!(hotkeys.binding(1), None);
+
+    hotkeys.register(binding("Ctrl+Alt+T"), 1).unwrap();
+    assert_eq!(forwarded(&hotkeys, &[100]), [1]);
+}
+
+#[test]
+fn failed_unregister_still_stops_forwarding() {
+    let mock = Mock::default();
+    let mut hotkeys = GlobalHotkeys::new(mock.clone());
+    hotkeys.register(binding("F1"), 1).unwrap();
+    mock.0.borrow_mut().fail_unregister = true;
+
+    assert_eq!(hotkeys.unregister(1), Err(Error::Manager(String::from("cannot unregister"))));
+    assert_eq!(hotkeys.unregister(1), Err(Error::UnknownId(1)));
+    assert_eq!(forwarded(&hotkeys, &[100]), []);
+}
+
+#[test]
+fn parse_errors_convert() {
+    fn register <M: HotkeyManager> (hotkeys: &mut GlobalHotkeys <M>, s: &str, id: u32) -> Result <(), Error> {
+        hotkeys.register(s.parse()?, id)
+    }
+
+    let mut hotkeys = GlobalHotkeys::new(Mock::default());
+    let error = register(&mut hotkeys, "Hyper+K", 1).unwrap_err();
+    assert!(matches!(error, Error::Parse(_)));
+    assert_eq!(error.to_string(), "unknown modifier `Hyper`, expected one of `Ctrl`, `Shift`, `Alt`, `Logo`");
+    register(&mut hotkeys, "Logo+Space", 1).unwrap();
+}
+
+#[test]
+fn drop_unregisters_everything() {
+    let mock = Mock::default();
+    let hotkeys = GlobalHotkeys::new(mock.clone());
+    let forwarder = hotkeys.forwarder();
+    {
+        let mut hotkeys = hotkeys;
+        hotkeys.register(binding("F1"), 1).unwrap();
+        hotkeys.register(binding("F2"), 2).unwrap();
+    }
+
+    let mut unregistered = mock.0.borrow().unregistered.clone();
+    unregistered.sort_by_key(|b| b.to_string());
+    assert_eq!(unregistered, [binding("F1"), binding("F2")]);
+    assert!(forwarder.event(100).is_none());
+}
+
+#[test]
+fn forwarding_stops_once_the_loop_is_closed() {
+    let mut hotkeys = GlobalHotkeys::new(Mock::default());
+    hotkeys.register(binding("F1"), 1).unwrap();
+
+    let mut sent = 0;
+    hotkeys.forwarder().forward([100, 100, 100], |_| {
+        sent += 1;
+        if sent == 2 { Err("closed") } else { Ok(()) }
+    });
+    assert_eq!(sent, 2);
+}
+
+#[test]
+fn forwarding_from_the_listener_thread() {
+    let mut hotkeys = GlobalHotkeys::new(Mock::default());
+    let (presses, listener) = mpsc::channel::<u32>();
+    let (proxy, events) = mpsc::channel::<UserEvent>();
+
+    let forwarder = hotkeys.forwarder();
+    let thread = thread::spawn(move || forwarder.forward(listener, |event| proxy.send(event)));
+
+    // Registered after the forwarder was made, still seen by it
+    hotkeys.register(binding("Ctrl+Shift+K"), 7).unwrap();
+    presses.send(100).unwrap();
+    presses.send(555).unwrap();
+    presses.send(100).unwrap();
+    drop(presses);
+    thread.join().unwrap();
+
+    let ids: Vec <_> = events.try_iter().map(|event| match event {
+        UserEvent::GlobalHotkey(id) => id,
+        other => panic!("unexpected {other:?}")
+    }).collect();
+    assert_eq!(ids, [7, 7]);
+}
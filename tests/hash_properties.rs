@@ -0,0 +1,90 @@
+//!
+//! Property-based tests of `HashableVec`.
+//!
+//! Equality through canonical bits is checked to be the float equality
+//! with all the `NaN`s equal, and hashes to agree with it.
+//!
+
+extern crate proptest;
+extern crate rokoko;
+
+use proptest::prelude::*;
+use proptest::num::f32::{POSITIVE, NEGATIVE, NORMAL, SUBNORMAL, ZERO, INFINITE, QUIET_NAN, SIGNALING_NAN};
+use rokoko::prelude::*;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+
+fn hash <T: Hash> (x: &T) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    x.hash(&mut hasher);
+    hasher.finish()
+}
+
+fn any_f32() -> impl Strategy <Value = f32> {
+    POSITIVE | NEGATIVE | NORMAL | SUBNORMAL | ZERO | INFINITE | QUIET_NAN | SIGNALING_NAN
+}
+
+/// The float equality with all the `NaN`s equal
+fn equivalent(a: f32, b: f32) -> bool {
+    a == b || (a.is_nan() && b.is_nan())
+}
+
+#[test]
+fn zeros_are_equal() {
+    let a = vec2::from([0.0, -0.0]).hashable();
+    let b = vec2::from([-0.0, 0.0]).hashable();
+    assert_eq!(a, b);
+    assert_eq!(hash(&a), hash(&b));
+}
+
+#[test]
+fn nan_payloads_are_equal() {
+    let payload = f32::from_bits(f32::NAN.to_bits() | 0x1234);
+    let signaling = f32::from_bits(0x7F80_0001);
+    assert!(payload.is_nan() && signaling.is_nan());
+
+    let a = vec3::from([payload, -payload, 1.0]).hashable();
+    let b = vec3::from([signaling, f32::NAN, 1.0]).hashable();
+    assert_eq!(a, b);
+    assert_eq!(hash(&a), hash(&b));
+    assert_eq!(a.0.canonical_bits(), b.0.canonical_bits());
+}
+
+#[test]
+fn f64_too() {
+    let a = dvec2::from([-0.0, f64::from_bits(f64::NAN.to_bits() | 1)]).hashable();
+    let b = dvec2::from([0.0, f64::NAN]).hashable();
+    assert_eq!(a, b);
+    assert_eq!(hash(&a), hash(&b));
+    assert_ne!(dvec2::from([1.0, 2.0]).hashable(), dvec2::from([1.0, 2.0 + f64::EPSILON * 2.0]).hashable());
+}
+
+proptest! {
+    #[test]
+    fn equality_is_float_equality_with_nans(a in prop::array::uniform3(any_f32()), b in prop::array::uniform3(any_f32())) {
+        let expected = (0..3).all(|i| equivalent(a[i], b[i]));
+        prop_assert_eq!(vec3::from(a).hashable() == vec3::from(b).hashable(), expected);
+    }
+
+    #[test]
+    fn equal_values_hash_equally(a in prop::array::uniform3(any_f32()), i in 0usize..3, b in any_f32()) {
+        let mut other = a;
+        other[i] = b;
+        let (a, other) = (vec3::from(a).hashable(), vec3::from(other).hashable());
+        if a == other {
+            prop_assert_eq!(hash(&a), hash(&other));
+        }
+    }
+
+    #[test]
+    fn reflexive(a in prop::array::uniform4(any_f32())) {
+        let a = vec4::from(a).hashable();
+        prop_assert_eq!(a, a);
+    }
+
+    #[test]
+    fn canonical_bits_of_ordinary_values(a in prop::array::uniform2(POSITIVE | NEGATIVE | NORMAL | SUBNORMAL | INFINITE)) {
+        prop_assume!(a.iter().all(|x| *x != 0.0));
+        prop_assert_eq!(vec2::from(a).canonical_bits(), uvec2::from(a.map(f32::to_bits)));
+    }
+}
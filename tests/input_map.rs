@@ -0,0 +1,188 @@
+//!
+//! Checks the resolution rules of `window::input_map::ActionMap`,
+//! see the module documentation.
+//!
+//! Requires nightly and the `window` feature, compiled to nothing otherwise.
+//!
+
+#![cfg(all(feature = "window", nightly))]
+
+extern crate rokoko;
+
+use rokoko::window::input_map::{ActionMap, Bind, Binding, Input, Key, Modifiers, MouseButton};
+
+fn ctrl_shift() -> Modifiers {
+    Modifiers::CTRL | Modifiers::SHIFT
+}
+
+fn saving() -> ActionMap {
+    let mut map = ActionMap::new();
+    map.bind("back", Key::S)
+        .bind("save", Key::S.with(Modifiers::CTRL))
+        .bind("save_as", Key::S.with(ctrl_shift()));
+    map
+}
+
+#[test]
+fn most_modifiers_win() {
+    let mut map = saving();
+    for (modifiers, action) in [
+        (Modifiers::NONE, "back"),
+        (Modifiers::SHIFT, "back"),
+        (Modifiers::ALT, "back"),
+        (Modifiers::CTRL, "save"),
+        (Modifiers::CTRL | Modifiers::ALT, "save"),
+        (ctrl_shift(), "save_as"),
+        (ctrl_shift() | Modifiers::LOGO, "save_as")
+    ] {
+        map.set_modifiers(modifiers);
+        assert_eq!(map.press(Key::S), [(action, true)], "{modifiers}");
+        assert_eq!(map.release(Key::S), [(action, false)], "{modifiers}")
+    }
+}
+
+#[test]
+fn missing_modifiers_do_not_match() {
+    let mut map = ActionMap::new();
+    map.bind("save", Key::S.with(Modifiers::CTRL));
+
+    map.set_modifiers(Modifiers::SHIFT);
+    assert_eq!(map.press(Key::S), []);
+    assert!(!map.held("save"));
+    assert_eq!(map.release(Key::S), [])
+}
+
+#[test]
+fn modifiers_are_checked_on_press_only() {
+    let mut map = saving();
+
+    map.set_modifiers(Modifiers::CTRL);
+    assert_eq!(map.press(Key::S), [("save", true)]);
+    // Releasing `Ctrl` first neither releases `save` nor presses `back`
+    map.set_modifiers(Modifiers::NONE);
+    assert!(map.held("save"));
+    assert!(!map.held("back"));
+    assert_eq!(map.release(Key::S), [("save", false)]);
+
+    // Pressing `Ctrl` after `S` does not turn `back` into `save`
+    assert_eq!(map.press(Key::S), [("back", true)]);
+    map.set_modifiers(Modifiers::CTRL);
+    assert_eq!(map.press(Key::S), []);
+    assert_eq!(map.release(Key::S), [("back", false)])
+}
+
+#[test]
+fn shared_bindings_trigger_every_action() {
+    let mut map = ActionMap::new();
+    map.bind("jump", Key::Space)
+        .bind("confirm", Key::Space)
+        // Bound twice, reported once
+        .bind("jump", Key::Space);
+
+    assert_eq!(map.bindings("jump").count(), 1);
+    assert_eq!(map.press(Key::Space), [("jump", true), ("confirm", true)]);
+    assert_eq!(map.release(Key::Space), [("jump", false), ("confirm", false)])
+}
+
+#[test]
+fn several_inputs_hold_an_action() {
+    let mut map = ActionMap::new();
+    map.bind("fire", Key::F)
+        .bind("fire", MouseButton::Left)
+        .bind("fire", MouseButton::Other(4));
+
+    assert_eq!(map.press(MouseButton::Left), [("fire", true)]);
+    assert_eq!(map.press(Key::F), []);
+    assert_eq!(map.press(MouseButton::Other(4)), []);
+    assert_eq!(map.release(MouseButton::Left), []);
+    assert_eq!(map.release(Key::F), []);
+    assert!(map.held("fire"));
+    assert_eq!(map.release(MouseButton::Other(4)), [("fire", false)])
+}
+
+#[test]
+fn repeats_and_stray_releases_are_ignored() {
+    let mut map = ActionMap::new();
+    map.bind("jump", Key::Space);
+
+    assert_eq!(map.release(Key::Space), []);
+    assert_eq!(map.press(Key::Space), [("jump", true)]);
+    for _ in 0..10 {
+        assert_eq!(map.press(Key::Space), [])
+    }
+    assert_eq!(map.release(Key::Space), [("jump", false)]);
+    assert_eq!(map.release(Key::Space), []);
+
+    // Unbound inputs
+    assert_eq!(map.press(Key::Q), []);
+    assert_eq!(map.release(Key::Q), [])
+}
+
+#[test]
+fn rebinding_keeps_held_actions_until_release() {
+    let mut map = ActionMap::new();
+    map.bind("jump", Key::Space);
+
+    assert_eq!(map.press(Key::Space), [("jump", true)]);
+    map.rebind("jump", Key::J);
+    assert!(map.held("jump"));
+    assert_eq!(map.release(Key::Space), [("jump", false)]);
+
+    assert_eq!(map.press(Key::Space), []);
+    assert_eq!(map.press(Key::J), [("jump", true)]);
+
+    map.unbind("jump");
+    assert_eq!(map.bindings("jump").count(), 0);
+    assert_eq!(map.release(Key::J), [("jump", false)]);
+    assert_eq!(map.press(Key::J), [])
+}
+
+#[test]
+fn release_all_reports_each_action_once() {
+    let mut map = ActionMap::new();
+    map.bind("fire", Key::F)
+        .bind("fire", MouseButton::Left)
+        .bind("run", Key::LShift);
+
+    map.press(Key::F);
+    map.press(MouseButton::Left);
+    map.press(Key::LShift);
+    assert_eq!(map.release_all(), [("fire", false), ("run", false)]);
+    assert_eq!(map.release_all(), []);
+    assert!(!map.held("fire"));
+
+    // Pressed again afterwards, not a repeat
+    assert_eq!(map.press(Key::F), [("fire", true)])
+}
+
+#[test]
+fn bindings_round_trip_through_strings() {
+    let bindings = [
+        Binding::from(Key::Space),
+        Key::S.with(Modifiers::CTRL),
+        Key::Key0.with(ctrl_shift() | Modifiers::ALT | Modifiers::LOGO),
+        Key::F12.with(Modifiers::ALT),
+        Key::NumpadAdd.into(),
+        MouseButton::Middle.with(Modifiers::SHIFT),
+        MouseButton::Other(12).into()
+    ];
+    for binding in bindings {
+        assert_eq!(binding.to_string().parse(), Ok(binding), "{binding}")
+    }
+    for key in Input::KEYS.iter().map(|(key, _)| *key) {
+        let binding = key.with(ctrl_shift());
+        assert_eq!(binding.to_string().parse(), Ok(binding), "{binding}")
+    }
+}
+
+#[test]
+fn names_are_readable() {
+    assert_eq!(Key::Key0.with(ctrl_shift()).to_string(), "Ctrl+Shift+0");
+    assert_eq!(MouseButton::Left.with(Modifiers::LOGO).to_string(), "Logo+MouseLeft");
+    assert_eq!("ctrl+enter".parse(), Ok(Key::Return.with(Modifiers::CTRL)));
+    assert_eq!("Cmd+Backspace".parse(), Ok(Key::Back.with(Modifiers::LOGO)));
+
+    let mut map = saving();
+    map.bind("back", MouseButton::Other(4));
+    assert_eq!(format!("{map:?}"), r#"{"back": [S, Mouse4], "save": [Ctrl+S], "save_as": [Ctrl+Shift+S]}"#)
+}
@@ -0,0 +1,145 @@
+//!
+//! Drives `window::loading::LoadingPhase` through the sequences of `WindowBuilder::loading_phase`,
+//! see the module documentation.
+//!
+//! Requires nightly and the `window` feature, compiled to nothing otherwise.
+//!
+
+#![cfg(all(feature = "window", nightly))]
+
+extern crate rokoko;
+
+use rokoko::window::loading::{LoadingPhase, LoadingProgress, LoadingCtx, Step};
+use rokoko::window::build::user_data::UserDataStore;
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::time::{Duration, Instant};
+
+#[derive(Debug, PartialEq)]
+struct Assets(u32);
+
+/// A loader done after `calls` calls, recording the contexts it was given
+fn loader(calls: u32, seen: &Rc <RefCell <Vec <LoadingCtx>>>) -> impl FnMut(LoadingCtx) -> LoadingProgress <Assets> {
+    let seen = seen.clone();
+    let mut done = 0;
+    move |ctx| {
+        seen.borrow_mut().push(ctx);
+        done += 1;
+        if done < calls {
+            LoadingProgress::Pending(done as f32 / calls as f32)
+        } else {
+            LoadingProgress::Done(Assets(done))
+        }
+    }
+}
+
+/// What the generated loop does on every iteration, returns whether callbacks are called
+fn iteration(phase: &mut LoadingPhase, store: &UserDataStore, now: Instant, close: bool) -> (Option <Step>, bool) {
+    if close {
+        phase.abort()
+    }
+    let step = phase.step(now, store);
+    (step, phase.is_done())
+}
+
+#[test]
+fn completion() {
+    let start = Instant::now();
+    let seen = Rc::default();
+    let mut store = UserDataStore::default();
+    let mut phase = LoadingPhase::new(&mut store, start, loader(3, &seen));
+
+    assert!(phase.is_loading());
+    assert_eq!(phase.progress(), 0.0);
+
+    let secs = |s| start + Duration::from_secs(s);
+    assert_eq!(iteration(&mut phase, &store, secs(0), false), (Some(Step::Pending), false));
+    assert_eq!(iteration(&mut phase, &store, secs(1), false), (Some(Step::Pending), false));
+    assert!((phase.progress() - 2.0 / 3.0).abs() < 1e-6);
+    assert!(store.get::<Assets>().is_none());
+
+    assert_eq!(iteration(&mut phase, &store, secs(2), false), (Some(Step::Loaded), true));
+    assert_eq!(*store.get::<Assets>().unwrap(), Assets(3));
+    assert_eq!(phase.progress(), 1.0);
+
+    // Not called anymore
+    assert_eq!(phase.step(secs(3), &store), None);
+    assert_eq!(seen.borrow().len(), 3);
+
+    let elapsed: Vec <_> = seen.borrow().iter().map(LoadingCtx::elapsed).collect();
+    assert_eq!(elapsed, [Duration::ZERO, Duration::from_secs(1), Duration::from_secs(2)]);
+    assert!(seen.borrow().iter().all(|ctx| !ctx.should_abort()));
+}
+
+#[test]
+fn abort() {
+    let start = Instant::now();
+    let seen = Rc::default();
+    let mut store = UserDataStore::default();
+    let mut phase = LoadingPhase::new(&mut store, start, loader(5, &seen));
+
+    assert_eq!(iteration(&mut phase, &store, start, false), (Some(Step::Pending), false));
+
+    // The user closes the window: the loader is told once, and nothing is loaded
+    assert_eq!(iteration(&mut phase, &store, start, true), (Some(Step::Aborted), false));
+    assert!(!phase.is_loading());
+    assert_eq!(seen.borrow().iter().map(LoadingCtx::should_abort).collect::<Vec <_>>(), [false, true]);
+
+    // No `on_init`, nor any other callback, until the loop ends
+    assert_eq!(iteration(&mut phase, &store, start, true), (None, false));
+    assert_eq!(iteration(&mut phase, &store, start, false), (None, false));
+    assert_eq!(seen.borrow().len(), 2);
+    assert!(store.get::<Assets>().is_none());
+}
+
+#[test]
+fn abort_on_the_last_call_drops_the_value() {
+    let seen = Rc::default();
+    let mut store = UserDataStore::default();
+    let mut phase = LoadingPhase::new(&mut store, Instant::now(), loader(1, &seen));
+
+    phase.abort();
+    assert_eq!(phase.step(Instant::now(), &store), Some(Step::Aborted));
+    assert!(store.get::<Assets>().is_none());
+}
+
+#[test]
+fn abort_after_loading_is_noop() {
+    let seen = Rc::default();
+    let mut store = UserDataStore::default();
+    let mut phase = LoadingPhase::new(&mut store, Instant::now(), loader(1, &seen));
+
+    assert_eq!(phase.step(Instant::now(), &store), Some(Step::Loaded));
+    phase.abort();
+    assert!(phase.is_done());
+    assert_eq!(*store.get::<Assets>().unwrap(), Assets(1));
+}
+
+#[test]
+fn loaded_value_hides_attached_one() {
+    let seen = Rc::default();
+    let mut store = UserDataStore::default();
+    store.push(Assets(100));
+    store.push(7u8);
+    let mut phase = LoadingPhase::new(&mut store, Instant::now(), loader(2, &seen));
+
+    // Neither is visible while loading, so callbacks could not see a stale value anyway
+    assert!(store.get::<Assets>().is_none());
+    assert_eq!(*store.get::<u8>().unwrap(), 7);
+
+    phase.step(Instant::now(), &store);
+    phase.step(Instant::now(), &store);
+    assert_eq!(*store.get::<Assets>().unwrap(), Assets(2));
+}
+
+#[test]
+fn callbacks_wait_for_the_loader() {
+    let start = Instant::now();
+    let seen = Rc::default();
+    let mut store = UserDataStore::default();
+    let mut phase = LoadingPhase::new(&mut store, start, loader(4, &seen));
+
+    // Every iteration until loaded calls only the loader
+    let dispatched: Vec <bool> = (0..6).map(|_| iteration(&mut phase, &store, start, false).1).collect();
+    assert_eq!(dispatched, [false, false, false, true, true, true]);
+}
@@ -0,0 +1,125 @@
+//!
+//! Checks the conventions every `WindowBuilder` option and event follows, as described in `meta`,
+//! so that a new one breaking them fails here instead of slipping through review.
+//!
+//! Every check reports all the offending options and events at once, by name.
+//!
+//! Requires nightly and the `window` feature, compiled to nothing otherwise.
+//!
+
+#![cfg(all(feature = "window", nightly))]
+
+extern crate rokoko;
+
+use rokoko::window::build::meta::{OPTIONS, EVENTS};
+
+/// Typed options that may have neither a default, nor a requirement, nor a check, with the reason
+const UNCHECKED: &[(&str, &str)] = &[
+    ("action_map", "any map is valid, bindings are only looked up")
+];
+
+/// Panics listing `violations`, if there are any
+fn assert_none(rule: &str, violations: Vec <String>) {
+    assert!(violations.is_empty(), "{rule}, but:\n  {}", violations.join("\n  "))
+}
+
+/// Same as `snake_to_upper_case` of `rokoko-macro`, which names the types of options and events
+fn snake_to_upper_case(snake: &str) -> String {
+    snake.split('_').map(|word| {
+        let mut chars = word.chars();
+        chars.next().map(|first| first.to_uppercase().chain(chars).collect::<String>()).unwrap_or_default()
+    }).collect()
+}
+
+fn names() -> impl Iterator <Item = &'static str> {
+    OPTIONS.iter().map(|o| o.name).chain(EVENTS.iter().map(|e| e.name))
+}
+
+#[test]
+fn documented() {
+    assert_none("every option and event must have a documentation summary", OPTIONS.iter()
+        .map(|o| (o.name, o.doc))
+        .chain(EVENTS.iter().map(|e| (e.name, e.doc)))
+        .filter(|(_, doc)| doc.trim().is_empty())
+        .map(|(name, _)| format!("`{name}` has none"))
+        .collect());
+}
+
+#[test]
+fn typed_options_are_defaulted_required_or_checked() {
+    assert_none("every typed option must have #[default], #[require] or a validation", OPTIONS.iter()
+        .filter(|o| o.ty.is_some() && o.default.is_none() && o.requires.is_empty() && !o.validated)
+        .filter(|o| !UNCHECKED.iter().any(|(name, _)| *name == o.name))
+        .map(|o| format!("`{}` has none, check it or add it to `UNCHECKED` with the reason", o.name))
+        .collect());
+
+    assert_none("every option in `UNCHECKED` must be typed and unchecked", UNCHECKED.iter()
+        .filter(|(name, _)| !OPTIONS.iter().any(|o| o.name == *name && o.ty.is_some() && o.default.is_none() && o.requires.is_empty() && !o.validated))
+        .map(|(name, _)| format!("`{name}` is not, remove it from there"))
+        .collect());
+}
+
+#[test]
+fn conflicts_and_requirements_are_consistent() {
+    let find = |name: &str| OPTIONS.iter().find(|o| o.name == name);
+    let mut violations = Vec::new();
+
+    for option in OPTIONS {
+        for conflict in option.conflicts {
+            match find(conflict) {
+                None => violations.push(format!("`{}` conflicts with `{conflict}`, which is not an option", option.name)),
+                Some(other) if !other.conflicts.contains(&option.name) => violations.push(format!(
+                    "`{}` conflicts with `{conflict}`, but not the other way around", option.name
+                )),
+                Some(_) => ()
+            }
+        }
+        for require in option.requires {
+            if find(require).is_none() {
+                violations.push(format!("`{}` requires `{require}`, which is not an option", option.name))
+            }
+            if option.conflicts.contains(require) {
+                violations.push(format!("`{}` both requires and conflicts with `{require}`", option.name))
+            }
+        }
+    }
+
+    assert_none("conflicts must be symmetric and refer to options, as must requirements", violations);
+}
+
+#[test]
+fn events_are_bound() {
+    assert_none("every event must have #[on] or #[unique]", EVENTS.iter()
+        .filter(|e| e.on.is_none() && e.unique.is_none())
+        .map(|e| format!("`{}` has neither", e.name))
+        .collect());
+}
+
+#[test]
+fn names_are_snake_case() {
+    let is_snake_case = |name: &str| name.split('_').all(|word| {
+        word.starts_with(|c: char| c.is_ascii_lowercase())
+            && word.chars().all(|c| c.is_ascii_lowercase() || c.is_ascii_digit())
+    });
+
+    assert_none("every option and event must be named in snake_case", names()
+        .filter(|name| !is_snake_case(name))
+        .map(|name| format!("`{name}` is not"))
+        .collect());
+}
+
+#[test]
+fn names_are_unique_in_upper_case() {
+    let mut seen: Vec <(String, &str)> = Vec::new();
+    let mut violations = Vec::new();
+
+    for name in names() {
+        let upper = snake_to_upper_case(name);
+        match seen.iter().find(|(other, _)| *other == upper) {
+            Some((_, other)) => violations.push(format!("`{name}` and `{other}` are both `{upper}`")),
+            None => seen.push((upper, name))
+        }
+    }
+
+    assert_none("every option and event must name a distinct type", violations);
+}
@@ -0,0 +1,15 @@
+#![no_std]
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+use rokoko::prelude::*;
+use rokoko::math::vec::{flatten, unflatten};
+
+pub fn normals(flat: &[f32]) -> Option <Vec <f32>> {
+    let normals = unflatten::<f32, 3>(flat)?
+        .into_iter()
+        .map(|v: vec3| v.normalize_or_zero())
+        .collect::<Vec <_>>();
+    Some(flatten(&normals))
+}
@@ -0,0 +1,42 @@
+//!
+//! Checks that the `math` side of `rokoko` builds for `#![no_std]` with only `alloc`,
+//! see `tests/no-std-alloc`.
+//!
+//! Always built for the host, so that nothing silently requires `std`,
+//! and for a bare-metal target without `std` at all if it is installed
+//! (`rustup target add thumbv7em-none-eabihf`).
+//!
+
+use std::process::Command;
+
+const BARE_METAL: &str = "thumbv7em-none-eabihf";
+
+fn build(target: Option <&str>) {
+    let mut command = Command::new(env!("CARGO"));
+    command
+        .args(["build", "--color", "never", "--manifest-path"])
+        .arg(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/no-std-alloc/Cargo.toml"))
+        .arg("--target-dir")
+        .arg(concat!(env!("CARGO_TARGET_TMPDIR"), "/no-std-alloc"));
+    if let Some(target) = target {
+        command.args(["--target", target]);
+    }
+    let output = command.output().unwrap();
+
+    assert!(output.status.success(), "must build for {}:\n{}", target.unwrap_or("the host"), String::from_utf8_lossy(&output.stderr))
+}
+
+#[test]
+fn math_builds_on_no_std_with_alloc() {
+    build(None);
+
+    let installed = Command::new("rustup")
+        .args(["target", "list", "--installed"])
+        .output()
+        .map_or(false, |o| String::from_utf8_lossy(&o.stdout).lines().any(|line| line == BARE_METAL));
+    if installed {
+        build(Some(BARE_METAL))
+    } else {
+        eprintln!("note: `{BARE_METAL}` is not installed, skipping the build for it")
+    }
+}
@@ -0,0 +1,162 @@
+//!
+//! Property-based tests of packing into `RGB10A2` and `R11G11B10F`.
+//!
+//! Every code of every element is checked exhaustively to survive unpacking and packing again,
+//! the rest are properties of packing arbitrary floats:
+//! - the round-trip error is at most half a step;
+//! - packing is monotonic;
+//! - elements do not leak into each other's bits.
+//!
+
+extern crate proptest;
+extern crate rokoko;
+
+use proptest::prelude::*;
+use proptest::num::f32::{POSITIVE, NEGATIVE, NORMAL, SUBNORMAL, ZERO, INFINITE, QUIET_NAN};
+use rokoko::prelude::*;
+
+/// Largest finite `R11G11B10F` values
+const MAX_11: f32 = 65024.0;
+const MAX_10: f32 = 64512.0;
+
+/// Packs and unpacks the `i`-th element of `R11G11B10F` alone
+fn small_float(i: usize, x: f32) -> f32 {
+    let mut v = vec3::single(0.0);
+    v[i] = x;
+    vec3::from_r11g11b10f(v.to_r11g11b10f())[i]
+}
+
+/// Bits of the `i`-th element of `R11G11B10F`, with its number of mantissa bits
+fn field(i: usize) -> (u32, u32, u32) {
+    [(0, 11, 6), (11, 11, 6), (22, 10, 5)][i]
+}
+
+#[test]
+fn rgb10a2_codes_round_trip() {
+    for code in 0..1024u32 {
+        for shift in [0, 10, 20] {
+            assert_eq!(vec4::from_rgb10a2(code << shift).to_rgb10a2(), code << shift)
+        }
+    }
+    for code in 0..4u32 {
+        assert_eq!(vec4::from_rgb10a2(code << 30).to_rgb10a2(), code << 30)
+    }
+}
+
+#[test]
+fn r11g11b10f_codes_round_trip() {
+    for i in 0..3 {
+        let (offset, bits, mantissa) = field(i);
+        let nan = (31 << mantissa) | ((1 << mantissa) - 1);
+        for code in 0..1u32 << bits {
+            let unpacked = vec3::from_r11g11b10f(code << offset);
+            let expected = if code >> mantissa == 31 && code & ((1 << mantissa) - 1) != 0 {
+                // Every `NaN` is packed as the one with all the mantissa bits set
+                assert!(unpacked[i].is_nan());
+                nan
+            } else {
+                code
+            };
+            assert_eq!(unpacked.to_r11g11b10f(), expected << offset, "element {i}, code {code:#x}")
+        }
+    }
+}
+
+#[test]
+fn r11g11b10f_specials() {
+    for i in 0..3 {
+        let (offset, _, mantissa) = field(i);
+        let mut v = vec3::single(0.0);
+
+        v[i] = f32::INFINITY;
+        assert_eq!(v.to_r11g11b10f(), 31 << mantissa << offset);
+        v[i] = f32::MAX;
+        assert_eq!(v.to_r11g11b10f(), ((30 << mantissa) | ((1 << mantissa) - 1)) << offset);
+        v[i] = f32::MIN_POSITIVE;
+        assert_eq!(v.to_r11g11b10f(), 0);
+        v[i] = -f32::MIN_POSITIVE;
+        assert_eq!(v.to_r11g11b10f(), 0);
+    }
+}
+
+proptest! {
+    #[test]
+    fn rgb10a2_error_is_half_a_step(x in prop::array::uniform4(-0.5f32..1.5)) {
+        let v = vec4::from(x);
+        let back = vec4::from_rgb10a2(v.to_rgb10a2());
+        for i in 0..4 {
+            let step = if i == 3 { 1.0 / 3.0 } else { 1.0 / 1023.0 };
+            let clamped = x[i].clamp(0.0, 1.0);
+            // A tiny bit of slack for the error of the division itself
+            prop_assert!((back[i] - clamped).abs() <= step * 0.5 * (1.0 + 1e-5), "{} -> {}", x[i], back[i]);
+        }
+    }
+
+    #[test]
+    fn rgb10a2_is_monotonic(a in -0.5f32..1.5, b in -0.5f32..1.5) {
+        let (lo, hi) = if a <= b { (a, b) } else { (b, a) };
+        for i in 0..4 {
+            let mut l = vec4::single(0.0);
+            let mut h = vec4::single(0.0);
+            l[i] = lo;
+            h[i] = hi;
+            prop_assert!(l.to_rgb10a2() <= h.to_rgb10a2());
+        }
+    }
+
+    #[test]
+    fn rgb10a2_elements_are_independent(x in prop::array::uniform4(-0.5f32..1.5), i in 0usize..4, y in -0.5f32..1.5) {
+        let v = vec4::from(x);
+        let mut w = v;
+        w[i] = y;
+        let mask = if i == 3 { 0xC000_0000 } else { 0x3FF << (10 * i) };
+        prop_assert_eq!(v.to_rgb10a2() & !mask, w.to_rgb10a2() & !mask);
+    }
+
+    #[test]
+    fn r11g11b10f_error_is_half_a_step(x in POSITIVE | NORMAL | SUBNORMAL | ZERO, i in 0usize..3) {
+        let (_, _, mantissa) = field(i);
+        let max = if i == 2 { MAX_10 } else { MAX_11 };
+        let back = small_float(i, x);
+        if x.is_sign_negative() {
+            prop_assert_eq!(back, 0.0);
+        } else if x >= max {
+            prop_assert_eq!(back, max);
+        } else {
+            // Half of the distance between neighbouring values around `x`, denormals spaced as the smallest normals
+            let exponent = (x.log2().floor() as i32).max(-14);
+            let half_step = 2f32.powi(exponent - mantissa as i32 - 1);
+            prop_assert!((back - x).abs() <= half_step, "{} -> {}", x, back);
+        }
+    }
+
+    #[test]
+    fn r11g11b10f_is_monotonic(a in POSITIVE | NEGATIVE | NORMAL | SUBNORMAL | INFINITE | ZERO, b in POSITIVE | NEGATIVE | NORMAL | SUBNORMAL | INFINITE | ZERO, i in 0usize..3) {
+        let (lo, hi) = if a <= b { (a, b) } else { (b, a) };
+        let mut l = vec3::single(0.0);
+        let mut h = vec3::single(0.0);
+        l[i] = lo;
+        h[i] = hi;
+        prop_assert!(l.to_r11g11b10f() <= h.to_r11g11b10f());
+    }
+
+    #[test]
+    fn r11g11b10f_elements_are_independent(x in prop::array::uniform3(POSITIVE | NEGATIVE | NORMAL | QUIET_NAN), i in 0usize..3, y in POSITIVE | NEGATIVE | NORMAL | QUIET_NAN) {
+        let v = vec3::from(x);
+        let mut w = v;
+        w[i] = y;
+        let (offset, bits, _) = field(i);
+        let mask = ((1u32 << bits) - 1) << offset;
+        prop_assert_eq!(v.to_r11g11b10f() & !mask, w.to_r11g11b10f() & !mask);
+    }
+
+    #[test]
+    fn r11g11b10f_nan_is_pinned(x in prop::array::uniform3(POSITIVE), i in 0usize..3) {
+        let mut v = vec3::from(x);
+        v[i] = f32::NAN;
+        let (offset, bits, _) = field(i);
+        let mask = ((1u32 << bits) - 1) << offset;
+        prop_assert_eq!(v.to_r11g11b10f() & mask, mask);
+        prop_assert!(vec3::from_r11g11b10f(v.to_r11g11b10f())[i].is_nan());
+    }
+}
@@ -0,0 +1,76 @@
+//!
+//! Checks the decision of how the event loop waits, see `window::power`,
+//! against the table of its documentation for every combination of inputs.
+//!
+//! Requires nightly and the `window` feature, compiled to nothing otherwise.
+//!
+
+#![cfg(all(feature = "window", nightly))]
+
+extern crate rokoko;
+extern crate winit;
+
+use rokoko::window::power::{control_flow, LoopState, PowerMode};
+use winit::event_loop::ControlFlow;
+use std::time::{Duration, Instant};
+
+const MODES: [PowerMode; 3] = [PowerMode::Balanced, PowerMode::Performance, PowerMode::Efficiency];
+
+/// Whether frames run, written out as in the documentation:
+/// `continuous`, `visible`, `focused` => in `Balanced`, `Performance`, `Efficiency`
+const TABLE: [(bool, bool, bool, [bool; 3]); 8] = [
+    (false, false, false, [false, false, false]),
+    (false, false, true,  [false, false, false]),
+    (false, true,  false, [false, false, false]),
+    (false, true,  true,  [false, false, false]),
+    (true,  false, false, [false, true,  false]),
+    (true,  false, true,  [false, true,  false]),
+    (true,  true,  false, [true,  true,  false]),
+    (true,  true,  true,  [true,  true,  true])
+];
+
+/// Every `LoopState`, paced and not
+fn states() -> impl Iterator <Item = (LoopState, [bool; 3])> {
+    let deadline = Instant::now() + Duration::from_millis(16);
+    TABLE.iter().flat_map(move |&(continuous, visible, focused, runs)| [None, Some(deadline)].map(|next_frame| (
+        LoopState { continuous, visible, focused, next_frame },
+        runs
+    )))
+}
+
+#[test]
+fn full_matrix() {
+    for (state, runs) in states() {
+        for (mode, runs) in MODES.iter().copied().zip(runs) {
+            let expected = match (runs, state.next_frame) {
+                (false, _) => ControlFlow::Wait,
+                (true, None) => ControlFlow::Poll,
+                (true, Some(deadline)) => ControlFlow::WaitUntil(deadline)
+            };
+            assert_eq!(control_flow(mode, state), expected, "{mode:?} with {state:?}")
+        }
+    }
+}
+
+#[test]
+fn modes_are_ordered() {
+    // Whatever runs frames in a mode also runs them in any more eager one
+    let runs = |mode, state| control_flow(mode, state) != ControlFlow::Wait;
+    for (state, _) in states() {
+        if runs(PowerMode::Efficiency, state) {
+            assert!(runs(PowerMode::Balanced, state), "{:?}", state)
+        }
+        if runs(PowerMode::Balanced, state) {
+            assert!(runs(PowerMode::Performance, state), "{:?}", state)
+        }
+    }
+}
+
+#[test]
+fn balanced_by_default() {
+    assert_eq!(PowerMode::default(), PowerMode::Balanced);
+
+    let hidden = LoopState { continuous: true, visible: false, focused: true, next_frame: None };
+    assert_eq!(control_flow(PowerMode::default(), hidden), ControlFlow::Wait);
+    assert_eq!(control_flow(PowerMode::default(), LoopState { visible: true, ..hidden }), ControlFlow::Poll);
+}
@@ -0,0 +1,89 @@
+//!
+//! Checks that every key documented for `rokoko::run!` maps to a method of `WindowBuilder`.
+//!
+//! A test is generated for every key, building(but not creating) the window `run!` would,
+//! so renaming a method without updating the macro fails to compile.
+//!
+//! Requires nightly and the `window` feature, compiled to nothing otherwise.
+//!
+
+#![cfg(all(feature = "window", nightly))]
+
+extern crate rokoko;
+
+use rokoko::window::{Window, size_policy::SizePolicy, input_map::ActionMap, power::PowerMode};
+use rokoko::math::vec::uvec2;
+use std::time::Duration;
+
+macro_rules! keys {
+    ($($(#[$attr:meta])* $test:ident => ($($config:tt)*)),* $(,)?) => {$(
+        $(#[$attr])*
+        #[test]
+        fn $test() {
+            let _builder = rokoko::__run_builder!($($config)*);
+        }
+    )*};
+}
+
+keys! {
+    empty => (),
+    trailing_comma => (title: "a",),
+    several => (title: "a", logical_size: (1., 2.), on_close: Window::close),
+    resize_with_size => (size: (1., 2.), maximized, on_resize: |_, _size| ()),
+
+    title => (title: "a"),
+    size => (size: (1., 2.)),
+    maximized => (maximized),
+    auto_size => (auto_size: SizePolicy::P720),
+    #[allow(deprecated)]
+    size_is_logical => (size: (1., 2.), size_is_logical),
+    logical_size => (logical_size: (1., 2.)),
+    position => (position: (100., 200.)),
+    position_is_logical => (position: (100., 200.), position_is_logical),
+    not_resizable => (not_resizable),
+    no_decorations => (no_decorations),
+    splash => (size: (400., 300.), not_resizable, no_decorations),
+    fixed_maximized => (maximized, not_resizable, no_decorations),
+    transparent => (transparent),
+    always_on_top => (always_on_top),
+    overlay => (transparent, always_on_top, no_decorations),
+    icon => (icon: (&[0; 16][..], uvec2::from([2, 2]))),
+    icon_with_title => (title: "a", icon: (&[255; 4][..], uvec2::from([1, 1]))),
+    no_panic_context => (no_panic_context),
+    target_fps => (target_fps: 60.0),
+    power_mode => (power_mode: PowerMode::Efficiency, on_frame: |_, _dt| ()),
+    drag_select => (drag_select),
+    drag_select_threshold => (drag_select, drag_select_threshold: 10.0),
+    gestures => (gestures),
+    double_tap_interval => (gestures, double_tap_interval: Duration::from_millis(250)),
+    fullscreen => (fullscreen),
+    fullscreen_management => (fullscreen, fullscreen_management),
+    #[cfg(feature = "unstable-minimize-on-focus-loss")]
+    minimize_on_focus_loss => (fullscreen_management, minimize_on_focus_loss),
+    count_events => (count_events),
+    action_map => (action_map: ActionMap::new()),
+
+    on_close_request => (on_close_request: |_| true),
+    on_close => (on_close: |w| w.close()),
+    on_init => (on_init: |_| ()),
+    on_exit => (on_exit: |_| ()),
+    on_close_scheduled => (on_close_scheduled: |_, _remaining| ()),
+    on_frame => (on_frame: |_, _dt| ()),
+    on_resize => (on_resize: |_, _size| ()),
+    on_redraw => (on_redraw: Window::request_redraw),
+    on_key => (on_key: |_, _key, _pressed| ()),
+    on_char => (on_char: |_, _c| ()),
+    on_mouse_move => (on_mouse_move: |_, _position| ()),
+    on_mouse_button => (on_mouse_button: |_, _button, _pressed| ()),
+    on_scroll => (on_scroll: |_, _delta| ()),
+    on_cursor_entered => (on_cursor_entered: |_| ()),
+    on_cursor_left => (on_cursor_left: |_| ()),
+    on_focus => (on_focus: |_, _focused| ()),
+    on_file_drop => (on_file_drop: |_, _path| ()),
+    on_file_hover => (on_file_hover: |_, _path| ()),
+    on_drag_select => (drag_select, on_drag_select: |_, _rect, _phase| ()),
+    on_pinch => (gestures, on_pinch: |_, _scale_delta, _center| ()),
+    on_pan => (gestures, on_pan: |_, _delta| ()),
+    on_tap => (gestures, on_tap: |_, _position, _count| ()),
+    on_action => (action_map: ActionMap::new(), on_action: |_, _action, _pressed| ())
+}
@@ -0,0 +1,146 @@
+//!
+//! Drives a user-defined [`Scalar`] through `vec` features end to end.
+//!
+//! `Q8` is a fixed-point number with 8 fractional bits, implementing nothing
+//! but the operators, ordering and [`Scalar`]/[`SignedScalar`] - no `Default` in particular,
+//! so whatever works here works because of the scalar layer alone.
+//!
+
+extern crate rokoko;
+
+use rokoko::prelude::*;
+use rokoko::math::scalar::{Scalar, SignedScalar};
+use rokoko::math::vec::CellVec;
+use std::ops::*;
+
+/// `self.0 / 256`
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd)]
+struct Q8(i32);
+
+impl Add for Q8 {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self { Q8(self.0 + rhs.0) }
+}
+
+impl Sub for Q8 {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self { Q8(self.0 - rhs.0) }
+}
+
+impl Mul for Q8 {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self { Q8(self.0 * rhs.0 >> 8) }
+}
+
+impl Div for Q8 {
+    type Output = Self;
+    fn div(self, rhs: Self) -> Self { Q8((self.0 << 8) / rhs.0) }
+}
+
+impl Neg for Q8 {
+    type Output = Self;
+    fn neg(self) -> Self { Q8(-self.0) }
+}
+
+impl Scalar for Q8 {
+    const ZERO: Self = Q8(0);
+    const ONE: Self = Q8(256);
+}
+
+impl SignedScalar for Q8 {}
+
+/// `x` in fixed-point, exact for the multiples of `1 / 256` used below
+fn q(x: f64) -> Q8 {
+    Q8((x * 256.0) as i32)
+}
+
+fn v <const N: usize> (xs: [f64; N]) -> vec <Q8, N> {
+    vec::from(xs.map(q))
+}
+
+#[test]
+fn constants_and_default() {
+    assert_eq!(vec::<Q8, 3>::default(), vec::ZERO);
+    assert_eq!(vec::<Q8, 3>::ZERO, v([0.0; 3]));
+    assert_eq!(vec::<Q8, 2>::ONE, v([1.0, 1.0]));
+    assert_eq!(vec::<Q8, 3>::UNIT_Y, v([0.0, 1.0, 0.0]));
+    assert_eq!(CellVec::<Q8, 2>::default().get(), vec::ZERO);
+}
+
+#[test]
+fn arithmetic() {
+    let a = v([1.5, -2.0]);
+    let b = v([0.5, 4.0]);
+
+    assert_eq!(a + b, v([2.0, 2.0]));
+    assert_eq!(a - b, v([1.0, -6.0]));
+    assert_eq!(a * b, v([0.75, -8.0]));
+    assert_eq!(a / b, v([3.0, -0.5]));
+    assert_eq!(-a, v([-1.5, 2.0]));
+    assert_eq!(a * q(2.0), v([3.0, -4.0]));
+
+    let mut c = a;
+    c += b;
+    c *= q(0.5);
+    assert_eq!(c, vec::ONE);
+}
+
+#[test]
+fn metric() {
+    let a = v([3.0, 4.0]);
+    let b = v([0.5, -0.25]);
+
+    assert_eq!(a.dot(b), q(0.5));
+    assert_eq!(a.length_squared(), q(25.0));
+    assert_eq!(a.distance_squared(v([0.0, 0.0])), q(25.0));
+    assert_eq!(a.distance_squared(a), Q8::ZERO);
+}
+
+#[test]
+fn interpolation() {
+    let a = v([1.0, -2.0]);
+    let b = v([3.0, 6.0]);
+
+    assert_eq!(a.lerp(b, Q8::ZERO), a);
+    assert_eq!(a.lerp(b, Q8::ONE), b);
+    assert_eq!(a.lerp(b, q(0.25)), v([1.5, 0.0]));
+    assert_eq!(a.lerp(b, q(2.0)), v([5.0, 14.0]));
+
+    assert_eq!(vec::step(v([0.0, 1.0, 2.0]), v([1.0; 3])), v([1.0, 1.0, 0.0]));
+    assert_eq!(v([-0.5, 0.0, 2.0]).signum(), v([-1.0, 0.0, 1.0]));
+}
+
+#[test]
+fn reductions_and_ordering() {
+    let a = v([0.5, -2.0, 4.0]);
+
+    assert_eq!(a.sum(), q(2.5));
+    assert_eq!(a.product(), q(-4.0));
+    assert_eq!(a.min_element(), q(-2.0));
+    assert_eq!(a.max_element(), q(4.0));
+    assert_eq!(a.clamp_scalar(q(-1.0), q(1.0)), v([0.5, -1.0, 1.0]));
+    assert_eq!(a.min(vec::ZERO), v([0.0, -2.0, 0.0]));
+}
+
+#[test]
+fn linear() {
+    type Q8vec3 = vec <Q8, 3>;
+    assert_eq!(Q8vec3::UNIT_X.cross(Q8vec3::UNIT_Y), Q8vec3::UNIT_Z);
+    assert_eq!(v([2.0, 4.0]).perp_dot(v([1.0, 2.0])), Q8::ZERO);
+    assert_eq!(
+        vec::linear_combine(v([0.5, 2.0]), [vec::<Q8, 2>::UNIT_X, vec::<Q8, 2>::UNIT_Y]),
+        v([0.5, 2.0])
+    );
+    assert_eq!(v([1.0, 2.0]).hadamard(v([0.5, 0.5])), v([0.5, 1.0]));
+}
+
+#[test]
+fn helpers() {
+    let a = v([1.0, 2.0]);
+
+    assert_eq!(vec::single(q(0.5)), v([0.5, 0.5]));
+    assert_eq!(a.apply_binary(a, Add::add), a * q(2.0));
+    assert_eq!(a.apply_unary(SignedScalar::abs), a);
+    assert_eq!((-a).apply_unary(SignedScalar::abs), a);
+    assert!(a.apply_unary_bool(|x| x > Q8::ZERO));
+}
@@ -0,0 +1,144 @@
+//!
+//! Checks naming and writing of screenshots into a temporary directory, see `window::screenshot`.
+//!
+//! Requires nightly and the `window` feature, compiled to nothing otherwise.
+//!
+
+#![cfg(all(feature = "window", nightly))]
+
+extern crate rokoko;
+
+use rokoko::prelude::*;
+use rokoko::window::screenshot::{self, file_name, create_file, try_save};
+use std::{
+    fs,
+    io::ErrorKind,
+    path::{Path, PathBuf},
+    time::{Duration, SystemTime, UNIX_EPOCH}
+};
+
+/// A fresh directory of its own for each test, removed on drop
+struct TempDir(PathBuf);
+
+impl TempDir {
+    fn new(name: &str) -> Self {
+        let path = std::env::temp_dir().join(format!("rokoko-screenshot-{}-{name}", std::process::id()));
+        let _ = fs::remove_dir_all(&path);
+        TempDir(path)
+    }
+
+    fn files(&self) -> Vec <String> {
+        let mut files: Vec <_> = fs::read_dir(&self.0).unwrap()
+            .map(|e| e.unwrap().file_name().into_string().unwrap())
+            .collect();
+        files.sort();
+        files
+    }
+}
+
+impl Drop for TempDir {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.0);
+    }
+}
+
+/// 2000-02-29 23:59:58.5 UTC
+fn leap_day() -> SystemTime {
+    UNIX_EPOCH + Duration::from_millis(951_868_798_500)
+}
+
+/// A simulated 3x2 frame, a gradient of red to the right and of green to the bottom
+fn frame() -> (uvec2, Vec <[u8; 3]>) {
+    let size = uvec2::from([3, 2]);
+    let rgb = (0..6).map(|i| [(i % 3 * 100) as u8, (i / 3 * 200) as u8, 7]).collect();
+    (size, rgb)
+}
+
+#[test]
+fn names() {
+    assert_eq!(file_name(leap_day(), 0), "screenshot-2000-02-29_23-59-58.500.ppm");
+    assert_eq!(file_name(leap_day() + Duration::from_millis(1500), 0), "screenshot-2000-03-01_00-00-00.000.ppm");
+    assert_eq!(file_name(UNIX_EPOCH, 0), "screenshot-1970-01-01_00-00-00.000.ppm");
+    assert_eq!(file_name(UNIX_EPOCH - Duration::from_secs(1), 0), "screenshot-1970-01-01_00-00-00.000.ppm");
+    assert_eq!(file_name(UNIX_EPOCH + Duration::from_secs(4_102_444_800), 10), "screenshot-2100-01-01_00-00-00.000-10.ppm");
+}
+
+#[test]
+fn missing_directories_are_created() {
+    let temp = TempDir::new("nested");
+    let dir = temp.0.join("a").join("b");
+
+    let (path, _) = create_file(&dir, leap_day()).unwrap();
+    assert_eq!(path, dir.join("screenshot-2000-02-29_23-59-58.500.ppm"));
+    assert!(path.is_file());
+}
+
+#[test]
+fn same_time_does_not_collide() {
+    let temp = TempDir::new("collisions");
+    let (size, rgb) = frame();
+
+    let paths: Vec <_> = (0..3).map(|_| try_save(&temp.0, leap_day(), size, &rgb).unwrap()).collect();
+    assert_eq!(temp.files(), [
+        "screenshot-2000-02-29_23-59-58.500-1.ppm",
+        "screenshot-2000-02-29_23-59-58.500-2.ppm",
+        "screenshot-2000-02-29_23-59-58.500.ppm"
+    ]);
+    assert_eq!(paths[0], temp.0.join("screenshot-2000-02-29_23-59-58.500.ppm"));
+    assert_eq!(paths[2], temp.0.join("screenshot-2000-02-29_23-59-58.500-2.ppm"));
+
+    // Nothing is overwritten by a later capture
+    fs::write(&paths[1], b"kept").unwrap();
+    try_save(&temp.0, leap_day(), size, &rgb).unwrap();
+    assert_eq!(fs::read(&paths[1]).unwrap(), b"kept");
+}
+
+#[test]
+fn written_frame() {
+    let temp = TempDir::new("frame");
+    let (size, rgb) = frame();
+
+    let path = screenshot::save(&temp.0, leap_day(), size, &rgb).unwrap();
+    let ppm = fs::read(path).unwrap();
+
+    let header = b"P6\n3 2\n255\n";
+    assert_eq!(&ppm[..header.len()], header);
+    assert_eq!(ppm[header.len()..], [
+        0, 0, 7,   100, 0, 7,   200, 0, 7,
+        0, 200, 7, 100, 200, 7, 200, 200, 7
+    ]);
+}
+
+#[test]
+fn wrong_pixel_count_leaves_no_file() {
+    let temp = TempDir::new("wrong-count");
+    let (_, rgb) = frame();
+
+    let error = try_save(&temp.0, leap_day(), uvec2::from([2, 2]), &rgb).unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::InvalidInput);
+    assert!(temp.files().is_empty());
+}
+
+#[test]
+fn failures_are_not_panics() {
+    let temp = TempDir::new("not-a-dir");
+    fs::create_dir_all(&temp.0).unwrap();
+    // A file where the directory should be
+    let file = temp.0.join("file");
+    fs::write(&file, b"").unwrap();
+    let (size, rgb) = frame();
+
+    assert!(try_save(&file, leap_day(), size, &rgb).is_err());
+    assert_eq!(screenshot::save(&file, leap_day(), size, &rgb), None);
+    assert_eq!(screenshot::save(&file.join("below"), leap_day(), size, &rgb), None);
+    assert_eq!(temp.files(), ["file"]);
+}
+
+#[test]
+fn empty_frame() {
+    let temp = TempDir::new("empty");
+
+    let path = try_save(&temp.0, leap_day(), uvec2::from([0, 4]), &[]).unwrap();
+    assert_eq!(fs::read(&path).unwrap(), b"P6\n0 4\n255\n");
+    assert!(Path::new(&path).starts_with(&temp.0));
+}
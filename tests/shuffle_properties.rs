@@ -0,0 +1,63 @@
+//!
+//! Property-based tests of vec shuffles and interleaving against manual indexing.
+//!
+
+extern crate proptest;
+extern crate rokoko;
+
+use proptest::prelude::*;
+use rokoko::prelude::*;
+
+proptest! {
+    #[test]
+    fn shuffles_match_indexing(a in prop::array::uniform4(any::<i32>())) {
+        let v = ivec4::from(a);
+
+        prop_assert_eq!(v.shuffle2::<3, 1>(), ivec2::from([a[3], a[1]]));
+        prop_assert_eq!(v.shuffle2::<0, 0>(), ivec2::from([a[0], a[0]]));
+        prop_assert_eq!(v.shuffle3::<2, 0, 3>(), ivec3::from([a[2], a[0], a[3]]));
+        prop_assert_eq!(v.shuffle4::<3, 2, 1, 0>(), ivec4::from([a[3], a[2], a[1], a[0]]));
+        prop_assert_eq!(v.shuffle4::<0, 1, 2, 3>(), v);
+    }
+
+    #[test]
+    fn shuffles_of_other_sizes(a in prop::array::uniform2(any::<f64>()), b in prop::array::uniform3(any::<u8>())) {
+        let v = vec::from(a);
+        prop_assert_eq!(v.shuffle4::<1, 0, 1, 1>().into_array().map(f64::to_bits), [a[1], a[0], a[1], a[1]].map(f64::to_bits));
+
+        let w = vec::from(b);
+        prop_assert_eq!(w.shuffle3::<1, 2, 0>(), vec::from([b[1], b[2], b[0]]));
+        prop_assert_eq!(w.shuffle2::<2, 1>(), vec::from([b[2], b[1]]));
+    }
+
+    #[test]
+    fn bgra_round_trip(a in prop::array::uniform4(any::<u8>())) {
+        let rgba = vec::from(a);
+        let bgra = rgba.shuffle4::<2, 1, 0, 3>();
+        prop_assert_eq!(bgra, vec::from([a[2], a[1], a[0], a[3]]));
+        prop_assert_eq!(bgra.shuffle4::<2, 1, 0, 3>(), rgba);
+    }
+
+    #[test]
+    fn interleave_matches_indexing(a in prop::array::uniform6(any::<i32>()), b in prop::array::uniform6(any::<i32>())) {
+        let (low, high) = vec::from(a).interleave(vec::from(b));
+
+        let mut expected = Vec::new();
+        for i in 0..6 {
+            expected.push(a[i]);
+            expected.push(b[i]);
+        }
+        prop_assert_eq!(&low.into_array()[..], &expected[..6]);
+        prop_assert_eq!(&high.into_array()[..], &expected[6..]);
+    }
+}
+
+#[test]
+fn interleave_of_pairs() {
+    let (low, high) = ivec2::from([1, 2]).interleave(ivec2::from([3, 4]));
+    assert_eq!(low, ivec2::from([1, 3]));
+    assert_eq!(high, ivec2::from([2, 4]));
+
+    let empty = vec::<i32, 0>::from([]);
+    assert_eq!(empty.interleave(empty), (empty, empty));
+}
@@ -0,0 +1,191 @@
+//!
+//! Drives `window::shutdown::CloseSchedule` through the sequences of `Window::close_after`,
+//! see the module documentation.
+//!
+//! Requires nightly and the `window` feature, compiled to nothing otherwise.
+//!
+
+#![cfg(all(feature = "window", nightly))]
+
+extern crate rokoko;
+extern crate winit;
+
+use rokoko::window::shutdown::CloseSchedule;
+use winit::event_loop::ControlFlow;
+use std::time::{Duration, Instant};
+
+/// Returns a function giving the instant `s` seconds after a common start
+fn clock() -> impl Fn(f32) -> Instant {
+    let start = Instant::now();
+    move |s| start + Duration::from_secs_f32(s)
+}
+
+#[test]
+fn nothing_scheduled() {
+    let t = clock();
+    let mut schedule = CloseSchedule::new();
+
+    assert!(!schedule.is_scheduled());
+    assert_eq!(schedule.deadline(), None);
+    assert!(!schedule.fire(t(100.0)));
+    assert_eq!(schedule.announce(t(0.0)), None);
+    assert_eq!(schedule.control_flow(ControlFlow::Wait), ControlFlow::Wait);
+}
+
+#[test]
+fn schedule_and_fire() {
+    let t = clock();
+    let mut schedule = CloseSchedule::new();
+    schedule.schedule(t(3.0));
+
+    assert!(schedule.is_scheduled());
+    assert_eq!(schedule.deadline(), Some(t(3.0)));
+    assert!(!schedule.fire(t(0.0)));
+    assert!(!schedule.fire(t(2.9)));
+    assert!(schedule.fire(t(3.0)));
+
+    // Only once, and closing from then on
+    assert!(!schedule.fire(t(4.0)));
+    assert!(!schedule.is_scheduled());
+    assert_eq!(schedule.control_flow(ControlFlow::Wait), ControlFlow::Wait);
+}
+
+#[test]
+fn fires_late() {
+    let t = clock();
+    let mut schedule = CloseSchedule::new();
+    schedule.schedule(t(1.0));
+
+    // E.g. the loop was blocked past the deadline
+    assert!(schedule.fire(t(10.0)));
+}
+
+#[test]
+fn cancel() {
+    let t = clock();
+    let mut schedule = CloseSchedule::new();
+    let id = schedule.schedule(t(3.0));
+
+    assert!(schedule.cancel(id));
+    assert!(!schedule.is_scheduled());
+    assert!(!schedule.fire(t(3.0)));
+    assert!(!schedule.fire(t(100.0)));
+
+    // Cancelling twice
+    assert!(!schedule.cancel(id));
+}
+
+#[test]
+fn cancel_after_fire_is_noop() {
+    let t = clock();
+    let mut schedule = CloseSchedule::new();
+    let id = schedule.schedule(t(3.0));
+
+    assert!(schedule.fire(t(3.0)));
+    assert!(!schedule.cancel(id));
+
+    // Nor can it be scheduled again
+    let again = schedule.schedule(t(5.0));
+    assert!(!schedule.is_scheduled());
+    assert!(!schedule.cancel(again));
+    assert!(!schedule.fire(t(5.0)));
+}
+
+#[test]
+fn reschedule_replaces() {
+    let t = clock();
+    let mut schedule = CloseSchedule::new();
+    let first = schedule.schedule(t(3.0));
+    let second = schedule.schedule(t(5.0));
+
+    assert_ne!(first, second);
+    assert_eq!(schedule.deadline(), Some(t(5.0)));
+    assert!(!schedule.fire(t(3.0)));
+
+    // The token of the replaced one does nothing
+    assert!(!schedule.cancel(first));
+    assert!(schedule.is_scheduled());
+
+    assert!(schedule.cancel(second));
+    assert!(!schedule.fire(t(5.0)));
+
+    // A new one after cancelling
+    let third = schedule.schedule(t(6.0));
+    assert!(!schedule.cancel(second));
+    assert!(schedule.fire(t(6.0)));
+    assert!(!schedule.cancel(third));
+}
+
+#[test]
+fn reschedule_earlier() {
+    let t = clock();
+    let mut schedule = CloseSchedule::new();
+    schedule.schedule(t(5.0));
+    schedule.schedule(t(1.0));
+
+    assert!(schedule.fire(t(1.0)));
+}
+
+#[test]
+fn announced_once_per_scheduling() {
+    let t = clock();
+    let mut schedule = CloseSchedule::new();
+
+    schedule.schedule(t(3.0));
+    assert_eq!(schedule.announce(t(1.0)), Some(Duration::from_secs(2)));
+    assert_eq!(schedule.announce(t(1.0)), None);
+
+    // Past the deadline, but not fired yet
+    schedule.schedule(t(3.0));
+    assert_eq!(schedule.announce(t(4.0)), Some(Duration::ZERO));
+
+    // Cancelled or fired before it is announced
+    let id = schedule.schedule(t(3.0));
+    schedule.cancel(id);
+    assert_eq!(schedule.announce(t(0.0)), None);
+
+    schedule.schedule(t(3.0));
+    schedule.fire(t(3.0));
+    assert_eq!(schedule.announce(t(3.0)), None);
+}
+
+#[test]
+fn wakes_by_deadline() {
+    let t = clock();
+    let mut schedule = CloseSchedule::new();
+    schedule.schedule(t(3.0));
+
+    assert_eq!(schedule.control_flow(ControlFlow::Wait), ControlFlow::WaitUntil(t(3.0)));
+    assert_eq!(schedule.control_flow(ControlFlow::WaitUntil(t(1.0))), ControlFlow::WaitUntil(t(1.0)));
+    assert_eq!(schedule.control_flow(ControlFlow::WaitUntil(t(4.0))), ControlFlow::WaitUntil(t(3.0)));
+    assert_eq!(schedule.control_flow(ControlFlow::Poll), ControlFlow::Poll);
+    assert_eq!(schedule.control_flow(ControlFlow::Exit), ControlFlow::Exit);
+}
+
+/// What a loop waking at `wakes`(in seconds) does with a close scheduled for t=3 at t=0,
+/// cancelled at `cancel_at` if any: returns when it began closing
+fn run(wakes: &[f32], cancel_at: Option <f32>) -> Option <f32> {
+    let t = clock();
+    let mut schedule = CloseSchedule::new();
+    let id = schedule.schedule(t(3.0));
+
+    for &now in wakes {
+        if cancel_at == Some(now) {
+            schedule.cancel(id);
+        }
+        if schedule.fire(t(now)) {
+            return Some(now)
+        }
+    }
+    None
+}
+
+#[test]
+fn interaction_cancels_the_countdown() {
+    // A key at t=1 cancels, nothing closes
+    assert_eq!(run(&[0.0, 1.0, 2.0, 3.0, 10.0], Some(1.0)), None);
+    // No key, the window starts closing once the deadline passes
+    assert_eq!(run(&[0.0, 1.0, 2.0, 3.0, 10.0], None), Some(3.0));
+    // Waking late, it starts closing on the first iteration past the deadline
+    assert_eq!(run(&[0.0, 3.5], None), Some(3.5));
+}
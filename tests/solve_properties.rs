@@ -0,0 +1,146 @@
+//!
+//! Property-based tests of `solve` on small integer matrices.
+//!
+//! Determinants of those are integers, computed exactly, so a matrix
+//! is singular exactly when its determinant is zero, and the rest are
+//! far enough from singular to check the answers within a tight epsilon.
+//!
+
+extern crate proptest;
+extern crate rokoko;
+
+use proptest::prelude::*;
+use proptest::array::{uniform2, uniform3};
+use rokoko::prelude::*;
+use rokoko::math::solve::{det2, det3, solve2, solve3, inverse2, inverse3};
+
+/// Tolerance of multiplying back
+const EPSILON: f64 = 1e-9;
+
+/// Small integers, sometimes repeated, so that singular matrices are generated often
+fn elem() -> impl Strategy <Value = f64> {
+    (-3i32..4).prop_map(|x| x as f64)
+}
+
+fn m2() -> impl Strategy <Value = [dvec2; 2]> {
+    uniform2(uniform2(elem()).prop_map(dvec2::from))
+}
+
+fn m3() -> impl Strategy <Value = [dvec3; 3]> {
+    uniform3(uniform3(elem()).prop_map(dvec3::from))
+}
+
+/// Returns `a * x`, `a` being rows
+fn mul <const N: usize> (a: [vec <f64, N>; N], x: vec <f64, N>) -> vec <f64, N> {
+    let mut result = vec::single(0.0);
+    for i in 0..N {
+        result[i] = (0..N).map(|j| a[i][j] * x[j]).sum()
+    }
+    result
+}
+
+/// Returns `a * b`, both being rows
+fn mul_m <const N: usize> (a: [vec <f64, N>; N], b: [vec <f64, N>; N]) -> [vec <f64, N>; N] {
+    let mut result = [vec::single(0.0); N];
+    for i in 0..N {
+        for j in 0..N {
+            result[i][j] = (0..N).map(|k| a[i][k] * b[k][j]).sum()
+        }
+    }
+    result
+}
+
+fn transpose <const N: usize> (a: [vec <f64, N>; N]) -> [vec <f64, N>; N] {
+    let mut result = a;
+    for i in 0..N {
+        for j in 0..N {
+            result[i][j] = a[j][i]
+        }
+    }
+    result
+}
+
+fn close <const N: usize> (a: vec <f64, N>, b: vec <f64, N>) -> bool {
+    (0..N).all(|i| (a[i] - b[i]).abs() <= EPSILON * (1.0 + b[i].abs()))
+}
+
+fn identity <const N: usize> () -> [vec <f64, N>; N] {
+    let mut result = [vec::single(0.0); N];
+    for i in 0..N {
+        result[i][i] = 1.0
+    }
+    result
+}
+
+macro_rules! properties {
+    ($( $m:ident: $n:literal, $matrix:ident, $uniform:ident, $det:ident, $solve:ident, $inverse:ident; )*) => {$(
+        mod $m {
+            use super::*;
+
+            proptest! {
+                #[test]
+                fn singular_iff_zero_det(a in $matrix(), b in $uniform(elem())) {
+                    let b = vec::from(b);
+                    prop_assert_eq!($solve(a, b).is_none(), $det(a) == 0.0);
+                    prop_assert_eq!($inverse(a).is_none(), $det(a) == 0.0);
+                }
+
+                #[test]
+                fn solution_multiplies_back(a in $matrix(), b in $uniform(elem())) {
+                    let b = vec::from(b);
+                    if let Some(x) = $solve(a, b) {
+                        prop_assert!(close(mul(a, x), b), "{:?} * {:?} != {:?}", a, x, b);
+                    }
+                }
+
+                #[test]
+                fn inverse_multiplies_to_identity(a in $matrix()) {
+                    if let Some(inv) = $inverse(a) {
+                        for (row, expected) in mul_m(a, inv).iter().zip(identity::<$n>().iter()) {
+                            prop_assert!(close(*row, *expected));
+                        }
+                        for (row, expected) in mul_m(inv, a).iter().zip(identity::<$n>().iter()) {
+                            prop_assert!(close(*row, *expected));
+                        }
+                    }
+                }
+
+                #[test]
+                fn solution_is_inverse_times_b(a in $matrix(), b in $uniform(elem())) {
+                    let b = vec::from(b);
+                    if let (Some(x), Some(inv)) = ($solve(a, b), $inverse(a)) {
+                        prop_assert!(close(mul(inv, b), x));
+                    }
+                }
+
+                #[test]
+                fn det_signs(a in $matrix(), k in elem()) {
+                    prop_assert_eq!($det(identity::<$n>()), 1.0);
+                    prop_assert_eq!($det(transpose(a)), $det(a));
+
+                    let mut swapped = a;
+                    swapped.swap(0, 1);
+                    prop_assert_eq!($det(swapped), -$det(a));
+
+                    let mut scaled = a;
+                    scaled[$n - 1] = scaled[$n - 1] * k;
+                    prop_assert_eq!($det(scaled), $det(a) * k);
+                }
+            }
+        }
+    )*};
+}
+
+properties! {
+    m2: 2, m2, uniform2, det2, solve2, inverse2;
+    m3: 3, m3, uniform3, det3, solve3, inverse3;
+}
+
+#[test]
+fn det3_is_triple_product_of_rows() {
+    // Right-handed
+    assert_eq!(det3([dvec3::UNIT_X, dvec3::UNIT_Y, dvec3::UNIT_Z]), 1.0);
+    // Left-handed
+    assert_eq!(det3([dvec3::UNIT_X, dvec3::UNIT_Z, dvec3::UNIT_Y]), -1.0);
+    assert_eq!(det2([dvec2::UNIT_Y, dvec2::UNIT_X]), -1.0);
+}
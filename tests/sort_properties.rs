@@ -0,0 +1,177 @@
+//!
+//! Property-based tests of point comparators, see `math::vec::sort`.
+//!
+//! Sorting by each comparator is checked to give a sequence consistent with its definition,
+//! computed here the straightforward way, and never to panic on `NaN`s.
+//!
+
+extern crate proptest;
+extern crate rokoko;
+
+use proptest::prelude::*;
+use proptest::num::f32::{POSITIVE, NEGATIVE, NORMAL, SUBNORMAL, ZERO, INFINITE, QUIET_NAN, SIGNALING_NAN};
+use rokoko::prelude::*;
+use rokoko::math::scalar::TotalOrd;
+use std::cmp::Ordering;
+
+fn any_f32() -> impl Strategy <Value = f32> {
+    POSITIVE | NEGATIVE | NORMAL | SUBNORMAL | ZERO | INFINITE | QUIET_NAN | SIGNALING_NAN
+}
+
+fn ivec3s() -> impl Strategy <Value = Vec <ivec3>> {
+    prop::collection::vec(any::<[i32; 3]>().prop_map(ivec3::from), 0..32)
+}
+
+fn fvec3s() -> impl Strategy <Value = Vec <fvec3>> {
+    prop::collection::vec([any_f32(), any_f32(), any_f32()].prop_map(fvec3::from), 0..32)
+}
+
+/// The exact squared distance, which fits in `i128` for `i32` elements
+fn distance_squared(a: ivec3, b: ivec3) -> i128 {
+    a.into_array().iter().zip(b.into_array()).map(|(&a, b)| (a as i128 - b as i128).pow(2)).sum()
+}
+
+/// The Morton code with bits of the last element the most significant ones of each level
+fn morton(v: vec <u16, 3>) -> u64 {
+    (0..16).flat_map(|bit| (0..3).map(move |axis| (bit, axis)))
+        .map(|(bit, axis)| ((v[axis] as u64 >> bit) & 1) << (bit * 3 + axis))
+        .sum()
+}
+
+#[test]
+fn sorted_by_axis() {
+    let mut points = [ivec2::from([3, -1]), ivec2::from([1, 2]), ivec2::from([2, 0])];
+
+    ivec2::sort_points_by_axis(&mut points, 0);
+    assert_eq!(points, [ivec2::from([1, 2]), ivec2::from([2, 0]), ivec2::from([3, -1])]);
+
+    ivec2::sort_points_by_axis(&mut points, 1);
+    assert_eq!(points, [ivec2::from([3, -1]), ivec2::from([2, 0]), ivec2::from([1, 2])]);
+}
+
+#[test]
+#[should_panic(expected = "axis 3 is out of bounds for a vec of 3 elements")]
+fn bad_axis() {
+    let _ = ivec3::cmp_by_axis(3);
+}
+
+#[test]
+#[should_panic(expected = "axis 0 is out of bounds for a vec of 0 elements")]
+fn no_axis() {
+    ivec::<0>::sort_points_by_axis(&mut [], 0);
+}
+
+#[test]
+fn float_axis_places_nans_by_sign() {
+    let mut points = [f32::NAN, 1.0, -f32::NAN, 0.0, f32::NEG_INFINITY, -0.0].map(vec1::single);
+
+    vec1::sort_points_by_axis(&mut points, 0);
+    let bits = points.map(|p| p[0].to_bits());
+    assert_eq!(bits, [(-f32::NAN).to_bits(), f32::NEG_INFINITY.to_bits(), (-0.0f32).to_bits(), 0, 1.0f32.to_bits(), f32::NAN.to_bits()]);
+}
+
+#[test]
+fn integer_distances_do_not_overflow() {
+    let far = vec::<i64, 2>::from([i64::MIN, i64::MIN]);
+    let near = vec::<i64, 2>::from([i64::MAX, 0]);
+    let cmp = vec::<i64, 2>::cmp_by_distance_to(vec::from([i64::MAX, i64::MAX]));
+
+    assert_eq!(cmp(&far, &near), Ordering::Greater);
+    assert_eq!(cmp(&near, &far), Ordering::Less);
+    assert_eq!(cmp(&far, &far), Ordering::Equal);
+
+    // Squares of the widest differences, which differ only in their lowest bits
+    let cmp = vec::<u128, 2>::cmp_by_distance_to(vec::single(0));
+    assert_eq!(cmp(&vec::from([u128::MAX, u128::MAX]), &vec::from([u128::MAX, u128::MAX - 1])), Ordering::Greater);
+    let cmp = vec::<i128, 1>::cmp_by_distance_to(vec::single(i128::MIN));
+    assert_eq!(cmp(&vec::single(i128::MAX), &vec::single(i128::MAX - 1)), Ordering::Greater);
+    assert_eq!(u128::cmp_distances_squared(&[u128::MAX; 4], &[u128::MAX; 4], &[0; 4]), Ordering::Equal);
+}
+
+#[test]
+fn nan_distances_go_last() {
+    let mut points = [[f32::NAN, 0.0], [-f32::NAN, 0.0], [f32::INFINITY, 0.0], [0.0, 1.0]].map(vec2::from);
+
+    vec2::sort_points_by_distance_to(&mut points, vec2::ZERO);
+    assert_eq!(points[..2], [vec2::from([0.0, 1.0]), vec2::from([f32::INFINITY, 0.0])]);
+    assert!(points[2..].iter().all(|p| p[0].is_nan()));
+
+    // `inf - inf` is `NaN`
+    let cmp = vec2::cmp_by_distance_to(vec2::single(f32::INFINITY));
+    assert_eq!(cmp(&vec2::single(f32::INFINITY), &vec2::single(f32::MAX)), Ordering::Greater);
+}
+
+#[test]
+fn morton_agrees_with_codes() {
+    let mut points: Vec <_> = (0..4).flat_map(|y| (0..4).map(move |x| uvec2::from([x, y]))).collect();
+
+    uvec2::sort_points_morton(&mut points);
+    let order: Vec <_> = points.iter().map(|p| (p[0], p[1])).collect();
+    assert_eq!(order, [
+        (0, 0), (1, 0), (0, 1), (1, 1),
+        (2, 0), (3, 0), (2, 1), (3, 1),
+        (0, 2), (1, 2), (0, 3), (1, 3),
+        (2, 2), (3, 2), (2, 3), (3, 3)
+    ]);
+}
+
+#[test]
+fn morton_of_signed_follows_values() {
+    let cmp = ivec2::cmp_morton();
+
+    assert_eq!(cmp(&ivec2::from([-1, 0]), &ivec2::from([0, 0])), Ordering::Less);
+    assert_eq!(cmp(&ivec2::from([i32::MIN, i32::MIN]), &ivec2::from([i32::MAX, i32::MAX])), Ordering::Less);
+    assert_eq!(cmp(&ivec2::from([5, -5]), &ivec2::from([5, -5])), Ordering::Equal);
+    assert_eq!(ivec::<0>::cmp_morton()(&ivec::<0>::ZERO, &ivec::<0>::ZERO), Ordering::Equal);
+}
+
+proptest! {
+    #[test]
+    fn axis_sort_is_ordered(mut points in ivec3s(), axis in 0..3usize) {
+        ivec3::sort_points_by_axis(&mut points, axis);
+        prop_assert!(points.windows(2).all(|w| w[0][axis] <= w[1][axis]));
+    }
+
+    #[test]
+    fn float_axis_sort_is_ordered(mut points in fvec3s(), axis in 0..3usize) {
+        fvec3::sort_points_by_axis(&mut points, axis);
+        prop_assert!(points.windows(2).all(|w| w[0][axis].total_cmp(&w[1][axis]) != Ordering::Greater));
+    }
+
+    #[test]
+    fn distance_sort_is_ordered(mut points in ivec3s(), origin in any::<[i32; 3]>().prop_map(ivec3::from)) {
+        ivec3::sort_points_by_distance_to(&mut points, origin);
+        prop_assert!(points.windows(2).all(|w| distance_squared(w[0], origin) <= distance_squared(w[1], origin)));
+    }
+
+    #[test]
+    fn float_distance_sort_is_ordered(mut points in fvec3s(), origin in [any_f32(), any_f32(), any_f32()].prop_map(fvec3::from)) {
+        fvec3::sort_points_by_distance_to(&mut points, origin);
+
+        let distances: Vec <f32> = points.iter().map(|&p| p.distance_squared(origin)).collect();
+        let nans = distances.iter().position(|d| d.is_nan()).unwrap_or(distances.len());
+        prop_assert!(distances[nans..].iter().all(|d| d.is_nan()));
+        prop_assert!(distances[..nans].windows(2).all(|w| w[0] <= w[1]));
+    }
+
+    #[test]
+    fn morton_sort_is_ordered(mut points in prop::collection::vec(any::<[u16; 3]>().prop_map(vec::<u16, 3>::from), 0..32)) {
+        vec::<u16, 3>::sort_points_morton(&mut points);
+        prop_assert!(points.windows(2).all(|w| morton(w[0]) <= morton(w[1])));
+    }
+
+    #[test]
+    fn morton_of_signed_is_morton_of_ordered_bits(a in any::<[i8; 3]>(), b in any::<[i8; 3]>()) {
+        let unsigned = |v: [i8; 3]| vec::<u16, 3>::from(v.map(|e| (e as u8 ^ 0x80) as u16));
+        let (a, b) = (vec::<i8, 3>::from(a), vec::<i8, 3>::from(b));
+        prop_assert_eq!(vec::<i8, 3>::cmp_morton()(&a, &b), morton(unsigned(a.into_array())).cmp(&morton(unsigned(b.into_array()))));
+    }
+
+    #[test]
+    fn comparators_are_antisymmetric(a in [any_f32(), any_f32()].prop_map(vec2::from), b in [any_f32(), any_f32()].prop_map(vec2::from)) {
+        for cmp in [&vec2::cmp_by_axis(0) as &dyn Fn(&vec2, &vec2) -> Ordering, &vec2::cmp_by_axis(1), &vec2::cmp_by_distance_to(vec2::ONE)] {
+            prop_assert_eq!(cmp(&a, &b), cmp(&b, &a).reverse());
+            prop_assert_eq!(cmp(&a, &a), Ordering::Equal);
+        }
+    }
+}
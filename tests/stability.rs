@@ -0,0 +1,62 @@
+//!
+//! Checks stability markers of `WindowBuilder` options and events as described in `meta`.
+//!
+//! Requires nightly and the `window` feature, compiled to nothing otherwise.
+//!
+
+#![cfg(all(feature = "window", nightly))]
+
+extern crate rokoko;
+#[cfg(feature = "winit-interop")]
+extern crate winit;
+
+use rokoko::window::build::meta::{OPTIONS, EVENTS};
+
+#[test]
+fn unstable_options() {
+    let unstable: Vec <_> = OPTIONS.iter().filter_map(|o| o.unstable.map(|feature| (o.name, feature))).collect();
+    assert_eq!(unstable, [("minimize_on_focus_loss", "unstable-minimize-on-focus-loss")]);
+    assert!(EVENTS.iter().all(|e| e.unstable.is_none()));
+}
+
+#[test]
+fn deprecated_options() {
+    let deprecated: Vec <_> = OPTIONS.iter().filter_map(|o| o.deprecated.map(|note| (o.name, note))).collect();
+    assert_eq!(deprecated, [("size_is_logical", "use `WindowBuilder::logical_size` instead")]);
+    assert!(EVENTS.iter().all(|e| e.deprecated.is_none()));
+
+    // The replacement is a stable option itself
+    let logical_size = OPTIONS.iter().find(|o| o.name == "logical_size").unwrap();
+    assert!(logical_size.deprecated.is_none() && logical_size.unstable.is_none());
+}
+
+/// Still honored by `create` during the migration, just as the replacement is
+#[cfg(feature = "winit-interop")]
+#[test]
+#[allow(deprecated)]
+fn deprecated_setter_still_applies() {
+    use rokoko::window::Window;
+    use winit::dpi::{Size, LogicalSize};
+
+    let expected = Some(Size::Logical(LogicalSize::new(800., 600.)));
+
+    let old = Window::new()
+        .size((800., 600.))
+        .size_is_logical()
+        .apply_to_winit(winit::window::WindowBuilder::new());
+    assert_eq!(old.window.inner_size, expected);
+
+    let new = Window::new()
+        .logical_size((800., 600.))
+        .apply_to_winit(winit::window::WindowBuilder::new());
+    assert_eq!(new.window.inner_size, expected);
+}
+
+#[cfg(feature = "unstable-minimize-on-focus-loss")]
+#[test]
+fn unstable_setter_with_feature() {
+    let _builder = rokoko::window::Window::new()
+        .fullscreen()
+        .fullscreen_management()
+        .minimize_on_focus_loss();
+}
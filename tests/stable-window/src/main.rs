@@ -0,0 +1,8 @@
+use rokoko::prelude::*;
+
+fn main() {
+    Window::new()
+        .on_close(Window::close)
+        .create()
+        .unwrap()
+}
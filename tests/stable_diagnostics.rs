@@ -0,0 +1,44 @@
+//!
+//! Checks that using the `window` feature on stable fails
+//! with a single clear error instead of a wall of feature-gate ones.
+//!
+//! Requires `rustup` with a stable toolchain, skipped otherwise.
+//!
+
+use std::process::Command;
+
+#[test]
+fn window_on_stable_reports_a_single_error() {
+    let stable = Command::new("rustup")
+        .args(["run", "stable", "cargo", "--version"])
+        .output()
+        .map_or(false, |o| o.status.success());
+    if !stable {
+        eprintln!("note: no stable toolchain found via `rustup`, skipping");
+        return
+    }
+
+    let output = Command::new("rustup")
+        .args(["run", "stable", "cargo", "build", "--color", "never", "--manifest-path"])
+        .arg(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/stable-window/Cargo.toml"))
+        .arg("--target-dir")
+        .arg(concat!(env!("CARGO_TARGET_TMPDIR"), "/stable-window"))
+        // Otherwise the toolchain of this very test would be used
+        .env_remove("RUSTUP_TOOLCHAIN")
+        .env_remove("RUSTC")
+        .env_remove("CARGO")
+        .output()
+        .unwrap();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+
+    assert!(!output.status.success(), "must not compile on stable:\n{}", stderr);
+
+    let errors = stderr
+        .lines()
+        .filter(|line| line.starts_with("error") && !line.starts_with("error: could not compile"))
+        .collect::<Vec <_>>();
+    assert_eq!(errors, ["error: the `window` feature of `rokoko` requires nightly Rust."], "full output:\n{}", stderr);
+
+    // From the build script
+    assert!(stderr.contains("requires nightly Rust, install it with `rustup toolchain install nightly`"), "full output:\n{}", stderr)
+}
@@ -0,0 +1,248 @@
+//!
+//! Feeds synthetic key and character sequences to `window::textedit::TextEdit`,
+//! see the module documentation.
+//!
+//! Requires nightly and the `window` feature, compiled to nothing otherwise.
+//!
+
+#![cfg(all(feature = "window", nightly))]
+
+extern crate rokoko;
+
+use rokoko::window::textedit::{TextEdit, Clipboard};
+use rokoko::window::input_map::{Key, Modifiers};
+
+/// A clipboard that may be empty, unlike `String`
+#[derive(Default)]
+struct Slot(Option <String>);
+
+impl Clipboard for Slot {
+    fn get(&mut self) -> Option <String> {
+        self.0.clone()
+    }
+
+    fn set(&mut self, text: String) {
+        self.0 = Some(text)
+    }
+}
+
+/// A step of a sequence
+#[derive(Copy, Clone)]
+enum Input {
+    K(Key),
+    Shift(Key),
+    Ctrl(Key),
+    C(char),
+    S(&'static str)
+}
+
+use Input::*;
+
+/// The state after a sequence
+struct State {
+    text: String,
+    edit: TextEdit,
+    clipboard: String,
+    changes: Vec <bool>
+}
+
+impl State {
+    /// Renders the text with the cursor as `|` and the selection in brackets
+    fn render(&self) -> String {
+        let selection = self.edit.selection();
+        let mut rendered = String::new();
+        for (i, c) in self.text.chars().chain(Some('\0')).enumerate() {
+            if i == selection.start && !selection.is_empty() {
+                rendered.push('[')
+            }
+            if i == self.edit.cursor() {
+                rendered.push('|')
+            }
+            if i == selection.end && !selection.is_empty() {
+                rendered.push(']')
+            }
+            if c != '\0' {
+                rendered.push(c)
+            }
+        }
+        rendered.replace("[|", "|[").replace("|]", "]|")
+    }
+}
+
+fn run(text: &str, cursor: usize, inputs: &[Input]) -> State {
+    let mut state = State { text: String::from(text), edit: TextEdit::new(), clipboard: String::from("clip"), changes: Vec::new() };
+    state.edit.set_cursor(&state.text, cursor);
+
+    for input in inputs {
+        let changed = match *input {
+            K(key) => state.edit.key(&mut state.text, key, Modifiers::NONE, &mut state.clipboard),
+            Shift(key) => state.edit.key(&mut state.text, key, Modifiers::SHIFT, &mut state.clipboard),
+            Ctrl(key) => state.edit.key(&mut state.text, key, Modifiers::CTRL, &mut state.clipboard),
+            C(c) => state.edit.char(&mut state.text, c),
+            S(s) => state.edit.insert(&mut state.text, s)
+        };
+        state.changes.push(changed)
+    }
+    state
+}
+
+/// Runs `inputs` on `text` with the cursor at `cursor` and checks the rendering
+fn check(text: &str, cursor: usize, inputs: &[Input], expected: &str) {
+    assert_eq!(run(text, cursor, inputs).render(), expected, "{text:?} at {cursor}")
+}
+
+#[test]
+fn movement() {
+    check("abc", 0, &[K(Key::Right)], "a|bc");
+    check("abc", 0, &[K(Key::Left)], "|abc");
+    check("abc", 3, &[K(Key::Right)], "abc|");
+    check("abc", 3, &[K(Key::Left), K(Key::Left)], "a|bc");
+    check("abc", 1, &[K(Key::End)], "abc|");
+    check("abc", 2, &[K(Key::Home)], "|abc");
+    check("", 0, &[K(Key::Left), K(Key::Right), K(Key::Home), K(Key::End)], "|");
+}
+
+#[test]
+fn selection() {
+    check("abcd", 1, &[Shift(Key::Right), Shift(Key::Right)], "a[bc]|d");
+    check("abcd", 3, &[Shift(Key::Left), Shift(Key::Left)], "a|[bc]d");
+    check("abcd", 1, &[Shift(Key::Right), Shift(Key::Left), Shift(Key::Left)], "|[a]bcd");
+    check("abcd", 2, &[Shift(Key::End)], "ab[cd]|");
+    check("abcd", 2, &[Shift(Key::Home)], "|[ab]cd");
+    check("abcd", 2, &[Shift(Key::Home), Shift(Key::End)], "ab[cd]|");
+    check("abcd", 4, &[Shift(Key::Right)], "abcd|");
+    check("abcd", 0, &[Ctrl(Key::A)], "[abcd]|");
+}
+
+#[test]
+fn moving_collapses_selection() {
+    // To the side of the selection, not past it
+    check("abcd", 1, &[Shift(Key::Right), Shift(Key::Right), K(Key::Left)], "a|bcd");
+    check("abcd", 3, &[Shift(Key::Left), Shift(Key::Left), K(Key::Right)], "abc|d");
+    check("abcd", 1, &[Shift(Key::Right), K(Key::Right)], "ab|cd");
+    check("abcd", 2, &[Ctrl(Key::A), K(Key::Home)], "|abcd");
+    check("abcd", 2, &[Ctrl(Key::A), K(Key::End)], "abcd|");
+}
+
+#[test]
+fn deletion() {
+    check("abc", 2, &[K(Key::Back)], "a|c");
+    check("abc", 1, &[K(Key::Delete)], "a|c");
+    check("abc", 0, &[K(Key::Back)], "|abc");
+    check("abc", 3, &[K(Key::Delete)], "abc|");
+    check("abcd", 1, &[Shift(Key::Right), Shift(Key::Right), K(Key::Back)], "a|d");
+    check("abcd", 3, &[Shift(Key::Left), Shift(Key::Left), K(Key::Delete)], "a|d");
+    check("abc", 0, &[Ctrl(Key::A), K(Key::Back)], "|");
+    check("abc", 3, &[K(Key::Back), K(Key::Back), K(Key::Back), K(Key::Back)], "|");
+}
+
+#[test]
+fn typing() {
+    check("", 0, &[C('h'), C('i')], "hi|");
+    check("ac", 1, &[C('b')], "ab|c");
+    check("abcd", 1, &[Shift(Key::Right), Shift(Key::Right), C('x')], "ax|d");
+    check("ab", 1, &[S("xyz")], "axyz|b");
+    check("ab", 0, &[Ctrl(Key::A), S("new")], "new|");
+}
+
+#[test]
+fn control_characters_are_ignored() {
+    // As reported alongside `Back`, `Return`, `Tab` and `Escape`
+    for c in ['\u{8}', '\r', '\n', '\t', '\u{1b}', '\u{7f}'] {
+        let state = run("ab", 1, &[C(c)]);
+        assert_eq!((state.render().as_str(), state.changes.as_slice()), ("a|b", &[false][..]), "{c:?}")
+    }
+    check("ab", 2, &[S("c\nd\te")], "abcde|");
+    // Typed over a selection, it does not remove it
+    check("ab", 0, &[Ctrl(Key::A), C('\u{1}')], "[ab]|");
+}
+
+#[test]
+fn clipboard() {
+    let copied = run("hello", 1, &[Shift(Key::Right), Shift(Key::Right), Ctrl(Key::C)]);
+    assert_eq!((copied.render().as_str(), copied.clipboard.as_str()), ("h[el]|lo", "el"));
+
+    let cut = run("hello", 1, &[Shift(Key::Right), Shift(Key::Right), Ctrl(Key::X)]);
+    assert_eq!((cut.render().as_str(), cut.clipboard.as_str()), ("h|lo", "el"));
+
+    let moved = run("hello", 1, &[Shift(Key::Right), Shift(Key::Right), Ctrl(Key::X), K(Key::End), Ctrl(Key::V)]);
+    assert_eq!(moved.render(), "hloel|");
+
+    // Nothing selected keeps the clipboard
+    let empty = run("hello", 1, &[Ctrl(Key::C), Ctrl(Key::X)]);
+    assert_eq!((empty.render().as_str(), empty.clipboard.as_str()), ("h|ello", "clip"));
+
+    check("ab", 1, &[Ctrl(Key::V)], "aclip|b");
+    check("ab", 0, &[Ctrl(Key::A), Ctrl(Key::V)], "clip|");
+}
+
+#[test]
+fn shortcuts_need_ctrl_or_logo() {
+    let mut text = String::from("ab");
+    let mut edit = TextEdit::new();
+    let mut clipboard = String::from("x");
+
+    for modifiers in [Modifiers::NONE, Modifiers::SHIFT, Modifiers::ALT] {
+        assert!(!edit.key(&mut text, Key::V, modifiers, &mut clipboard));
+    }
+    assert!(edit.key(&mut text, Key::V, Modifiers::LOGO, &mut clipboard));
+    assert!(edit.key(&mut text, Key::V, Modifiers::CTRL | Modifiers::SHIFT, &mut clipboard));
+    assert_eq!(text, "xxab");
+
+    // Keys without an action
+    for key in [Key::Up, Key::Down, Key::Tab, Key::F1, Key::A] {
+        assert!(!edit.key(&mut text, key, Modifiers::NONE, &mut clipboard));
+    }
+    assert_eq!((text.as_str(), edit.cursor()), ("xxab", 2));
+}
+
+#[test]
+fn empty_clipboard_pastes_nothing() {
+    let mut text = String::from("ab");
+    let mut edit = TextEdit::new();
+    edit.select_all(&text);
+
+    assert!(!edit.key(&mut text, Key::V, Modifiers::CTRL, &mut Slot::default()));
+    assert_eq!((text.as_str(), edit.selection()), ("ab", 0..2));
+
+    let mut slot = Slot::default();
+    assert!(edit.key(&mut text, Key::X, Modifiers::CTRL, &mut slot));
+    assert_eq!(slot.0.as_deref(), Some("ab"));
+}
+
+#[test]
+fn changes_are_reported() {
+    let state = run("ab", 1, &[K(Key::Right), C('c'), K(Key::Home), K(Key::Back), K(Key::Delete), Shift(Key::End), Ctrl(Key::C), Ctrl(Key::X), S("")]);
+    assert_eq!(state.changes, [false, true, false, false, true, false, false, true, false]);
+    assert_eq!(state.render(), "|");
+}
+
+#[test]
+fn chars_not_bytes() {
+    check("añb", 3, &[K(Key::Left), K(Key::Back)], "a|b");
+    check("日本語", 0, &[K(Key::Right), Shift(Key::Right), C('x')], "日x|語");
+    check("🦀", 1, &[K(Key::Back), C('é')], "é|");
+
+    // A combining accent is a char of its own
+    check("e\u{301}", 2, &[K(Key::Back)], "e|");
+
+    let state = run("añb", 1, &[Shift(Key::Right)]);
+    assert_eq!(state.edit.selected(&state.text), "ñ");
+}
+
+#[test]
+fn text_changed_elsewhere() {
+    let mut text = String::from("abcdef");
+    let mut edit = TextEdit::new();
+    edit.set_cursor(&text, 5);
+    edit.key(&mut text, Key::Home, Modifiers::SHIFT, &mut String::new());
+
+    // Shortened behind its back, the state is clamped before use
+    text.truncate(2);
+    assert_eq!(edit.selected(&text), "ab");
+    assert!(edit.char(&mut text, 'x'));
+    assert_eq!((text.as_str(), edit.cursor()), ("x", 1));
+
+    edit.set_cursor(&text, 10);
+    assert_eq!(edit.cursor(), 1);
+}
@@ -0,0 +1,110 @@
+//!
+//! Checks `WindowBuilder::data` and the way its values are moved into the window,
+//! see `window::build::user_data`.
+//!
+//! Only builds(but does not create) windows, as there may be no display.
+//!
+//! Requires nightly and the `window` feature, compiled to nothing otherwise.
+//!
+
+#![cfg(all(feature = "window", nightly))]
+
+extern crate rokoko;
+
+use rokoko::window::{
+    Window,
+    build::{
+        Maximized, WindowBuilder,
+        getters::{HasData, has_data},
+        type_list::{With, Empty},
+        user_data::{UserData, UserDataStore, CollectUserData}
+    }
+};
+
+#[derive(Debug, PartialEq)]
+struct Counter(u32);
+
+#[test]
+fn collecting() {
+    let mut list = With {
+        data: UserData::new(Counter(2)),
+        next: With {
+            data: Maximized,
+            next: With {
+                data: UserData::new("scene"),
+                next: With { data: UserData::new(Counter(1)), next: Empty }
+            }
+        }
+    };
+
+    let mut store = UserDataStore::default();
+    list.collect(&mut store);
+
+    // The very last one wins
+    assert_eq!(*store.get::<Counter>().unwrap(), Counter(2));
+    assert_eq!(*store.get::<&str>().unwrap(), "scene");
+    assert!(store.get::<Maximized>().is_none());
+
+    // Moved out only once
+    let mut again = UserDataStore::default();
+    list.collect(&mut again);
+    assert!(again.get::<Counter>().is_none());
+}
+
+#[test]
+fn shared_between_borrows() {
+    let mut store = UserDataStore::default();
+    store.push(Counter(0));
+
+    for _ in 0..3 {
+        store.get::<Counter>().unwrap().0 += 1
+    }
+    assert_eq!(*store.get::<Counter>().unwrap(), Counter(3));
+
+    // Different types are borrowed independently
+    store.push(vec![1, 2, 3]);
+    let counter = store.get::<Counter>().unwrap();
+    let mut numbers = store.get::<Vec <i32>>().unwrap();
+    numbers.push(counter.0 as i32);
+    assert_eq!(*numbers, [1, 2, 3, 3]);
+}
+
+#[test]
+fn borrowed_twice() {
+    let mut store = UserDataStore::default();
+    store.push(Counter(0));
+
+    let first = store.get::<Counter>();
+    assert!(first.is_some());
+    // Not a panic, as a callback cannot know what its caller borrows
+    assert!(store.get::<Counter>().is_none());
+
+    drop(first);
+    assert!(store.get::<Counter>().is_some());
+}
+
+fn counts <C: HasData <UserData <Counter>>> (_: &WindowBuilder <C>) -> bool {
+    has_data::<UserData <Counter>, C>()
+}
+
+#[test]
+fn in_compile_time() {
+    assert!(!counts(&Window::new()));
+    assert!(!counts(&Window::new().data(0u32)));
+    assert!(counts(&Window::new().maximized().data(Counter(0)).title("counted")));
+}
+
+#[test]
+fn from_callbacks() {
+    let _builder = Window::new()
+        .data(Counter(0))
+        .on_init(|w| w.user_data::<Counter>().unwrap().0 = 1)
+        .on_close(|w| {
+            let mut counter = w.user_data::<Counter>().unwrap();
+            counter.0 += 1;
+            if counter.0 > 3 {
+                w.close()
+            }
+        })
+        .on_exit(|w| assert!(w.user_data::<String>().is_none()));
+}
@@ -0,0 +1,107 @@
+//!
+//! Checks the `try_` setters of `WindowBuilder` against their plain counterparts
+//! and `WindowBuilder::validate`.
+//!
+//! Requires nightly and the `window` feature, compiled to nothing otherwise.
+//!
+
+#![cfg(all(feature = "window", nightly))]
+
+extern crate rokoko;
+#[cfg(feature = "winit-interop")]
+extern crate winit;
+
+use rokoko::window::{Window, build::InvalidValue};
+
+const NAN_SIZE: InvalidValue = InvalidValue {
+    field: "size",
+    reason: "must be finite and non-negative"
+};
+
+// The plain setters stay usable in `const` contexts, only titles are checked there,
+// see the `compile_fail` examples of `WindowBuilder::title`.
+// Not with `dyn-callbacks`, whose table of callbacks cannot be dropped there
+#[cfg(not(feature = "dyn-callbacks"))]
+const _: () = {
+    let _ = Window::new().title("const").size((f32::NAN, 1.));
+    let _ = Window::new().title("Ünïcödé, 日本語 and spaces\u{a0}");
+};
+
+// And so are the `try_` ones, checking at compile time
+#[cfg(not(feature = "dyn-callbacks"))]
+const CONST_OK: bool = Window::new().try_size((800., 600.)).is_ok();
+#[cfg(not(feature = "dyn-callbacks"))]
+const CONST_ERR: bool = Window::new().try_title("").is_err();
+
+#[test]
+#[cfg(not(feature = "dyn-callbacks"))]
+fn const_contexts() {
+    assert!(CONST_OK);
+    assert!(CONST_ERR);
+}
+
+#[test]
+fn try_size_rejects_at_once() {
+    for size in [(f32::NAN, 1.), (1., f32::NAN), (f32::INFINITY, 1.), (1., -1.)] {
+        assert_eq!(Window::new().try_size(size).err(), Some(NAN_SIZE), "{size:?}");
+    }
+
+    for size in [(0., 0.), (800., 600.), (f32::MAX, 1.)] {
+        assert!(Window::new().try_size(size).is_ok(), "{size:?}");
+    }
+}
+
+#[test]
+fn plain_setter_defers_to_validate() {
+    let builder = Window::new().size((f32::NAN, 600.));
+    assert_eq!(builder.validate(), Err(NAN_SIZE));
+
+    let builder = Window::new().try_size((800., 600.)).unwrap().title("ok");
+    assert_eq!(builder.validate(), Ok(()));
+}
+
+#[test]
+fn validate_checks_in_declaration_order() {
+    let builder = Window::new().size((f32::NAN, 600.)).title("");
+    assert_eq!(builder.validate().unwrap_err().field, "title");
+    assert_eq!(Window::new().validate(), Ok(()));
+}
+
+#[test]
+fn runtime_titles_defer_to_validate() {
+    let control = InvalidValue {
+        field: "title",
+        reason: "must not contain control characters"
+    };
+
+    for title in ["two\nlines", "tab\there", "\u{7f}", "c1\u{85}", "\r"] {
+        let title = String::from(title);
+        assert_eq!(Window::new().title(title.as_str()).validate(), Err(control), "{title:?}");
+        assert_eq!(Window::new().try_title(title.as_str()).err(), Some(control), "{title:?}");
+    }
+
+    let empty = String::new();
+    assert_eq!(Window::new().title(empty.as_str()).validate().unwrap_err().reason, "must not be empty");
+
+    for title in ["plain", "Ünïcödé", "日本語", "nbsp\u{a0}"] {
+        let title = String::from(title);
+        assert_eq!(Window::new().title(title.as_str()).validate(), Ok(()), "{title:?}");
+    }
+}
+
+#[test]
+fn error_message() {
+    assert_eq!(NAN_SIZE.to_string(), "invalid `size`: must be finite and non-negative");
+}
+
+// Checked before the data is applied, so not a panic of the conversion instead
+#[test]
+#[cfg(feature = "winit-interop")]
+#[should_panic(expected = "invalid `icon`: must have exactly `4 * width * height` bytes of RGBA")]
+fn invalid_icon_panics_with_the_reason() {
+    use rokoko::math::vec::uvec2;
+
+    let _ = Window::new()
+        .icon((&[0u8; 3][..], uvec2::from([2, 2])))
+        .apply_to_winit(winit::window::WindowBuilder::new());
+}
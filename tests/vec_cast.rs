@@ -0,0 +1,67 @@
+//!
+//! Checks checked and saturating conversions of vec elements, see `math::vec::cast`.
+//!
+
+extern crate rokoko;
+
+use rokoko::prelude::*;
+use rokoko::math::vec::CastError;
+
+#[test]
+fn exact_values_pass_through() {
+    let v = vec::<i64, 4>::from([i32::MIN as i64, -1, 0, i32::MAX as i64]);
+    assert_eq!(v.try_cast::<i32>(), Ok(ivec4::from([i32::MIN, -1, 0, i32::MAX])));
+    assert_eq!(v.cast_saturating::<i32>(), ivec4::from([i32::MIN, -1, 0, i32::MAX]));
+
+    let u = uvec3::from([0, 255, 7]);
+    assert_eq!(u.try_cast::<u8>().unwrap(), vec::<u8, 3>::from([0, 255, 7]));
+
+    // Widening never fails
+    assert_eq!(vec::<u8, 2>::from([0, 255]).try_cast::<i16>(), Ok(vec::<i16, 2>::from([0, 255])));
+}
+
+#[test]
+fn first_lane_out_of_range() {
+    let error = uvec4::from([1, 2, u32::MAX, u32::MAX - 1]).try_cast::<i32>().unwrap_err();
+    assert_eq!(error.index(), 2);
+    assert_eq!(error.value(), "4294967295");
+    assert_eq!(error.target(), "i32");
+    assert_eq!(error.to_string(), "component 2: 4294967295 out of range for i32");
+
+    for lane in 0..4 {
+        let mut v = ivec4::single(1);
+        v[lane] = -1;
+        assert_eq!(v.try_cast::<u32>().unwrap_err().index(), lane);
+    }
+
+    // Signed to unsigned of the same width
+    let error: CastError = vec::<i8, 2>::from([5, -128]).try_cast::<u8>().unwrap_err();
+    assert_eq!(error.to_string(), "component 1: -128 out of range for u8");
+}
+
+#[test]
+fn long_values_are_truncated() {
+    // 39 digits and a sign do not fit the 40 bytes kept
+    let error = vec::<i128, 1>::from([i128::MIN]).try_cast::<i8>().unwrap_err();
+    assert_eq!(error.value(), "-170141183460469231731687303715884105728");
+    assert_eq!(format!("{error:?}"), r#"CastError { index: 0, value: "-170141183460469231731687303715884105728", target: "i8" }"#);
+
+    let error = vec::<u128, 1>::from([u128::MAX]).try_cast::<u64>().unwrap_err();
+    assert_eq!(error.value(), u128::MAX.to_string());
+}
+
+#[test]
+fn saturating_integers() {
+    assert_eq!(uvec3::from([0, 1 << 31, u32::MAX]).cast_saturating::<i32>(), ivec3::from([0, i32::MAX, i32::MAX]));
+    assert_eq!(ivec3::from([i32::MIN, -1, 300]).cast_saturating::<u8>(), vec::<u8, 3>::from([0, 0, 255]));
+    assert_eq!(ivec2::from([i32::MIN, i32::MAX]).cast_saturating::<i8>(), vec::<i8, 2>::from([i8::MIN, i8::MAX]));
+    assert_eq!(vec::<u64, 2>::from([u64::MAX, 3]).cast_saturating::<i64>(), vec::<i64, 2>::from([i64::MAX, 3]));
+}
+
+#[test]
+fn saturating_floats() {
+    assert_eq!(vec4::from([-0.5, -1e30, 0.0, -0.0]).cast_saturating::<u8>(), vec::<u8, 4>::from([0, 0, 0, 0]));
+    assert_eq!(vec3::from([f32::NAN, f32::INFINITY, f32::NEG_INFINITY]).cast_saturating::<i16>(), vec::<i16, 3>::from([0, i16::MAX, i16::MIN]));
+    assert_eq!(vec2::from([2.9, -2.9]).cast_saturating::<i32>(), ivec2::from([2, -2]));
+    assert_eq!(vec::<f64, 2>::from([1e300, -1e300]).cast_saturating::<u32>(), uvec2::from([u32::MAX, 0]));
+}
@@ -0,0 +1,87 @@
+//!
+//! Checks sharing a vec between callbacks, see `math::vec::CellVec`.
+//!
+
+extern crate rokoko;
+
+use rokoko::prelude::*;
+use rokoko::math::vec::CellVec;
+use std::rc::Rc;
+
+#[test]
+fn closures_see_each_other() {
+    let shared = Rc::new(CellVec::new(vec2::ZERO));
+
+    let a = {
+        let shared = shared.clone();
+        move || shared.update(|v| v + vec2::from([1.0, 0.0]))
+    };
+    let b = {
+        let shared = shared.clone();
+        move || {
+            shared.set_component(1, shared.get_component(1) + 2.0);
+            shared.get()
+        }
+    };
+
+    assert_eq!(a(), vec2::from([1.0, 0.0]));
+    assert_eq!(b(), vec2::from([1.0, 2.0]));
+    assert_eq!(a(), vec2::from([2.0, 2.0]));
+    assert_eq!(shared.get(), vec2::from([2.0, 2.0]));
+    assert_eq!(Rc::strong_count(&shared), 3);
+}
+
+#[test]
+fn reentrant_callbacks() {
+    let shared = Rc::new(CellVec::new(ivec3::from([1, 2, 3])));
+
+    let inner = {
+        let shared = shared.clone();
+        move || {
+            shared.set_component(0, 100);
+            shared.get()
+        }
+    };
+    let outer = {
+        let shared = shared.clone();
+        move || shared.update(|v| {
+            // Would be a `BorrowMutError` with a `RefCell`
+            let seen = inner();
+            assert_eq!(seen, ivec3::from([100, 2, 3]));
+            assert_eq!(v, ivec3::from([1, 2, 3]));
+            v * 2
+        })
+    };
+
+    // The outer update wins, as documented
+    assert_eq!(outer(), ivec3::from([2, 4, 6]));
+    assert_eq!(shared.get(), ivec3::from([2, 4, 6]));
+
+    // Reading and replacing while updating
+    let outer = {
+        let shared = shared.clone();
+        move || shared.update(|v| {
+            assert_eq!(shared.replace(ivec3::ZERO), v);
+            shared.get() + ivec3::single(1)
+        })
+    };
+    assert_eq!(outer(), ivec3::single(1));
+}
+
+#[test]
+fn conversions() {
+    let cell = CellVec::from(vec3::from([1.0, 2.0, 3.0]));
+    let copy = cell.clone();
+    cell.set(vec3::ZERO);
+
+    assert_eq!(copy.get(), vec3::from([1.0, 2.0, 3.0]));
+    assert_eq!(CellVec::<f32, 3>::default().get(), vec3::ZERO);
+    assert_eq!(format!("{:?}", copy), format!("CellVec({:?})", copy.get()));
+    assert_eq!(cell.into_inner(), vec3::ZERO);
+}
+
+#[test]
+#[should_panic]
+fn component_out_of_bounds() {
+    CellVec::new(vec2::ZERO).set_component(2, 1.0)
+}
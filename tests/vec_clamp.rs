@@ -0,0 +1,89 @@
+//!
+//! Checks componentwise least, greatest, clamped and absolute values,
+//! see `math::vec::clamp`.
+//!
+//! Their constness is checked in `const_clamp`.
+//!
+
+extern crate rokoko;
+
+use rokoko::prelude::*;
+
+#[test]
+fn min_and_max() {
+    let a = ivec4::from([1, -5, 3, 0]);
+    let b = ivec4::from([2, -7, 3, -1]);
+
+    assert_eq!(a.min(b), ivec4::from([1, -7, 3, -1]));
+    assert_eq!(a.max(b), ivec4::from([2, -5, 3, 0]));
+
+    // Commutative for totally ordered elements
+    assert_eq!(a.min(b), b.min(a));
+    assert_eq!(a.max(b), b.max(a));
+    assert_eq!(a.min(a), a);
+
+    assert_eq!(uvec2::from([640, 480]).max(uvec2::from([800, 200])), uvec2::from([800, 480]));
+    assert_eq!(dvec3::from([0.5, -0.5, 2.0]).min(dvec3::ZERO), dvec3::from([0.0, -0.5, 0.0]));
+}
+
+#[test]
+fn nan_of_self_stays() {
+    let nan = fvec2::from([f32::NAN, 1.0]);
+
+    assert!(nan.min(fvec2::ZERO)[0].is_nan());
+    assert!(nan.max(fvec2::ZERO)[0].is_nan());
+    assert_eq!(fvec2::ZERO.min(nan), fvec2::from([0.0, 0.0]));
+    assert_eq!(fvec2::ZERO.max(nan), fvec2::from([0.0, 1.0]));
+
+    assert!(nan.clamp_scalar(-1.0, 1.0)[0].is_nan());
+    assert!(nan.abs()[0].is_nan());
+}
+
+#[test]
+fn clamp() {
+    let lo = ivec3::from([0, -1, 5]);
+    let hi = ivec3::from([10, 1, 5]);
+
+    assert_eq!(ivec3::from([-3, 0, 0]).clamp(lo, hi), ivec3::from([0, 0, 5]));
+    assert_eq!(ivec3::from([11, -2, 9]).clamp(lo, hi), ivec3::from([10, -1, 5]));
+
+    // Within the range, and on its bounds
+    assert_eq!(ivec3::from([4, 1, 5]).clamp(lo, hi), ivec3::from([4, 1, 5]));
+    assert_eq!(lo.clamp(lo, hi), lo);
+    assert_eq!(hi.clamp(lo, hi), hi);
+
+    assert_eq!(fvec4::from([-0.5, 0.25, 1.0, 7.0]).clamp_scalar(0.0, 1.0), fvec4::from([0.0, 0.25, 1.0, 1.0]));
+    assert_eq!(ivec2::from([-9, 9]).clamp_scalar(3, 3), ivec2::single(3));
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "every element of `lo` must be less than or equal to the one of `hi`")]
+fn clamp_to_inverted_range() {
+    ivec2::ZERO.clamp(ivec2::from([0, 2]), ivec2::from([1, 1]));
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "every element of `lo` must be less than or equal to the one of `hi`")]
+fn clamp_scalar_to_inverted_range() {
+    fvec2::ZERO.clamp_scalar(1.0, -1.0);
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "every element of `lo` must be less than or equal to the one of `hi`")]
+fn clamp_to_nan() {
+    fvec2::ZERO.clamp_scalar(f32::NAN, 1.0);
+}
+
+#[test]
+fn abs() {
+    assert_eq!(ivec4::from([-3, 0, 3, -1]).abs(), ivec4::from([3, 0, 3, 1]));
+    assert_eq!(vec::<i8, 2>::from([-127, 127]).abs(), vec::<i8, 2>::from([127, 127]));
+    assert_eq!(dvec2::from([-2.5, f64::NEG_INFINITY]).abs(), dvec2::from([2.5, f64::INFINITY]));
+
+    // The sign of zero is cleared too
+    assert_eq!(fvec2::from([-0.0, 0.0]).abs().into_array().map(f32::to_bits), [0, 0]);
+    assert_eq!(dvec1::from([-0.0]).abs()[0].to_bits(), 0);
+}
@@ -0,0 +1,67 @@
+//!
+//! Checks cross products of `vec3`s and perp dot products of `vec2`s, see `math::vec::linear`.
+//!
+
+extern crate rokoko;
+
+use rokoko::prelude::*;
+
+#[test]
+fn basis_identities() {
+    assert_eq!(ivec3::UNIT_X.cross(ivec3::UNIT_Y), ivec3::UNIT_Z);
+    assert_eq!(ivec3::UNIT_Y.cross(ivec3::UNIT_Z), ivec3::UNIT_X);
+    assert_eq!(ivec3::UNIT_Z.cross(ivec3::UNIT_X), ivec3::UNIT_Y);
+
+    assert_eq!(fvec3::UNIT_X.cross(fvec3::UNIT_Y), fvec3::UNIT_Z);
+    assert_eq!(fvec3::UNIT_Y.cross(fvec3::UNIT_Z), fvec3::UNIT_X);
+    assert_eq!(fvec3::UNIT_Z.cross(fvec3::UNIT_X), fvec3::UNIT_Y);
+}
+
+#[test]
+fn anti_commutative() {
+    let ints = [
+        ivec3::from([1, 2, 3]),
+        ivec3::from([-4, 0, 7]),
+        ivec3::from([10, -10, 1]),
+        ivec3::ZERO
+    ];
+    for a in ints {
+        for b in ints {
+            assert_eq!(a.cross(b), -(b.cross(a)), "{:?} x {:?}", a, b);
+            // Perpendicular to both
+            assert_eq!(a.cross(b).dot(a), 0);
+            assert_eq!(a.cross(b).dot(b), 0)
+        }
+        assert_eq!(a.cross(a), ivec3::ZERO)
+    }
+
+    let floats = [
+        fvec3::from([0.5, -1.5, 2.0]),
+        fvec3::from([3.0, 0.25, -1.0]),
+        fvec3::from([-2.0, 4.0, 8.0])
+    ];
+    for a in floats {
+        for b in floats {
+            assert_eq!(a.cross(b), -(b.cross(a)), "{:?} x {:?}", a, b)
+        }
+    }
+}
+
+#[test]
+fn unsigned_elements() {
+    // Only `Mul` and `Sub` are needed, as long as nothing underflows
+    assert_eq!(uvec3::from([0, 0, 2]).cross(uvec3::from([0, 0, 3])), uvec3::ZERO);
+    assert_eq!(uvec3::from([2, 1, 0]).cross(uvec3::from([1, 1, 0])), uvec3::from([0, 0, 1]));
+}
+
+#[test]
+fn perp_dot_is_signed_area() {
+    assert_eq!(ivec2::from([3, 0]).perp_dot(ivec2::from([0, 2])), 6);
+    assert_eq!(ivec2::from([0, 2]).perp_dot(ivec2::from([3, 0])), -6);
+    assert_eq!(ivec2::from([2, 3]).perp_dot(ivec2::from([4, 6])), 0);
+
+    // The `z` of the cross product of the vecs extended with zero `z`
+    let (a, b) = (fvec2::from([1.5, -2.0]), fvec2::from([0.5, 4.0]));
+    let cross = fvec3::from([a[0], a[1], 0.0]).cross(fvec3::from([b[0], b[1], 0.0]));
+    assert_eq!(cross, fvec3::from([0.0, 0.0, a.perp_dot(b)]));
+}
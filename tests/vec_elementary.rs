@@ -0,0 +1,108 @@
+//!
+//! Checks elementwise square roots, trigonometric and exponential functions
+//! against `std`, see `math::vec::elementary`.
+//!
+//! With `libm` as the backend they may be off by a rounding or so, not more.
+//!
+
+extern crate rokoko;
+
+use rokoko::prelude::*;
+
+/// Allowed distance from `std`, in units in the last place
+const MAX_ULPS: u64 = 2;
+
+/// Arguments of every sign and magnitude, with edge cases
+const ARGS: [f64; 16] = [0.0, -0.0, 1e-300, 1e-7, 0.1, 0.5, 1.0, 2.0, 3.14159, 10.0, 100.5, 1e4, -0.3, -1.0, -7.25, -700.0];
+
+/// Maps a float to an integer preserving order, so that neighbours differ by one
+fn ordered32(x: f32) -> i64 {
+    let bits = x.to_bits() as i32;
+    (if bits < 0 { i32::MIN - bits } else { bits }) as i64
+}
+
+fn ordered64(x: f64) -> i64 {
+    let bits = x.to_bits() as i64;
+    if bits < 0 { i64::MIN - bits } else { bits }
+}
+
+/// Returns how many floats are there between `a` and `b`, `0` for equal ones and for two `NaN`s
+fn ulps(a: f64, b: f64, ordered: impl Fn(f64) -> i64) -> u64 {
+    match (a.is_nan(), b.is_nan()) {
+        (true, true) => 0,
+        (false, false) if a == b => 0,
+        (false, false) => (ordered(a) - ordered(b)).unsigned_abs(),
+        _ => u64::MAX
+    }
+}
+
+/// Checks `op` on vecs of `ARGS` against `std` on every element
+fn check32(name: &str, op: impl Fn(vec4) -> vec4, std: impl Fn(f32) -> f32) {
+    for chunk in ARGS.chunks(4) {
+        let v = vec4::from([chunk[0] as f32, chunk[1] as f32, chunk[2] as f32, chunk[3] as f32]);
+        let result = op(v);
+        for i in 0..4 {
+            let (got, expected) = (result[i], std(v[i]));
+            assert!(ulps(got as f64, expected as f64, |x| ordered32(x as f32)) <= MAX_ULPS, "{}({:?}) = {:?}, expected {:?}", name, v[i], got, expected);
+        }
+    }
+}
+
+fn check64(name: &str, op: impl Fn(dvec4) -> dvec4, std: impl Fn(f64) -> f64) {
+    for chunk in ARGS.chunks(4) {
+        let v = dvec4::from([chunk[0], chunk[1], chunk[2], chunk[3]]);
+        let result = op(v);
+        for i in 0..4 {
+            let (got, expected) = (result[i], std(v[i]));
+            assert!(ulps(got, expected, ordered64) <= MAX_ULPS, "{}({:?}) = {:?}, expected {:?}", name, v[i], got, expected);
+        }
+    }
+}
+
+#[test]
+fn sqrt() {
+    // Required to be correctly rounded, so exact
+    for chunk in ARGS.chunks(4) {
+        let v = dvec4::from([chunk[0], chunk[1], chunk[2], chunk[3]]);
+        let expected = v.into_array().map(f64::sqrt);
+        assert_eq!(v.sqrt().into_array().map(f64::to_bits), expected.map(f64::to_bits));
+    }
+    check32("sqrt", vec4::sqrt, f32::sqrt);
+}
+
+#[test]
+fn trigonometric() {
+    check32("sin", vec4::sin, f32::sin);
+    check32("cos", vec4::cos, f32::cos);
+    check32("tan", vec4::tan, f32::tan);
+    check64("sin", dvec4::sin, f64::sin);
+    check64("cos", dvec4::cos, f64::cos);
+    check64("tan", dvec4::tan, f64::tan);
+}
+
+#[test]
+fn exponential() {
+    check32("exp", vec4::exp, f32::exp);
+    check32("ln", vec4::ln, f32::ln);
+    check64("exp", dvec4::exp, f64::exp);
+    check64("ln", dvec4::ln, f64::ln);
+
+    for n in [0.0, 0.5, 2.0, -1.5, 3.0] {
+        check32("powf", |v| v.powf(n as f32), |x| x.powf(n as f32));
+        check64("powf", |v| v.powf(n), |x| x.powf(n));
+    }
+}
+
+#[test]
+fn special_values() {
+    let special = dvec4::from([f64::INFINITY, f64::NEG_INFINITY, f64::NAN, -0.0]);
+
+    assert_eq!(special.exp().into_array()[..2], [f64::INFINITY, 0.0]);
+    assert!(special.sin().into_array()[..3].iter().all(|e| e.is_nan()));
+    assert_eq!(special.sqrt()[3].to_bits(), (-0.0f64).to_bits());
+    assert_eq!(special.ln()[3], f64::NEG_INFINITY);
+    assert!(special.ln()[1].is_nan());
+
+    // `x^0` is `1` for anything, `NaN` included
+    assert_eq!(special.powf(0.0), dvec4::single(1.0));
+}
@@ -0,0 +1,321 @@
+//!
+//! Checks that `vec` is generic over its elements not only on paper,
+//! with elements that are not primitives.
+//!
+//! - `Ratio` is a `Copy` number of its own, with checked arithmetic.
+//! - `Tracked` is not `Copy` and counts its clones and drops,
+//!   so a missed or extra drop of an element shows up.
+//!
+
+extern crate rokoko;
+
+use rokoko::prelude::*;
+use rokoko::math::scalar::{Scalar, SignedScalar};
+use std::{
+    cell::Cell,
+    cmp::Ordering,
+    ops::*,
+    panic
+};
+
+/// Greatest common divisor, non-negative
+fn gcd(a: i32, b: i32) -> i32 {
+    if b == 0 { a.abs() } else { gcd(b, a % b) }
+}
+
+///
+/// A fraction, always reduced and with a positive denominator,
+/// panicking on overflow instead of wrapping.
+///
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+struct Ratio(i32, i32);
+
+impl Ratio {
+    fn new(num: i32, den: i32) -> Self {
+        assert_ne!(den, 0, "zero denominator");
+        let d = gcd(num, den) * den.signum();
+        Ratio(num / d, den / d)
+    }
+}
+
+impl Default for Ratio {
+    fn default() -> Self {
+        Self::ZERO
+    }
+}
+
+impl From <i32> for Ratio {
+    fn from(x: i32) -> Self {
+        Ratio(x, 1)
+    }
+}
+
+impl Add for Ratio {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self {
+        let num = self.0.checked_mul(rhs.1).and_then(|a| rhs.0.checked_mul(self.1).and_then(|b| a.checked_add(b)));
+        Ratio::new(num.expect("overflow"), self.1.checked_mul(rhs.1).expect("overflow"))
+    }
+}
+
+impl Sub for Ratio {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self {
+        self + -rhs
+    }
+}
+
+impl Mul for Ratio {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self {
+        Ratio::new(self.0.checked_mul(rhs.0).expect("overflow"), self.1.checked_mul(rhs.1).expect("overflow"))
+    }
+}
+
+impl Div for Ratio {
+    type Output = Self;
+    fn div(self, rhs: Self) -> Self {
+        self * Ratio::new(rhs.1, rhs.0)
+    }
+}
+
+impl Neg for Ratio {
+    type Output = Self;
+    fn neg(self) -> Self {
+        Ratio(self.0.checked_neg().expect("overflow"), self.1)
+    }
+}
+
+impl PartialOrd for Ratio {
+    fn partial_cmp(&self, rhs: &Self) -> Option <Ordering> {
+        (self.0 as i64 * rhs.1 as i64).partial_cmp(&(rhs.0 as i64 * self.1 as i64))
+    }
+}
+
+impl Scalar for Ratio {
+    const ZERO: Self = Ratio(0, 1);
+    const ONE: Self = Ratio(1, 1);
+}
+
+impl SignedScalar for Ratio {}
+
+fn r(num: i32, den: i32) -> Ratio {
+    Ratio::new(num, den)
+}
+
+#[test]
+fn ratio_construction() {
+    let v = vec::from_array([r(1, 2), r(2, 4), r(-3, -1)]);
+    assert_eq!(v.into_array(), [Ratio(1, 2), Ratio(1, 2), Ratio(3, 1)]);
+    assert_eq!(vec::from([r(1, 2); 3]), vec::single(r(1, 2)));
+
+    assert_eq!(vec::<Ratio, 3>::default(), vec::ZERO);
+    assert_eq!(vec::<Ratio, 2>::UNIT_X + vec::<Ratio, 2>::UNIT_Y, vec::ONE);
+    assert_eq!(vec::<Ratio, 0>::default().into_array(), []);
+}
+
+#[test]
+fn ratio_operators() {
+    let a = vec::from_array([r(1, 2), r(1, 3)]);
+    let b = vec::from_array([r(1, 6), r(-1, 3)]);
+
+    assert_eq!(a + b, vec::from_array([r(2, 3), r(0, 1)]));
+    assert_eq!(a - b, vec::from_array([r(1, 3), r(2, 3)]));
+    assert_eq!(a * b, vec::from_array([r(1, 12), r(-1, 9)]));
+    assert_eq!(a / b, vec::from_array([r(3, 1), r(-1, 1)]));
+    assert_eq!(-a, vec::from_array([r(-1, 2), r(-1, 3)]));
+    assert_eq!(a * r(6, 1), vec::from_array([r(3, 1), r(2, 1)]));
+
+    let mut c = a;
+    c += b;
+    c -= a;
+    assert_eq!(c, b);
+    c *= r(3, 1);
+    c /= r(1, 2);
+    assert_eq!(c, vec::from_array([r(1, 1), r(-2, 1)]));
+
+    // Checked arithmetic panics through the operators as well
+    let huge = vec::<Ratio, 2>::single(r(i32::MAX, 1));
+    assert!(panic::catch_unwind(|| huge + huge).is_err());
+}
+
+#[test]
+fn ratio_apply_and_modify() {
+    let a = vec::from_array([r(1, 2), r(3, 4), r(-5, 6)]);
+
+    assert_eq!(a.apply_binary(vec::single(2), |x, n| x * Ratio::from(n)), a + a);
+    assert_eq!(a.apply_binary_single(r(1, 2), Add::add), vec::from_array([r(1, 1), r(5, 4), r(-1, 3)]));
+    assert_eq!(a.apply_unary(|x| x.0 as f64 / x.1 as f64), dvec3::from([0.5, 0.75, -5.0 / 6.0]));
+    assert_eq!(a.apply_unary(SignedScalar::abs), vec::from_array([r(1, 2), r(3, 4), r(5, 6)]));
+
+    assert!(a.apply_binary_bool(a + vec::ONE, |x, y| x < y));
+    assert!(!a.apply_unary_bool(|x| x > Ratio::ZERO));
+
+    let mut b = a;
+    b.modify_binary(a, Mul::mul);
+    b.modify_binary_single(r(4, 1), Mul::mul);
+    b.modify_unary(|x| x - Ratio::ONE);
+    assert_eq!(b, vec::from_array([r(0, 1), r(5, 4), r(16, 9)]));
+}
+
+#[test]
+fn ratio_debug() {
+    let a = vec::from_array([r(1, 2), r(3, 1)]);
+    assert_eq!(format!("{a:?}"), "vec<Ratio, 2>(Ratio(1, 2), Ratio(3, 1))");
+    assert_eq!(format!("{:?}", vec::from_array([a])), "vec<vec<Ratio, 2>, 1>(vec<Ratio, 2>(Ratio(1, 2), Ratio(3, 1)))");
+}
+
+#[test]
+fn ratio_conversions() {
+    let a = vec::from_array([r(1, 2), r(3, 4), r(5, 6), r(7, 8)]);
+
+    let array: [Ratio; 4] = a.into();
+    assert_eq!(vec::from(array), a);
+    assert_eq!(a.first::<2>(), vec::from_array([r(1, 2), r(3, 4)]));
+    assert_eq!(a.last::<1>(), vec::from_array([r(7, 8)]));
+    assert_eq!(a.segment::<1, 2>(), vec::from_array([r(3, 4), r(5, 6)]));
+
+    assert_eq!(a.hadamard(vec::single(r(2, 1))), a + a);
+    let outer = a.first::<2>().outer(vec::from_array([r(2, 1), r(-1, 1)]));
+    assert_eq!(outer[1], vec::from_array([r(3, 2), r(-3, 4)]));
+    assert_eq!(
+        vec::linear_combine(vec::from_array([r(1, 2), r(1, 2)]), [vec::<Ratio, 2>::UNIT_X, vec::<Ratio, 2>::UNIT_Y]),
+        vec::<Ratio, 2>::single(r(1, 2))
+    );
+}
+
+#[test]
+#[cfg(nightly)]
+fn ratio_new() {
+    // Not given elements are `Default`
+    assert_eq!(vec::<Ratio, 4>::new(r(1, 2), 3), vec::from_array([r(1, 2), r(3, 1), Ratio::ZERO, Ratio::ZERO]));
+}
+
+thread_local! {
+    static CLONES: Cell <usize> = Cell::new(0);
+    static DROPS: Cell <usize> = Cell::new(0);
+}
+
+/// Counts of `Tracked` clones and drops made on this thread so far
+fn counts() -> (usize, usize) {
+    (CLONES.with(Cell::get), DROPS.with(Cell::get))
+}
+
+/// A non-`Copy` element with drop glue, counting its clones and drops
+#[derive(Debug, PartialEq)]
+struct Tracked(f32);
+
+impl Clone for Tracked {
+    fn clone(&self) -> Self {
+        CLONES.with(|c| c.set(c.get() + 1));
+        Tracked(self.0)
+    }
+}
+
+impl Drop for Tracked {
+    fn drop(&mut self) {
+        DROPS.with(|c| c.set(c.get() + 1))
+    }
+}
+
+fn tracked <const N: usize> (values: [f32; N]) -> vec <Tracked, N> {
+    vec::from(values.map(Tracked))
+}
+
+/// Values of `tracked`, compared instead of `Tracked`s, which would count drops of their own
+fn values(tracked: &[Tracked]) -> Vec <f32> {
+    tracked.iter().map(|t| t.0).collect()
+}
+
+#[test]
+fn tracked_moves_without_clones_or_drops() {
+    let v = tracked([1.0, 2.0, 3.0]);
+    let array = v.into_array();
+    assert_eq!(counts(), (0, 0));
+
+    let v = vec::from_array(array);
+    let array: [Tracked; 3] = v.into();
+    assert_eq!(counts(), (0, 0));
+    assert_eq!(values(&array), [1.0, 2.0, 3.0]);
+
+    drop(array);
+    assert_eq!(counts(), (0, 3))
+}
+
+#[test]
+fn tracked_clone_and_drop_every_element_once() {
+    let v = tracked([1.0, 2.0]);
+    let w = v.clone();
+    assert_eq!(counts(), (2, 0));
+    assert_eq!(w.as_array(), v.as_array());
+
+    drop(v);
+    assert_eq!(counts(), (2, 2));
+    drop(w);
+    assert_eq!(counts(), (2, 4));
+
+    // Nested
+    let nested = vec::from_array([tracked([1.0, 2.0]), tracked([3.0, 4.0])]);
+    drop(nested.clone());
+    drop(nested);
+    assert_eq!(counts(), (6, 12))
+}
+
+#[test]
+fn tracked_access() {
+    let mut v = tracked([1.0, 2.0, 3.0]);
+    v[1] = Tracked(5.0);
+    // The replaced element
+    assert_eq!(counts(), (0, 1));
+
+    v.as_array_mut()[2].0 += 1.0;
+    assert_eq!(values(&*v), [1.0, 5.0, 4.0]);
+    assert_eq!(format!("{v:?}"), "vec<Tracked, 3>(Tracked(1.0), Tracked(5.0), Tracked(4.0))");
+    assert_eq!(format!("{:?}", tracked([0.5])), "vec<Tracked, 1>(Tracked(0.5))");
+    assert_eq!(counts(), (0, 2))
+}
+
+#[test]
+fn tracked_results_of_apply() {
+    let v = ivec3::from([1, 2, 3]).apply_unary(|x| Tracked(x as f32));
+    let w = ivec3::from([1, 2, 3]).apply_binary(vec3::single(0.5), |x, y| Tracked(x as f32 * y));
+    let u = vec3::ONE.apply_binary_single(2.0, |x, y| Tracked(x + y));
+    assert_eq!(counts(), (0, 0));
+    assert_eq!(values(&*v), [1.0, 2.0, 3.0]);
+    assert_eq!(values(&*w), [0.5, 1.0, 1.5]);
+    assert_eq!(values(&*u), [3.0, 3.0, 3.0]);
+
+    drop((v, w, u));
+    assert_eq!(counts(), (0, 9))
+}
+
+#[test]
+fn tracked_apply_interrupted_by_panic() {
+    let result = panic::catch_unwind(|| ivec4::from([1, 2, 3, 4]).apply_unary(|x| {
+        assert!(x < 3, "interrupted");
+        Tracked(x as f32)
+    }));
+    assert!(result.is_err());
+    // Neither the two finished elements nor the garbage in the rest are dropped
+    assert_eq!(counts(), (0, 0))
+}
+
+#[test]
+fn tracked_uninit() {
+    let mut uninit = vec::<Tracked, 2>::uninit();
+    // Dropping an unfilled vec drops nothing
+    drop(vec::<Tracked, 2>::uninit());
+
+    let base = uninit.as_mut_ptr() as *mut Tracked;
+    // SAFETY: both elements are written before `assume_init`
+    let v = unsafe {
+        base.write(Tracked(1.0));
+        base.add(1).write(Tracked(2.0));
+        uninit.assume_init()
+    };
+    assert_eq!(counts(), (0, 0));
+    assert_eq!(values(&*v), [1.0, 2.0]);
+
+    drop(v);
+    assert_eq!(counts(), (0, 2))
+}
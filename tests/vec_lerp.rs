@@ -0,0 +1,72 @@
+//!
+//! Checks linear interpolation and rounding, see `math::vec::shader`.
+//!
+
+extern crate rokoko;
+
+use rokoko::prelude::*;
+
+#[test]
+fn lerp_ends_are_exact() {
+    let a = vec3::from([0.1, -7.3, 1e30]);
+    let b = vec3::from([0.7, 2.9, -1e-30]);
+
+    assert_eq!(a.lerp(b, 0.0), a);
+    assert_eq!(a.lerp(b, 1.0), b);
+    assert_eq!(b.lerp(a, 0.0), b);
+    assert_eq!(b.lerp(a, 1.0), a);
+
+    let a = dvec4::from([0.1, 0.2, 0.3, -1e300]);
+    let b = dvec4::from([1.0 / 3.0, 1e-300, -0.5, 1e300]);
+    assert_eq!(a.lerp(b, 0.0), a);
+    assert_eq!(a.lerp(b, 1.0), b);
+}
+
+#[test]
+fn lerp_between_and_beyond() {
+    let a = dvec2::from([2.0, -4.0]);
+    let b = dvec2::from([4.0, 4.0]);
+
+    assert_eq!(a.lerp(b, 0.5), dvec2::from([3.0, 0.0]));
+    assert_eq!(a.lerp(b, -1.0), dvec2::from([0.0, -12.0]));
+    assert_eq!(a.lerp(a, 0.3), a);
+
+    // Any number, not only floats
+    assert_eq!(ivec2::from([1, 5]).lerp(ivec2::from([9, -5]), 1), ivec2::from([9, -5]));
+    assert_eq!(vec::<f32, 0>::ZERO.lerp(vec::ZERO, 0.5), vec::ZERO);
+}
+
+#[test]
+fn floor_and_ceil() {
+    let v = vec4::from([1.5, -1.5, 3.0, -0.0]);
+
+    assert_eq!(v.floor(), vec4::from([1.0, -2.0, 3.0, -0.0]));
+    assert_eq!(v.ceil(), vec4::from([2.0, -1.0, 3.0, -0.0]));
+    assert_eq!(dvec2::from([0.999, -0.001]).floor(), dvec2::from([0.0, -1.0]));
+    assert_eq!(dvec2::from([0.001, -0.999]).ceil(), dvec2::from([1.0, -0.0]));
+
+    // Already integral or not finite
+    let special = vec3::from([f32::INFINITY, f32::NEG_INFINITY, 1e20]);
+    assert_eq!(special.floor(), special);
+    assert_eq!(special.ceil(), special);
+    assert!(vec1::single(f32::NAN).floor()[0].is_nan());
+}
+
+#[test]
+fn round() {
+    assert_eq!(vec4::from([0.5, -0.5, 2.5, 2.49]).round(), vec4::from([1.0, -1.0, 3.0, 2.0]));
+    assert_eq!(dvec2::from([-2.51, 1e300]).round(), dvec2::from([-3.0, 1e300]));
+}
+
+#[test]
+fn fract() {
+    assert_eq!(vec4::from([2.25, -2.25, -3.0, 0.5]).fract(), vec4::from([0.25, 0.75, 0.0, 0.5]));
+    assert_eq!(dvec2::from([7.125, -0.125]).fract(), dvec2::from([0.125, 0.875]));
+
+    // Within `[0, 1]`, adding up to the element with `floor`
+    for x in [-10.3, -1.0, -0.7, 0.0, 0.3, 5.9] {
+        let v = dvec1::single(x);
+        assert!((0.0..=1.0).contains(&v.fract()[0]), "{:?}", x);
+        assert_eq!(v.floor() + v.fract(), v);
+    }
+}
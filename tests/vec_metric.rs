@@ -0,0 +1,68 @@
+//!
+//! Checks dot products, lengths, distances and normalization, see `math::vec::metric`.
+//!
+
+extern crate rokoko;
+
+use rokoko::prelude::*;
+
+#[test]
+fn integer_dot_products() {
+    assert_eq!(ivec2::from([2, -3]).dot(ivec2::from([4, 5])), -7);
+    assert_eq!(ivec4::from([1, 2, 3, 4]).dot(ivec4::from([4, 3, 2, 1])), 20);
+    assert_eq!(uvec3::from([1, 2, 3]).dot(uvec3::from([1, 2, 3])), 14);
+
+    // Orthogonal
+    assert_eq!(ivec3::UNIT_X.dot(ivec3::UNIT_Y), 0);
+
+    assert_eq!(ivec3::from([2, -3, 6]).length_squared(), 49);
+    assert_eq!(ivec3::from([2, -3, 6]).distance_squared(ivec3::ZERO), 49);
+}
+
+#[test]
+fn float_lengths() {
+    assert_eq!(fvec2::from([-6.0, 8.0]).length(), 10.0);
+    assert_eq!(fvec3::from([2.0, 3.0, 6.0]).length(), 7.0);
+    assert_eq!(fvec4::from([1.0, 1.0, 1.0, 1.0]).length(), 2.0);
+
+    assert_eq!(fvec3::from([1.0, 2.0, 3.0]).dot(fvec3::from([-1.0, 0.5, 2.0])), 6.0);
+    assert_eq!(fvec4::single(3.0).length_squared(), 36.0);
+}
+
+#[test]
+fn distances_are_symmetric() {
+    let a = fvec3::from([1.0, -2.0, 0.5]);
+    let b = fvec3::from([3.0, 4.0, -2.5]);
+
+    assert_eq!(a.distance(b), b.distance(a));
+    assert_eq!(a.distance(a), 0.0);
+    assert_eq!(fvec2::from([0.0, 0.0]).distance(fvec2::from([3.0, 4.0])), 5.0);
+}
+
+#[test]
+fn normalized_vectors_have_unit_length() {
+    let vectors = [
+        fvec4::from([1.0, -2.0, 3.0, -4.0]),
+        fvec4::from([1e-15, 0.0, 0.0, 0.0]),
+        fvec4::from([1e18, 1e18, 0.0, 0.0]),
+        fvec4::UNIT_W
+    ];
+    for v in vectors {
+        let n = v.normalize();
+        assert!((n.length() - 1.0).abs() < 1e-6, "{v:?} normalized to {n:?}");
+        // Same direction
+        assert!((n.dot(v) - v.length()).abs() <= v.length() * 1e-6, "{v:?} normalized to {n:?}");
+    }
+
+    assert_eq!(fvec2::from([0.0, -3.0]).normalize(), fvec2::from([0.0, -1.0]));
+}
+
+#[test]
+fn zero_vector_normalizes_to_nan() {
+    assert!(fvec2::ZERO.normalize().into_array().iter().all(|x| x.is_nan()));
+    assert!(fvec3::ZERO.normalize().into_array().iter().all(|x| x.is_nan()));
+
+    // Unlike the variants that decide
+    assert_eq!(fvec3::ZERO.normalize_or_zero(), fvec3::ZERO);
+    assert_eq!(fvec3::ZERO.try_normalize(0.0), None);
+}
@@ -0,0 +1,233 @@
+//!
+//! Property-based tests of `vec` operations.
+//!
+//! Every property is generated for all element types of aliases
+//! and arities from 1 to 4.
+//!
+
+extern crate proptest;
+extern crate rokoko;
+
+use proptest::prelude::*;
+use proptest::array::{uniform1, uniform2, uniform3, uniform4};
+use rokoko::prelude::*;
+
+/// Sum of products of corresponding elements
+fn dot <T: Copy + std::ops::Mul <Output = T> + std::iter::Sum, const N: usize> (a: vec <T, N>, b: vec <T, N>) -> T {
+    a.apply_binary(b, |a, b| a * b).into_array().iter().copied().sum()
+}
+
+/// Small values, so that integer arithmetic does not overflow
+macro_rules! small {
+    (i32) => { -1000i32..1000 };
+    (u32) => { 0u32..1000 };
+    (f32) => { -1000.0f32..1000.0 };
+    (f64) => { -1000.0f64..1000.0 };
+}
+
+///
+/// Properties common to all element types.
+///
+macro_rules! common {
+    ($( $m:ident: $t:ident, $n:literal, $uniform:ident; )*) => {$(
+        mod $m {
+            use super::*;
+
+            proptest! {
+                #[test]
+                fn array_round_trip(a in $uniform(any::<$t>())) {
+                    let v = vec::from_array(a);
+                    prop_assert_eq!(v.into_array(), a);
+                    prop_assert_eq!(vec::<$t, $n>::from(a).into_array(), a);
+                }
+
+                #[test]
+                fn index_deref_consistency(a in $uniform(any::<$t>())) {
+                    let v = vec::from_array(a);
+                    for i in 0..$n {
+                        prop_assert_eq!(v[i], a[i]);
+                        prop_assert_eq!(v.as_array()[i], a[i]);
+                        prop_assert_eq!((*v)[i], a[i]);
+                        prop_assert_eq!(unsafe { *v.get_unchecked(i) }, a[i]);
+                    }
+                }
+
+                #[test]
+                fn index_mut_consistency(a in $uniform(any::<$t>()), b in $uniform(any::<$t>())) {
+                    let mut v = vec::from_array(a);
+                    for i in 0..$n {
+                        v[i] = b[i];
+                    }
+                    prop_assert_eq!(v.into_array(), b);
+                }
+            }
+        }
+    )*};
+}
+
+common! {
+    bool1: bool, 1, uniform1;
+    bool2: bool, 2, uniform2;
+    bool3: bool, 3, uniform3;
+    bool4: bool, 4, uniform4;
+    i32_1: i32, 1, uniform1;
+    i32_2: i32, 2, uniform2;
+    i32_3: i32, 3, uniform3;
+    i32_4: i32, 4, uniform4;
+    u32_1: u32, 1, uniform1;
+    u32_2: u32, 2, uniform2;
+    u32_3: u32, 3, uniform3;
+    u32_4: u32, 4, uniform4;
+}
+
+///
+/// Exact algebraic laws of integer vecs.
+///
+macro_rules! integer {
+    ($( $m:ident: $t:ident, $n:literal, $uniform:ident; )*) => {$(
+        mod $m {
+            use super::*;
+
+            proptest! {
+                #[test]
+                fn add_commutative(a in $uniform(small!($t)), b in $uniform(small!($t))) {
+                    let (a, b) = (vec::from_array(a), vec::from_array(b));
+                    prop_assert_eq!(a + b, b + a);
+                }
+
+                #[test]
+                fn mul_commutative(a in $uniform(small!($t)), b in $uniform(small!($t))) {
+                    let (a, b) = (vec::from_array(a), vec::from_array(b));
+                    prop_assert_eq!(a * b, b * a);
+                }
+
+                #[test]
+                fn add_associative(a in $uniform(small!($t)), b in $uniform(small!($t)), c in $uniform(small!($t))) {
+                    let (a, b, c) = (vec::from_array(a), vec::from_array(b), vec::from_array(c));
+                    prop_assert_eq!((a + b) + c, a + (b + c));
+                }
+
+                #[test]
+                fn mul_associative(a in $uniform(small!($t)), b in $uniform(small!($t)), c in $uniform(small!($t))) {
+                    let (a, b, c) = (vec::from_array(a), vec::from_array(b), vec::from_array(c));
+                    prop_assert_eq!((a * b) * c, a * (b * c));
+                }
+
+                #[test]
+                fn scalar_mul_distributive(a in $uniform(small!($t)), b in $uniform(small!($t)), k in small!($t)) {
+                    let (a, b) = (vec::from_array(a), vec::from_array(b));
+                    prop_assert_eq!((a + b) * k, a * k + b * k);
+                }
+
+                #[test]
+                fn sub_self_is_zero(a in $uniform(any::<$t>())) {
+                    let a = vec::from_array(a);
+                    prop_assert_eq!(a - a, vec::ZERO);
+                }
+
+                #[test]
+                fn dot_distributive(a in $uniform(small!($t)), b in $uniform(small!($t)), c in $uniform(small!($t))) {
+                    let (a, b, c) = (vec::from_array(a), vec::from_array(b), vec::from_array(c));
+                    prop_assert_eq!(dot(a + b, c), dot(a, c) + dot(b, c));
+                }
+
+                #[test]
+                fn assign_ops_match(a in $uniform(small!($t)), b in $uniform(small!($t))) {
+                    let (a, b) = (vec::<$t, $n>::from_array(a), vec::from_array(b));
+                    let mut c = a;
+                    c += b;
+                    prop_assert_eq!(c, a + b);
+                    c *= b;
+                    prop_assert_eq!(c, (a + b) * b);
+                }
+            }
+        }
+    )*};
+}
+
+integer! {
+    i32_laws_1: i32, 1, uniform1;
+    i32_laws_2: i32, 2, uniform2;
+    i32_laws_3: i32, 3, uniform3;
+    i32_laws_4: i32, 4, uniform4;
+    u32_laws_1: u32, 1, uniform1;
+    u32_laws_2: u32, 2, uniform2;
+    u32_laws_3: u32, 3, uniform3;
+    u32_laws_4: u32, 4, uniform4;
+}
+
+///
+/// Laws of float vecs, within tolerance.
+///
+macro_rules! float {
+    ($( $m:ident: $t:ident, $bits:ident, $n:literal, $uniform:ident; )*) => {$(
+        mod $m {
+            use super::*;
+
+            proptest! {
+                #[test]
+                fn add_commutative(a in $uniform(small!($t)), b in $uniform(small!($t))) {
+                    let (a, b) = (vec::from_array(a), vec::from_array(b));
+                    prop_assert_eq!(a + b, b + a);
+                }
+
+                #[test]
+                fn sub_self_is_zero(a in $uniform(small!($t))) {
+                    let a = vec::from_array(a);
+                    prop_assert_eq!(a - a, vec::ZERO);
+                }
+
+                #[test]
+                fn dot_distributive(a in $uniform(small!($t)), b in $uniform(small!($t)), c in $uniform(small!($t))) {
+                    let (a, b, c) = (vec::from_array(a), vec::from_array(b), vec::from_array(c));
+                    let left = dot(a + b, c);
+                    let right = dot(a, c) + dot(b, c);
+                    prop_assert!((left - right).abs() <= 1e-3 * (1.0 + left.abs()), "{} != {}", left, right);
+                }
+
+                #[test]
+                fn nan_payload_round_trip(bits in $uniform(any::<$bits>())) {
+                    let a = bits.map($t::from_bits);
+                    let v = vec::from_array(a);
+                    prop_assert_eq!(v.into_array().map($t::to_bits), bits);
+                    prop_assert_eq!(v.apply_unary(|x| x).into_array().map($t::to_bits), bits);
+                }
+            }
+        }
+    )*};
+}
+
+float! {
+    f32_laws_1: f32, u32, 1, uniform1;
+    f32_laws_2: f32, u32, 2, uniform2;
+    f32_laws_3: f32, u32, 3, uniform3;
+    f32_laws_4: f32, u32, 4, uniform4;
+    f64_laws_1: f64, u64, 1, uniform1;
+    f64_laws_2: f64, u64, 2, uniform2;
+    f64_laws_3: f64, u64, 3, uniform3;
+    f64_laws_4: f64, u64, 4, uniform4;
+}
+
+///
+/// `new` is available on nightly only.
+///
+#[cfg(nightly)]
+mod piece {
+    use super::*;
+
+    proptest! {
+        #[test]
+        fn arrays_and_scalars(a in any::<f32>(), b in any::<f32>(), c in any::<f32>(), d in any::<f32>()) {
+            let bits = |v: fvec4| v.apply_unary(f32::to_bits);
+            prop_assert_eq!(bits(fvec4::new([a, b], [c, d])), bits(fvec4::new(a, b, c, d)));
+            prop_assert_eq!(bits(fvec4::new((a, b), [[c]], d)), bits(fvec4::new(a, b, c, d)));
+            prop_assert_eq!(bits(fvec4::new(fvec2::new(a, b), c, d)), bits(fvec4::from([a, b, c, d])));
+        }
+
+        #[test]
+        fn defaults(a in small!(i32), b in small!(i32)) {
+            prop_assert_eq!(ivec4::new(a, b), ivec4::from([a, b, 0, 0]));
+            prop_assert_eq!(ivec4::new([a], ()), ivec4::from([a, 0, 0, 0]));
+        }
+    }
+}
@@ -0,0 +1,102 @@
+//!
+//! Checks folds, sums, products, least and greatest elements and indexed maps,
+//! see `math::vec::reduce`.
+//!
+//! Their constness is checked in `const_reduce`.
+//!
+
+extern crate rokoko;
+
+use rokoko::prelude::*;
+
+#[test]
+fn fold_goes_in_order() {
+    let v = ivec4::from([1, 2, 3, 4]);
+
+    assert_eq!(v.fold(0, |a, e| a + e), v.sum());
+    assert_eq!(v.fold(1, |a, e| a * e), v.product());
+
+    // Not commutative, so the order shows
+    assert_eq!(v.fold(0, |a, e| a * 10 + e), 1234);
+    assert_eq!(v.fold(0, |a, e| e - a), 2);
+
+    // Into another type
+    assert_eq!(v.fold(String::new(), |s, e| s + &e.to_string()), "1234");
+    assert_eq!(bvec3::from([true, false, true]).fold(0, |count, e| count + e as u32), 2);
+}
+
+#[test]
+fn sums_and_products() {
+    assert_eq!(ivec3::from([-1, 5, -4]).sum(), 0);
+    assert_eq!(ivec3::from([-1, 5, -4]).product(), 20);
+    assert_eq!(uvec2::from([1920, 1080]).product(), 2_073_600);
+    assert_eq!(dvec4::single(0.25).sum(), 1.0);
+    assert_eq!(fvec3::from([2.0, 0.5, -3.0]).product(), -3.0);
+
+    assert_eq!(ivec1::from([7]).sum(), 7);
+    assert_eq!(ivec1::from([7]).product(), 7);
+}
+
+#[test]
+fn empty() {
+    let empty = ivec::<0>::ZERO;
+
+    assert_eq!(empty.sum(), 0);
+    assert_eq!(empty.product(), 1);
+    assert_eq!(empty.fold("init", |a, _| a), "init");
+    assert_eq!(empty.map_indexed(|i, e| (i, e)).into_array(), []);
+}
+
+#[test]
+#[should_panic(expected = "an empty vec has no least element")]
+fn min_of_empty() {
+    ivec::<0>::ZERO.min_element();
+}
+
+#[test]
+#[should_panic(expected = "an empty vec has no greatest element")]
+fn max_of_empty() {
+    ivec::<0>::ZERO.max_element();
+}
+
+#[test]
+fn least_and_greatest() {
+    let v = ivec4::from([2, -7, 9, -7]);
+    assert_eq!((v.min_element(), v.max_element()), (-7, 9));
+
+    // At any position
+    for i in 0..4 {
+        let one_hot = ivec4::ZERO.map_indexed(|j, _| (i == j) as i32);
+        assert_eq!((one_hot.min_element(), one_hot.max_element()), (0, 1), "{:?}", one_hot);
+    }
+
+    assert_eq!(ivec1::from([5]).min_element(), 5);
+    assert_eq!(ivec1::from([5]).max_element(), 5);
+    assert_eq!(fvec3::from([-0.5, 0.25, -1.5]).min_element(), -1.5);
+}
+
+#[test]
+fn nan_is_skipped_unless_first() {
+    let later = fvec3::from([1.0, f32::NAN, -1.0]);
+    assert_eq!((later.min_element(), later.max_element()), (-1.0, 1.0));
+
+    let first = fvec3::from([f32::NAN, 1.0, -1.0]);
+    assert!(first.min_element().is_nan());
+    assert!(first.max_element().is_nan());
+}
+
+#[test]
+fn map_indexed() {
+    let v = ivec4::from([10, 20, 30, 40]);
+
+    assert_eq!(v.map_indexed(|i, e| e * i as i32), ivec4::from([0, 20, 60, 120]));
+    assert_eq!(v.map_indexed(|_, e| e), v);
+    assert_eq!(v.map_indexed(|i, _| i), vec::<usize, 4>::from([0, 1, 2, 3]));
+
+    // Agrees with `apply_unary` when the index is ignored
+    assert_eq!(v.map_indexed(|_, e| e / 10), v.apply_unary(|e| e / 10));
+
+    // Into types with drop glue
+    let names = ivec2::from([1, 2]).map_indexed(|i, e| format!("{i}:{e}"));
+    assert_eq!(names.into_array(), [String::from("0:1"), String::from("1:2")]);
+}
@@ -0,0 +1,59 @@
+//!
+//! Checks `GLSL`-like swizzles of vecs, see `math::vec::swizzle`.
+//!
+
+extern crate rokoko;
+
+use rokoko::prelude::*;
+
+#[test]
+fn single_elements() {
+    let v = ivec4::from([1, 2, 3, 4]);
+    assert_eq!((v.x(), v.y(), v.z(), v.w()), (1, 2, 3, 4));
+
+    let v = vec2::from([0.5, -0.5]);
+    assert_eq!((v.x(), v.y()), (0.5, -0.5));
+
+    assert_eq!(ivec1::from([7]).x(), 7);
+}
+
+#[test]
+fn mutable_elements() {
+    let mut v = ivec4::ZERO;
+    *v.x_mut() = 1;
+    *v.y_mut() = 2;
+    *v.z_mut() = 3;
+    *v.w_mut() += 4;
+    assert_eq!(v, ivec4::from([1, 2, 3, 4]));
+
+    let mut v = uvec2::ZERO;
+    *v.y_mut() = 9;
+    assert_eq!(v, uvec2::from([0, 9]));
+}
+
+#[test]
+fn same_as_shuffles() {
+    let v = ivec4::from([10, 20, 30, 40]);
+
+    assert_eq!(v.xy(), v.shuffle2::<0, 1>());
+    assert_eq!(v.wx(), v.shuffle2::<3, 0>());
+    assert_eq!(v.zzy(), v.shuffle3::<2, 2, 1>());
+    assert_eq!(v.xyz(), v.shuffle3::<0, 1, 2>());
+    assert_eq!(v.wzyx(), v.shuffle4::<3, 2, 1, 0>());
+    assert_eq!(v.xyzw(), v);
+    assert_eq!(v.yyyy(), ivec4::single(20));
+}
+
+#[test]
+fn smaller_vecs_widen() {
+    let v = ivec2::from([1, 2]);
+    assert_eq!(v.yx(), ivec2::from([2, 1]));
+    assert_eq!(v.xyx(), ivec3::from([1, 2, 1]));
+    assert_eq!(v.xyxy(), ivec4::from([1, 2, 1, 2]));
+
+    let v = vec3::from([1.0, 2.0, 3.0]);
+    assert_eq!(v.xz(), vec2::from([1.0, 3.0]));
+    assert_eq!(v.zyxz(), vec4::from([3.0, 2.0, 1.0, 3.0]));
+
+    assert_eq!(ivec1::from([5]).xxxx(), ivec4::single(5));
+}
@@ -0,0 +1,72 @@
+//!
+//! Checks that the runtime setters and queries of `Window` can be called from callbacks.
+//!
+//! Only builds(but does not create) windows, as there may be no display.
+//!
+//! Requires nightly and the `window` feature, compiled to nothing otherwise.
+//!
+
+#![cfg(all(feature = "window", nightly))]
+
+extern crate rokoko;
+
+use rokoko::window::{Window, WeakWindow, Key};
+use std::cell::Cell;
+
+#[test]
+fn from_callbacks() {
+    let _builder = Window::new()
+        .on_init(|w| {
+            w.set_title("initialized");
+            w.set_size((800., 600.));
+            w.set_visible(true)
+        })
+        .on_key(|w, key, pressed| if pressed {
+            match key {
+                Key::M => w.set_maximized(true),
+                Key::H => w.set_visible(false),
+                _ => w.set_title("pressed!")
+            }
+        })
+        .on_resize(|w, size| if size[0] < 100. {
+            w.set_size([100., size[1]])
+        });
+}
+
+#[test]
+fn through_a_copied_handle() {
+    let _builder = Window::new().on_frame(|w, _dt| {
+        let handle = w;
+        handle.set_maximized(false);
+        w.set_title(&format!("{}", w.is_fullscreen()))
+    });
+}
+
+#[test]
+fn queries() {
+    let _builder = Window::new()
+        .on_resize(|w, _size| {
+            let size = w.inner_size();
+            let aspect = size[0] / size[1];
+            let _decorations = w.outer_size() - size;
+            let _dpi: f64 = w.scale_factor();
+            if let Some(position) = w.position() {
+                w.set_title(&format!("{aspect} at {position:?}"))
+            }
+        });
+}
+
+#[test]
+fn weak_handles() {
+    // As cheap to keep as a strong handle is
+    assert_eq!(core::mem::size_of::<WeakWindow>(), core::mem::size_of::<Window>());
+    assert!(core::mem::size_of::<WeakWindow>() <= 2 * core::mem::size_of::<usize>());
+
+    let stored = Cell::new(None::<WeakWindow>);
+    let _builder = Window::new()
+        .on_init(|w| stored.set(Some(w.downgrade())))
+        .on_frame(|_, _dt| match stored.get().and_then(WeakWindow::upgrade) {
+            Some(w) => w.request_redraw(),
+            None => stored.set(None)
+        });
+}
@@ -0,0 +1,76 @@
+//!
+//! Checks `window::Windows`: pushing builders of different types and merging
+//! what the windows ask the loop to do.
+//!
+//! Only builds(but does not create) windows, as there may be no display.
+//!
+//! Requires nightly and the `window` feature, compiled to nothing otherwise.
+//!
+
+#![cfg(all(feature = "window", nightly))]
+
+extern crate rokoko;
+extern crate winit;
+
+use rokoko::window::{
+    Window, Windows,
+    windows::{OpenAll, merge_control_flows}
+};
+use winit::event_loop::ControlFlow;
+use std::time::{Duration, Instant};
+
+// Pushing is available in `const` contexts, just as the setters are(not with `dyn-callbacks`, see `validate_now`)
+#[cfg(not(feature = "dyn-callbacks"))]
+const _: () = {
+    let _ = Windows::new().push(Window::new().title("first")).push(Window::new().maximized());
+};
+
+fn opens <L: OpenAll> (_: &Windows <L>) {}
+
+#[test]
+fn builders_of_any_configuration() {
+    opens(&Windows::new());
+    opens(&Windows::new().push(Window::new()));
+
+    // Each with its own callbacks and data
+    let windows = Windows::new()
+        .push(Window::new()
+            .title("main")
+            .on_frame(|_, _| ())
+            .on_close(|w| {
+                println!("closing {}", w.config());
+                w.close()
+            }))
+        .push(Window::new()
+            .title("tool")
+            .size((200., 400.))
+            .data(0u32)
+            .on_key(|w, _, pressed| if pressed {
+                *w.user_data::<u32>().unwrap() += 1
+            }))
+        .push(Window::new());
+    opens(&windows);
+}
+
+#[test]
+fn control_flows() {
+    let soon = Instant::now();
+    let later = soon + Duration::from_millis(16);
+
+    // All closed
+    assert_eq!(merge_control_flows([]), ControlFlow::Exit);
+    assert_eq!(merge_control_flows([ControlFlow::Exit, ControlFlow::Exit]), ControlFlow::Exit);
+
+    // Closed windows do not affect the others
+    for flow in [ControlFlow::Poll, ControlFlow::Wait, ControlFlow::WaitUntil(soon)] {
+        assert_eq!(merge_control_flows([ControlFlow::Exit, flow]), flow);
+        assert_eq!(merge_control_flows([flow, ControlFlow::Exit]), flow);
+        assert_eq!(merge_control_flows([flow]), flow);
+    }
+
+    // The loop waits only as long as every window allows
+    assert_eq!(merge_control_flows([ControlFlow::Wait, ControlFlow::Wait]), ControlFlow::Wait);
+    assert_eq!(merge_control_flows([ControlFlow::Wait, ControlFlow::WaitUntil(later)]), ControlFlow::WaitUntil(later));
+    assert_eq!(merge_control_flows([ControlFlow::WaitUntil(later), ControlFlow::WaitUntil(soon)]), ControlFlow::WaitUntil(soon));
+    assert_eq!(merge_control_flows([ControlFlow::WaitUntil(soon), ControlFlow::Poll, ControlFlow::Wait]), ControlFlow::Poll);
+}